@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS users_search_trigram_idx;
+DROP INDEX IF EXISTS users_search_document_idx;
@@ -0,0 +1,9 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS users_search_document_idx
+    ON users
+    USING GIN (to_tsvector('simple', first_name || ' ' || last_name || ' ' || email));
+
+CREATE INDEX IF NOT EXISTS users_search_trigram_idx
+    ON users
+    USING GIN ((first_name || ' ' || last_name || ' ' || email) gin_trgm_ops);
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS audit_entries;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS sessions_revoked_at,
+    DROP COLUMN IF EXISTS password_reset_required,
+    DROP COLUMN IF EXISTS status;
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS email_domain_policy (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE
+        CONSTRAINT email_domain_policy_single_row CHECK (id),
+    mode VARCHAR(16) NOT NULL DEFAULT 'denylist'
+        CONSTRAINT email_domain_policy_mode_check CHECK (mode IN ('allowlist', 'denylist')),
+    block_disposable BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by UUID REFERENCES users (id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO email_domain_policy (id) VALUES (TRUE) ON CONFLICT (id) DO NOTHING;
+
+CREATE TABLE IF NOT EXISTS email_domain_rules (
+    domain VARCHAR(253) PRIMARY KEY,
+    list VARCHAR(8) NOT NULL
+        CONSTRAINT email_domain_rules_list_check CHECK (list IN ('allow', 'deny'))
+);
@@ -0,0 +1,29 @@
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- Accounts whose e-mails only differ by case or surrounding whitespace cannot be made unique
+-- automatically, so refuse to migrate until an operator has merged or renamed them.
+DO $$
+DECLARE
+    collisions TEXT;
+BEGIN
+    SELECT string_agg(colliding_emails, '; ')
+    INTO collisions
+    FROM (
+        SELECT string_agg(email, ', ' ORDER BY created_at) AS colliding_emails
+        FROM users
+        GROUP BY LOWER(TRIM(email))
+        HAVING COUNT(*) > 1
+    ) AS duplicates;
+
+    IF collisions IS NOT NULL THEN
+        RAISE EXCEPTION 'e-mails that only differ by case must be resolved before migrating: %', collisions;
+    END IF;
+END
+$$;
+
+UPDATE users
+SET email = split_part(TRIM(email), '@', 1) || '@' || LOWER(split_part(TRIM(email), '@', 2))
+WHERE email <> split_part(TRIM(email), '@', 1) || '@' || LOWER(split_part(TRIM(email), '@', 2));
+
+-- The existing unique constraint now compares e-mails case-insensitively.
+ALTER TABLE users ALTER COLUMN email TYPE CITEXT;
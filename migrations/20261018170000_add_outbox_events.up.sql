@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS outbox_events (
+    id UUID PRIMARY KEY,
+    event_type VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'pending'
+        CONSTRAINT outbox_events_status_check
+            CHECK (status IN ('pending', 'delivered', 'dead_lettered')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    next_attempt_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS outbox_events_pending_idx
+    ON outbox_events (next_attempt_at)
+    WHERE status = 'pending';
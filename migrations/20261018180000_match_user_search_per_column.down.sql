@@ -0,0 +1,9 @@
+DROP FUNCTION IF EXISTS html_escape(TEXT);
+
+DROP INDEX IF EXISTS users_email_trigram_idx;
+DROP INDEX IF EXISTS users_last_name_trigram_idx;
+DROP INDEX IF EXISTS users_first_name_trigram_idx;
+
+CREATE INDEX IF NOT EXISTS users_search_trigram_idx
+    ON users
+    USING GIN ((first_name || ' ' || last_name || ' ' || email) gin_trgm_ops);
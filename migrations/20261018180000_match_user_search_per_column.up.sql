@@ -0,0 +1,11 @@
+DROP INDEX IF EXISTS users_search_trigram_idx;
+
+CREATE INDEX IF NOT EXISTS users_first_name_trigram_idx ON users USING GIN (first_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS users_last_name_trigram_idx ON users USING GIN (last_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS users_email_trigram_idx ON users USING GIN (email gin_trgm_ops);
+
+CREATE OR REPLACE FUNCTION html_escape(value TEXT) RETURNS TEXT
+    LANGUAGE SQL
+    IMMUTABLE
+    STRICT
+    RETURN replace(replace(replace(replace(replace(value, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '"', '&quot;'), '''', '&#39;');
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLogLevelDto {
+    pub directives: String,
+    /// Restores the previous directives after this many seconds; the change is permanent when
+    /// absent.
+    pub revert_after_seconds: Option<u64>,
+}
+
+impl ChangeLogLevelDto {
+    #[must_use]
+    pub const fn new(directives: String, revert_after_seconds: Option<u64>) -> Self {
+        Self {
+            directives,
+            revert_after_seconds,
+        }
+    }
+}
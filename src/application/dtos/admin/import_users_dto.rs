@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportUserRowDto {
+    pub line: usize,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub password: Option<String>,
+    pub password_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportUserIssueDto {
+    pub field: String,
+    pub error_code: String,
+    pub error_message: String,
+}
+
+impl ImportUserIssueDto {
+    #[must_use]
+    pub fn new(field: &str, error_code: &str, error_message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            error_code: error_code.to_string(),
+            error_message: error_message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportUserRowErrorDto {
+    pub line: usize,
+    pub email: Option<String>,
+    pub errors: Vec<ImportUserIssueDto>,
+}
+
+impl ImportUserRowErrorDto {
+    #[must_use]
+    pub const fn new(line: usize, email: Option<String>, errors: Vec<ImportUserIssueDto>) -> Self {
+        Self {
+            line,
+            email,
+            errors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportUsersDto {
+    pub rows: Vec<ImportUserRowDto>,
+    /// Rows that could not even be parsed; they are reported back untouched.
+    pub rejected_rows: Vec<ImportUserRowErrorDto>,
+    pub dry_run: bool,
+}
+
+impl ImportUsersDto {
+    #[must_use]
+    pub const fn new(
+        rows: Vec<ImportUserRowDto>,
+        rejected_rows: Vec<ImportUserRowErrorDto>,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            rows,
+            rejected_rows,
+            dry_run,
+        }
+    }
+
+    #[must_use]
+    pub const fn total_rows(&self) -> usize {
+        self.rows.len() + self.rejected_rows.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportUsersReportDto {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub imported_rows: usize,
+    pub failed_rows: usize,
+    pub errors: Vec<ImportUserRowErrorDto>,
+}
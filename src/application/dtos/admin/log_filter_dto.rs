@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilterDto {
+    pub directives: String,
+    pub previous_directives: String,
+    /// Directives restored once the revert delay elapses, when the change is temporary.
+    pub revert_to: Option<String>,
+    pub revert_after_seconds: Option<u64>,
+}
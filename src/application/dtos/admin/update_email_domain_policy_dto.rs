@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailDomainPolicyDto {
+    /// Either `allowlist` or `denylist`.
+    pub mode: String,
+    pub block_disposable: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+}
+
+impl UpdateEmailDomainPolicyDto {
+    #[must_use]
+    pub const fn new(
+        mode: String,
+        block_disposable: bool,
+        allowed_domains: Vec<String>,
+        denied_domains: Vec<String>,
+    ) -> Self {
+        Self {
+            mode,
+            block_disposable,
+            allowed_domains,
+            denied_domains,
+        }
+    }
+}
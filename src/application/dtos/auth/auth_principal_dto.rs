@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthPrincipalDto {
+    pub user_id: String,
+    pub is_admin: bool,
+}
+
+impl AuthPrincipalDto {
+    #[must_use]
+    pub const fn new(user_id: String, is_admin: bool) -> Self {
+        Self { user_id, is_admin }
+    }
+}
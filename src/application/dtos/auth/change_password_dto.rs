@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePasswordDto {
+    pub email: String,
+    pub password: String,
+    pub new_password: String,
+    pub new_password_confirmation: String,
+}
+
+impl ChangePasswordDto {
+    #[must_use]
+    pub const fn new(
+        email: String,
+        password: String,
+        new_password: String,
+        new_password_confirmation: String,
+    ) -> Self {
+        Self {
+            email,
+            password,
+            new_password,
+            new_password_confirmation,
+        }
+    }
+}
+
+impl std::fmt::Debug for ChangePasswordDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangePasswordDto")
+            .field("email", &"[redacted]")
+            .field("password", &"[redacted]")
+            .field("new_password", &"[redacted]")
+            .field("new_password_confirmation", &"[redacted]")
+            .finish()
+    }
+}
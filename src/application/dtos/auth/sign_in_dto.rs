@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SignInDto {
     pub email: String,
     pub password: String,
@@ -12,3 +13,12 @@ impl SignInDto {
         Self { email, password }
     }
 }
+
+impl std::fmt::Debug for SignInDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignInDto")
+            .field("email", &"[redacted]")
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
@@ -1,7 +1,10 @@
 use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SignUpDto {
+    /// Assigned by the server, never read from the request.
+    #[serde(skip)]
     pub id: Option<String>,
     pub first_name: String,
     pub last_name: String,
@@ -29,3 +32,16 @@ impl SignUpDto {
         }
     }
 }
+
+impl std::fmt::Debug for SignUpDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignUpDto")
+            .field("id", &self.id)
+            .field("first_name", &self.first_name)
+            .field("last_name", &self.last_name)
+            .field("email", &"[redacted]")
+            .field("password", &"[redacted]")
+            .field("password_confirmation", &"[redacted]")
+            .finish()
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataExportArchiveDto {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl DataExportArchiveDto {
+    #[must_use]
+    pub fn new(user_id: &str, file_extension: &str, content_type: &str, content: Vec<u8>) -> Self {
+        Self {
+            file_name: format!("user-data-{user_id}.{file_extension}"),
+            content_type: content_type.to_string(),
+            content,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::entities::{
+    audit::audit_entry_entity::AuditEntryEntity, session::session_entity::SessionEntity,
+    user::user_entity::UserEntity,
+};
+
+/// Everything stored about a single user, as handed over in a subject access request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDataExportDto {
+    pub user: UserEntity,
+    pub sessions: Vec<SessionEntity>,
+    pub audit_entries: Vec<AuditEntryEntity>,
+}
+
+impl UserDataExportDto {
+    #[must_use]
+    pub const fn new(
+        user: UserEntity,
+        sessions: Vec<SessionEntity>,
+        audit_entries: Vec<AuditEntryEntity>,
+    ) -> Self {
+        Self {
+            user,
+            sessions,
+            audit_entries,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvatarDto {
+    pub content: Vec<u8>,
+    pub content_type: String,
+    /// Strong validator that changes whenever a new avatar is uploaded.
+    pub etag: String,
+}
+
+impl AvatarDto {
+    #[must_use]
+    pub const fn new(content: Vec<u8>, content_type: String, etag: String) -> Self {
+        Self {
+            content,
+            content_type,
+            etag,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::application::dtos::data_export::user_data_export_dto::UserDataExportDto;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArchiveError {
+    SerializationError { message: String },
+    CompressionError { message: String },
+}
+
+impl std::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SerializationError { message } => {
+                write!(
+                    f,
+                    "an error occurred while serializing archive data: {message}"
+                )
+            }
+            Self::CompressionError { message } => {
+                write!(f, "an error occurred while compressing archive: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+pub trait ArchivePort: Send + Sync {
+    /// Returns the MIME type of the archives produced by this adapter.
+    fn content_type(&self) -> &'static str;
+
+    /// Returns the file extension, without the leading dot, of the produced archives.
+    fn file_extension(&self) -> &'static str;
+
+    /// Packs the user's data into a single downloadable archive holding JSON and CSV files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArchiveError` if the data cannot be serialized or compressed.
+    fn build_user_data_archive(
+        &self,
+        user_data_export_dto: &UserDataExportDto,
+    ) -> Result<Vec<u8>, ArchiveError>;
+}
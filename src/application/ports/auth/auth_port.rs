@@ -1,8 +1,11 @@
+use crate::domain::value_objects::shared::timestamp::Timestamp;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum AuthError {
     GenerateTokenError { message: String },
     InvalidTokenError,
     ExpiredTokenError,
+    RevokedTokenError,
     UnexpectedError,
 }
 
@@ -21,6 +24,9 @@ impl std::fmt::Display for AuthError {
             Self::ExpiredTokenError => {
                 write!(f, "the provided authorization token has expired")
             }
+            Self::RevokedTokenError => {
+                write!(f, "the provided authorization token has been revoked")
+            }
             Self::UnexpectedError => {
                 write!(f, "an unexpected error has occurred")
             }
@@ -30,18 +36,34 @@ impl std::fmt::Display for AuthError {
 
 impl std::error::Error for AuthError {}
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthClaims {
+    pub user_id: String,
+    /// Sub-second precise, so a token issued right after a revocation is told apart from the
+    /// tokens it revoked.
+    pub issued_at: Timestamp,
+}
+
+/// A freshly issued token together with the metadata recorded for the session it opens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthToken {
+    pub value: String,
+    pub issued_at: i64,
+    pub expires_at: i64,
+}
+
 pub trait AuthPort: Send + Sync {
     /// Generates an authentication token for the given user ID.
     ///
     /// # Errors
     ///
     /// Returns `AuthError` if the token generation fails for any reason.
-    fn generate_auth_token(&self, user_id: &str) -> Result<String, AuthError>;
+    fn generate_auth_token(&self, user_id: &str) -> Result<AuthToken, AuthError>;
 
-    /// Verifies an authentication token.
+    /// Verifies an authentication token and returns the claims it carries.
     ///
     /// # Errors
     ///
     /// Returns `AuthError` if the token is invalid, expired, or cannot be verified.
-    fn verify_auth_token(&self, token: &str) -> Result<(), AuthError>;
+    fn verify_auth_token(&self, token: &str) -> Result<AuthClaims, AuthError>;
 }
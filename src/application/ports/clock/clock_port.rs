@@ -0,0 +1,5 @@
+use crate::domain::value_objects::shared::timestamp::Timestamp;
+
+pub trait ClockPort: Send + Sync {
+    fn now(&self) -> Timestamp;
+}
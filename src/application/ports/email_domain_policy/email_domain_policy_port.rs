@@ -0,0 +1,62 @@
+use std::pin::Pin;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EmailDomainPolicyError {
+    DomainNotAllowed { domain: String },
+    DomainDenied { domain: String },
+    DisposableDomain { domain: String },
+    LoadError { message: String },
+}
+
+impl EmailDomainPolicyError {
+    /// Why the domain was refused, or `None` when the policy itself could not be read.
+    #[must_use]
+    pub const fn reason(&self) -> Option<&'static str> {
+        match self {
+            Self::DomainNotAllowed { .. } => Some("not_allowlisted"),
+            Self::DomainDenied { .. } => Some("denylisted"),
+            Self::DisposableDomain { .. } => Some("disposable"),
+            Self::LoadError { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for EmailDomainPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DomainNotAllowed { domain } => {
+                write!(f, "the e-mail domain '{domain}' is not on the allowlist")
+            }
+            Self::DomainDenied { domain } => {
+                write!(f, "the e-mail domain '{domain}' is on the denylist")
+            }
+            Self::DisposableDomain { domain } => {
+                write!(
+                    f,
+                    "the e-mail domain '{domain}' belongs to a disposable e-mail provider"
+                )
+            }
+            Self::LoadError { message } => {
+                write!(f, "failed to load the e-mail domain policy: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EmailDomainPolicyError {}
+
+pub type EmailDomainPolicyFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), EmailDomainPolicyError>> + Send + 'a>>;
+
+pub trait EmailDomainPolicyPort: Send + Sync {
+    /// Checks whether an account may be registered with the provided e-mail.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmailDomainPolicyError` naming the rule that refused the domain, or
+    /// `LoadError` if the policy could not be read.
+    fn check(&self, email: &str) -> EmailDomainPolicyFuture<'_>;
+
+    /// Drops any cached copy of the policy so the next check reads the stored one.
+    fn invalidate(&self);
+}
@@ -0,0 +1,4 @@
+pub trait EmailNormalizerPort: Send + Sync {
+    /// Returns the canonical form of the provided e-mail, under which it is stored and looked up.
+    fn normalize(&self, email: &str) -> String;
+}
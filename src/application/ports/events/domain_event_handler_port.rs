@@ -0,0 +1,34 @@
+use std::pin::Pin;
+
+use crate::domain::events::domain_event::DomainEvent;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEventHandlerError {
+    HandlingError { message: String },
+}
+
+impl std::fmt::Display for DomainEventHandlerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HandlingError { message } => {
+                write!(f, "handling error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DomainEventHandlerError {}
+
+pub type DomainEventHandlerFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), DomainEventHandlerError>> + Send + 'a>>;
+
+/// Reacts to domain events delivered from the outbox.
+///
+/// Delivery is at least once: an event is handed over again whenever any handler fails or the
+/// dispatcher stops before recording the outcome, so handlers must be idempotent.
+pub trait DomainEventHandlerPort: Send + Sync {
+    /// Identifies the handler in logs and in the error recorded on failed deliveries.
+    fn name(&self) -> &'static str;
+
+    fn handle(&self, domain_event: DomainEvent) -> DomainEventHandlerFuture<'_>;
+}
@@ -0,0 +1,39 @@
+use std::pin::Pin;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileStorageError {
+    InvalidKey { key: String },
+    WriteError { message: String },
+    ReadError { message: String },
+    DeleteError { message: String },
+}
+
+impl std::fmt::Display for FileStorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidKey { key } => write!(f, "invalid storage key: {key}"),
+            Self::WriteError { message } => write!(f, "write error: {message}"),
+            Self::ReadError { message } => write!(f, "read error: {message}"),
+            Self::DeleteError { message } => write!(f, "delete error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FileStorageError {}
+
+pub type PutFileFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), FileStorageError>> + Send + 'a>>;
+
+pub type GetFileFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, FileStorageError>> + Send + 'a>>;
+
+pub type DeleteFileFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), FileStorageError>> + Send + 'a>>;
+
+/// Stores opaque files under slash-separated keys such as `avatars/{user_id}/{version}/64`.
+pub trait FileStoragePort: Send + Sync {
+    fn put(&self, key: String, content: Vec<u8>) -> PutFileFuture<'_>;
+    fn get(&self, key: String) -> GetFileFuture<'_>;
+    /// Deletes the file stored under `key`; deleting a missing file is not an error.
+    fn delete(&self, key: String) -> DeleteFileFuture<'_>;
+}
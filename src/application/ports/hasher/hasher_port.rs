@@ -1,3 +1,5 @@
+use crate::domain::value_objects::user::password_hash::PasswordHash;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum HasherError {
     HashingError { message: String },
@@ -25,7 +27,7 @@ pub trait HasherPort: Send + Sync {
     /// # Errors
     ///
     /// Returns a `HasherError` if hashing fails for any reason.
-    fn hash(&self, password: &str) -> Result<String, HasherError>;
+    fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
     /// Verifies a password against a hashed password.
     ///
     /// # Errors
@@ -0,0 +1,59 @@
+use std::{collections::BTreeMap, pin::Pin};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+impl HealthStatus {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Down => "down",
+        }
+    }
+}
+
+/// Numeric facts about a dependency worth exposing, e.g. connection pool usage. Keyed by name so
+/// they serialize in a stable order.
+pub type HealthCheckMetrics = BTreeMap<&'static str, u64>;
+
+/// Outcome of probing a single dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthCheckResult {
+    pub status: HealthStatus,
+    pub metrics: HealthCheckMetrics,
+    pub error: Option<String>,
+}
+
+impl HealthCheckResult {
+    #[must_use]
+    pub const fn up(metrics: HealthCheckMetrics) -> Self {
+        Self {
+            status: HealthStatus::Up,
+            metrics,
+            error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn down(error: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Down,
+            metrics: HealthCheckMetrics::new(),
+            error: Some(error.into()),
+        }
+    }
+}
+
+pub type HealthCheckFuture<'a> = Pin<Box<dyn Future<Output = HealthCheckResult> + Send + 'a>>;
+
+pub trait HealthCheckPort: Send + Sync {
+    /// Identifies the dependency in the readiness report.
+    fn name(&self) -> &'static str;
+
+    /// Probes the dependency; failures are reported as `HealthStatus::Down`, never as errors.
+    fn check(&self) -> HealthCheckFuture<'_>;
+}
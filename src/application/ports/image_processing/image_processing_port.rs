@@ -0,0 +1,41 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImageProcessingError {
+    DecodeError { message: String },
+    EncodeError { message: String },
+}
+
+impl std::fmt::Display for ImageProcessingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DecodeError { message } => {
+                write!(f, "the image could not be decoded: {message}")
+            }
+            Self::EncodeError { message } => {
+                write!(f, "the image could not be encoded: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageProcessingError {}
+
+pub trait ImageProcessingPort: Send + Sync {
+    /// Detects the MIME type of an image from its leading bytes, ignoring any client-provided
+    /// content type. Returns `None` for unrecognised formats.
+    fn sniff_content_type(&self, content: &[u8]) -> Option<&'static str>;
+
+    /// Returns the MIME type of the thumbnails produced by `thumbnails`.
+    fn thumbnail_content_type(&self) -> &'static str;
+
+    /// Crops the image to a square and resizes it to each of the given edge lengths, returning
+    /// the encoded thumbnails in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImageProcessingError` if the image cannot be decoded or a thumbnail encoded.
+    fn thumbnails(
+        &self,
+        content: &[u8],
+        sizes: &[u32],
+    ) -> Result<Vec<Vec<u8>>, ImageProcessingError>;
+}
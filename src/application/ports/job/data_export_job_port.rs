@@ -0,0 +1,4 @@
+pub trait DataExportJobPort: Send + Sync {
+    /// Schedules the generation of a pending data export without waiting for it to finish.
+    fn enqueue(&self, data_export_id: String);
+}
@@ -0,0 +1,12 @@
+/// Translates user-facing messages. English is the source language: messages are written in
+/// English where they are produced, and catalogs provide the other languages.
+pub trait LocalizationPort: Send + Sync {
+    /// Picks the supported locale that best matches an `Accept-Language` header value, using the
+    /// default locale when the header is absent or nothing matches.
+    fn negotiate_locale(&self, accept_language: Option<&str>) -> String;
+
+    /// Returns the message stored under `key` for `locale`, with `{name}` placeholders replaced
+    /// by `args`. Returns `None` when the message is not translated or a placeholder has no
+    /// value, so the caller keeps the English message.
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String>;
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use crate::application::dtos::admin::log_filter_dto::LogFilterDto;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LogFilterError {
+    InvalidDirectives { message: String },
+    ReloadError { message: String },
+}
+
+impl std::fmt::Display for LogFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDirectives { message } => {
+                write!(f, "invalid log filter directives: {message}")
+            }
+            Self::ReloadError { message } => write!(f, "log filter reload error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LogFilterError {}
+
+/// Changes which spans and events are logged while the process is running, using `tracing`
+/// filter directives such as `info,rust_tdd_rest_api::infrastructure=trace`.
+pub trait LogFilterPort: Send + Sync {
+    /// Replaces the active directives. When `revert_after` is given, the directives that were
+    /// active before the first temporary change are restored once it elapses, unless another
+    /// change is made in the meantime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LogFilterError::InvalidDirectives` if `directives` cannot be parsed, leaving the
+    /// active filter untouched.
+    fn replace_directives(
+        &self,
+        directives: &str,
+        revert_after: Option<Duration>,
+    ) -> Result<LogFilterDto, LogFilterError>;
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MetricsError {
+    RegistrationError { message: String },
+    ExportError { message: String },
+}
+
+impl std::fmt::Display for MetricsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RegistrationError { message } => {
+                write!(f, "an error occurred while registering metrics: {message}")
+            }
+            Self::ExportError { message } => {
+                write!(f, "an error occurred while exporting metrics: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MetricsError {}
+
+/// Records what the application does so it can be scraped by a monitoring system.
+///
+/// Labels such as `outcome` or `reason` are short `snake_case` identifiers and must come from a
+/// fixed set, never from user input, to keep the number of series bounded.
+pub trait MetricsPort: Send + Sync {
+    /// Records a served HTTP request; `route` is the matched route template, not the raw path.
+    fn observe_http_request(&self, method: &str, route: &str, status_code: u16, duration: Duration);
+
+    /// Records a sign-in attempt; `outcome` is `success` or the reason it failed.
+    fn record_sign_in(&self, outcome: &str);
+
+    /// Records a sign-up attempt; `outcome` is `success` or the reason it failed.
+    fn record_sign_up(&self, outcome: &str);
+
+    /// Records how long hashing or verifying a password took.
+    fn observe_password_hashing(&self, operation: &str, duration: Duration);
+
+    /// Records a rejected authentication token, labelled by why it was rejected.
+    fn record_token_verification_failure(&self, reason: &str);
+
+    /// Records a request turned away by the rate limiter.
+    fn record_rate_limit_rejection(&self);
+
+    /// Renders every metric in the Prometheus text exposition format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MetricsError` if the metrics cannot be encoded.
+    fn export(&self) -> Result<String, MetricsError>;
+}
@@ -0,0 +1,158 @@
+use std::{pin::Pin, str::FromStr};
+
+/// A kind of character a password policy may require at least one of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClass {
+    Lowercase,
+    Uppercase,
+    Digit,
+    /// Anything that is neither a letter nor a digit, including spaces.
+    Symbol,
+}
+
+impl CharacterClass {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Lowercase => "lowercase",
+            Self::Uppercase => "uppercase",
+            Self::Digit => "digit",
+            Self::Symbol => "symbol",
+        }
+    }
+
+    /// Checks `character` against the class, counting non-ASCII letters and digits as well.
+    #[must_use]
+    pub fn contains(self, character: char) -> bool {
+        match self {
+            Self::Lowercase => character.is_lowercase(),
+            Self::Uppercase => character.is_uppercase(),
+            Self::Digit => character.is_numeric(),
+            Self::Symbol => !character.is_alphanumeric(),
+        }
+    }
+}
+
+impl FromStr for CharacterClass {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "lowercase" => Ok(Self::Lowercase),
+            "uppercase" => Ok(Self::Uppercase),
+            "digit" => Ok(Self::Digit),
+            "symbol" => Ok(Self::Symbol),
+            _ => Err(format!("'{value}' is not a character class")),
+        }
+    }
+}
+
+/// A password policy rule a password broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordPolicyViolation {
+    TooShort {
+        min_length: usize,
+    },
+    TooLong {
+        max_length: usize,
+    },
+    /// Short enough in characters, but too long once encoded, e.g. because of many accents or
+    /// emoji; the hasher cannot store more bytes than this.
+    TooManyBytes {
+        max_bytes: usize,
+    },
+    MissingCharacterClass {
+        character_class: CharacterClass,
+    },
+    /// The estimated strength, from 0 (guessable in a few attempts) to 4, is below the minimum.
+    TooWeak {
+        score: u8,
+        min_score: u8,
+    },
+    /// The password contains the user's e-mail or one of their names.
+    ContainsPersonalInfo,
+    /// The password appears in a list of passwords exposed by data breaches.
+    Breached,
+}
+
+impl PasswordPolicyViolation {
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "password_too_short",
+            Self::TooLong { .. } => "password_too_long",
+            Self::TooManyBytes { .. } => "password_too_many_bytes",
+            Self::MissingCharacterClass { .. } => "password_missing_character_class",
+            Self::TooWeak { .. } => "password_too_weak",
+            Self::ContainsPersonalInfo => "password_contains_personal_info",
+            Self::Breached => "password_breached",
+        }
+    }
+}
+
+impl std::fmt::Display for PasswordPolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort { min_length } => {
+                write!(f, "the password must have at least {min_length} characters")
+            }
+            Self::TooLong { max_length } => {
+                write!(f, "the password must have at most {max_length} characters")
+            }
+            Self::TooManyBytes { max_bytes } => write!(
+                f,
+                "the password must take at most {max_bytes} bytes, where accented letters and symbols take several"
+            ),
+            Self::MissingCharacterClass { character_class } => write!(
+                f,
+                "the password must contain at least one {} character",
+                character_class.name()
+            ),
+            Self::TooWeak { score, min_score } => write!(
+                f,
+                "the password is too easy to guess (strength {score} of 4, at least {min_score} required)"
+            ),
+            Self::ContainsPersonalInfo => {
+                write!(f, "the password must not contain your e-mail or name")
+            }
+            Self::Breached => write!(
+                f,
+                "the password has appeared in a data breach and must not be used"
+            ),
+        }
+    }
+}
+
+/// Every rule a password broke, so the user can fix them all at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicyError {
+    pub violations: Vec<PasswordPolicyViolation>,
+}
+
+impl std::fmt::Display for PasswordPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let violations = self
+            .violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", violations.join("; "))
+    }
+}
+
+impl std::error::Error for PasswordPolicyError {}
+
+pub type PasswordPolicyFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), PasswordPolicyError>> + Send + 'a>>;
+
+pub trait PasswordPolicyPort: Send + Sync {
+    /// Checks `password` against every rule of the policy. `personal_info` holds values such as
+    /// the user's e-mail and names, which the password must not contain. Asynchronous because a
+    /// rule may need to look the password up in a list on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PasswordPolicyError` listing every rule the password breaks.
+    fn validate(&self, password: &str, personal_info: &[&str]) -> PasswordPolicyFuture<'_>;
+}
@@ -1,9 +1,8 @@
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum PatternMatchingError {
     InvalidRegex,
     InvalidEmail,
-    InvalidEmailDomain,
-    InvalidPassword,
+    InvalidPasswordHash,
     InvalidUuid,
 }
 
@@ -16,14 +15,8 @@ impl std::fmt::Display for PatternMatchingError {
             Self::InvalidEmail => {
                 write!(f, "the provided e-mail is invalid")
             }
-            Self::InvalidEmailDomain => {
-                write!(f, "the provided e-mail's domain is blacklisted")
-            }
-            Self::InvalidPassword => {
-                write!(
-                    f,
-                    "the provided password should contain at least 12 characters"
-                )
+            Self::InvalidPasswordHash => {
+                write!(f, "the provided password hash is not a valid bcrypt hash")
             }
             Self::InvalidUuid => {
                 write!(f, "the provided uuid is invalid")
@@ -42,19 +35,12 @@ pub trait PatternMatchingPort: Send + Sync {
     /// Returns `PatternMatchingError` if the email cannot be processed by the validation regex.
     fn is_valid_email(&self, email: &str) -> Result<bool, PatternMatchingError>;
 
-    /// Checks if the domain part of the provided email is valid.
-    ///
-    /// # Errors
-    ///
-    /// Returns `PatternMatchingError` if the domain cannot be processed by the validation regex.
-    fn is_valid_email_domain(&self, email: &str) -> Result<bool, PatternMatchingError>;
-
-    /// Checks if the provided password meets validation requirements.
+    /// Checks if the provided value is a well-formed bcrypt password hash.
     ///
     /// # Errors
     ///
-    /// Returns `PatternMatchingError` if the password cannot be processed by the validation regex.
-    fn is_valid_password(&self, password: &str) -> Result<bool, PatternMatchingError>;
+    /// Returns `PatternMatchingError` if the hash cannot be processed by the validation regex.
+    fn is_valid_password_hash(&self, password_hash: &str) -> Result<bool, PatternMatchingError>;
 
     /// Checks if the provided UUID meets validation requirements.
     ///
@@ -0,0 +1,29 @@
+use std::pin::Pin;
+
+use crate::domain::entities::data_export::data_export_entity::DataExportEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CreateDataExportRepositoryError {
+    InsertError { message: String },
+}
+
+impl std::fmt::Display for CreateDataExportRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsertError { message } => {
+                write!(f, "insert error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CreateDataExportRepositoryError {}
+
+pub type CreateDataExportRepositoryFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<DataExportEntity, CreateDataExportRepositoryError>> + Send + 'a>,
+>;
+
+pub trait CreateDataExportRepositoryPort: Send + Sync {
+    fn execute(&self, data_export_entity: DataExportEntity)
+    -> CreateDataExportRepositoryFuture<'_>;
+}
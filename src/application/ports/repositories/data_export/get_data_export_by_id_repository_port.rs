@@ -0,0 +1,32 @@
+use std::pin::Pin;
+
+use crate::domain::entities::data_export::data_export_entity::DataExportEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetDataExportByIdRepositoryError {
+    FindByIdError { message: String },
+}
+
+impl std::fmt::Display for GetDataExportByIdRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FindByIdError { message } => {
+                write!(f, "find by id error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GetDataExportByIdRepositoryError {}
+
+pub type GetDataExportByIdRepositoryFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<Option<DataExportEntity>, GetDataExportByIdRepositoryError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait GetDataExportByIdRepositoryPort: Send + Sync {
+    fn execute(&self, id: String) -> GetDataExportByIdRepositoryFuture<'_>;
+}
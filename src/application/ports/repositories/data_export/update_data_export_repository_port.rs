@@ -0,0 +1,33 @@
+use std::pin::Pin;
+
+use crate::domain::entities::data_export::data_export_entity::DataExportEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateDataExportRepositoryError {
+    UpdateError { message: String },
+    DataExportNotFound,
+}
+
+impl std::fmt::Display for UpdateDataExportRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpdateError { message } => {
+                write!(f, "update error: {message}")
+            }
+            Self::DataExportNotFound => {
+                write!(f, "no data export with the provided id was found")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateDataExportRepositoryError {}
+
+pub type UpdateDataExportRepositoryFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<DataExportEntity, UpdateDataExportRepositoryError>> + Send + 'a>,
+>;
+
+pub trait UpdateDataExportRepositoryPort: Send + Sync {
+    fn execute(&self, data_export_entity: DataExportEntity)
+    -> UpdateDataExportRepositoryFuture<'_>;
+}
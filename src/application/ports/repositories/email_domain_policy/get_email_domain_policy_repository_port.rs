@@ -0,0 +1,32 @@
+use std::pin::Pin;
+
+use crate::domain::entities::email_domain_policy::email_domain_policy_entity::EmailDomainPolicyEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetEmailDomainPolicyRepositoryError {
+    FindError { message: String },
+}
+
+impl std::fmt::Display for GetEmailDomainPolicyRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FindError { message } => {
+                write!(f, "find error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GetEmailDomainPolicyRepositoryError {}
+
+pub type GetEmailDomainPolicyRepositoryFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<EmailDomainPolicyEntity, GetEmailDomainPolicyRepositoryError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait GetEmailDomainPolicyRepositoryPort: Send + Sync {
+    fn execute(&self) -> GetEmailDomainPolicyRepositoryFuture<'_>;
+}
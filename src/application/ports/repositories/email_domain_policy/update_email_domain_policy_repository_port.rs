@@ -0,0 +1,36 @@
+use std::pin::Pin;
+
+use crate::domain::entities::email_domain_policy::email_domain_policy_entity::EmailDomainPolicyEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateEmailDomainPolicyRepositoryError {
+    UpdateError { message: String },
+}
+
+impl std::fmt::Display for UpdateEmailDomainPolicyRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpdateError { message } => {
+                write!(f, "update error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateEmailDomainPolicyRepositoryError {}
+
+pub type UpdateEmailDomainPolicyRepositoryFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<EmailDomainPolicyEntity, UpdateEmailDomainPolicyRepositoryError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait UpdateEmailDomainPolicyRepositoryPort: Send + Sync {
+    /// Replaces the stored policy, mode and both domain lists, in a single transaction.
+    fn execute(
+        &self,
+        email_domain_policy_entity: EmailDomainPolicyEntity,
+    ) -> UpdateEmailDomainPolicyRepositoryFuture<'_>;
+}
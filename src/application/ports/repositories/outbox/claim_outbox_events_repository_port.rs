@@ -0,0 +1,40 @@
+use std::pin::Pin;
+
+use crate::domain::entities::outbox::outbox_event_entity::OutboxEventEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ClaimOutboxEventsRepositoryError {
+    ClaimError { message: String },
+}
+
+impl std::fmt::Display for ClaimOutboxEventsRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ClaimError { message } => {
+                write!(f, "claim error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClaimOutboxEventsRepositoryError {}
+
+pub type ClaimOutboxEventsRepositoryFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<Vec<OutboxEventEntity>, ClaimOutboxEventsRepositoryError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait ClaimOutboxEventsRepositoryPort: Send + Sync {
+    /// Takes up to `limit` pending events due at `now`, oldest first, and postpones them to
+    /// `lease_until` so that no other dispatcher picks them up meanwhile. Events a crashed
+    /// dispatcher never settled become due again once their lease runs out.
+    fn execute(
+        &self,
+        now: i64,
+        lease_until: i64,
+        limit: u32,
+    ) -> ClaimOutboxEventsRepositoryFuture<'_>;
+}
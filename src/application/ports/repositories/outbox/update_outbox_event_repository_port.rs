@@ -0,0 +1,36 @@
+use std::pin::Pin;
+
+use crate::domain::entities::outbox::outbox_event_entity::OutboxEventEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateOutboxEventRepositoryError {
+    UpdateError { message: String },
+    OutboxEventNotFound,
+}
+
+impl std::fmt::Display for UpdateOutboxEventRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpdateError { message } => {
+                write!(f, "update error: {message}")
+            }
+            Self::OutboxEventNotFound => {
+                write!(f, "no outbox event with the provided id was found")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateOutboxEventRepositoryError {}
+
+pub type UpdateOutboxEventRepositoryFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), UpdateOutboxEventRepositoryError>> + Send + 'a>>;
+
+pub trait UpdateOutboxEventRepositoryPort: Send + Sync {
+    /// Persists the delivery state of `outbox_event_entity`: its status, attempts, last error and
+    /// next attempt.
+    fn execute(
+        &self,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> UpdateOutboxEventRepositoryFuture<'_>;
+}
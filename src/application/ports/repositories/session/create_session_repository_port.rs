@@ -0,0 +1,34 @@
+use std::pin::Pin;
+
+use crate::domain::entities::{
+    outbox::outbox_event_entity::OutboxEventEntity, session::session_entity::SessionEntity,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CreateSessionRepositoryError {
+    InsertError { message: String },
+}
+
+impl std::fmt::Display for CreateSessionRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsertError { message } => {
+                write!(f, "insert error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CreateSessionRepositoryError {}
+
+pub type CreateSessionRepositoryFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<SessionEntity, CreateSessionRepositoryError>> + Send + 'a>>;
+
+pub trait CreateSessionRepositoryPort: Send + Sync {
+    /// Inserts the session together with the event announcing it, in a single transaction.
+    fn execute(
+        &self,
+        session_entity: SessionEntity,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> CreateSessionRepositoryFuture<'_>;
+}
@@ -1,15 +1,38 @@
 use std::pin::Pin;
 
-use crate::domain::entities::user::user_entity::UserEntity;
+use crate::domain::entities::{
+    outbox::outbox_event_entity::OutboxEventEntity, user::user_entity::UserEntity,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum CreateUserRepositoryError {
-    InsertError { message: String },
+    /// A unique constraint, such as the one on the e-mail, rejected the row.
+    UniqueViolation {
+        constraint: String,
+    },
+    ConnectionError {
+        message: String,
+    },
+    TimeoutError {
+        message: String,
+    },
+    InsertError {
+        message: String,
+    },
 }
 
 impl std::fmt::Display for CreateUserRepositoryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::UniqueViolation { constraint } => {
+                write!(f, "unique violation: {constraint}")
+            }
+            Self::ConnectionError { message } => {
+                write!(f, "connection error: {message}")
+            }
+            Self::TimeoutError { message } => {
+                write!(f, "timeout error: {message}")
+            }
             Self::InsertError { message } => {
                 write!(f, "insert error: {message}")
             }
@@ -22,6 +45,21 @@ impl std::error::Error for CreateUserRepositoryError {}
 pub type CreateUserRepositoryFuture<'a> =
     Pin<Box<dyn Future<Output = Result<UserEntity, CreateUserRepositoryError>> + Send + 'a>>;
 
+pub type CreateUsersRepositoryFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<UserEntity>, CreateUserRepositoryError>> + Send + 'a>>;
+
 pub trait CreateUserRepositoryPort: Send + Sync {
-    fn execute(&self, user_entity: UserEntity) -> CreateUserRepositoryFuture<'_>;
+    /// Inserts the user together with the event announcing it, in a single transaction.
+    fn execute(
+        &self,
+        user_entity: UserEntity,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> CreateUserRepositoryFuture<'_>;
+    /// Inserts every user and event inside a single transaction: either all of them are created
+    /// or none.
+    fn execute_batch(
+        &self,
+        user_entities: Vec<UserEntity>,
+        outbox_event_entities: Vec<OutboxEventEntity>,
+    ) -> CreateUsersRepositoryFuture<'_>;
 }
@@ -0,0 +1,37 @@
+use std::pin::Pin;
+
+use crate::application::dtos::data_export::user_data_export_dto::UserDataExportDto;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetUserDataRepositoryError {
+    FindError { message: String },
+}
+
+impl std::fmt::Display for GetUserDataRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FindError { message } => {
+                write!(f, "find error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GetUserDataRepositoryError {}
+
+pub type CountUserRecordsFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<u64>, GetUserDataRepositoryError>> + Send + 'a>>;
+
+pub type GetUserDataRepositoryFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<Option<UserDataExportDto>, GetUserDataRepositoryError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait GetUserDataRepositoryPort: Send + Sync {
+    /// Counts the records linked to the user, or returns `None` if the user does not exist.
+    fn count_records(&self, user_id: String) -> CountUserRecordsFuture<'_>;
+    fn execute(&self, user_id: String) -> GetUserDataRepositoryFuture<'_>;
+}
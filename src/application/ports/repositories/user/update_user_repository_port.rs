@@ -0,0 +1,42 @@
+use std::pin::Pin;
+
+use crate::domain::entities::{
+    audit::audit_entry_entity::AuditEntryEntity, outbox::outbox_event_entity::OutboxEventEntity,
+    user::user_entity::UserEntity,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateUserRepositoryError {
+    UpdateError { message: String },
+    UserNotFound,
+}
+
+impl std::fmt::Display for UpdateUserRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpdateError { message } => {
+                write!(f, "update error: {message}")
+            }
+            Self::UserNotFound => {
+                write!(f, "update error: the user to update does not exist")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateUserRepositoryError {}
+
+pub type UpdateUserRepositoryFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserEntity, UpdateUserRepositoryError>> + Send + 'a>>;
+
+pub trait UpdateUserRepositoryPort: Send + Sync {
+    /// Persists the mutable fields of `user_entity` together with the audit entry describing
+    /// the change, so that a user is never modified without leaving an audit trail, and the
+    /// events other parts of the application should react to.
+    fn execute(
+        &self,
+        user_entity: UserEntity,
+        audit_entry_entity: AuditEntryEntity,
+        outbox_event_entities: Vec<OutboxEventEntity>,
+    ) -> UpdateUserRepositoryFuture<'_>;
+}
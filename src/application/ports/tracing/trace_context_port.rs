@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use tracing::Span;
+
+/// Carries trace context across process boundaries in HTTP headers, following W3C Trace Context.
+pub trait TraceContextPort: Send + Sync {
+    /// Makes `span` a child of the remote span described by `headers`, such as a `traceparent`
+    /// header. Without valid trace headers `span` starts a new trace.
+    fn continue_trace(&self, span: &Span, headers: &HashMap<String, String>);
+
+    /// Returns the headers identifying `span` to the next hop, keyed by lowercase header name.
+    fn trace_headers(&self, span: &Span) -> HashMap<String, String>;
+}
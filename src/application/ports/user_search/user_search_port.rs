@@ -0,0 +1,78 @@
+use std::pin::Pin;
+
+use crate::domain::entities::user::user_entity::UserEntity;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UserSearchError {
+    SearchError { message: String },
+}
+
+impl std::fmt::Display for UserSearchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SearchError { message } => {
+                write!(f, "search error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UserSearchError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSearchQuery {
+    pub term: String,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl UserSearchQuery {
+    #[must_use]
+    pub const fn new(term: String, page: u32, per_page: u32) -> Self {
+        Self {
+            term,
+            page,
+            per_page,
+        }
+    }
+
+    #[must_use]
+    pub const fn offset(&self) -> u32 {
+        self.page.saturating_sub(1).saturating_mul(self.per_page)
+    }
+}
+
+/// HTML-escaped field values with the matched words wrapped in `<mark>`, safe to render as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSearchHighlights {
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSearchHit {
+    pub user: UserEntity,
+    pub rank: f32,
+    pub highlights: UserSearchHighlights,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSearchPage {
+    pub hits: Vec<UserSearchHit>,
+    pub total: u64,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+pub type UserSearchFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserSearchPage, UserSearchError>> + Send + 'a>>;
+
+pub trait UserSearchPort: Send + Sync {
+    /// Searches users by first name, last name and e-mail, ranking the best matches first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserSearchError` if the underlying search backend fails.
+    fn search(&self, query: UserSearchQuery) -> UserSearchFuture<'_>;
+}
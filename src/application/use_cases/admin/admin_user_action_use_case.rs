@@ -0,0 +1,499 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        clock::clock_port::ClockPort,
+        id_generator::id_generator_port::IdGeneratorPort,
+        repositories::user::{
+            get_user_by_id_repository_port::{
+                GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+            },
+            update_user_repository_port::{UpdateUserRepositoryError, UpdateUserRepositoryPort},
+        },
+    },
+    domain::{
+        entities::{
+            audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+            user::user_entity::{UserEntity, UserStatus},
+        },
+        errors::user::user_errors::UserError,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminUserAction {
+    Promote,
+    Demote,
+    Suspend,
+    Unsuspend,
+    ForcePasswordReset,
+    RevokeSessions,
+}
+
+impl AdminUserAction {
+    #[must_use]
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "promote" => Some(Self::Promote),
+            "demote" => Some(Self::Demote),
+            "suspend" => Some(Self::Suspend),
+            "unsuspend" => Some(Self::Unsuspend),
+            "force-password-reset" => Some(Self::ForcePasswordReset),
+            "revoke-sessions" => Some(Self::RevokeSessions),
+            _ => None,
+        }
+    }
+
+    const fn audit_action(self) -> AuditAction {
+        match self {
+            Self::Promote => AuditAction::UserPromoted,
+            Self::Demote => AuditAction::UserDemoted,
+            Self::Suspend => AuditAction::UserSuspended,
+            Self::Unsuspend => AuditAction::UserUnsuspended,
+            Self::ForcePasswordReset => AuditAction::PasswordResetForced,
+            Self::RevokeSessions => AuditAction::SessionsRevoked,
+        }
+    }
+
+    /// Actions an administrator may not perform on their own account, since they would lock
+    /// them out of the administration endpoints.
+    const fn is_self_locking(self) -> bool {
+        matches!(self, Self::Demote | Self::Suspend)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AdminUserActionUseCaseError {
+    UserError(UserError),
+    DatabaseError(GetUserByIdRepositoryError),
+    RepositoryError(UpdateUserRepositoryError),
+}
+
+impl std::fmt::Display for AdminUserActionUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for AdminUserActionUseCaseError {}
+
+pub type AdminUserActionUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserEntity, AdminUserActionUseCaseError>> + Send + 'a>>;
+
+pub trait AdminUserActionUseCasePort: Send + Sync {
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        target_user_id: String,
+        action: AdminUserAction,
+    ) -> AdminUserActionUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct AdminUserActionUseCase<I, G, U, K> {
+    id_generator_adapter: I,
+    get_user_by_id_repository: G,
+    update_user_repository: U,
+    clock_adapter: K,
+}
+
+impl<I, G, U, K> AdminUserActionUseCase<I, G, U, K>
+where
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        id_generator_adapter: I,
+        get_user_by_id_repository: G,
+        update_user_repository: U,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            id_generator_adapter,
+            get_user_by_id_repository,
+            update_user_repository,
+            clock_adapter,
+        }
+    }
+}
+
+impl<I, G, U, K> AdminUserActionUseCasePort for AdminUserActionUseCase<I, G, U, K>
+where
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        target_user_id: String,
+        action: AdminUserAction,
+    ) -> AdminUserActionUseCaseFuture<'_> {
+        let future = async move {
+            let acting_admin = self
+                .get_user_by_id_repository
+                .execute(acting_admin_id)
+                .await
+                .map_err(AdminUserActionUseCaseError::DatabaseError)?
+                .filter(|user| user.is_admin && user.status == UserStatus::Active)
+                .ok_or(AdminUserActionUseCaseError::UserError(
+                    UserError::AdminPrivilegesRequired,
+                ))?;
+
+            if action.is_self_locking() && acting_admin.id == target_user_id {
+                return Err(AdminUserActionUseCaseError::UserError(
+                    UserError::CannotTargetSelf,
+                ));
+            }
+
+            let mut target_user = self
+                .get_user_by_id_repository
+                .execute(target_user_id)
+                .await
+                .map_err(AdminUserActionUseCaseError::DatabaseError)?
+                .ok_or(AdminUserActionUseCaseError::UserError(
+                    UserError::UserNotFound,
+                ))?;
+
+            let now = self.clock_adapter.now();
+
+            match action {
+                AdminUserAction::Promote => target_user.is_admin = true,
+                AdminUserAction::Demote => target_user.is_admin = false,
+                AdminUserAction::Suspend => target_user.status = UserStatus::Suspended,
+                AdminUserAction::Unsuspend => target_user.status = UserStatus::Active,
+                AdminUserAction::ForcePasswordReset => target_user.password_reset_required = true,
+                AdminUserAction::RevokeSessions => target_user.sessions_revoked_at = Some(now),
+            }
+
+            target_user.updated_at = now;
+
+            let audit_entry_entity = AuditEntryEntity::new(
+                self.id_generator_adapter.generate_id(),
+                Some(acting_admin.id.to_string()),
+                action.audit_action(),
+                target_user.id.to_string(),
+                now.unix_timestamp(),
+            );
+
+            let updated_user = self
+                .update_user_repository
+                .execute(target_user, audit_entry_entity, vec![])
+                .await
+                .map_err(AdminUserActionUseCaseError::RepositoryError)?;
+
+            Ok(updated_user)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("AdminUserActionUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                id_generator::id_generator_port::IdGeneratorPort,
+                repositories::user::{
+                    get_user_by_id_repository_port::{
+                        GetUserByIdFuture, GetUserByIdRepositoryPort,
+                    },
+                    update_user_repository_port::{
+                        UpdateUserRepositoryError, UpdateUserRepositoryFuture,
+                        UpdateUserRepositoryPort,
+                    },
+                },
+            },
+            use_cases::admin::admin_user_action_use_case::{
+                AdminUserAction, AdminUserActionUseCase, AdminUserActionUseCaseError,
+                AdminUserActionUseCasePort,
+            },
+        },
+        domain::{
+            entities::{
+                audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+                outbox::outbox_event_entity::OutboxEventEntity,
+                user::user_entity::{UserEntity, UserEntityBuilder, UserStatus},
+            },
+            errors::user::user_errors::UserError,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    const ADMIN_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+    const TARGET_ID: &str = "d836bc7f-014e-4818-a97f-dd1bb1987b66";
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateUserRepository {}
+
+        impl UpdateUserRepositoryPort for UpdateUserRepository {
+            fn execute(
+                &self,
+                user_entity: UserEntity,
+                audit_entry_entity: AuditEntryEntity,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> UpdateUserRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateUserRepository {
+            fn clone(&self) -> Self {
+                MockUpdateUserRepository::new()
+            }
+        }
+    }
+
+    fn build_user(id: &str, is_admin: bool) -> UserEntity {
+        UserEntityBuilder::default()
+            .id(id)
+            .first_name("John")
+            .last_name("Doe")
+            .email("johndoe@gmail.com")
+            .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+            .is_admin(is_admin)
+            .created_at(1_695_996_669)
+            .updated_at(1_695_996_669)
+            .build()
+            .unwrap()
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        acting_user_is_admin: bool,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |id| {
+                Box::pin(async move {
+                    if id == ADMIN_ID {
+                        Ok(Some(build_user(ADMIN_ID, acting_user_is_admin)))
+                    } else if id == TARGET_ID {
+                        Ok(Some(build_user(TARGET_ID, false)))
+                    } else {
+                        Ok(None)
+                    }
+                })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    fn build_id_generator_adapter_mock() -> MockIdGeneratorAdapter {
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "5b0f3d0e-6f5c-4a8e-9a43-2b1c4f1f2a10".to_string());
+
+        id_generator_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_suspend_user_and_write_audit_entry() {
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|user_entity, audit_entry_entity, outbox_event_entities| {
+                outbox_event_entities.is_empty()
+                    && user_entity.status == UserStatus::Suspended
+                    && audit_entry_entity.action == AuditAction::UserSuspended
+                    && audit_entry_entity.actor_id.as_deref() == Some(ADMIN_ID)
+                    && audit_entry_entity.target_user_id == TARGET_ID
+            })
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(true),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                TARGET_ID.to_string(),
+                AdminUserAction::Suspend,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status, UserStatus::Suspended);
+    }
+
+    #[tokio::test]
+    async fn should_revoke_sessions_of_user() {
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(true),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                TARGET_ID.to_string(),
+                AdminUserAction::RevokeSessions,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().sessions_revoked_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_acting_user_is_not_admin() {
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_id_repository_mock(false),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                TARGET_ID.to_string(),
+                AdminUserAction::Promote,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AdminUserActionUseCaseError::UserError(UserError::AdminPrivilegesRequired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_admin_suspends_themselves() {
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_id_repository_mock(true),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                ADMIN_ID.to_string(),
+                AdminUserAction::Suspend,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AdminUserActionUseCaseError::UserError(UserError::CannotTargetSelf)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_target_user_is_not_found() {
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_id_repository_mock(true),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                "5b0f3d0e-6f5c-4a8e-9a43-2b1c4f1f2a10".to_string(),
+                AdminUserAction::ForcePasswordReset,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AdminUserActionUseCaseError::UserError(UserError::UserNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_update_user_repository_fails() {
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(UpdateUserRepositoryError::UpdateError {
+                        message: "database error".to_string(),
+                    })
+                })
+            });
+
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(true),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = admin_user_action_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                TARGET_ID.to_string(),
+                AdminUserAction::Promote,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AdminUserActionUseCaseError::RepositoryError(UpdateUserRepositoryError::UpdateError {
+                message: _
+            })
+        ));
+    }
+}
@@ -0,0 +1,332 @@
+use std::{pin::Pin, time::Duration};
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::admin::{change_log_level_dto::ChangeLogLevelDto, log_filter_dto::LogFilterDto},
+        ports::{
+            logging::log_filter_port::{LogFilterError, LogFilterPort},
+            repositories::user::get_user_by_id_repository_port::{
+                GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+            },
+        },
+    },
+    domain::{entities::user::user_entity::UserStatus, errors::user::user_errors::UserError},
+};
+
+/// Longest a temporary log level change may last, so a forgotten `trace` does not flood the
+/// logs for days.
+pub const MAX_LOG_LEVEL_REVERT_SECONDS: u64 = 86_400;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChangeLogLevelUseCaseError {
+    UserError(UserError),
+    RevertDelayOutOfRange { max_seconds: u64 },
+    LogFilterError(LogFilterError),
+    DatabaseError(GetUserByIdRepositoryError),
+}
+
+impl std::fmt::Display for ChangeLogLevelUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::RevertDelayOutOfRange { max_seconds } => {
+                write!(
+                    f,
+                    "the revert delay must be between 1 and {max_seconds} seconds"
+                )
+            }
+            Self::LogFilterError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ChangeLogLevelUseCaseError {}
+
+pub type ChangeLogLevelUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<LogFilterDto, ChangeLogLevelUseCaseError>> + Send + 'a>>;
+
+pub trait ChangeLogLevelUseCasePort: Send + Sync {
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        change_log_level_dto: ChangeLogLevelDto,
+    ) -> ChangeLogLevelUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct ChangeLogLevelUseCase<G, L> {
+    get_user_by_id_repository: G,
+    log_filter_adapter: L,
+}
+
+impl<G, L> ChangeLogLevelUseCase<G, L>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    L: LogFilterPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(get_user_by_id_repository: G, log_filter_adapter: L) -> Self {
+        Self {
+            get_user_by_id_repository,
+            log_filter_adapter,
+        }
+    }
+}
+
+impl<G, L> ChangeLogLevelUseCasePort for ChangeLogLevelUseCase<G, L>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    L: LogFilterPort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        change_log_level_dto: ChangeLogLevelDto,
+    ) -> ChangeLogLevelUseCaseFuture<'_> {
+        let future = async move {
+            let acting_admin = self
+                .get_user_by_id_repository
+                .execute(acting_admin_id)
+                .await
+                .map_err(ChangeLogLevelUseCaseError::DatabaseError)?
+                .filter(|user| user.is_admin && user.status == UserStatus::Active)
+                .ok_or(ChangeLogLevelUseCaseError::UserError(
+                    UserError::AdminPrivilegesRequired,
+                ))?;
+
+            let revert_after = change_log_level_dto
+                .revert_after_seconds
+                .map(|revert_after_seconds| {
+                    if (1..=MAX_LOG_LEVEL_REVERT_SECONDS).contains(&revert_after_seconds) {
+                        Ok(Duration::from_secs(revert_after_seconds))
+                    } else {
+                        Err(ChangeLogLevelUseCaseError::RevertDelayOutOfRange {
+                            max_seconds: MAX_LOG_LEVEL_REVERT_SECONDS,
+                        })
+                    }
+                })
+                .transpose()?;
+
+            let log_filter_dto = self
+                .log_filter_adapter
+                .replace_directives(&change_log_level_dto.directives, revert_after)
+                .map_err(ChangeLogLevelUseCaseError::LogFilterError)?;
+
+            tracing::warn!(
+                "Log filter directives changed from '{}' to '{}' by administrator '{}'.",
+                log_filter_dto.previous_directives,
+                log_filter_dto.directives,
+                acting_admin.id
+            );
+
+            Ok(log_filter_dto)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("ChangeLogLevelUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::admin::{change_log_level_dto::ChangeLogLevelDto, log_filter_dto::LogFilterDto},
+            ports::{
+                logging::log_filter_port::{LogFilterError, LogFilterPort},
+                repositories::user::get_user_by_id_repository_port::{
+                    GetUserByIdFuture, GetUserByIdRepositoryPort,
+                },
+            },
+            use_cases::admin::change_log_level_use_case::{
+                ChangeLogLevelUseCase, ChangeLogLevelUseCaseError, ChangeLogLevelUseCasePort,
+                MAX_LOG_LEVEL_REVERT_SECONDS,
+            },
+        },
+        domain::{
+            entities::user::user_entity::{UserEntity, UserEntityBuilder},
+            errors::user::user_errors::UserError,
+        },
+    };
+
+    const ADMIN_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+    const DIRECTIVES: &str = "info,rust_tdd_rest_api::infrastructure=trace";
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub LogFilterAdapter {}
+
+        impl LogFilterPort for LogFilterAdapter {
+            fn replace_directives(
+                &self,
+                directives: &str,
+                revert_after: Option<Duration>,
+            ) -> Result<LogFilterDto, LogFilterError>;
+        }
+
+        impl Clone for LogFilterAdapter {
+            fn clone(&self) -> Self {
+                MockLogFilterAdapter::new()
+            }
+        }
+    }
+
+    fn build_user(is_admin: bool) -> UserEntity {
+        UserEntityBuilder::default()
+            .id(ADMIN_ID)
+            .first_name("John")
+            .last_name("Doe")
+            .email("johndoe@gmail.com")
+            .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+            .is_admin(is_admin)
+            .created_at(1_695_996_669)
+            .updated_at(1_695_996_669)
+            .build()
+            .unwrap()
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        acting_user_is_admin: bool,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |_| {
+                Box::pin(async move { Ok(Some(build_user(acting_user_is_admin))) })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    #[tokio::test]
+    async fn should_replace_directives_with_revert_delay() {
+        let mut log_filter_adapter_mock = MockLogFilterAdapter::default();
+
+        log_filter_adapter_mock
+            .expect_replace_directives()
+            .times(1)
+            .withf(|directives, revert_after| {
+                directives == DIRECTIVES && *revert_after == Some(Duration::from_mins(5))
+            })
+            .returning(|directives, revert_after| {
+                Ok(LogFilterDto {
+                    directives: directives.to_string(),
+                    previous_directives: "debug".to_string(),
+                    revert_to: Some("debug".to_string()),
+                    revert_after_seconds: revert_after.map(|revert_after| revert_after.as_secs()),
+                })
+            });
+
+        let change_log_level_use_case = ChangeLogLevelUseCase::new(
+            build_get_user_by_id_repository_mock(true),
+            log_filter_adapter_mock,
+        );
+
+        let result = change_log_level_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                ChangeLogLevelDto::new(DIRECTIVES.to_string(), Some(300)),
+            )
+            .await;
+
+        let log_filter_dto = result.unwrap();
+
+        assert_eq!(log_filter_dto.directives, DIRECTIVES);
+        assert_eq!(log_filter_dto.revert_to.as_deref(), Some("debug"));
+        assert_eq!(log_filter_dto.revert_after_seconds, Some(300));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_acting_user_is_not_admin() {
+        let change_log_level_use_case = ChangeLogLevelUseCase::new(
+            build_get_user_by_id_repository_mock(false),
+            MockLogFilterAdapter::default(),
+        );
+
+        let result = change_log_level_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                ChangeLogLevelDto::new(DIRECTIVES.to_string(), None),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ChangeLogLevelUseCaseError::UserError(UserError::AdminPrivilegesRequired)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_revert_delay_is_out_of_range() {
+        let change_log_level_use_case = ChangeLogLevelUseCase::new(
+            build_get_user_by_id_repository_mock(true),
+            MockLogFilterAdapter::default(),
+        );
+
+        for revert_after_seconds in [0, MAX_LOG_LEVEL_REVERT_SECONDS + 1] {
+            let result = change_log_level_use_case
+                .perform(
+                    ADMIN_ID.to_string(),
+                    ChangeLogLevelDto::new(DIRECTIVES.to_string(), Some(revert_after_seconds)),
+                )
+                .await;
+
+            assert_eq!(
+                result.unwrap_err(),
+                ChangeLogLevelUseCaseError::RevertDelayOutOfRange {
+                    max_seconds: MAX_LOG_LEVEL_REVERT_SECONDS
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_directives_are_invalid() {
+        let mut log_filter_adapter_mock = MockLogFilterAdapter::default();
+
+        log_filter_adapter_mock
+            .expect_replace_directives()
+            .times(1)
+            .returning(|_, _| {
+                Err(LogFilterError::InvalidDirectives {
+                    message: "invalid filter directive".to_string(),
+                })
+            });
+
+        let change_log_level_use_case = ChangeLogLevelUseCase::new(
+            build_get_user_by_id_repository_mock(true),
+            log_filter_adapter_mock,
+        );
+
+        let result = change_log_level_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                ChangeLogLevelDto::new("rust_tdd_rest_api=loud".to_string(), None),
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ChangeLogLevelUseCaseError::LogFilterError(LogFilterError::InvalidDirectives { .. })
+        ));
+    }
+}
@@ -0,0 +1,98 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::{
+        email_domain_policy::get_email_domain_policy_repository_port::{
+            GetEmailDomainPolicyRepositoryError, GetEmailDomainPolicyRepositoryPort,
+        },
+        user::get_user_by_id_repository_port::{
+            GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+        },
+    },
+    domain::{
+        entities::{
+            email_domain_policy::email_domain_policy_entity::EmailDomainPolicyEntity,
+            user::user_entity::UserStatus,
+        },
+        errors::user::user_errors::UserError,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetEmailDomainPolicyUseCaseError {
+    UserError(UserError),
+    DatabaseError(GetUserByIdRepositoryError),
+    RepositoryError(GetEmailDomainPolicyRepositoryError),
+}
+
+impl std::fmt::Display for GetEmailDomainPolicyUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for GetEmailDomainPolicyUseCaseError {}
+
+pub type GetEmailDomainPolicyUseCaseFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<EmailDomainPolicyEntity, GetEmailDomainPolicyUseCaseError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait GetEmailDomainPolicyUseCasePort: Send + Sync {
+    fn perform(&self, acting_admin_id: String) -> GetEmailDomainPolicyUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct GetEmailDomainPolicyUseCase<G, E> {
+    get_user_by_id_repository: G,
+    get_email_domain_policy_repository: E,
+}
+
+impl<G, E> GetEmailDomainPolicyUseCase<G, E>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    E: GetEmailDomainPolicyRepositoryPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(get_user_by_id_repository: G, get_email_domain_policy_repository: E) -> Self {
+        Self {
+            get_user_by_id_repository,
+            get_email_domain_policy_repository,
+        }
+    }
+}
+
+impl<G, E> GetEmailDomainPolicyUseCasePort for GetEmailDomainPolicyUseCase<G, E>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    E: GetEmailDomainPolicyRepositoryPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, acting_admin_id: String) -> GetEmailDomainPolicyUseCaseFuture<'_> {
+        let future = async move {
+            self.get_user_by_id_repository
+                .execute(acting_admin_id)
+                .await
+                .map_err(GetEmailDomainPolicyUseCaseError::DatabaseError)?
+                .filter(|user| user.is_admin && user.status == UserStatus::Active)
+                .ok_or(GetEmailDomainPolicyUseCaseError::UserError(
+                    UserError::AdminPrivilegesRequired,
+                ))?;
+
+            // Read from the database rather than the cache, so admins see their change at once.
+            self.get_email_domain_policy_repository
+                .execute()
+                .await
+                .map_err(GetEmailDomainPolicyUseCaseError::RepositoryError)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GetEmailDomainPolicyUseCase::perform")))
+    }
+}
@@ -0,0 +1,973 @@
+use std::{collections::HashSet, pin::Pin};
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::admin::import_users_dto::{
+            ImportUserIssueDto, ImportUserRowDto, ImportUserRowErrorDto, ImportUsersDto,
+            ImportUsersReportDto,
+        },
+        ports::{
+            clock::clock_port::ClockPort,
+            email_domain_policy::email_domain_policy_port::{
+                EmailDomainPolicyError, EmailDomainPolicyPort,
+            },
+            email_normalizer::email_normalizer_port::EmailNormalizerPort,
+            hasher::hasher_port::{HasherError, HasherPort},
+            id_generator::id_generator_port::IdGeneratorPort,
+            password_policy::password_policy_port::PasswordPolicyPort,
+            pattern_matching::pattern_matching_port::{PatternMatchingError, PatternMatchingPort},
+            repositories::user::{
+                create_user_repository_port::{
+                    CreateUserRepositoryError, CreateUserRepositoryPort,
+                },
+                get_user_by_email_repository_port::{
+                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+                },
+            },
+        },
+    },
+    domain::{
+        entities::{
+            outbox::outbox_event_entity::OutboxEventEntity,
+            user::user_entity::{UserEntity, UserEntityBuilder},
+        },
+        errors::{
+            user::user_errors::UserError, value_object::value_object_errors::ValueObjectError,
+        },
+        events::domain_event::DomainEvent,
+        value_objects::{shared::timestamp::Timestamp, user::person_name::PersonName},
+    },
+};
+
+pub const MAX_IMPORT_ROWS: usize = 1_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImportUsersUseCaseError {
+    EmptyImport,
+    TooManyRows { limit: usize },
+    PatternMatchingError(PatternMatchingError),
+    EmailDomainPolicyError(EmailDomainPolicyError),
+    HasherError(HasherError),
+    ValidationError(ValueObjectError),
+    DatabaseError(GetUserByEmailRepositoryError),
+    RepositoryError(CreateUserRepositoryError),
+}
+
+impl std::fmt::Display for ImportUsersUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyImport => write!(f, "the import does not contain any rows"),
+            Self::TooManyRows { limit } => {
+                write!(f, "the import exceeds the limit of {limit} rows")
+            }
+            Self::PatternMatchingError(error) => write!(f, "{error}"),
+            Self::EmailDomainPolicyError(error) => write!(f, "{error}"),
+            Self::HasherError(error) => write!(f, "{error}"),
+            Self::ValidationError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ImportUsersUseCaseError {}
+
+pub type ImportUsersUseCaseFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<ImportUsersReportDto, ImportUsersUseCaseError>> + Send + 'a>,
+>;
+
+pub trait ImportUsersUseCasePort: Send + Sync {
+    fn perform(&self, import_users_dto: ImportUsersDto) -> ImportUsersUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct ImportUsersUseCase<P, R, E, N, H, I, G, C, K> {
+    pattern_matching_adapter: P,
+    password_policy_adapter: R,
+    email_domain_policy_adapter: E,
+    email_normalizer_adapter: N,
+    hasher_adapter: H,
+    id_generator_adapter: I,
+    get_user_by_email_repository: G,
+    create_user_repository: C,
+    clock_adapter: K,
+}
+
+impl<P, R, E, N, H, I, G, C, K> ImportUsersUseCase<P, R, E, N, H, I, G, C, K>
+where
+    P: PatternMatchingPort + Send + Sync + Clone + 'static,
+    R: PasswordPolicyPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    C: CreateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        pattern_matching_adapter: P,
+        password_policy_adapter: R,
+        email_domain_policy_adapter: E,
+        email_normalizer_adapter: N,
+        hasher_adapter: H,
+        id_generator_adapter: I,
+        get_user_by_email_repository: G,
+        create_user_repository: C,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            password_policy_adapter,
+            email_domain_policy_adapter,
+            email_normalizer_adapter,
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_email_repository,
+            create_user_repository,
+            clock_adapter,
+        }
+    }
+
+    /// Applies the same rules as the sign up flow to a single row, collecting every violation
+    /// instead of stopping at the first one so the report is actionable.
+    async fn validate_row(
+        &self,
+        row: &ImportUserRowDto,
+    ) -> Result<Vec<ImportUserIssueDto>, ImportUsersUseCaseError> {
+        let mut issues = vec![];
+
+        for (field, value) in [("firstName", &row.first_name), ("lastName", &row.last_name)] {
+            if value.trim().is_empty() {
+                issues.push(ImportUserIssueDto::new(
+                    field,
+                    "empty",
+                    "the field must not be empty",
+                ));
+            } else if let Err(err) = PersonName::parse(value.as_str()) {
+                issues.push(ImportUserIssueDto::new(
+                    field,
+                    "invalid_name",
+                    err.to_string(),
+                ));
+            }
+        }
+
+        if row.email.trim().is_empty() {
+            issues.push(ImportUserIssueDto::new(
+                "email",
+                "empty",
+                "the field must not be empty",
+            ));
+        } else if !self
+            .pattern_matching_adapter
+            .is_valid_email(&row.email)
+            .map_err(ImportUsersUseCaseError::PatternMatchingError)?
+        {
+            issues.push(ImportUserIssueDto::new(
+                "email",
+                "invalid_email",
+                PatternMatchingError::InvalidEmail.to_string(),
+            ));
+        } else {
+            match self.email_domain_policy_adapter.check(&row.email).await {
+                Ok(()) => {}
+                Err(err @ EmailDomainPolicyError::LoadError { .. }) => {
+                    return Err(ImportUsersUseCaseError::EmailDomainPolicyError(err));
+                }
+                Err(err) => issues.push(ImportUserIssueDto::new(
+                    "email",
+                    "invalid_email_domain",
+                    err.to_string(),
+                )),
+            }
+        }
+
+        match (row.password.as_deref(), row.password_hash.as_deref()) {
+            (Some(password), None) => {
+                let personal_info = [
+                    row.email.as_str(),
+                    row.first_name.as_str(),
+                    row.last_name.as_str(),
+                ];
+
+                if let Err(err) = self
+                    .password_policy_adapter
+                    .validate(password, &personal_info)
+                    .await
+                {
+                    issues.extend(err.violations.iter().map(|violation| {
+                        ImportUserIssueDto::new("password", violation.code(), violation.to_string())
+                    }));
+                }
+            }
+            (None, Some(password_hash)) => {
+                if !self
+                    .pattern_matching_adapter
+                    .is_valid_password_hash(password_hash)
+                    .map_err(ImportUsersUseCaseError::PatternMatchingError)?
+                {
+                    issues.push(ImportUserIssueDto::new(
+                        "passwordHash",
+                        "invalid_password_hash",
+                        PatternMatchingError::InvalidPasswordHash.to_string(),
+                    ));
+                }
+            }
+            (Some(_), Some(_)) => issues.push(ImportUserIssueDto::new(
+                "password",
+                "ambiguous_password",
+                "provide either a password or a password hash, not both",
+            )),
+            (None, None) => issues.push(ImportUserIssueDto::new(
+                "password",
+                "missing",
+                "a password or a password hash is required",
+            )),
+        }
+
+        Ok(issues)
+    }
+
+    /// Replaces the plaintext password of every row with its hash. Hashing runs on the blocking
+    /// pool, since bcrypt over a full import would otherwise stall a runtime worker for minutes.
+    async fn hash_passwords(
+        &self,
+        rows: &mut [ImportUserRowDto],
+    ) -> Result<(), ImportUsersUseCaseError> {
+        let passwords = rows
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, row)| row.password.take().map(|password| (index, password)))
+            .collect::<Vec<_>>();
+
+        if passwords.is_empty() {
+            return Ok(());
+        }
+
+        let hasher_adapter = self.hasher_adapter.clone();
+
+        let password_hashes = tokio::task::spawn_blocking(move || {
+            passwords
+                .into_iter()
+                .map(|(index, password)| {
+                    hasher_adapter
+                        .hash(&password)
+                        .map(|password_hash| (index, password_hash.into_inner()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+        .map_err(|err| {
+            ImportUsersUseCaseError::HasherError(HasherError::HashingError {
+                message: err.to_string(),
+            })
+        })?
+        .map_err(ImportUsersUseCaseError::HasherError)?;
+
+        for (index, password_hash) in password_hashes {
+            rows[index].password_hash = Some(password_hash);
+        }
+
+        Ok(())
+    }
+
+    fn build_user_entity(
+        &self,
+        row: ImportUserRowDto,
+        now: Timestamp,
+    ) -> Result<UserEntity, ImportUsersUseCaseError> {
+        UserEntityBuilder::default()
+            .id(self.id_generator_adapter.generate_id())
+            .first_name(row.first_name)
+            .last_name(row.last_name)
+            .email(row.email)
+            .password(row.password_hash.unwrap_or_default())
+            .created_at(now.unix_timestamp())
+            .updated_at(now.unix_timestamp())
+            .build()
+            .map_err(ImportUsersUseCaseError::ValidationError)
+    }
+}
+
+impl<P, R, E, N, H, I, G, C, K> ImportUsersUseCasePort
+    for ImportUsersUseCase<P, R, E, N, H, I, G, C, K>
+where
+    P: PatternMatchingPort + Send + Sync + Clone + 'static,
+    R: PasswordPolicyPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    C: CreateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, import_users_dto: ImportUsersDto) -> ImportUsersUseCaseFuture<'_> {
+        let future = async move {
+            let total_rows = import_users_dto.total_rows();
+
+            if total_rows == 0 {
+                return Err(ImportUsersUseCaseError::EmptyImport);
+            }
+
+            if total_rows > MAX_IMPORT_ROWS {
+                return Err(ImportUsersUseCaseError::TooManyRows {
+                    limit: MAX_IMPORT_ROWS,
+                });
+            }
+
+            let dry_run = import_users_dto.dry_run;
+            let mut errors = import_users_dto.rejected_rows;
+            let mut seen_emails = HashSet::new();
+            let mut valid_rows = vec![];
+
+            for mut row in import_users_dto.rows {
+                row.email = self.email_normalizer_adapter.normalize(&row.email);
+                row.password = row.password.filter(|password| !password.is_empty());
+                row.password_hash = row
+                    .password_hash
+                    .filter(|password_hash| !password_hash.is_empty());
+
+                let mut issues = self.validate_row(&row).await?;
+
+                // E-mails are unique regardless of case, so two rows only differing by case clash.
+                if issues.is_empty() && !seen_emails.insert(row.email.to_lowercase()) {
+                    issues.push(ImportUserIssueDto::new(
+                        "email",
+                        "duplicate_email",
+                        "the e-mail appears more than once in the import",
+                    ));
+                }
+
+                if issues.is_empty()
+                    && self
+                        .get_user_by_email_repository
+                        .execute(row.email.clone())
+                        .await
+                        .map_err(ImportUsersUseCaseError::DatabaseError)?
+                        .is_some()
+                {
+                    issues.push(ImportUserIssueDto::new(
+                        "email",
+                        "user_already_exists",
+                        UserError::UserAlreadyExists.to_string(),
+                    ));
+                }
+
+                if issues.is_empty() {
+                    valid_rows.push(row);
+                } else {
+                    let email = Some(row.email).filter(|email| !email.is_empty());
+
+                    errors.push(ImportUserRowErrorDto::new(row.line, email, issues));
+                }
+            }
+
+            let imported_rows = valid_rows.len();
+
+            if !dry_run && !valid_rows.is_empty() {
+                self.hash_passwords(&mut valid_rows).await?;
+
+                let now = self.clock_adapter.now();
+
+                let user_entities = valid_rows
+                    .into_iter()
+                    .map(|row| self.build_user_entity(row, now))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let outbox_event_entities = user_entities
+                    .iter()
+                    .map(|user_entity| {
+                        OutboxEventEntity::new(
+                            self.id_generator_adapter.generate_id(),
+                            DomainEvent::UserRegistered {
+                                user_id: user_entity.id,
+                                email: user_entity.email.clone(),
+                            },
+                            now.unix_timestamp(),
+                        )
+                    })
+                    .collect();
+
+                self.create_user_repository
+                    .execute_batch(user_entities, outbox_event_entities)
+                    .await
+                    .map_err(ImportUsersUseCaseError::RepositoryError)?;
+            }
+
+            errors.sort_by_key(|error| error.line);
+
+            Ok(ImportUsersReportDto {
+                dry_run,
+                total_rows,
+                imported_rows,
+                failed_rows: errors.len(),
+                errors,
+            })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("ImportUsersUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::struct_field_names)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::admin::import_users_dto::{
+                ImportUserIssueDto, ImportUserRowDto, ImportUserRowErrorDto, ImportUsersDto,
+            },
+            ports::{
+                email_domain_policy::email_domain_policy_port::{
+                    EmailDomainPolicyError, EmailDomainPolicyFuture, EmailDomainPolicyPort,
+                },
+                email_normalizer::email_normalizer_port::EmailNormalizerPort,
+                hasher::hasher_port::{HasherError, HasherPort},
+                id_generator::id_generator_port::IdGeneratorPort,
+                password_policy::password_policy_port::{
+                    PasswordPolicyError, PasswordPolicyFuture, PasswordPolicyPort,
+                    PasswordPolicyViolation,
+                },
+                pattern_matching::pattern_matching_port::{
+                    PatternMatchingError, PatternMatchingPort,
+                },
+                repositories::user::{
+                    create_user_repository_port::{
+                        CreateUserRepositoryError, CreateUserRepositoryFuture,
+                        CreateUserRepositoryPort, CreateUsersRepositoryFuture,
+                    },
+                    get_user_by_email_repository_port::{
+                        GetUserByEmailRepositoryFuture, GetUserByEmailRepositoryPort,
+                    },
+                },
+            },
+            use_cases::admin::import_users_use_case::{
+                ImportUsersUseCase, ImportUsersUseCaseError, ImportUsersUseCasePort,
+                MAX_IMPORT_ROWS,
+            },
+        },
+        domain::{
+            entities::{
+                outbox::outbox_event_entity::OutboxEventEntity,
+                user::user_entity::{UserEntity, UserEntityBuilder},
+            },
+            value_objects::user::password_hash::PasswordHash,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    const PASSWORD_HASH: &str = "$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.";
+
+    mock! {
+        pub PatternMatchingAdapter {}
+
+        impl PatternMatchingPort for PatternMatchingAdapter {
+            fn is_valid_email(&self, email: &str) -> Result<bool, PatternMatchingError>;
+            fn is_valid_password_hash(&self, password_hash: &str) -> Result<bool, PatternMatchingError>;
+            fn is_valid_uuid(&self, uuid: &str) -> Result<bool, PatternMatchingError>;
+        }
+
+        impl Clone for PatternMatchingAdapter {
+            fn clone(&self) -> Self {
+                MockPatternMatchingAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub PasswordPolicyAdapter {}
+
+        impl PasswordPolicyPort for PasswordPolicyAdapter {
+            fn validate<'a>(&self, password: &str, personal_info: &[&'a str]) -> PasswordPolicyFuture<'_>;
+        }
+
+        impl Clone for PasswordPolicyAdapter {
+            fn clone(&self) -> Self {
+                MockPasswordPolicyAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub EmailDomainPolicyAdapter {}
+
+        impl EmailDomainPolicyPort for EmailDomainPolicyAdapter {
+            fn check(&self, email: &str) -> EmailDomainPolicyFuture<'_>;
+            fn invalidate(&self);
+        }
+
+        impl Clone for EmailDomainPolicyAdapter {
+            fn clone(&self) -> Self {
+                MockEmailDomainPolicyAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub EmailNormalizerAdapter {}
+
+        impl EmailNormalizerPort for EmailNormalizerAdapter {
+            fn normalize(&self, email: &str) -> String;
+        }
+
+        impl Clone for EmailNormalizerAdapter {
+            fn clone(&self) -> Self {
+                MockEmailNormalizerAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub HasherAdapter {}
+
+        impl HasherPort for HasherAdapter {
+            fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
+            fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError>;
+        }
+
+        impl Clone for HasherAdapter {
+            fn clone(&self) -> Self {
+                MockHasherAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByEmailRepository {}
+
+        impl GetUserByEmailRepositoryPort for GetUserByEmailRepository {
+            fn execute(&self, email: String) -> GetUserByEmailRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetUserByEmailRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByEmailRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub CreateUserRepository {}
+
+        impl CreateUserRepositoryPort for CreateUserRepository {
+            fn execute(
+                &self,
+                user_entity: UserEntity,
+                outbox_event_entity: OutboxEventEntity,
+            ) -> CreateUserRepositoryFuture<'_>;
+            fn execute_batch(
+                &self,
+                user_entities: Vec<UserEntity>,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> CreateUsersRepositoryFuture<'_>;
+        }
+
+        impl Clone for CreateUserRepository {
+            fn clone(&self) -> Self {
+                MockCreateUserRepository::new()
+            }
+        }
+    }
+
+    fn build_pattern_matching_adapter_mock() -> MockPatternMatchingAdapter {
+        let mut pattern_matching_adapter_mock = MockPatternMatchingAdapter::default();
+
+        pattern_matching_adapter_mock
+            .expect_is_valid_email()
+            .returning(|email| Ok(email.contains('@')));
+
+        pattern_matching_adapter_mock
+            .expect_is_valid_password_hash()
+            .returning(|password_hash| Ok(password_hash.starts_with("$2b$")));
+
+        pattern_matching_adapter_mock
+    }
+
+    fn build_password_policy_adapter_mock() -> MockPasswordPolicyAdapter {
+        let mut password_policy_adapter_mock = MockPasswordPolicyAdapter::default();
+
+        password_policy_adapter_mock
+            .expect_validate()
+            .returning(|password, _| {
+                let is_long_enough = password.len() >= 12;
+
+                Box::pin(async move {
+                    if is_long_enough {
+                        return Ok(());
+                    }
+
+                    Err(PasswordPolicyError {
+                        violations: vec![PasswordPolicyViolation::TooShort { min_length: 12 }],
+                    })
+                })
+            });
+
+        password_policy_adapter_mock
+    }
+
+    fn build_email_domain_policy_adapter_mock() -> MockEmailDomainPolicyAdapter {
+        let mut email_domain_policy_adapter_mock = MockEmailDomainPolicyAdapter::default();
+
+        email_domain_policy_adapter_mock
+            .expect_check()
+            .returning(|email| {
+                let result = if email.ends_with("@gmail.com") {
+                    Ok(())
+                } else {
+                    Err(EmailDomainPolicyError::DomainNotAllowed {
+                        domain: email.rsplit('@').next().unwrap_or_default().to_string(),
+                    })
+                };
+
+                Box::pin(async move { result })
+            });
+
+        email_domain_policy_adapter_mock
+    }
+
+    fn build_email_normalizer_adapter_mock() -> MockEmailNormalizerAdapter {
+        let mut email_normalizer_adapter_mock = MockEmailNormalizerAdapter::default();
+
+        email_normalizer_adapter_mock
+            .expect_normalize()
+            .returning(|email| email.trim().to_string());
+
+        email_normalizer_adapter_mock
+    }
+
+    /// Passwords are hashed on the blocking pool through a clone of the adapter, so the
+    /// expectations go on the clone.
+    fn build_cloned_hasher_adapter_mock(
+        hasher_adapter_mock: MockHasherAdapter,
+    ) -> MockHasherAdapter {
+        let mut cloneable_hasher_adapter_mock = MockHasherAdapter::default();
+
+        cloneable_hasher_adapter_mock
+            .expect_clone()
+            .times(1)
+            .return_once(move || hasher_adapter_mock);
+
+        cloneable_hasher_adapter_mock
+    }
+
+    fn build_id_generator_adapter_mock() -> MockIdGeneratorAdapter {
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
+
+        id_generator_adapter_mock
+    }
+
+    fn build_get_user_by_email_repository_mock() -> MockGetUserByEmailRepository {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|email| {
+                Box::pin(async move {
+                    if email == "existing@gmail.com" {
+                        let user_entity = UserEntityBuilder::default()
+                            .id("dba86129-90be-4409-a5a3-396db9335a57")
+                            .first_name("Existing")
+                            .last_name("User")
+                            .email(email)
+                            .password("hashed_password")
+                            .build()
+                            .unwrap();
+
+                        return Ok(Some(user_entity));
+                    }
+
+                    Ok(None)
+                })
+            });
+
+        get_user_by_email_repository_mock
+    }
+
+    fn build_row(line: usize, email: &str, password: Option<&str>) -> ImportUserRowDto {
+        ImportUserRowDto {
+            line,
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: email.to_string(),
+            password: password.map(str::to_string),
+            password_hash: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_successfully_import_valid_rows_in_a_single_batch() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_hash()
+            .times(1)
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
+
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock
+            .expect_execute_batch()
+            .times(1)
+            .withf(|user_entities, outbox_event_entities| {
+                user_entities.len() == 2
+                    && user_entities[0].password.as_str() == "hashed_password"
+                    && user_entities[1].password.as_str() == PASSWORD_HASH
+                    && outbox_event_entities.len() == 2
+                    && outbox_event_entities.iter().all(|outbox_event_entity| {
+                        outbox_event_entity.event.name() == "user.registered"
+                    })
+            })
+            .returning(|user_entities, _| Box::pin(async move { Ok(user_entities) }));
+
+        let mut pre_hashed_row = build_row(3, "janedoe@gmail.com", None);
+        pre_hashed_row.password_hash = Some(PASSWORD_HASH.to_string());
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            build_pattern_matching_adapter_mock(),
+            build_password_policy_adapter_mock(),
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            build_cloned_hasher_adapter_mock(hasher_adapter_mock),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_email_repository_mock(),
+            create_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = import_users_use_case
+            .perform(ImportUsersDto::new(
+                vec![
+                    build_row(2, "johndoe@gmail.com", Some("Password123!")),
+                    pre_hashed_row,
+                ],
+                vec![],
+                false,
+            ))
+            .await
+            .unwrap();
+
+        assert!(!result.dry_run);
+        assert_eq!(result.total_rows, 2);
+        assert_eq!(result.imported_rows, 2);
+        assert_eq!(result.failed_rows, 0);
+    }
+
+    #[tokio::test]
+    async fn should_not_persist_anything_on_dry_run() {
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock.expect_execute_batch().times(0);
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            build_pattern_matching_adapter_mock(),
+            build_password_policy_adapter_mock(),
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_email_repository_mock(),
+            create_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = import_users_use_case
+            .perform(ImportUsersDto::new(
+                vec![build_row(2, "johndoe@gmail.com", Some("Password123!"))],
+                vec![],
+                true,
+            ))
+            .await
+            .unwrap();
+
+        assert!(result.dry_run);
+        assert_eq!(result.imported_rows, 1);
+        assert_eq!(result.failed_rows, 0);
+    }
+
+    #[tokio::test]
+    async fn should_report_invalid_rows_and_import_the_remaining_ones() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_hash()
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
+
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock
+            .expect_execute_batch()
+            .times(1)
+            .withf(|user_entities, _| user_entities.len() == 1)
+            .returning(|user_entities, _| Box::pin(async move { Ok(user_entities) }));
+
+        let rejected_row = ImportUserRowErrorDto::new(
+            7,
+            None,
+            vec![ImportUserIssueDto::new(
+                "row",
+                "malformed_row",
+                "the row could not be parsed",
+            )],
+        );
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            build_pattern_matching_adapter_mock(),
+            build_password_policy_adapter_mock(),
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            build_cloned_hasher_adapter_mock(hasher_adapter_mock),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_email_repository_mock(),
+            create_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = import_users_use_case
+            .perform(ImportUsersDto::new(
+                vec![
+                    build_row(2, "johndoe@gmail.com", Some("Password123!")),
+                    build_row(3, "JohnDoe@gmail.com", Some("Password123!")),
+                    build_row(4, "existing@gmail.com", Some("Password123!")),
+                    build_row(5, "johndoe@example.com", Some("short")),
+                    build_row(6, "janedoe@gmail.com", None),
+                ],
+                vec![rejected_row],
+                false,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_rows, 6);
+        assert_eq!(result.imported_rows, 1);
+        assert_eq!(result.failed_rows, 5);
+
+        let error_codes = result
+            .errors
+            .iter()
+            .map(|error| {
+                (
+                    error.line,
+                    error
+                        .errors
+                        .iter()
+                        .map(|issue| issue.error_code.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            error_codes,
+            vec![
+                (3, vec!["duplicate_email"]),
+                (4, vec!["user_already_exists"]),
+                (5, vec!["invalid_email_domain", "password_too_short"]),
+                (6, vec!["missing"]),
+                (7, vec!["malformed_row"]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_import_exceeds_row_limit() {
+        let rows = (0..=MAX_IMPORT_ROWS)
+            .map(|line| build_row(line, "johndoe@gmail.com", Some("Password123!")))
+            .collect();
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            MockPatternMatchingAdapter::default(),
+            MockPasswordPolicyAdapter::default(),
+            MockEmailDomainPolicyAdapter::default(),
+            MockEmailNormalizerAdapter::default(),
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            MockGetUserByEmailRepository::default(),
+            MockCreateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = import_users_use_case
+            .perform(ImportUsersDto::new(rows, vec![], false))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ImportUsersUseCaseError::TooManyRows {
+                limit: MAX_IMPORT_ROWS
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_batch_insert_fails() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_hash()
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
+
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock
+            .expect_execute_batch()
+            .returning(|_, _| {
+                Box::pin(async move {
+                    Err(CreateUserRepositoryError::InsertError {
+                        message: "database error".to_string(),
+                    })
+                })
+            });
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            build_pattern_matching_adapter_mock(),
+            build_password_policy_adapter_mock(),
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            build_cloned_hasher_adapter_mock(hasher_adapter_mock),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_email_repository_mock(),
+            create_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = import_users_use_case
+            .perform(ImportUsersDto::new(
+                vec![build_row(2, "johndoe@gmail.com", Some("Password123!"))],
+                vec![],
+                false,
+            ))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ImportUsersUseCaseError::RepositoryError(CreateUserRepositoryError::InsertError {
+                message: _
+            })
+        ));
+    }
+}
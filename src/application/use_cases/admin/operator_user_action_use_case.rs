@@ -0,0 +1,434 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        clock::clock_port::ClockPort,
+        hasher::hasher_port::{HasherError, HasherPort},
+        id_generator::id_generator_port::IdGeneratorPort,
+        repositories::user::{
+            get_user_by_id_repository_port::{
+                GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+            },
+            update_user_repository_port::{UpdateUserRepositoryError, UpdateUserRepositoryPort},
+        },
+    },
+    domain::{
+        entities::{
+            audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+            outbox::outbox_event_entity::OutboxEventEntity,
+            user::user_entity::UserEntity,
+        },
+        errors::user::user_errors::UserError,
+        events::domain_event::DomainEvent,
+    },
+};
+
+/// Actions an operator performs from the admin CLI. Unlike `AdminUserAction` there is no acting
+/// account, since shell access to the server is what authorizes them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperatorUserAction {
+    GrantAdmin,
+    ResetPassword { new_password: String },
+    RevokeSessions,
+}
+
+impl OperatorUserAction {
+    const fn audit_action(&self) -> AuditAction {
+        match self {
+            Self::GrantAdmin => AuditAction::UserPromoted,
+            Self::ResetPassword { .. } => AuditAction::PasswordReset,
+            Self::RevokeSessions => AuditAction::SessionsRevoked,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OperatorUserActionUseCaseError {
+    UserError(UserError),
+    HasherError(HasherError),
+    DatabaseError(GetUserByIdRepositoryError),
+    RepositoryError(UpdateUserRepositoryError),
+}
+
+impl std::fmt::Display for OperatorUserActionUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::HasherError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for OperatorUserActionUseCaseError {}
+
+pub type OperatorUserActionUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserEntity, OperatorUserActionUseCaseError>> + Send + 'a>>;
+
+pub trait OperatorUserActionUseCasePort: Send + Sync {
+    fn perform(
+        &self,
+        target_user_id: String,
+        action: OperatorUserAction,
+    ) -> OperatorUserActionUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct OperatorUserActionUseCase<H, I, G, U, K> {
+    hasher_adapter: H,
+    id_generator_adapter: I,
+    get_user_by_id_repository: G,
+    update_user_repository: U,
+    clock_adapter: K,
+}
+
+impl<H, I, G, U, K> OperatorUserActionUseCase<H, I, G, U, K>
+where
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        hasher_adapter: H,
+        id_generator_adapter: I,
+        get_user_by_id_repository: G,
+        update_user_repository: U,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_id_repository,
+            update_user_repository,
+            clock_adapter,
+        }
+    }
+}
+
+impl<H, I, G, U, K> OperatorUserActionUseCasePort for OperatorUserActionUseCase<H, I, G, U, K>
+where
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        target_user_id: String,
+        action: OperatorUserAction,
+    ) -> OperatorUserActionUseCaseFuture<'_> {
+        let future = async move {
+            let mut target_user = self
+                .get_user_by_id_repository
+                .execute(target_user_id)
+                .await
+                .map_err(OperatorUserActionUseCaseError::DatabaseError)?
+                .ok_or(OperatorUserActionUseCaseError::UserError(
+                    UserError::UserNotFound,
+                ))?;
+
+            let now = self.clock_adapter.now();
+            let audit_action = action.audit_action();
+            let password_changed = matches!(action, OperatorUserAction::ResetPassword { .. });
+
+            match action {
+                OperatorUserAction::GrantAdmin => target_user.is_admin = true,
+                OperatorUserAction::ResetPassword { new_password } => {
+                    target_user.password = self
+                        .hasher_adapter
+                        .hash(&new_password)
+                        .map_err(OperatorUserActionUseCaseError::HasherError)?;
+                    target_user.password_reset_required = false;
+                    // Tokens issued with the old password must not outlive it.
+                    target_user.sessions_revoked_at = Some(now);
+                }
+                OperatorUserAction::RevokeSessions => target_user.sessions_revoked_at = Some(now),
+            }
+
+            target_user.updated_at = now;
+
+            let audit_entry_entity = AuditEntryEntity::new(
+                self.id_generator_adapter.generate_id(),
+                None,
+                audit_action,
+                target_user.id.to_string(),
+                now.unix_timestamp(),
+            );
+
+            let outbox_event_entities = if password_changed {
+                vec![OutboxEventEntity::new(
+                    self.id_generator_adapter.generate_id(),
+                    DomainEvent::PasswordChanged {
+                        user_id: target_user.id,
+                    },
+                    now.unix_timestamp(),
+                )]
+            } else {
+                vec![]
+            };
+
+            let updated_user = self
+                .update_user_repository
+                .execute(target_user, audit_entry_entity, outbox_event_entities)
+                .await
+                .map_err(OperatorUserActionUseCaseError::RepositoryError)?;
+
+            Ok(updated_user)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("OperatorUserActionUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                hasher::hasher_port::{HasherError, HasherPort},
+                id_generator::id_generator_port::IdGeneratorPort,
+                repositories::user::{
+                    get_user_by_id_repository_port::{
+                        GetUserByIdFuture, GetUserByIdRepositoryPort,
+                    },
+                    update_user_repository_port::{
+                        UpdateUserRepositoryFuture, UpdateUserRepositoryPort,
+                    },
+                },
+            },
+            use_cases::admin::operator_user_action_use_case::{
+                OperatorUserAction, OperatorUserActionUseCase, OperatorUserActionUseCaseError,
+                OperatorUserActionUseCasePort,
+            },
+        },
+        domain::{
+            entities::{
+                audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+                outbox::outbox_event_entity::OutboxEventEntity,
+                user::user_entity::{UserEntity, UserEntityBuilder},
+            },
+            errors::user::user_errors::UserError,
+            events::domain_event::DomainEvent,
+            value_objects::user::password_hash::PasswordHash,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    const TARGET_ID: &str = "d836bc7f-014e-4818-a97f-dd1bb1987b66";
+    const NEW_PASSWORD_HASH: &str = "$2b$12$Zk4ZLuB7jFQfNWZyGk9x6uXQ2m8S0bq1oG0r4sT8S3kq2m8S0bq1o";
+
+    mock! {
+        pub HasherAdapter {}
+
+        impl HasherPort for HasherAdapter {
+            fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
+            fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError>;
+        }
+
+        impl Clone for HasherAdapter {
+            fn clone(&self) -> Self {
+                MockHasherAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateUserRepository {}
+
+        impl UpdateUserRepositoryPort for UpdateUserRepository {
+            fn execute(
+                &self,
+                user_entity: UserEntity,
+                audit_entry_entity: AuditEntryEntity,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> UpdateUserRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateUserRepository {
+            fn clone(&self) -> Self {
+                MockUpdateUserRepository::new()
+            }
+        }
+    }
+
+    fn build_get_user_by_id_repository_mock() -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(|id| {
+                Box::pin(async move {
+                    if id == TARGET_ID {
+                        Ok(Some(
+                            UserEntityBuilder::default()
+                                .id(TARGET_ID)
+                                .first_name("John")
+                                .last_name("Doe")
+                                .email("johndoe@gmail.com")
+                                .password(
+                                    "$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.",
+                                )
+                                .password_reset_required(true)
+                                .created_at(1_695_996_669)
+                                .updated_at(1_695_996_669)
+                                .build()
+                                .unwrap(),
+                        ))
+                    } else {
+                        Ok(None)
+                    }
+                })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    fn build_id_generator_adapter_mock() -> MockIdGeneratorAdapter {
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "5b0f3d0e-6f5c-4a8e-9a43-2b1c4f1f2a10".to_string());
+
+        id_generator_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_grant_admin_and_write_operator_audit_entry() {
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|user_entity, audit_entry_entity, outbox_event_entities| {
+                outbox_event_entities.is_empty()
+                    && user_entity.is_admin
+                    && audit_entry_entity.action == AuditAction::UserPromoted
+                    && audit_entry_entity.actor_id.is_none()
+                    && audit_entry_entity.target_user_id == TARGET_ID
+            })
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let operator_user_action_use_case = OperatorUserActionUseCase::new(
+            MockHasherAdapter::default(),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = operator_user_action_use_case
+            .perform(TARGET_ID.to_string(), OperatorUserAction::GrantAdmin)
+            .await;
+
+        assert!(result.unwrap().is_admin);
+    }
+
+    #[tokio::test]
+    async fn should_reset_password_and_revoke_sessions() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_hash()
+            .times(1)
+            .withf(|password| password == "N3w-Passw0rd!")
+            .returning(|_| Ok(PasswordHash::parse(NEW_PASSWORD_HASH).unwrap()));
+
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|user_entity, audit_entry_entity, outbox_event_entities| {
+                audit_entry_entity.action == AuditAction::PasswordReset
+                    && audit_entry_entity.actor_id.is_none()
+                    && outbox_event_entities.len() == 1
+                    && outbox_event_entities[0].event
+                        == DomainEvent::PasswordChanged {
+                            user_id: user_entity.id,
+                        }
+            })
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let operator_user_action_use_case = OperatorUserActionUseCase::new(
+            hasher_adapter_mock,
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let user_entity = operator_user_action_use_case
+            .perform(
+                TARGET_ID.to_string(),
+                OperatorUserAction::ResetPassword {
+                    new_password: "N3w-Passw0rd!".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(user_entity.password.as_str(), NEW_PASSWORD_HASH);
+        assert!(!user_entity.password_reset_required);
+        assert!(user_entity.sessions_revoked_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_does_not_exist() {
+        let operator_user_action_use_case = OperatorUserActionUseCase::new(
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_id_repository_mock(),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = operator_user_action_use_case
+            .perform(
+                "00000000-0000-0000-0000-000000000000".to_string(),
+                OperatorUserAction::RevokeSessions,
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            OperatorUserActionUseCaseError::UserError(UserError::UserNotFound)
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::admin::update_email_domain_policy_dto::UpdateEmailDomainPolicyDto,
+        ports::{
+            clock::clock_port::ClockPort,
+            email_domain_policy::email_domain_policy_port::EmailDomainPolicyPort,
+            repositories::{
+                email_domain_policy::update_email_domain_policy_repository_port::{
+                    UpdateEmailDomainPolicyRepositoryError, UpdateEmailDomainPolicyRepositoryPort,
+                },
+                user::get_user_by_id_repository_port::{
+                    GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+                },
+            },
+        },
+    },
+    domain::{
+        entities::{
+            email_domain_policy::email_domain_policy_entity::{
+                EmailDomainPolicyEntity, EmailDomainPolicyMode,
+            },
+            user::user_entity::UserStatus,
+        },
+        errors::user::user_errors::UserError,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateEmailDomainPolicyUseCaseError {
+    UserError(UserError),
+    UnknownMode { mode: String },
+    DomainInBothLists { domain: String },
+    DatabaseError(GetUserByIdRepositoryError),
+    RepositoryError(UpdateEmailDomainPolicyRepositoryError),
+}
+
+impl std::fmt::Display for UpdateEmailDomainPolicyUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::UnknownMode { mode } => {
+                write!(
+                    f,
+                    "the mode '{mode}' is unknown, expected 'allowlist' or 'denylist'"
+                )
+            }
+            Self::DomainInBothLists { domain } => {
+                write!(
+                    f,
+                    "the domain '{domain}' cannot be on both the allowlist and the denylist"
+                )
+            }
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for UpdateEmailDomainPolicyUseCaseError {}
+
+pub type UpdateEmailDomainPolicyUseCaseFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<EmailDomainPolicyEntity, UpdateEmailDomainPolicyUseCaseError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait UpdateEmailDomainPolicyUseCasePort: Send + Sync {
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        update_email_domain_policy_dto: UpdateEmailDomainPolicyDto,
+    ) -> UpdateEmailDomainPolicyUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct UpdateEmailDomainPolicyUseCase<G, U, E, K> {
+    get_user_by_id_repository: G,
+    update_email_domain_policy_repository: U,
+    email_domain_policy_adapter: E,
+    clock_adapter: K,
+}
+
+impl<G, U, E, K> UpdateEmailDomainPolicyUseCase<G, U, E, K>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateEmailDomainPolicyRepositoryPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        get_user_by_id_repository: G,
+        update_email_domain_policy_repository: U,
+        email_domain_policy_adapter: E,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            get_user_by_id_repository,
+            update_email_domain_policy_repository,
+            email_domain_policy_adapter,
+            clock_adapter,
+        }
+    }
+
+    /// Lowercases, trims and deduplicates the listed domains, so `ACME.com.` and `acme.com`
+    /// are a single rule.
+    fn normalize_domains(domains: Vec<String>) -> Vec<String> {
+        let mut domains = domains
+            .into_iter()
+            .map(|domain| domain.trim().trim_end_matches('.').to_ascii_lowercase())
+            .filter(|domain| !domain.is_empty())
+            .collect::<Vec<_>>();
+
+        domains.sort();
+        domains.dedup();
+
+        domains
+    }
+}
+
+impl<G, U, E, K> UpdateEmailDomainPolicyUseCasePort for UpdateEmailDomainPolicyUseCase<G, U, E, K>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateEmailDomainPolicyRepositoryPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        acting_admin_id: String,
+        update_email_domain_policy_dto: UpdateEmailDomainPolicyDto,
+    ) -> UpdateEmailDomainPolicyUseCaseFuture<'_> {
+        let future = async move {
+            let acting_admin = self
+                .get_user_by_id_repository
+                .execute(acting_admin_id)
+                .await
+                .map_err(UpdateEmailDomainPolicyUseCaseError::DatabaseError)?
+                .filter(|user| user.is_admin && user.status == UserStatus::Active)
+                .ok_or(UpdateEmailDomainPolicyUseCaseError::UserError(
+                    UserError::AdminPrivilegesRequired,
+                ))?;
+
+            let mode = EmailDomainPolicyMode::parse(&update_email_domain_policy_dto.mode)
+                .ok_or_else(|| UpdateEmailDomainPolicyUseCaseError::UnknownMode {
+                    mode: update_email_domain_policy_dto.mode.clone(),
+                })?;
+
+            let allowed_domains =
+                Self::normalize_domains(update_email_domain_policy_dto.allowed_domains);
+            let denied_domains =
+                Self::normalize_domains(update_email_domain_policy_dto.denied_domains);
+
+            if let Some(domain) = allowed_domains
+                .iter()
+                .find(|domain| denied_domains.contains(domain))
+            {
+                return Err(UpdateEmailDomainPolicyUseCaseError::DomainInBothLists {
+                    domain: domain.clone(),
+                });
+            }
+
+            let email_domain_policy_entity = EmailDomainPolicyEntity {
+                mode,
+                block_disposable: update_email_domain_policy_dto.block_disposable,
+                allowed_domains,
+                denied_domains,
+                updated_by: Some(acting_admin.id.to_string()),
+                updated_at: self.clock_adapter.now().unix_timestamp(),
+            };
+
+            let updated_email_domain_policy = self
+                .update_email_domain_policy_repository
+                .execute(email_domain_policy_entity)
+                .await
+                .map_err(UpdateEmailDomainPolicyUseCaseError::RepositoryError)?;
+
+            // Other instances pick the change up once their cache expires.
+            self.email_domain_policy_adapter.invalidate();
+
+            tracing::warn!(
+                "E-mail domain policy changed to {} mode with {} allowed and {} denied domain(s) by administrator '{}'.",
+                updated_email_domain_policy.mode,
+                updated_email_domain_policy.allowed_domains.len(),
+                updated_email_domain_policy.denied_domains.len(),
+                acting_admin.id
+            );
+
+            Ok(updated_email_domain_policy)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "UpdateEmailDomainPolicyUseCase::perform"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::admin::update_email_domain_policy_dto::UpdateEmailDomainPolicyDto,
+            ports::{
+                email_domain_policy::email_domain_policy_port::{
+                    EmailDomainPolicyFuture, EmailDomainPolicyPort,
+                },
+                repositories::{
+                    email_domain_policy::update_email_domain_policy_repository_port::{
+                        UpdateEmailDomainPolicyRepositoryFuture,
+                        UpdateEmailDomainPolicyRepositoryPort,
+                    },
+                    user::get_user_by_id_repository_port::{
+                        GetUserByIdFuture, GetUserByIdRepositoryPort,
+                    },
+                },
+            },
+            use_cases::admin::update_email_domain_policy_use_case::{
+                UpdateEmailDomainPolicyUseCase, UpdateEmailDomainPolicyUseCaseError,
+                UpdateEmailDomainPolicyUseCasePort,
+            },
+        },
+        domain::{
+            entities::{
+                email_domain_policy::email_domain_policy_entity::{
+                    EmailDomainPolicyEntity, EmailDomainPolicyMode,
+                },
+                user::user_entity::{UserEntity, UserEntityBuilder},
+            },
+            errors::user::user_errors::UserError,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    const ADMIN_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateEmailDomainPolicyRepository {}
+
+        impl UpdateEmailDomainPolicyRepositoryPort for UpdateEmailDomainPolicyRepository {
+            fn execute(
+                &self,
+                email_domain_policy_entity: EmailDomainPolicyEntity,
+            ) -> UpdateEmailDomainPolicyRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateEmailDomainPolicyRepository {
+            fn clone(&self) -> Self {
+                MockUpdateEmailDomainPolicyRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub EmailDomainPolicyAdapter {}
+
+        impl EmailDomainPolicyPort for EmailDomainPolicyAdapter {
+            fn check(&self, email: &str) -> EmailDomainPolicyFuture<'_>;
+            fn invalidate(&self);
+        }
+
+        impl Clone for EmailDomainPolicyAdapter {
+            fn clone(&self) -> Self {
+                MockEmailDomainPolicyAdapter::new()
+            }
+        }
+    }
+
+    fn build_user(is_admin: bool) -> UserEntity {
+        UserEntityBuilder::default()
+            .id(ADMIN_ID)
+            .first_name("John")
+            .last_name("Doe")
+            .email("johndoe@gmail.com")
+            .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+            .is_admin(is_admin)
+            .created_at(1_695_996_669)
+            .updated_at(1_695_996_669)
+            .build()
+            .unwrap()
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        acting_user_is_admin: bool,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |_| {
+                Box::pin(async move { Ok(Some(build_user(acting_user_is_admin))) })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    fn build_dto(allowed_domains: &[&str], denied_domains: &[&str]) -> UpdateEmailDomainPolicyDto {
+        UpdateEmailDomainPolicyDto::new(
+            "allowlist".to_string(),
+            true,
+            allowed_domains.iter().map(ToString::to_string).collect(),
+            denied_domains.iter().map(ToString::to_string).collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_store_normalized_policy_and_invalidate_cache() {
+        let mut update_email_domain_policy_repository_mock =
+            MockUpdateEmailDomainPolicyRepository::default();
+
+        update_email_domain_policy_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|email_domain_policy_entity| {
+                email_domain_policy_entity.mode == EmailDomainPolicyMode::Allowlist
+                    && email_domain_policy_entity.allowed_domains == ["acme.com", "contoso.com"]
+                    && email_domain_policy_entity.denied_domains == ["eu.acme.com"]
+                    && email_domain_policy_entity.updated_by.as_deref() == Some(ADMIN_ID)
+            })
+            .returning(|email_domain_policy_entity| {
+                Box::pin(async move { Ok(email_domain_policy_entity) })
+            });
+
+        let mut email_domain_policy_adapter_mock = MockEmailDomainPolicyAdapter::default();
+
+        email_domain_policy_adapter_mock
+            .expect_invalidate()
+            .times(1)
+            .return_const(());
+
+        let update_email_domain_policy_use_case = UpdateEmailDomainPolicyUseCase::new(
+            build_get_user_by_id_repository_mock(true),
+            update_email_domain_policy_repository_mock,
+            email_domain_policy_adapter_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = update_email_domain_policy_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                build_dto(
+                    &["Contoso.com", " acme.com.", "acme.com", ""],
+                    &["EU.acme.com"],
+                ),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_domain_is_in_both_lists() {
+        let update_email_domain_policy_use_case = UpdateEmailDomainPolicyUseCase::new(
+            build_get_user_by_id_repository_mock(true),
+            MockUpdateEmailDomainPolicyRepository::default(),
+            MockEmailDomainPolicyAdapter::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = update_email_domain_policy_use_case
+            .perform(
+                ADMIN_ID.to_string(),
+                build_dto(&["acme.com"], &["ACME.com"]),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            UpdateEmailDomainPolicyUseCaseError::DomainInBothLists {
+                domain: "acme.com".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_acting_user_is_not_admin() {
+        let update_email_domain_policy_use_case = UpdateEmailDomainPolicyUseCase::new(
+            build_get_user_by_id_repository_mock(false),
+            MockUpdateEmailDomainPolicyRepository::default(),
+            MockEmailDomainPolicyAdapter::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = update_email_domain_policy_use_case
+            .perform(ADMIN_ID.to_string(), build_dto(&["acme.com"], &[]))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            UpdateEmailDomainPolicyUseCaseError::UserError(UserError::AdminPrivilegesRequired)
+        );
+    }
+}
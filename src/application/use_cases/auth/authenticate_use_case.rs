@@ -0,0 +1,358 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::auth::auth_principal_dto::AuthPrincipalDto,
+        ports::{
+            auth::auth_port::{AuthError, AuthPort},
+            repositories::user::get_user_by_id_repository_port::{
+                GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+            },
+        },
+    },
+    domain::{entities::user::user_entity::UserStatus, errors::user::user_errors::UserError},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AuthenticateUseCaseError {
+    AuthError(AuthError),
+    UserError(UserError),
+    RepositoryError(GetUserByIdRepositoryError),
+}
+
+impl std::fmt::Display for AuthenticateUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AuthError(error) => write!(f, "{error}"),
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for AuthenticateUseCaseError {}
+
+pub type AuthenticateUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<AuthPrincipalDto, AuthenticateUseCaseError>> + Send + 'a>>;
+
+pub trait AuthenticateUseCasePort: Send + Sync {
+    fn perform(&self, auth_token: String) -> AuthenticateUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct AuthenticateUseCase<A, G> {
+    auth_adapter: A,
+    get_user_by_id_repository: G,
+}
+
+impl<A, G> AuthenticateUseCase<A, G>
+where
+    A: AuthPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(auth_adapter: A, get_user_by_id_repository: G) -> Self {
+        Self {
+            auth_adapter,
+            get_user_by_id_repository,
+        }
+    }
+}
+
+impl<A, G> AuthenticateUseCasePort for AuthenticateUseCase<A, G>
+where
+    A: AuthPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, auth_token: String) -> AuthenticateUseCaseFuture<'_> {
+        let future = async move {
+            let auth_claims = self
+                .auth_adapter
+                .verify_auth_token(&auth_token)
+                .map_err(AuthenticateUseCaseError::AuthError)?;
+
+            let Some(user) = self
+                .get_user_by_id_repository
+                .execute(auth_claims.user_id)
+                .await
+                .map_err(AuthenticateUseCaseError::RepositoryError)?
+            else {
+                return Err(AuthenticateUseCaseError::AuthError(
+                    AuthError::InvalidTokenError,
+                ));
+            };
+
+            if user
+                .sessions_revoked_at
+                .is_some_and(|sessions_revoked_at| auth_claims.issued_at <= sessions_revoked_at)
+            {
+                return Err(AuthenticateUseCaseError::AuthError(
+                    AuthError::RevokedTokenError,
+                ));
+            }
+
+            if user.status == UserStatus::Suspended {
+                return Err(AuthenticateUseCaseError::UserError(
+                    UserError::UserSuspended,
+                ));
+            }
+
+            Ok(AuthPrincipalDto::new(user.id.to_string(), user.is_admin))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("AuthenticateUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                auth::auth_port::{AuthClaims, AuthError, AuthPort, AuthToken},
+                repositories::user::get_user_by_id_repository_port::{
+                    GetUserByIdFuture, GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+                },
+            },
+            use_cases::auth::authenticate_use_case::{
+                AuthenticateUseCase, AuthenticateUseCaseError, AuthenticateUseCasePort,
+            },
+        },
+        domain::{
+            entities::user::user_entity::{UserEntityBuilder, UserStatus},
+            errors::user::user_errors::UserError,
+            value_objects::shared::timestamp::Timestamp,
+        },
+    };
+
+    mock! {
+        pub AuthAdapter {}
+
+        impl AuthPort for AuthAdapter {
+            fn generate_auth_token(&self, user_id: &str) -> Result<AuthToken, AuthError>;
+            fn verify_auth_token(&self, token: &str) -> Result<AuthClaims, AuthError>;
+        }
+
+        impl Clone for AuthAdapter {
+            fn clone(&self) -> Self {
+                MockAuthAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    fn build_auth_adapter_mock(issued_at_micros: i64) -> MockAuthAdapter {
+        let mut auth_adapter_mock = MockAuthAdapter::default();
+
+        auth_adapter_mock
+            .expect_verify_auth_token()
+            .returning(move |_| {
+                Ok(AuthClaims {
+                    user_id: "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                    issued_at: Timestamp::from_unix_timestamp_micros(issued_at_micros).unwrap(),
+                })
+            });
+
+        auth_adapter_mock
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        status: UserStatus,
+        sessions_revoked_at_micros: Option<i64>,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |_| {
+                Box::pin(async move {
+                    let mut user_entity = UserEntityBuilder::default()
+                        .id("dba86129-90be-4409-a5a3-396db9335a57")
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+                        .is_admin(true)
+                        .status(status)
+                        .created_at(1_695_996_669)
+                        .updated_at(1_695_996_669)
+                        .build()
+                        .unwrap();
+
+                    user_entity.sessions_revoked_at =
+                        sessions_revoked_at_micros.map(|sessions_revoked_at_micros| {
+                            Timestamp::from_unix_timestamp_micros(sessions_revoked_at_micros)
+                                .unwrap()
+                        });
+
+                    Ok(Some(user_entity))
+                })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    #[tokio::test]
+    async fn should_successfully_perform_authenticate_use_case() {
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_000_000),
+            build_get_user_by_id_repository_mock(UserStatus::Active, Some(1_695_996_669_000_000)),
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(result.is_ok());
+
+        let auth_principal = result.unwrap();
+
+        assert_eq!(
+            auth_principal.user_id,
+            "dba86129-90be-4409-a5a3-396db9335a57"
+        );
+        assert!(auth_principal.is_admin);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_token_verification_fails() {
+        let mut auth_adapter_mock = MockAuthAdapter::default();
+
+        auth_adapter_mock
+            .expect_verify_auth_token()
+            .returning(|_| Err(AuthError::ExpiredTokenError));
+
+        let get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        let authenticate_use_case =
+            AuthenticateUseCase::new(auth_adapter_mock, get_user_by_id_repository_mock);
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::AuthError(AuthError::ExpiredTokenError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_no_longer_exists() {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(|_| Box::pin(async move { Ok(None) }));
+
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_000_000),
+            get_user_by_id_repository_mock,
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::AuthError(AuthError::InvalidTokenError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_token_was_issued_before_sessions_were_revoked() {
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_669_000_000),
+            build_get_user_by_id_repository_mock(UserStatus::Active, Some(1_695_996_700_000_000)),
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::AuthError(AuthError::RevokedTokenError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_accept_token_issued_after_sessions_were_revoked_within_the_same_second() {
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_500_000),
+            build_get_user_by_id_repository_mock(UserStatus::Active, Some(1_695_996_700_200_000)),
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_token_was_issued_earlier_within_the_revocation_second() {
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_100_000),
+            build_get_user_by_id_repository_mock(UserStatus::Active, Some(1_695_996_700_200_000)),
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::AuthError(AuthError::RevokedTokenError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_is_suspended() {
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_000_000),
+            build_get_user_by_id_repository_mock(UserStatus::Suspended, None),
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::UserError(UserError::UserSuspended)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_get_user_by_id_repository_fails() {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    Err(GetUserByIdRepositoryError::FindByIdError {
+                        message: "database error".to_string(),
+                    })
+                })
+            });
+
+        let authenticate_use_case = AuthenticateUseCase::new(
+            build_auth_adapter_mock(1_695_996_700_000_000),
+            get_user_by_id_repository_mock,
+        );
+
+        let result = authenticate_use_case.perform("any_token".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AuthenticateUseCaseError::RepositoryError(GetUserByIdRepositoryError::FindByIdError {
+                message: _
+            })
+        ));
+    }
+}
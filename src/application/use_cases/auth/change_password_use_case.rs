@@ -0,0 +1,443 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::auth::change_password_dto::ChangePasswordDto,
+        ports::{
+            clock::clock_port::ClockPort,
+            email_normalizer::email_normalizer_port::EmailNormalizerPort,
+            hasher::hasher_port::{HasherError, HasherPort},
+            id_generator::id_generator_port::IdGeneratorPort,
+            repositories::user::{
+                get_user_by_email_repository_port::{
+                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+                },
+                update_user_repository_port::{
+                    UpdateUserRepositoryError, UpdateUserRepositoryPort,
+                },
+            },
+        },
+    },
+    domain::{
+        entities::{
+            audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+            outbox::outbox_event_entity::OutboxEventEntity,
+            user::user_entity::{UserEntity, UserStatus},
+        },
+        errors::user::user_errors::UserError,
+        events::domain_event::DomainEvent,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChangePasswordUseCaseError {
+    HasherError(HasherError),
+    UserError(UserError),
+    DatabaseError(GetUserByEmailRepositoryError),
+    RepositoryError(UpdateUserRepositoryError),
+}
+
+impl std::fmt::Display for ChangePasswordUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HasherError(error) => write!(f, "{error}"),
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ChangePasswordUseCaseError {}
+
+pub type ChangePasswordUseCaseFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<Option<UserEntity>, ChangePasswordUseCaseError>> + Send + 'a>,
+>;
+
+pub trait ChangePasswordUseCasePort: Send + Sync {
+    fn perform(&self, change_password_dto: ChangePasswordDto) -> ChangePasswordUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct ChangePasswordUseCase<H, I, G, U, N, K> {
+    hasher_adapter: H,
+    id_generator_adapter: I,
+    get_user_by_email_repository: G,
+    update_user_repository: U,
+    email_normalizer_adapter: N,
+    clock_adapter: K,
+}
+
+impl<H, I, G, U, N, K> ChangePasswordUseCase<H, I, G, U, N, K>
+where
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        hasher_adapter: H,
+        id_generator_adapter: I,
+        get_user_by_email_repository: G,
+        update_user_repository: U,
+        email_normalizer_adapter: N,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_email_repository,
+            update_user_repository,
+            email_normalizer_adapter,
+            clock_adapter,
+        }
+    }
+}
+
+impl<H, I, G, U, N, K> ChangePasswordUseCasePort for ChangePasswordUseCase<H, I, G, U, N, K>
+where
+    H: HasherPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, change_password_dto: ChangePasswordDto) -> ChangePasswordUseCaseFuture<'_> {
+        let future = async move {
+            if change_password_dto.new_password != change_password_dto.new_password_confirmation {
+                return Err(ChangePasswordUseCaseError::UserError(
+                    UserError::PasswordsDoNotMatch,
+                ));
+            }
+
+            let Some(mut user) = self
+                .get_user_by_email_repository
+                .execute(
+                    self.email_normalizer_adapter
+                        .normalize(&change_password_dto.email),
+                )
+                .await
+                .map_err(ChangePasswordUseCaseError::DatabaseError)?
+            else {
+                return Ok(None);
+            };
+
+            let has_password_matched = self
+                .hasher_adapter
+                .verify(&change_password_dto.password, user.password.as_str())
+                .map_err(ChangePasswordUseCaseError::HasherError)?;
+
+            if !has_password_matched {
+                return Ok(None);
+            }
+
+            if user.status == UserStatus::Suspended {
+                return Err(ChangePasswordUseCaseError::UserError(
+                    UserError::UserSuspended,
+                ));
+            }
+
+            let hashed_password = self
+                .hasher_adapter
+                .hash(&change_password_dto.new_password)
+                .map_err(ChangePasswordUseCaseError::HasherError)?;
+
+            let now = self.clock_adapter.now();
+
+            user.password = hashed_password;
+            user.password_reset_required = false;
+            user.sessions_revoked_at = Some(now);
+            user.updated_at = now;
+
+            let audit_entry_entity = AuditEntryEntity::new(
+                self.id_generator_adapter.generate_id(),
+                Some(user.id.to_string()),
+                AuditAction::PasswordChanged,
+                user.id.to_string(),
+                now.unix_timestamp(),
+            );
+
+            let outbox_event_entity = OutboxEventEntity::new(
+                self.id_generator_adapter.generate_id(),
+                DomainEvent::PasswordChanged { user_id: user.id },
+                now.unix_timestamp(),
+            );
+
+            let updated_user = self
+                .update_user_repository
+                .execute(user, audit_entry_entity, vec![outbox_event_entity])
+                .await
+                .map_err(ChangePasswordUseCaseError::RepositoryError)?;
+
+            Ok(Some(updated_user))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("ChangePasswordUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::auth::change_password_dto::ChangePasswordDto,
+            ports::{
+                email_normalizer::email_normalizer_port::EmailNormalizerPort,
+                hasher::hasher_port::{HasherError, HasherPort},
+                id_generator::id_generator_port::IdGeneratorPort,
+                repositories::user::{
+                    get_user_by_email_repository_port::{
+                        GetUserByEmailRepositoryFuture, GetUserByEmailRepositoryPort,
+                    },
+                    update_user_repository_port::{
+                        UpdateUserRepositoryFuture, UpdateUserRepositoryPort,
+                    },
+                },
+            },
+            use_cases::auth::change_password_use_case::{
+                ChangePasswordUseCase, ChangePasswordUseCaseError, ChangePasswordUseCasePort,
+            },
+        },
+        domain::{
+            entities::{
+                audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+                outbox::outbox_event_entity::OutboxEventEntity,
+                user::user_entity::{UserEntity, UserEntityBuilder},
+            },
+            errors::user::user_errors::UserError,
+            events::domain_event::DomainEvent,
+            value_objects::user::password_hash::PasswordHash,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    mock! {
+        pub HasherAdapter {}
+
+        impl HasherPort for HasherAdapter {
+            fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
+            fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError>;
+        }
+
+        impl Clone for HasherAdapter {
+            fn clone(&self) -> Self {
+                MockHasherAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByEmailRepository {}
+
+        impl GetUserByEmailRepositoryPort for GetUserByEmailRepository {
+            fn execute(&self, email: String) -> GetUserByEmailRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetUserByEmailRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByEmailRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateUserRepository {}
+
+        impl UpdateUserRepositoryPort for UpdateUserRepository {
+            fn execute(
+                &self,
+                user_entity: UserEntity,
+                audit_entry_entity: AuditEntryEntity,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> UpdateUserRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateUserRepository {
+            fn clone(&self) -> Self {
+                MockUpdateUserRepository::new()
+            }
+        }
+    }
+
+    fn build_get_user_by_email_repository_mock() -> MockGetUserByEmailRepository {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    let user_entity = UserEntityBuilder::default()
+                        .id("dba86129-90be-4409-a5a3-396db9335a57")
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+                        .is_admin(false)
+                        .password_reset_required(true)
+                        .created_at(1_695_996_669)
+                        .updated_at(1_695_996_669)
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(user_entity))
+                })
+            });
+
+        get_user_by_email_repository_mock
+    }
+
+    fn build_change_password_dto(new_password_confirmation: &str) -> ChangePasswordDto {
+        ChangePasswordDto::new(
+            "johndoe@gmail.com".to_string(),
+            "Password123!".to_string(),
+            "NewPassword123!".to_string(),
+            new_password_confirmation.to_string(),
+        )
+    }
+
+    mock! {
+        pub EmailNormalizerAdapter {}
+
+        impl EmailNormalizerPort for EmailNormalizerAdapter {
+            fn normalize(&self, email: &str) -> String;
+        }
+
+        impl Clone for EmailNormalizerAdapter {
+            fn clone(&self) -> Self {
+                MockEmailNormalizerAdapter::new()
+            }
+        }
+    }
+
+    fn build_email_normalizer_adapter_mock() -> MockEmailNormalizerAdapter {
+        let mut email_normalizer_adapter_mock = MockEmailNormalizerAdapter::default();
+
+        email_normalizer_adapter_mock
+            .expect_normalize()
+            .returning(|email| email.trim().to_string());
+
+        email_normalizer_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_successfully_perform_change_password_use_case() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_verify()
+            .returning(|_, _| Ok(true));
+
+        hasher_adapter_mock
+            .expect_hash()
+            .times(1)
+            .returning(|_| Ok(PasswordHash::parse("new_hashed_password").unwrap()));
+
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
+
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|user_entity, audit_entry_entity, outbox_event_entities| {
+                user_entity.password.as_str() == "new_hashed_password"
+                    && !user_entity.password_reset_required
+                    && user_entity.sessions_revoked_at.is_some()
+                    && audit_entry_entity.action == AuditAction::PasswordChanged
+                    && audit_entry_entity.actor_id == Some(user_entity.id.to_string())
+                    && outbox_event_entities.len() == 1
+                    && outbox_event_entities[0].event
+                        == DomainEvent::PasswordChanged {
+                            user_id: user_entity.id,
+                        }
+            })
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let change_password_use_case = ChangePasswordUseCase::new(
+            hasher_adapter_mock,
+            id_generator_adapter_mock,
+            build_get_user_by_email_repository_mock(),
+            update_user_repository_mock,
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = change_password_use_case
+            .perform(build_change_password_dto("NewPassword123!"))
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_current_password_does_not_match() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_verify()
+            .returning(|_, _| Ok(false));
+
+        let change_password_use_case = ChangePasswordUseCase::new(
+            hasher_adapter_mock,
+            MockIdGeneratorAdapter::default(),
+            build_get_user_by_email_repository_mock(),
+            MockUpdateUserRepository::default(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = change_password_use_case
+            .perform(build_change_password_dto("NewPassword123!"))
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_new_passwords_do_not_match() {
+        let change_password_use_case = ChangePasswordUseCase::new(
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            MockGetUserByEmailRepository::default(),
+            MockUpdateUserRepository::default(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = change_password_use_case
+            .perform(build_change_password_dto("OtherPassword123!"))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ChangePasswordUseCaseError::UserError(UserError::PasswordsDoNotMatch)
+        ));
+    }
+}
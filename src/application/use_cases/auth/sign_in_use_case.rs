@@ -1,13 +1,32 @@
 use std::pin::Pin;
 
-use crate::application::{
-    dtos::auth::sign_in_dto::SignInDto,
-    ports::{
-        auth::auth_port::{AuthError, AuthPort},
-        hasher::hasher_port::{HasherError, HasherPort},
-        repositories::user::get_user_by_email_repository_port::{
-            GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::auth::sign_in_dto::SignInDto,
+        ports::{
+            auth::auth_port::{AuthError, AuthPort},
+            email_normalizer::email_normalizer_port::EmailNormalizerPort,
+            hasher::hasher_port::{HasherError, HasherPort},
+            id_generator::id_generator_port::IdGeneratorPort,
+            repositories::{
+                session::create_session_repository_port::{
+                    CreateSessionRepositoryError, CreateSessionRepositoryPort,
+                },
+                user::get_user_by_email_repository_port::{
+                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+                },
+            },
+        },
+    },
+    domain::{
+        entities::{
+            outbox::outbox_event_entity::OutboxEventEntity, session::session_entity::SessionEntity,
+            user::user_entity::UserStatus,
         },
+        errors::user::user_errors::UserError,
+        events::domain_event::DomainEvent,
     },
 };
 
@@ -16,6 +35,8 @@ pub enum SignInUseCaseError {
     HasherError(HasherError),
     AuthError(AuthError),
     DatabaseError(GetUserByEmailRepositoryError),
+    SessionRepositoryError(CreateSessionRepositoryError),
+    UserError(UserError),
 }
 
 impl std::fmt::Display for SignInUseCaseError {
@@ -24,6 +45,8 @@ impl std::fmt::Display for SignInUseCaseError {
             Self::HasherError(error) => write!(f, "{error}"),
             Self::AuthError(error) => write!(f, "{error}"),
             Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::SessionRepositoryError(error) => write!(f, "{error}"),
+            Self::UserError(error) => write!(f, "{error}"),
         }
     }
 }
@@ -38,61 +61,113 @@ pub trait SignInUseCasePort: Send + Sync {
 }
 
 #[derive(Clone)]
-pub struct SignInUseCase<H, A, G> {
+pub struct SignInUseCase<H, A, I, G, S, N> {
     hasher_adapter: H,
     auth_adapter: A,
+    id_generator_adapter: I,
     get_user_by_email_repository: G,
+    create_session_repository: S,
+    email_normalizer_adapter: N,
 }
 
-impl<H, A, G> SignInUseCase<H, A, G>
+impl<H, A, I, G, S, N> SignInUseCase<H, A, I, G, S, N>
 where
     H: HasherPort + Send + Sync + Clone + 'static,
     A: AuthPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
     G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    S: CreateSessionRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
 {
-    pub const fn new(hasher_adapter: H, auth_adapter: A, get_user_by_email_repository: G) -> Self {
+    pub const fn new(
+        hasher_adapter: H,
+        auth_adapter: A,
+        id_generator_adapter: I,
+        get_user_by_email_repository: G,
+        create_session_repository: S,
+        email_normalizer_adapter: N,
+    ) -> Self {
         Self {
             hasher_adapter,
             auth_adapter,
+            id_generator_adapter,
             get_user_by_email_repository,
+            create_session_repository,
+            email_normalizer_adapter,
         }
     }
 }
 
-impl<H, A, G> SignInUseCasePort for SignInUseCase<H, A, G>
+impl<H, A, I, G, S, N> SignInUseCasePort for SignInUseCase<H, A, I, G, S, N>
 where
     H: HasherPort + Send + Sync + Clone + 'static,
     A: AuthPort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
     G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    S: CreateSessionRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
 {
     fn perform(&self, sign_in_dto: SignInDto) -> SignInUseCaseFuture<'_> {
-        Box::pin(async move {
+        let future = async move {
             match self
                 .get_user_by_email_repository
-                .execute(sign_in_dto.email)
+                .execute(self.email_normalizer_adapter.normalize(&sign_in_dto.email))
                 .await
                 .map_err(SignInUseCaseError::DatabaseError)?
             {
                 Some(user) => {
                     let has_password_matched = self
                         .hasher_adapter
-                        .verify(&sign_in_dto.password, &user.password)
+                        .verify(&sign_in_dto.password, user.password.as_str())
                         .map_err(SignInUseCaseError::HasherError)?;
 
                     if !has_password_matched {
                         return Ok(None);
                     }
 
+                    if user.status == UserStatus::Suspended {
+                        return Err(SignInUseCaseError::UserError(UserError::UserSuspended));
+                    }
+
+                    if user.password_reset_required {
+                        return Err(SignInUseCaseError::UserError(
+                            UserError::PasswordResetRequired,
+                        ));
+                    }
+
                     let generated_auth_token = self
                         .auth_adapter
-                        .generate_auth_token(&user.id)
+                        .generate_auth_token(&user.id.to_string())
                         .map_err(SignInUseCaseError::AuthError)?;
 
-                    Ok(Some(generated_auth_token))
+                    let session_entity = SessionEntity::new(
+                        self.id_generator_adapter.generate_id(),
+                        user.id.to_string(),
+                        generated_auth_token.issued_at,
+                        generated_auth_token.expires_at,
+                    );
+
+                    let outbox_event_entity = OutboxEventEntity::new(
+                        self.id_generator_adapter.generate_id(),
+                        DomainEvent::UserSignedIn {
+                            user_id: user.id,
+                            session_id: session_entity.id.clone(),
+                        },
+                        session_entity.issued_at,
+                    );
+
+                    self.create_session_repository
+                        .execute(session_entity, outbox_event_entity)
+                        .await
+                        .map_err(SignInUseCaseError::SessionRepositoryError)?;
+
+                    Ok(Some(generated_auth_token.value))
                 }
                 None => Ok(None),
             }
-        })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("SignInUseCase::perform")))
     }
 }
 
@@ -104,25 +179,41 @@ mod tests {
         application::{
             dtos::auth::sign_in_dto::SignInDto,
             ports::{
-                auth::auth_port::{AuthError, AuthPort},
+                auth::auth_port::{AuthClaims, AuthError, AuthPort, AuthToken},
+                email_normalizer::email_normalizer_port::EmailNormalizerPort,
                 hasher::hasher_port::{HasherError, HasherPort},
-                repositories::user::get_user_by_email_repository_port::{
-                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryFuture,
-                    GetUserByEmailRepositoryPort,
+                id_generator::id_generator_port::IdGeneratorPort,
+                repositories::{
+                    session::create_session_repository_port::{
+                        CreateSessionRepositoryFuture, CreateSessionRepositoryPort,
+                    },
+                    user::get_user_by_email_repository_port::{
+                        GetUserByEmailRepositoryError, GetUserByEmailRepositoryFuture,
+                        GetUserByEmailRepositoryPort,
+                    },
                 },
             },
             use_cases::auth::sign_in_use_case::{
                 SignInUseCase, SignInUseCaseError, SignInUseCasePort,
             },
         },
-        domain::entities::user::user_entity::UserEntityBuilder,
+        domain::{
+            entities::{
+                outbox::outbox_event_entity::OutboxEventEntity,
+                session::session_entity::SessionEntity,
+                user::user_entity::{UserEntityBuilder, UserStatus},
+            },
+            errors::user::user_errors::UserError,
+            events::domain_event::DomainEvent,
+            value_objects::user::password_hash::PasswordHash,
+        },
     };
 
     mock! {
         pub HasherAdapter {}
 
         impl HasherPort for HasherAdapter {
-            fn hash(&self, password: &str) -> Result<String, HasherError>;
+            fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
             fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError>;
         }
 
@@ -137,8 +228,8 @@ mod tests {
         pub AuthAdapter {}
 
         impl AuthPort for AuthAdapter {
-            fn generate_auth_token(&self, user_id: &str) -> Result<String, AuthError>;
-            fn verify_auth_token(&self, token: &str) -> Result<(), AuthError>;
+            fn generate_auth_token(&self, user_id: &str) -> Result<AuthToken, AuthError>;
+            fn verify_auth_token(&self, token: &str) -> Result<AuthClaims, AuthError>;
         }
 
         impl Clone for AuthAdapter {
@@ -148,6 +239,38 @@ mod tests {
         }
     }
 
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub CreateSessionRepository {}
+
+        impl CreateSessionRepositoryPort for CreateSessionRepository {
+            fn execute(
+                &self,
+                session_entity: SessionEntity,
+                outbox_event_entity: OutboxEventEntity,
+            ) -> CreateSessionRepositoryFuture<'_>;
+        }
+
+        impl Clone for CreateSessionRepository {
+            fn clone(&self) -> Self {
+                MockCreateSessionRepository::new()
+            }
+        }
+    }
+
     mock! {
         pub GetUserByEmailRepository {}
 
@@ -162,6 +285,30 @@ mod tests {
         }
     }
 
+    mock! {
+        pub EmailNormalizerAdapter {}
+
+        impl EmailNormalizerPort for EmailNormalizerAdapter {
+            fn normalize(&self, email: &str) -> String;
+        }
+
+        impl Clone for EmailNormalizerAdapter {
+            fn clone(&self) -> Self {
+                MockEmailNormalizerAdapter::new()
+            }
+        }
+    }
+
+    fn build_email_normalizer_adapter_mock() -> MockEmailNormalizerAdapter {
+        let mut email_normalizer_adapter_mock = MockEmailNormalizerAdapter::default();
+
+        email_normalizer_adapter_mock
+            .expect_normalize()
+            .returning(|email| email.trim().to_string());
+
+        email_normalizer_adapter_mock
+    }
+
     #[tokio::test]
     async fn should_successfully_perform_sign_in_use_case() {
         let mut hasher_adapter_mock = MockHasherAdapter::default();
@@ -174,7 +321,13 @@ mod tests {
 
         auth_adapter_mock
             .expect_generate_auth_token()
-            .returning(|_| Ok("any_token".to_string()));
+            .returning(|_| {
+                Ok(AuthToken {
+                    value: "any_token".to_string(),
+                    issued_at: 1_695_996_669,
+                    expires_at: 1_695_996_789,
+                })
+            });
 
         let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
 
@@ -191,16 +344,42 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
             });
 
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
+
+        let mut create_session_repository_mock = MockCreateSessionRepository::default();
+
+        create_session_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|session_entity, outbox_event_entity| {
+                session_entity.user_id == "dba86129-90be-4409-a5a3-396db9335a57"
+                    && session_entity.expires_at == 1_695_996_789
+                    && outbox_event_entity.occurred_at == 1_695_996_669
+                    && matches!(
+                        &outbox_event_entity.event,
+                        DomainEvent::UserSignedIn { session_id, .. } if *session_id == session_entity.id
+                    )
+            })
+            .returning(|session_entity, _| Box::pin(async move { Ok(session_entity) }));
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -240,16 +419,23 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
             });
 
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -275,10 +461,16 @@ mod tests {
             .expect_execute()
             .returning(|_| Box::pin(async move { Ok(None) }));
 
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -320,16 +512,23 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
             });
 
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -365,10 +564,16 @@ mod tests {
                 })
             });
 
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -421,16 +626,23 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
             });
 
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
         let sign_in_use_case = SignInUseCase::new(
             hasher_adapter_mock,
             auth_adapter_mock,
+            id_generator_adapter_mock,
             get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
         );
 
         let sign_in_dto =
@@ -447,4 +659,124 @@ mod tests {
             SignInUseCaseError::AuthError(AuthError::GenerateTokenError { message: _ })
         ));
     }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_is_suspended() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_verify()
+            .returning(|_, _| Ok(true));
+
+        let auth_adapter_mock = MockAuthAdapter::default();
+
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    let user_entity = UserEntityBuilder::default()
+                        .id("dba86129-90be-4409-a5a3-396db9335a57")
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+                        .is_admin(false)
+                        .status(UserStatus::Suspended)
+                        .created_at(1_695_996_669)
+                        .updated_at(1_695_996_669)
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(user_entity))
+                })
+            });
+
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
+        let sign_in_use_case = SignInUseCase::new(
+            hasher_adapter_mock,
+            auth_adapter_mock,
+            id_generator_adapter_mock,
+            get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
+        );
+
+        let sign_in_dto =
+            SignInDto::new("johndoe@gmail.com".to_string(), "Password123!".to_string());
+
+        let result = sign_in_use_case.perform(sign_in_dto).await;
+
+        assert!(result.is_err());
+
+        let error = result.unwrap_err();
+
+        assert!(matches!(
+            error,
+            SignInUseCaseError::UserError(UserError::UserSuspended)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_password_reset_is_required() {
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_verify()
+            .returning(|_, _| Ok(true));
+
+        let auth_adapter_mock = MockAuthAdapter::default();
+
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    let user_entity = UserEntityBuilder::default()
+                        .id("dba86129-90be-4409-a5a3-396db9335a57")
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+                        .is_admin(false)
+                        .password_reset_required(true)
+                        .created_at(1_695_996_669)
+                        .updated_at(1_695_996_669)
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(user_entity))
+                })
+            });
+
+        let id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let create_session_repository_mock = MockCreateSessionRepository::default();
+
+        let sign_in_use_case = SignInUseCase::new(
+            hasher_adapter_mock,
+            auth_adapter_mock,
+            id_generator_adapter_mock,
+            get_user_by_email_repository_mock,
+            create_session_repository_mock,
+            build_email_normalizer_adapter_mock(),
+        );
+
+        let sign_in_dto =
+            SignInDto::new("johndoe@gmail.com".to_string(), "Password123!".to_string());
+
+        let result = sign_in_use_case.perform(sign_in_dto).await;
+
+        assert!(result.is_err());
+
+        let error = result.unwrap_err();
+
+        assert!(matches!(
+            error,
+            SignInUseCaseError::UserError(UserError::PasswordResetRequired)
+        ));
+    }
 }
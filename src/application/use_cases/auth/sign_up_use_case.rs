@@ -1,22 +1,37 @@
 use std::pin::Pin;
 
+use tracing::Instrument;
+
 use crate::{
     application::{
         dtos::auth::sign_up_dto::SignUpDto,
         ports::{
+            clock::clock_port::ClockPort,
+            email_domain_policy::email_domain_policy_port::{
+                EmailDomainPolicyError, EmailDomainPolicyPort,
+            },
+            email_normalizer::email_normalizer_port::EmailNormalizerPort,
             hasher::hasher_port::{HasherError, HasherPort},
             id_generator::id_generator_port::IdGeneratorPort,
             repositories::user::{
                 create_user_repository_port::{
                     CreateUserRepositoryError, CreateUserRepositoryPort,
                 },
-                get_user_by_email_repository_port::GetUserByEmailRepositoryPort,
+                get_user_by_email_repository_port::{
+                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+                },
             },
         },
     },
     domain::{
-        entities::user::user_entity::{UserEntity, UserEntityBuilder},
-        errors::user::user_errors::UserError,
+        entities::{
+            outbox::outbox_event_entity::OutboxEventEntity,
+            user::user_entity::{UserEntity, UserEntityBuilder},
+        },
+        errors::{
+            user::user_errors::UserError, value_object::value_object_errors::ValueObjectError,
+        },
+        events::domain_event::DomainEvent,
     },
 };
 
@@ -24,6 +39,9 @@ use crate::{
 pub enum SignUpUseCaseError {
     HasherError(HasherError),
     UserError(UserError),
+    EmailDomainPolicyError(EmailDomainPolicyError),
+    ValidationError(ValueObjectError),
+    DatabaseError(GetUserByEmailRepositoryError),
     RepositoryError(CreateUserRepositoryError),
 }
 
@@ -32,6 +50,9 @@ impl std::fmt::Display for SignUpUseCaseError {
         match self {
             Self::HasherError(error) => write!(f, "{error}"),
             Self::UserError(error) => write!(f, "{error}"),
+            Self::EmailDomainPolicyError(error) => write!(f, "{error}"),
+            Self::ValidationError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
             Self::RepositoryError(error) => write!(f, "{error}"),
         }
     }
@@ -47,54 +68,78 @@ pub trait SignUpUseCasePort: Send + Sync {
 }
 
 #[derive(Clone)]
-pub struct SignUpUseCase<H, I, C, G> {
+pub struct SignUpUseCase<H, I, C, G, E, N, K> {
     hasher_adapter: H,
     id_generator_adapter: I,
     create_user_repository: C,
     get_user_by_email_repository: G,
+    email_domain_policy_adapter: E,
+    email_normalizer_adapter: N,
+    clock_adapter: K,
 }
 
-impl<H, I, C, G> SignUpUseCase<H, I, C, G>
+impl<H, I, C, G, E, N, K> SignUpUseCase<H, I, C, G, E, N, K>
 where
     H: HasherPort + Send + Sync + Clone + 'static,
     I: IdGeneratorPort + Send + Sync + Clone + 'static,
     C: CreateUserRepositoryPort + Send + Sync + Clone + 'static,
     G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
 {
     pub const fn new(
         hasher_adapter: H,
         id_generator_adapter: I,
         create_user_repository: C,
         get_user_by_email_repository: G,
+        email_domain_policy_adapter: E,
+        email_normalizer_adapter: N,
+        clock_adapter: K,
     ) -> Self {
         Self {
             hasher_adapter,
             id_generator_adapter,
             create_user_repository,
             get_user_by_email_repository,
+            email_domain_policy_adapter,
+            email_normalizer_adapter,
+            clock_adapter,
         }
     }
 }
 
-impl<H, I, C, G> SignUpUseCasePort for SignUpUseCase<H, I, C, G>
+impl<H, I, C, G, E, N, K> SignUpUseCasePort for SignUpUseCase<H, I, C, G, E, N, K>
 where
     H: HasherPort + Send + Sync + Clone + 'static,
     I: IdGeneratorPort + Send + Sync + Clone + 'static,
     C: CreateUserRepositoryPort + Send + Sync + Clone + 'static,
     G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    E: EmailDomainPolicyPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
 {
     fn perform(&self, sign_up_dto: SignUpDto) -> SignUpUseCaseFuture<'_> {
-        Box::pin(async move {
+        let future = async move {
+            let email = self.email_normalizer_adapter.normalize(&sign_up_dto.email);
+
             if sign_up_dto.password != sign_up_dto.password_confirmation {
                 return Err(SignUpUseCaseError::UserError(
                     UserError::PasswordsDoNotMatch,
                 ));
             }
 
-            if let Ok(Some(_)) = self
+            self.email_domain_policy_adapter
+                .check(&email)
+                .await
+                .map_err(SignUpUseCaseError::EmailDomainPolicyError)?;
+
+            if self
                 .get_user_by_email_repository
-                .execute(sign_up_dto.email.clone())
+                .execute(email.clone())
                 .await
+                .map_err(SignUpUseCaseError::DatabaseError)?
+                .is_some()
             {
                 return Err(SignUpUseCaseError::UserError(UserError::UserAlreadyExists));
             }
@@ -105,23 +150,44 @@ where
                 .map_err(SignUpUseCaseError::HasherError)?;
 
             let generated_id = self.id_generator_adapter.generate_id();
+            let now = self.clock_adapter.now();
 
             let user_entity = UserEntityBuilder::default()
                 .id(generated_id)
                 .first_name(sign_up_dto.first_name)
                 .last_name(sign_up_dto.last_name)
-                .email(sign_up_dto.email)
-                .password(hashed_password)
-                .build();
+                .email(email)
+                .password(hashed_password.into_inner())
+                .created_at(now.unix_timestamp())
+                .updated_at(now.unix_timestamp())
+                .build()
+                .map_err(SignUpUseCaseError::ValidationError)?;
+
+            let outbox_event_entity = OutboxEventEntity::new(
+                self.id_generator_adapter.generate_id(),
+                DomainEvent::UserRegistered {
+                    user_id: user_entity.id,
+                    email: user_entity.email.clone(),
+                },
+                now.unix_timestamp(),
+            );
 
             let created_user = self
                 .create_user_repository
-                .execute(user_entity)
+                .execute(user_entity, outbox_event_entity)
                 .await
-                .map_err(SignUpUseCaseError::RepositoryError)?;
+                .map_err(|err| match err {
+                    // Another sign-up with the same e-mail won the race since the lookup above.
+                    CreateUserRepositoryError::UniqueViolation { .. } => {
+                        SignUpUseCaseError::UserError(UserError::UserAlreadyExists)
+                    }
+                    err => SignUpUseCaseError::RepositoryError(err),
+                })?;
 
             Ok(created_user)
-        })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("SignUpUseCase::perform")))
     }
 }
 
@@ -133,15 +199,20 @@ mod tests {
         application::{
             dtos::auth::sign_up_dto::SignUpDto,
             ports::{
+                email_domain_policy::email_domain_policy_port::{
+                    EmailDomainPolicyError, EmailDomainPolicyFuture, EmailDomainPolicyPort,
+                },
+                email_normalizer::email_normalizer_port::EmailNormalizerPort,
                 hasher::hasher_port::{HasherError, HasherPort},
                 id_generator::id_generator_port::IdGeneratorPort,
                 repositories::user::{
                     create_user_repository_port::{
                         CreateUserRepositoryError, CreateUserRepositoryFuture,
-                        CreateUserRepositoryPort,
+                        CreateUserRepositoryPort, CreateUsersRepositoryFuture,
                     },
                     get_user_by_email_repository_port::{
-                        GetUserByEmailRepositoryFuture, GetUserByEmailRepositoryPort,
+                        GetUserByEmailRepositoryError, GetUserByEmailRepositoryFuture,
+                        GetUserByEmailRepositoryPort,
                     },
                 },
             },
@@ -150,9 +221,15 @@ mod tests {
             },
         },
         domain::{
-            entities::user::user_entity::{UserEntity, UserEntityBuilder},
+            entities::{
+                outbox::outbox_event_entity::OutboxEventEntity,
+                user::user_entity::{UserEntity, UserEntityBuilder},
+            },
             errors::user::user_errors::UserError,
+            events::domain_event::DomainEvent,
+            value_objects::user::password_hash::PasswordHash,
         },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
     };
 
     mock! {
@@ -162,7 +239,13 @@ mod tests {
             fn execute(
                 &self,
                 user_entity: UserEntity,
+                outbox_event_entity: OutboxEventEntity,
             ) -> CreateUserRepositoryFuture<'_>;
+            fn execute_batch(
+                &self,
+                user_entities: Vec<UserEntity>,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> CreateUsersRepositoryFuture<'_>;
         }
 
         impl Clone for CreateUserRepository {
@@ -190,7 +273,7 @@ mod tests {
         pub HasherAdapter {}
 
         impl HasherPort for HasherAdapter {
-            fn hash(&self, password: &str) -> Result<String, HasherError>;
+            fn hash(&self, password: &str) -> Result<PasswordHash, HasherError>;
             fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError>;
         }
 
@@ -215,6 +298,55 @@ mod tests {
         }
     }
 
+    mock! {
+        pub EmailDomainPolicyAdapter {}
+
+        impl EmailDomainPolicyPort for EmailDomainPolicyAdapter {
+            fn check(&self, email: &str) -> EmailDomainPolicyFuture<'_>;
+            fn invalidate(&self);
+        }
+
+        impl Clone for EmailDomainPolicyAdapter {
+            fn clone(&self) -> Self {
+                MockEmailDomainPolicyAdapter::new()
+            }
+        }
+    }
+
+    fn build_email_domain_policy_adapter_mock() -> MockEmailDomainPolicyAdapter {
+        let mut email_domain_policy_adapter_mock = MockEmailDomainPolicyAdapter::default();
+
+        email_domain_policy_adapter_mock
+            .expect_check()
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        email_domain_policy_adapter_mock
+    }
+
+    mock! {
+        pub EmailNormalizerAdapter {}
+
+        impl EmailNormalizerPort for EmailNormalizerAdapter {
+            fn normalize(&self, email: &str) -> String;
+        }
+
+        impl Clone for EmailNormalizerAdapter {
+            fn clone(&self) -> Self {
+                MockEmailNormalizerAdapter::new()
+            }
+        }
+    }
+
+    fn build_email_normalizer_adapter_mock() -> MockEmailNormalizerAdapter {
+        let mut email_normalizer_adapter_mock = MockEmailNormalizerAdapter::default();
+
+        email_normalizer_adapter_mock
+            .expect_normalize()
+            .returning(|email| email.trim().to_string());
+
+        email_normalizer_adapter_mock
+    }
+
     #[tokio::test]
     async fn should_succecssfully_execute_sign_up_repository() {
         let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
@@ -227,8 +359,17 @@ mod tests {
 
         create_user_repository_mock
             .expect_execute()
+            .withf(|user_entity, outbox_event_entity| {
+                user_entity.created_at.unix_timestamp() == 1_695_996_669
+                    && user_entity.updated_at.unix_timestamp() == 1_695_996_669
+                    && outbox_event_entity.event
+                        == DomainEvent::UserRegistered {
+                            user_id: user_entity.id,
+                            email: user_entity.email.clone(),
+                        }
+            })
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Box::pin(async move {
                     let user_entity = UserEntityBuilder::default()
                         .id("dba86129-90be-4409-a5a3-396db9335a57")
@@ -239,7 +380,8 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(user_entity)
                 })
@@ -250,13 +392,13 @@ mod tests {
         hasher_adapter_mock
             .expect_hash()
             .times(1)
-            .returning(|_| Ok("hashed_password".to_string()));
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
 
         let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
 
         id_generator_adapter_mock
             .expect_generate_id()
-            .times(1)
+            .times(2)
             .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
 
         let sign_up_use_case = SignUpUseCase::new(
@@ -264,6 +406,9 @@ mod tests {
             id_generator_adapter_mock,
             create_user_repository_mock,
             get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
         );
 
         let sign_up_dto = SignUpDto::new(
@@ -292,7 +437,7 @@ mod tests {
         create_user_repository_mock
             .expect_execute()
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Box::pin(async move {
                     Err(CreateUserRepositoryError::InsertError {
                         message: "database error".to_string(),
@@ -305,13 +450,13 @@ mod tests {
         hasher_adapter_mock
             .expect_hash()
             .times(1)
-            .returning(|_| Ok("hashed_password".to_string()));
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
 
         let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
 
         id_generator_adapter_mock
             .expect_generate_id()
-            .times(1)
+            .times(2)
             .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
 
         let sign_up_use_case = SignUpUseCase::new(
@@ -319,6 +464,9 @@ mod tests {
             id_generator_adapter_mock,
             create_user_repository_mock,
             get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
         );
 
         let sign_up_dto = SignUpDto::new(
@@ -343,6 +491,113 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn should_return_user_already_exists_if_insert_hits_unique_violation() {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| Box::pin(async move { Ok(None) }));
+
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async move {
+                    Err(CreateUserRepositoryError::UniqueViolation {
+                        constraint: "users_email_key".to_string(),
+                    })
+                })
+            });
+
+        let mut hasher_adapter_mock = MockHasherAdapter::default();
+
+        hasher_adapter_mock
+            .expect_hash()
+            .times(1)
+            .returning(|_| Ok(PasswordHash::parse("hashed_password").unwrap()));
+
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .times(2)
+            .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
+
+        let sign_up_use_case = SignUpUseCase::new(
+            hasher_adapter_mock,
+            id_generator_adapter_mock,
+            create_user_repository_mock,
+            get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let sign_up_dto = SignUpDto::new(
+            "John".to_string(),
+            "Doe".to_string(),
+            "johndoe@gmail.com".to_string(),
+            "Password123!".to_string(),
+            "Password123!".to_string(),
+        );
+
+        let result = sign_up_use_case.perform(sign_up_dto).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            SignUpUseCaseError::UserError(UserError::UserAlreadyExists)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_lookup_fails() {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    Err(GetUserByEmailRepositoryError::FindByEmailError {
+                        message: "connection refused".to_string(),
+                    })
+                })
+            });
+
+        let mut create_user_repository_mock = MockCreateUserRepository::default();
+
+        create_user_repository_mock.expect_execute().times(0);
+
+        let sign_up_use_case = SignUpUseCase::new(
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            create_user_repository_mock,
+            get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let sign_up_dto = SignUpDto::new(
+            "John".to_string(),
+            "Doe".to_string(),
+            "johndoe@gmail.com".to_string(),
+            "Password123!".to_string(),
+            "Password123!".to_string(),
+        );
+
+        let result = sign_up_use_case.perform(sign_up_dto).await;
+
+        assert!(matches!(
+            result,
+            Err(SignUpUseCaseError::DatabaseError(
+                GetUserByEmailRepositoryError::FindByEmailError { .. }
+            ))
+        ));
+    }
+
     #[tokio::test]
     async fn should_return_error_if_password_hash_fails() {
         let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
@@ -367,6 +622,9 @@ mod tests {
             id_generator_adapter_mock,
             create_user_repository_mock,
             get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
         );
 
         let sign_up_dto = SignUpDto::new(
@@ -401,6 +659,9 @@ mod tests {
             id_generator_adapter_mock,
             create_user_repository_mock,
             get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
         );
 
         let sign_up_dto = SignUpDto::new(
@@ -440,7 +701,8 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
@@ -455,6 +717,9 @@ mod tests {
             id_generator_adapter_mock,
             create_user_repository_mock,
             get_user_by_email_repository_mock,
+            build_email_domain_policy_adapter_mock(),
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
         );
 
         let sign_up_dto = SignUpDto::new(
@@ -476,4 +741,51 @@ mod tests {
             SignUpUseCaseError::UserError(UserError::UserAlreadyExists)
         ));
     }
+
+    #[tokio::test]
+    async fn should_return_error_if_email_domain_is_refused() {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock.expect_execute().times(0);
+
+        let mut email_domain_policy_adapter_mock = MockEmailDomainPolicyAdapter::default();
+
+        email_domain_policy_adapter_mock
+            .expect_check()
+            .times(1)
+            .returning(|_| {
+                Box::pin(async move {
+                    Err(EmailDomainPolicyError::DisposableDomain {
+                        domain: "yopmail.com".to_string(),
+                    })
+                })
+            });
+
+        let sign_up_use_case = SignUpUseCase::new(
+            MockHasherAdapter::default(),
+            MockIdGeneratorAdapter::default(),
+            MockCreateUserRepository::default(),
+            get_user_by_email_repository_mock,
+            email_domain_policy_adapter_mock,
+            build_email_normalizer_adapter_mock(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let sign_up_dto = SignUpDto::new(
+            "John".to_string(),
+            "Doe".to_string(),
+            "johndoe@yopmail.com".to_string(),
+            "Password123!".to_string(),
+            "Password123!".to_string(),
+        );
+
+        let result = sign_up_use_case.perform(sign_up_dto).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            SignUpUseCaseError::EmailDomainPolicyError(EmailDomainPolicyError::DisposableDomain {
+                domain: "yopmail.com".to_string()
+            })
+        );
+    }
 }
@@ -0,0 +1,249 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::application::{
+    dtos::{
+        auth::auth_principal_dto::AuthPrincipalDto,
+        data_export::data_export_archive_dto::DataExportArchiveDto,
+    },
+    ports::{
+        archive::archive_port::ArchivePort,
+        repositories::data_export::get_data_export_by_id_repository_port::{
+            GetDataExportByIdRepositoryError, GetDataExportByIdRepositoryPort,
+        },
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DownloadDataExportUseCaseError {
+    DataExportNotReady,
+    DatabaseError(GetDataExportByIdRepositoryError),
+}
+
+impl std::fmt::Display for DownloadDataExportUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DataExportNotReady => write!(f, "the data export has not completed yet"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for DownloadDataExportUseCaseError {}
+
+pub type DownloadDataExportUseCaseFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<Option<DataExportArchiveDto>, DownloadDataExportUseCaseError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait DownloadDataExportUseCasePort: Send + Sync {
+    fn perform(
+        &self,
+        principal: AuthPrincipalDto,
+        data_export_id: String,
+    ) -> DownloadDataExportUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct DownloadDataExportUseCase<G, A> {
+    get_data_export_by_id_repository: G,
+    archive_adapter: A,
+}
+
+impl<G, A> DownloadDataExportUseCase<G, A>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(get_data_export_by_id_repository: G, archive_adapter: A) -> Self {
+        Self {
+            get_data_export_by_id_repository,
+            archive_adapter,
+        }
+    }
+}
+
+impl<G, A> DownloadDataExportUseCasePort for DownloadDataExportUseCase<G, A>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        principal: AuthPrincipalDto,
+        data_export_id: String,
+    ) -> DownloadDataExportUseCaseFuture<'_> {
+        let future = async move {
+            let Some(data_export_entity) = self
+                .get_data_export_by_id_repository
+                .execute(data_export_id)
+                .await
+                .map_err(DownloadDataExportUseCaseError::DatabaseError)?
+                .filter(|data_export| {
+                    data_export.is_visible_to(&principal.user_id, principal.is_admin)
+                })
+            else {
+                return Ok(None);
+            };
+
+            let archive = data_export_entity
+                .archive
+                .ok_or(DownloadDataExportUseCaseError::DataExportNotReady)?;
+
+            Ok(Some(DataExportArchiveDto::new(
+                &data_export_entity.user_id,
+                self.archive_adapter.file_extension(),
+                self.archive_adapter.content_type(),
+                archive,
+            )))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("DownloadDataExportUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::{
+                auth::auth_principal_dto::AuthPrincipalDto,
+                data_export::user_data_export_dto::UserDataExportDto,
+            },
+            ports::{
+                archive::archive_port::{ArchiveError, ArchivePort},
+                repositories::data_export::get_data_export_by_id_repository_port::{
+                    GetDataExportByIdRepositoryFuture, GetDataExportByIdRepositoryPort,
+                },
+            },
+            use_cases::data_export::download_data_export_use_case::{
+                DownloadDataExportUseCase, DownloadDataExportUseCaseError,
+                DownloadDataExportUseCasePort,
+            },
+        },
+        domain::entities::data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+    };
+
+    mock! {
+        pub GetDataExportByIdRepository {}
+
+        impl GetDataExportByIdRepositoryPort for GetDataExportByIdRepository {
+            fn execute(&self, id: String) -> GetDataExportByIdRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetDataExportByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetDataExportByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub ArchiveAdapter {}
+
+        impl ArchivePort for ArchiveAdapter {
+            fn content_type(&self) -> &'static str;
+            fn file_extension(&self) -> &'static str;
+            fn build_user_data_archive(
+                &self,
+                user_data_export_dto: &UserDataExportDto,
+            ) -> Result<Vec<u8>, ArchiveError>;
+        }
+
+        impl Clone for ArchiveAdapter {
+            fn clone(&self) -> Self {
+                MockArchiveAdapter::new()
+            }
+        }
+    }
+
+    fn build_get_data_export_by_id_repository_mock(
+        archive: Option<Vec<u8>>,
+    ) -> MockGetDataExportByIdRepository {
+        let mut get_data_export_by_id_repository_mock = MockGetDataExportByIdRepository::default();
+
+        get_data_export_by_id_repository_mock
+            .expect_execute()
+            .returning(move |id| {
+                let mut data_export_entity = DataExportEntity::pending(
+                    id,
+                    "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                    "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                    1_695_993_189,
+                );
+
+                if archive.is_some() {
+                    data_export_entity.status = DataExportStatus::Completed;
+                    data_export_entity.archive.clone_from(&archive);
+                    data_export_entity.completed_at = Some(1_695_993_289);
+                }
+
+                Box::pin(async move { Ok(Some(data_export_entity)) })
+            });
+
+        get_data_export_by_id_repository_mock
+    }
+
+    fn build_archive_adapter_mock() -> MockArchiveAdapter {
+        let mut archive_adapter_mock = MockArchiveAdapter::default();
+
+        archive_adapter_mock
+            .expect_content_type()
+            .return_const("application/zip");
+
+        archive_adapter_mock
+            .expect_file_extension()
+            .return_const("zip");
+
+        archive_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_return_archive_of_completed_export() {
+        let download_data_export_use_case = DownloadDataExportUseCase::new(
+            build_get_data_export_by_id_repository_mock(Some(vec![80, 75, 5, 6])),
+            build_archive_adapter_mock(),
+        );
+
+        let result = download_data_export_use_case
+            .perform(
+                AuthPrincipalDto::new("dba86129-90be-4409-a5a3-396db9335a57".to_string(), false),
+                "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.content, vec![80, 75, 5, 6]);
+        assert_eq!(
+            result.file_name,
+            "user-data-dba86129-90be-4409-a5a3-396db9335a57.zip"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_export_is_not_ready() {
+        let download_data_export_use_case = DownloadDataExportUseCase::new(
+            build_get_data_export_by_id_repository_mock(None),
+            build_archive_adapter_mock(),
+        );
+
+        let result = download_data_export_use_case
+            .perform(
+                AuthPrincipalDto::new("2b3c0e4e-0f43-4d6c-9f0c-1c3c4b7a9c11".to_string(), true),
+                "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            DownloadDataExportUseCaseError::DataExportNotReady
+        );
+    }
+}
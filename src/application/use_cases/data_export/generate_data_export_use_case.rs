@@ -0,0 +1,388 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        archive::archive_port::ArchivePort,
+        clock::clock_port::ClockPort,
+        repositories::{
+            data_export::{
+                get_data_export_by_id_repository_port::{
+                    GetDataExportByIdRepositoryError, GetDataExportByIdRepositoryPort,
+                },
+                update_data_export_repository_port::{
+                    UpdateDataExportRepositoryError, UpdateDataExportRepositoryPort,
+                },
+            },
+            user::get_user_data_repository_port::GetUserDataRepositoryPort,
+        },
+    },
+    domain::{
+        entities::data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+        errors::user::user_errors::UserError,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GenerateDataExportUseCaseError {
+    DataExportNotFound,
+    DatabaseError(GetDataExportByIdRepositoryError),
+    RepositoryError(UpdateDataExportRepositoryError),
+}
+
+impl std::fmt::Display for GenerateDataExportUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DataExportNotFound => write!(f, "no data export with the provided id was found"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for GenerateDataExportUseCaseError {}
+
+pub type GenerateDataExportUseCaseFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<DataExportEntity, GenerateDataExportUseCaseError>> + Send + 'a>,
+>;
+
+pub trait GenerateDataExportUseCasePort: Send + Sync {
+    fn perform(&self, data_export_id: String) -> GenerateDataExportUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct GenerateDataExportUseCase<G, D, A, U, K> {
+    get_data_export_by_id_repository: G,
+    get_user_data_repository: D,
+    archive_adapter: A,
+    update_data_export_repository: U,
+    clock_adapter: K,
+}
+
+impl<G, D, A, U, K> GenerateDataExportUseCase<G, D, A, U, K>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+    D: GetUserDataRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+    U: UpdateDataExportRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        get_data_export_by_id_repository: G,
+        get_user_data_repository: D,
+        archive_adapter: A,
+        update_data_export_repository: U,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            get_data_export_by_id_repository,
+            get_user_data_repository,
+            archive_adapter,
+            update_data_export_repository,
+            clock_adapter,
+        }
+    }
+
+    async fn build_archive(&self, user_id: String) -> Result<Vec<u8>, String> {
+        let user_data_export_dto = self
+            .get_user_data_repository
+            .execute(user_id)
+            .await
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| UserError::UserNotFound.to_string())?;
+
+        self.archive_adapter
+            .build_user_data_archive(&user_data_export_dto)
+            .map_err(|error| error.to_string())
+    }
+}
+
+impl<G, D, A, U, K> GenerateDataExportUseCasePort for GenerateDataExportUseCase<G, D, A, U, K>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+    D: GetUserDataRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+    U: UpdateDataExportRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, data_export_id: String) -> GenerateDataExportUseCaseFuture<'_> {
+        let future = async move {
+            let mut data_export_entity = self
+                .get_data_export_by_id_repository
+                .execute(data_export_id)
+                .await
+                .map_err(GenerateDataExportUseCaseError::DatabaseError)?
+                .ok_or(GenerateDataExportUseCaseError::DataExportNotFound)?;
+
+            data_export_entity.status = DataExportStatus::Running;
+
+            let mut data_export_entity = self
+                .update_data_export_repository
+                .execute(data_export_entity)
+                .await
+                .map_err(GenerateDataExportUseCaseError::RepositoryError)?;
+
+            match self.build_archive(data_export_entity.user_id.clone()).await {
+                Ok(archive) => {
+                    data_export_entity.status = DataExportStatus::Completed;
+                    data_export_entity.archive = Some(archive);
+                }
+                Err(error_message) => {
+                    data_export_entity.status = DataExportStatus::Failed;
+                    data_export_entity.error_message = Some(error_message);
+                }
+            }
+
+            data_export_entity.completed_at = Some(self.clock_adapter.now().unix_timestamp());
+
+            self.update_data_export_repository
+                .execute(data_export_entity)
+                .await
+                .map_err(GenerateDataExportUseCaseError::RepositoryError)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GenerateDataExportUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::{Sequence, mock};
+
+    use crate::{
+        application::{
+            dtos::data_export::user_data_export_dto::UserDataExportDto,
+            ports::{
+                archive::archive_port::{ArchiveError, ArchivePort},
+                repositories::{
+                    data_export::{
+                        get_data_export_by_id_repository_port::{
+                            GetDataExportByIdRepositoryFuture, GetDataExportByIdRepositoryPort,
+                        },
+                        update_data_export_repository_port::{
+                            UpdateDataExportRepositoryFuture, UpdateDataExportRepositoryPort,
+                        },
+                    },
+                    user::get_user_data_repository_port::{
+                        CountUserRecordsFuture, GetUserDataRepositoryFuture,
+                        GetUserDataRepositoryPort,
+                    },
+                },
+            },
+            use_cases::data_export::generate_data_export_use_case::{
+                GenerateDataExportUseCase, GenerateDataExportUseCaseError,
+                GenerateDataExportUseCasePort,
+            },
+        },
+        domain::entities::{
+            data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+            user::user_entity::UserEntityBuilder,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    mock! {
+        pub GetDataExportByIdRepository {}
+
+        impl GetDataExportByIdRepositoryPort for GetDataExportByIdRepository {
+            fn execute(&self, id: String) -> GetDataExportByIdRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetDataExportByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetDataExportByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserDataRepository {}
+
+        impl GetUserDataRepositoryPort for GetUserDataRepository {
+            fn count_records(&self, user_id: String) -> CountUserRecordsFuture<'_>;
+            fn execute(&self, user_id: String) -> GetUserDataRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetUserDataRepository {
+            fn clone(&self) -> Self {
+                MockGetUserDataRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub ArchiveAdapter {}
+
+        impl ArchivePort for ArchiveAdapter {
+            fn content_type(&self) -> &'static str;
+            fn file_extension(&self) -> &'static str;
+            fn build_user_data_archive(
+                &self,
+                user_data_export_dto: &UserDataExportDto,
+            ) -> Result<Vec<u8>, ArchiveError>;
+        }
+
+        impl Clone for ArchiveAdapter {
+            fn clone(&self) -> Self {
+                MockArchiveAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateDataExportRepository {}
+
+        impl UpdateDataExportRepositoryPort for UpdateDataExportRepository {
+            fn execute(&self, data_export_entity: DataExportEntity) -> UpdateDataExportRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateDataExportRepository {
+            fn clone(&self) -> Self {
+                MockUpdateDataExportRepository::new()
+            }
+        }
+    }
+
+    fn build_get_data_export_by_id_repository_mock(
+        data_export_entity: Option<DataExportEntity>,
+    ) -> MockGetDataExportByIdRepository {
+        let mut get_data_export_by_id_repository_mock = MockGetDataExportByIdRepository::default();
+
+        get_data_export_by_id_repository_mock
+            .expect_execute()
+            .returning(move |_| {
+                let data_export_entity = data_export_entity.clone();
+
+                Box::pin(async move { Ok(data_export_entity) })
+            });
+
+        get_data_export_by_id_repository_mock
+    }
+
+    fn build_get_user_data_repository_mock(user_exists: bool) -> MockGetUserDataRepository {
+        let mut get_user_data_repository_mock = MockGetUserDataRepository::default();
+
+        get_user_data_repository_mock
+            .expect_execute()
+            .returning(move |user_id| {
+                Box::pin(async move {
+                    if !user_exists {
+                        return Ok(None);
+                    }
+
+                    let user_entity = UserEntityBuilder::default()
+                        .id(user_id)
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("hashed_password")
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(UserDataExportDto::new(user_entity, vec![], vec![])))
+                })
+            });
+
+        get_user_data_repository_mock
+    }
+
+    fn build_update_data_export_repository_mock(
+        final_status: DataExportStatus,
+    ) -> MockUpdateDataExportRepository {
+        let mut update_data_export_repository_mock = MockUpdateDataExportRepository::default();
+        let mut sequence = Sequence::new();
+
+        update_data_export_repository_mock
+            .expect_execute()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(|data_export_entity| data_export_entity.status == DataExportStatus::Running)
+            .returning(|data_export_entity| Box::pin(async move { Ok(data_export_entity) }));
+
+        update_data_export_repository_mock
+            .expect_execute()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(move |data_export_entity| {
+                data_export_entity.status == final_status
+                    && data_export_entity.completed_at.is_some()
+            })
+            .returning(|data_export_entity| Box::pin(async move { Ok(data_export_entity) }));
+
+        update_data_export_repository_mock
+    }
+
+    fn pending_data_export() -> DataExportEntity {
+        DataExportEntity::pending(
+            "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+            "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+            1_695_993_189,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_store_archive_once_generated() {
+        let mut archive_adapter_mock = MockArchiveAdapter::default();
+
+        archive_adapter_mock
+            .expect_build_user_data_archive()
+            .returning(|_| Ok(vec![80, 75, 5, 6]));
+
+        let generate_data_export_use_case = GenerateDataExportUseCase::new(
+            build_get_data_export_by_id_repository_mock(Some(pending_data_export())),
+            build_get_user_data_repository_mock(true),
+            archive_adapter_mock,
+            build_update_data_export_repository_mock(DataExportStatus::Completed),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = generate_data_export_use_case
+            .perform("d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.archive, Some(vec![80, 75, 5, 6]));
+        assert_eq!(result.error_message, None);
+    }
+
+    #[tokio::test]
+    async fn should_mark_export_as_failed_if_user_no_longer_exists() {
+        let generate_data_export_use_case = GenerateDataExportUseCase::new(
+            build_get_data_export_by_id_repository_mock(Some(pending_data_export())),
+            build_get_user_data_repository_mock(false),
+            MockArchiveAdapter::default(),
+            build_update_data_export_repository_mock(DataExportStatus::Failed),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = generate_data_export_use_case
+            .perform("d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.archive, None);
+        assert!(result.error_message.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_export_does_not_exist() {
+        let generate_data_export_use_case = GenerateDataExportUseCase::new(
+            build_get_data_export_by_id_repository_mock(None),
+            MockGetUserDataRepository::default(),
+            MockArchiveAdapter::default(),
+            MockUpdateDataExportRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = generate_data_export_use_case
+            .perform("d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string())
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            GenerateDataExportUseCaseError::DataExportNotFound
+        );
+    }
+}
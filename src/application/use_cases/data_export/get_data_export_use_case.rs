@@ -0,0 +1,170 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::auth::auth_principal_dto::AuthPrincipalDto,
+        ports::repositories::data_export::get_data_export_by_id_repository_port::{
+            GetDataExportByIdRepositoryError, GetDataExportByIdRepositoryPort,
+        },
+    },
+    domain::entities::data_export::data_export_entity::DataExportEntity,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetDataExportUseCaseError {
+    DatabaseError(GetDataExportByIdRepositoryError),
+}
+
+impl std::fmt::Display for GetDataExportUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for GetDataExportUseCaseError {}
+
+pub type GetDataExportUseCaseFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<Option<DataExportEntity>, GetDataExportUseCaseError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait GetDataExportUseCasePort: Send + Sync {
+    /// Returns `None` both when the export does not exist and when the principal is not allowed
+    /// to see it, so that export ids cannot be probed.
+    fn perform(
+        &self,
+        principal: AuthPrincipalDto,
+        data_export_id: String,
+    ) -> GetDataExportUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct GetDataExportUseCase<G> {
+    get_data_export_by_id_repository: G,
+}
+
+impl<G> GetDataExportUseCase<G>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(get_data_export_by_id_repository: G) -> Self {
+        Self {
+            get_data_export_by_id_repository,
+        }
+    }
+}
+
+impl<G> GetDataExportUseCasePort for GetDataExportUseCase<G>
+where
+    G: GetDataExportByIdRepositoryPort + Send + Sync + Clone + 'static,
+{
+    fn perform(
+        &self,
+        principal: AuthPrincipalDto,
+        data_export_id: String,
+    ) -> GetDataExportUseCaseFuture<'_> {
+        let future = async move {
+            let data_export_entity = self
+                .get_data_export_by_id_repository
+                .execute(data_export_id)
+                .await
+                .map_err(GetDataExportUseCaseError::DatabaseError)?;
+
+            Ok(data_export_entity.filter(|data_export| {
+                data_export.is_visible_to(&principal.user_id, principal.is_admin)
+            }))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GetDataExportUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::auth::auth_principal_dto::AuthPrincipalDto,
+            ports::repositories::data_export::get_data_export_by_id_repository_port::{
+                GetDataExportByIdRepositoryFuture, GetDataExportByIdRepositoryPort,
+            },
+            use_cases::data_export::get_data_export_use_case::{
+                GetDataExportUseCase, GetDataExportUseCasePort,
+            },
+        },
+        domain::entities::data_export::data_export_entity::DataExportEntity,
+    };
+
+    mock! {
+        pub GetDataExportByIdRepository {}
+
+        impl GetDataExportByIdRepositoryPort for GetDataExportByIdRepository {
+            fn execute(&self, id: String) -> GetDataExportByIdRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetDataExportByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetDataExportByIdRepository::new()
+            }
+        }
+    }
+
+    fn build_get_data_export_by_id_repository_mock() -> MockGetDataExportByIdRepository {
+        let mut get_data_export_by_id_repository_mock = MockGetDataExportByIdRepository::default();
+
+        get_data_export_by_id_repository_mock
+            .expect_execute()
+            .returning(|id| {
+                Box::pin(async move {
+                    Ok(Some(DataExportEntity::pending(
+                        id,
+                        "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                        "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                        1_695_993_189,
+                    )))
+                })
+            });
+
+        get_data_export_by_id_repository_mock
+    }
+
+    #[tokio::test]
+    async fn should_return_export_to_its_owner() {
+        let get_data_export_use_case =
+            GetDataExportUseCase::new(build_get_data_export_by_id_repository_mock());
+
+        let result = get_data_export_use_case
+            .perform(
+                AuthPrincipalDto::new("dba86129-90be-4409-a5a3-396db9335a57".to_string(), false),
+                "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_hide_export_from_other_users() {
+        let get_data_export_use_case =
+            GetDataExportUseCase::new(build_get_data_export_by_id_repository_mock());
+
+        let result = get_data_export_use_case
+            .perform(
+                AuthPrincipalDto::new("2b3c0e4e-0f43-4d6c-9f0c-1c3c4b7a9c11".to_string(), false),
+                "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,434 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::{
+        dtos::data_export::data_export_archive_dto::DataExportArchiveDto,
+        ports::{
+            archive::archive_port::{ArchiveError, ArchivePort},
+            clock::clock_port::ClockPort,
+            id_generator::id_generator_port::IdGeneratorPort,
+            job::data_export_job_port::DataExportJobPort,
+            repositories::{
+                data_export::create_data_export_repository_port::{
+                    CreateDataExportRepositoryError, CreateDataExportRepositoryPort,
+                },
+                user::get_user_data_repository_port::{
+                    GetUserDataRepositoryError, GetUserDataRepositoryPort,
+                },
+            },
+        },
+    },
+    domain::{
+        entities::data_export::data_export_entity::DataExportEntity,
+        errors::user::user_errors::UserError,
+    },
+};
+
+/// Exports linking more records than this are generated by a background job instead of
+/// being streamed back within the request.
+pub const INLINE_EXPORT_MAX_RECORDS: u64 = 1_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DataExportOutcome {
+    Ready(DataExportArchiveDto),
+    Scheduled(DataExportEntity),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RequestDataExportUseCaseError {
+    UserError(UserError),
+    ArchiveError(ArchiveError),
+    DatabaseError(GetUserDataRepositoryError),
+    RepositoryError(CreateDataExportRepositoryError),
+}
+
+impl std::fmt::Display for RequestDataExportUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::ArchiveError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for RequestDataExportUseCaseError {}
+
+pub type RequestDataExportUseCaseFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<DataExportOutcome, RequestDataExportUseCaseError>> + Send + 'a>,
+>;
+
+pub trait RequestDataExportUseCasePort: Send + Sync {
+    fn perform(&self, requested_by: String, user_id: String) -> RequestDataExportUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct RequestDataExportUseCase<I, D, A, C, J, K> {
+    id_generator_adapter: I,
+    get_user_data_repository: D,
+    archive_adapter: A,
+    create_data_export_repository: C,
+    data_export_job_adapter: J,
+    clock_adapter: K,
+}
+
+impl<I, D, A, C, J, K> RequestDataExportUseCase<I, D, A, C, J, K>
+where
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    D: GetUserDataRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+    C: CreateDataExportRepositoryPort + Send + Sync + Clone + 'static,
+    J: DataExportJobPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        id_generator_adapter: I,
+        get_user_data_repository: D,
+        archive_adapter: A,
+        create_data_export_repository: C,
+        data_export_job_adapter: J,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            id_generator_adapter,
+            get_user_data_repository,
+            archive_adapter,
+            create_data_export_repository,
+            data_export_job_adapter,
+            clock_adapter,
+        }
+    }
+}
+
+impl<I, D, A, C, J, K> RequestDataExportUseCasePort for RequestDataExportUseCase<I, D, A, C, J, K>
+where
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    D: GetUserDataRepositoryPort + Send + Sync + Clone + 'static,
+    A: ArchivePort + Send + Sync + Clone + 'static,
+    C: CreateDataExportRepositoryPort + Send + Sync + Clone + 'static,
+    J: DataExportJobPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, requested_by: String, user_id: String) -> RequestDataExportUseCaseFuture<'_> {
+        let future = async move {
+            let record_count = self
+                .get_user_data_repository
+                .count_records(user_id.clone())
+                .await
+                .map_err(RequestDataExportUseCaseError::DatabaseError)?
+                .ok_or(RequestDataExportUseCaseError::UserError(
+                    UserError::UserNotFound,
+                ))?;
+
+            if record_count > INLINE_EXPORT_MAX_RECORDS {
+                let data_export_entity = DataExportEntity::pending(
+                    self.id_generator_adapter.generate_id(),
+                    user_id,
+                    requested_by,
+                    self.clock_adapter.now().unix_timestamp(),
+                );
+
+                let created_data_export = self
+                    .create_data_export_repository
+                    .execute(data_export_entity)
+                    .await
+                    .map_err(RequestDataExportUseCaseError::RepositoryError)?;
+
+                self.data_export_job_adapter
+                    .enqueue(created_data_export.id.clone());
+
+                return Ok(DataExportOutcome::Scheduled(created_data_export));
+            }
+
+            let user_data_export_dto = self
+                .get_user_data_repository
+                .execute(user_id.clone())
+                .await
+                .map_err(RequestDataExportUseCaseError::DatabaseError)?
+                .ok_or(RequestDataExportUseCaseError::UserError(
+                    UserError::UserNotFound,
+                ))?;
+
+            let archive = self
+                .archive_adapter
+                .build_user_data_archive(&user_data_export_dto)
+                .map_err(RequestDataExportUseCaseError::ArchiveError)?;
+
+            Ok(DataExportOutcome::Ready(DataExportArchiveDto::new(
+                &user_id,
+                self.archive_adapter.file_extension(),
+                self.archive_adapter.content_type(),
+                archive,
+            )))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("RequestDataExportUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::data_export::user_data_export_dto::UserDataExportDto,
+            ports::{
+                archive::archive_port::{ArchiveError, ArchivePort},
+                id_generator::id_generator_port::IdGeneratorPort,
+                job::data_export_job_port::DataExportJobPort,
+                repositories::{
+                    data_export::create_data_export_repository_port::{
+                        CreateDataExportRepositoryFuture, CreateDataExportRepositoryPort,
+                    },
+                    user::get_user_data_repository_port::{
+                        CountUserRecordsFuture, GetUserDataRepositoryFuture,
+                        GetUserDataRepositoryPort,
+                    },
+                },
+            },
+            use_cases::data_export::request_data_export_use_case::{
+                DataExportOutcome, INLINE_EXPORT_MAX_RECORDS, RequestDataExportUseCase,
+                RequestDataExportUseCaseError, RequestDataExportUseCasePort,
+            },
+        },
+        domain::{
+            entities::{
+                data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+                user::user_entity::UserEntityBuilder,
+            },
+            errors::user::user_errors::UserError,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserDataRepository {}
+
+        impl GetUserDataRepositoryPort for GetUserDataRepository {
+            fn count_records(&self, user_id: String) -> CountUserRecordsFuture<'_>;
+            fn execute(&self, user_id: String) -> GetUserDataRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetUserDataRepository {
+            fn clone(&self) -> Self {
+                MockGetUserDataRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub ArchiveAdapter {}
+
+        impl ArchivePort for ArchiveAdapter {
+            fn content_type(&self) -> &'static str;
+            fn file_extension(&self) -> &'static str;
+            fn build_user_data_archive(
+                &self,
+                user_data_export_dto: &UserDataExportDto,
+            ) -> Result<Vec<u8>, ArchiveError>;
+        }
+
+        impl Clone for ArchiveAdapter {
+            fn clone(&self) -> Self {
+                MockArchiveAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub CreateDataExportRepository {}
+
+        impl CreateDataExportRepositoryPort for CreateDataExportRepository {
+            fn execute(&self, data_export_entity: DataExportEntity) -> CreateDataExportRepositoryFuture<'_>;
+        }
+
+        impl Clone for CreateDataExportRepository {
+            fn clone(&self) -> Self {
+                MockCreateDataExportRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub DataExportJobAdapter {}
+
+        impl DataExportJobPort for DataExportJobAdapter {
+            fn enqueue(&self, data_export_id: String);
+        }
+
+        impl Clone for DataExportJobAdapter {
+            fn clone(&self) -> Self {
+                MockDataExportJobAdapter::new()
+            }
+        }
+    }
+
+    fn build_get_user_data_repository_mock(record_count: Option<u64>) -> MockGetUserDataRepository {
+        let mut get_user_data_repository_mock = MockGetUserDataRepository::default();
+
+        get_user_data_repository_mock
+            .expect_count_records()
+            .returning(move |_| Box::pin(async move { Ok(record_count) }));
+
+        get_user_data_repository_mock
+            .expect_execute()
+            .returning(|user_id| {
+                Box::pin(async move {
+                    let user_entity = UserEntityBuilder::default()
+                        .id(user_id)
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email("johndoe@gmail.com")
+                        .password("hashed_password")
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(UserDataExportDto::new(user_entity, vec![], vec![])))
+                })
+            });
+
+        get_user_data_repository_mock
+    }
+
+    fn build_archive_adapter_mock() -> MockArchiveAdapter {
+        let mut archive_adapter_mock = MockArchiveAdapter::default();
+
+        archive_adapter_mock
+            .expect_content_type()
+            .return_const("application/zip");
+
+        archive_adapter_mock
+            .expect_file_extension()
+            .return_const("zip");
+
+        archive_adapter_mock
+            .expect_build_user_data_archive()
+            .returning(|_| Ok(vec![80, 75, 5, 6]));
+
+        archive_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_return_archive_inline_for_small_exports() {
+        let mut data_export_job_adapter_mock = MockDataExportJobAdapter::default();
+
+        data_export_job_adapter_mock.expect_enqueue().times(0);
+
+        let request_data_export_use_case = RequestDataExportUseCase::new(
+            MockIdGeneratorAdapter::default(),
+            build_get_user_data_repository_mock(Some(12)),
+            build_archive_adapter_mock(),
+            MockCreateDataExportRepository::default(),
+            data_export_job_adapter_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = request_data_export_use_case
+            .perform(
+                "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let DataExportOutcome::Ready(archive) = result else {
+            panic!("expected the export to be returned inline");
+        };
+
+        assert_eq!(
+            archive.file_name,
+            "user-data-dba86129-90be-4409-a5a3-396db9335a57.zip"
+        );
+        assert_eq!(archive.content_type, "application/zip");
+        assert_eq!(archive.content, vec![80, 75, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn should_schedule_background_job_for_large_exports() {
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(|| "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string());
+
+        let mut create_data_export_repository_mock = MockCreateDataExportRepository::default();
+
+        create_data_export_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|data_export_entity| {
+                data_export_entity.status == DataExportStatus::Pending
+                    && data_export_entity.requested_by == "2b3c0e4e-0f43-4d6c-9f0c-1c3c4b7a9c11"
+            })
+            .returning(|data_export_entity| Box::pin(async move { Ok(data_export_entity) }));
+
+        let mut data_export_job_adapter_mock = MockDataExportJobAdapter::default();
+
+        data_export_job_adapter_mock
+            .expect_enqueue()
+            .times(1)
+            .withf(|data_export_id| data_export_id == "d836bc7f-014e-4818-a97f-dd1bb1987b66")
+            .return_const(());
+
+        let request_data_export_use_case = RequestDataExportUseCase::new(
+            id_generator_adapter_mock,
+            build_get_user_data_repository_mock(Some(INLINE_EXPORT_MAX_RECORDS + 1)),
+            MockArchiveAdapter::default(),
+            create_data_export_repository_mock,
+            data_export_job_adapter_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = request_data_export_use_case
+            .perform(
+                "2b3c0e4e-0f43-4d6c-9f0c-1c3c4b7a9c11".to_string(),
+                "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(result, DataExportOutcome::Scheduled(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_user_does_not_exist() {
+        let request_data_export_use_case = RequestDataExportUseCase::new(
+            MockIdGeneratorAdapter::default(),
+            build_get_user_data_repository_mock(None),
+            MockArchiveAdapter::default(),
+            MockCreateDataExportRepository::default(),
+            MockDataExportJobAdapter::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = request_data_export_use_case
+            .perform(
+                "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+                "dba86129-90be-4409-a5a3-396db9335a57".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            RequestDataExportUseCaseError::UserError(UserError::UserNotFound)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tracing::Instrument;
+
+use crate::application::ports::health::health_check_port::{
+    HealthCheckPort, HealthCheckResult, HealthStatus,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthCheckReport {
+    pub name: &'static str,
+    pub result: HealthCheckResult,
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadinessReport {
+    /// `Up` only when every registered check is up.
+    pub status: HealthStatus,
+    pub checks: Vec<HealthCheckReport>,
+}
+
+pub type CheckReadinessUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = ReadinessReport> + Send + 'a>>;
+
+pub trait CheckReadinessUseCasePort: Send + Sync {
+    fn perform(&self) -> CheckReadinessUseCaseFuture<'_>;
+}
+
+/// Runs every registered health check concurrently, each bounded by the same timeout.
+#[derive(Clone)]
+pub struct CheckReadinessUseCase {
+    health_checks: Vec<Arc<dyn HealthCheckPort>>,
+    check_timeout: Duration,
+}
+
+impl CheckReadinessUseCase {
+    #[must_use]
+    pub const fn new(check_timeout: Duration) -> Self {
+        Self {
+            health_checks: vec![],
+            check_timeout,
+        }
+    }
+
+    /// Adds a dependency to the readiness report.
+    #[must_use]
+    pub fn register(mut self, health_check: Arc<dyn HealthCheckPort>) -> Self {
+        self.health_checks.push(health_check);
+
+        self
+    }
+}
+
+impl CheckReadinessUseCasePort for CheckReadinessUseCase {
+    fn perform(&self) -> CheckReadinessUseCaseFuture<'_> {
+        let future = async move {
+            let check_timeout = self.check_timeout;
+
+            let pending_checks = self
+                .health_checks
+                .iter()
+                .map(|health_check| {
+                    let health_check = health_check.clone();
+
+                    tokio::spawn(async move {
+                        let started_at = Instant::now();
+
+                        let result = tokio::time::timeout(check_timeout, health_check.check())
+                            .await
+                            .unwrap_or_else(|_| {
+                                HealthCheckResult::down(format!(
+                                    "timed out after {}ms",
+                                    check_timeout.as_millis()
+                                ))
+                            });
+
+                        HealthCheckReport {
+                            name: health_check.name(),
+                            result,
+                            latency_ms: u64::try_from(started_at.elapsed().as_millis())
+                                .unwrap_or(u64::MAX),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut checks = Vec::with_capacity(pending_checks.len());
+
+            for (pending_check, health_check) in pending_checks.into_iter().zip(&self.health_checks)
+            {
+                let health_check_report =
+                    pending_check.await.unwrap_or_else(|err| HealthCheckReport {
+                        name: health_check.name(),
+                        result: HealthCheckResult::down(format!("check panicked: {err}")),
+                        latency_ms: 0,
+                    });
+
+                checks.push(health_check_report);
+            }
+
+            let status = if checks
+                .iter()
+                .all(|check| check.result.status == HealthStatus::Up)
+            {
+                HealthStatus::Up
+            } else {
+                HealthStatus::Down
+            };
+
+            ReadinessReport { status, checks }
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("CheckReadinessUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use mockall::mock;
+
+    use crate::application::{
+        ports::health::health_check_port::{
+            HealthCheckFuture, HealthCheckMetrics, HealthCheckPort, HealthCheckResult, HealthStatus,
+        },
+        use_cases::health::check_readiness_use_case::{
+            CheckReadinessUseCase, CheckReadinessUseCasePort,
+        },
+    };
+
+    mock! {
+        pub HealthCheckAdapter {}
+
+        impl HealthCheckPort for HealthCheckAdapter {
+            fn name(&self) -> &'static str;
+            fn check(&self) -> HealthCheckFuture<'_>;
+        }
+    }
+
+    fn build_health_check_mock(
+        name: &'static str,
+        delay: Duration,
+        result: HealthCheckResult,
+    ) -> Arc<MockHealthCheckAdapter> {
+        let mut health_check_mock = MockHealthCheckAdapter::default();
+
+        health_check_mock.expect_name().return_const(name);
+        health_check_mock.expect_check().returning(move || {
+            let result = result.clone();
+
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+
+                result
+            })
+        });
+
+        Arc::new(health_check_mock)
+    }
+
+    #[tokio::test]
+    async fn should_report_up_when_every_check_is_up() {
+        let check_readiness_use_case = CheckReadinessUseCase::new(Duration::from_secs(1))
+            .register(build_health_check_mock(
+                "database",
+                Duration::ZERO,
+                HealthCheckResult::up(HealthCheckMetrics::from([("pool_size", 2)])),
+            ))
+            .register(build_health_check_mock(
+                "storage",
+                Duration::ZERO,
+                HealthCheckResult::up(HealthCheckMetrics::new()),
+            ));
+
+        let readiness_report = check_readiness_use_case.perform().await;
+
+        assert_eq!(readiness_report.status, HealthStatus::Up);
+        assert_eq!(readiness_report.checks.len(), 2);
+        assert_eq!(readiness_report.checks[0].name, "database");
+        assert_eq!(readiness_report.checks[0].result.metrics["pool_size"], 2);
+    }
+
+    #[tokio::test]
+    async fn should_report_down_when_any_check_is_down() {
+        let check_readiness_use_case = CheckReadinessUseCase::new(Duration::from_secs(1))
+            .register(build_health_check_mock(
+                "database",
+                Duration::ZERO,
+                HealthCheckResult::up(HealthCheckMetrics::new()),
+            ))
+            .register(build_health_check_mock(
+                "storage",
+                Duration::ZERO,
+                HealthCheckResult::down("directory is read-only"),
+            ));
+
+        let readiness_report = check_readiness_use_case.perform().await;
+
+        assert_eq!(readiness_report.status, HealthStatus::Down);
+        assert_eq!(readiness_report.checks[0].result.status, HealthStatus::Up);
+        assert_eq!(
+            readiness_report.checks[1].result.error.as_deref(),
+            Some("directory is read-only")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_down_when_check_exceeds_timeout() {
+        let check_readiness_use_case = CheckReadinessUseCase::new(Duration::from_millis(20))
+            .register(build_health_check_mock(
+                "database",
+                Duration::from_secs(5),
+                HealthCheckResult::up(HealthCheckMetrics::new()),
+            ));
+
+        let readiness_report = check_readiness_use_case.perform().await;
+
+        assert_eq!(readiness_report.status, HealthStatus::Down);
+        assert_eq!(
+            readiness_report.checks[0].result.error.as_deref(),
+            Some("timed out after 20ms")
+        );
+    }
+}
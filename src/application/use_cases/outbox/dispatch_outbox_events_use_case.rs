@@ -0,0 +1,508 @@
+use std::{pin::Pin, sync::Arc};
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        clock::clock_port::ClockPort,
+        events::domain_event_handler_port::DomainEventHandlerPort,
+        repositories::outbox::{
+            claim_outbox_events_repository_port::{
+                ClaimOutboxEventsRepositoryError, ClaimOutboxEventsRepositoryPort,
+            },
+            update_outbox_event_repository_port::{
+                UpdateOutboxEventRepositoryError, UpdateOutboxEventRepositoryPort,
+            },
+        },
+    },
+    domain::entities::outbox::outbox_event_entity::{OutboxEventEntity, OutboxEventStatus},
+};
+
+/// How long a claimed event stays hidden from other dispatchers while it is being delivered.
+const CLAIM_LEASE_SECONDS: i64 = 300;
+
+/// Caps the exponential backoff at `retry_delay_seconds * 2^10`.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DispatchOutboxEventsUseCaseError {
+    DatabaseError(ClaimOutboxEventsRepositoryError),
+    RepositoryError(UpdateOutboxEventRepositoryError),
+}
+
+impl std::fmt::Display for DispatchOutboxEventsUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for DispatchOutboxEventsUseCaseError {}
+
+/// What happened to the events claimed by one dispatch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboxDispatchReport {
+    pub claimed: usize,
+    pub delivered: usize,
+    pub retried: usize,
+    pub dead_lettered: usize,
+}
+
+pub type DispatchOutboxEventsUseCaseFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<OutboxDispatchReport, DispatchOutboxEventsUseCaseError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+pub trait DispatchOutboxEventsUseCasePort: Send + Sync {
+    /// Delivers one batch of due events to every registered handler.
+    fn perform(&self) -> DispatchOutboxEventsUseCaseFuture<'_>;
+}
+
+/// Delivers outbox events to in-process handlers.
+///
+/// An event is delivered once every handler accepted it; otherwise it is retried with an
+/// exponential backoff and dead-lettered after `max_attempts` deliveries.
+#[derive(Clone)]
+pub struct DispatchOutboxEventsUseCase<C, U, K> {
+    claim_outbox_events_repository: C,
+    update_outbox_event_repository: U,
+    clock_adapter: K,
+    domain_event_handlers: Vec<Arc<dyn DomainEventHandlerPort>>,
+    batch_size: u32,
+    max_attempts: u32,
+    retry_delay_seconds: u64,
+}
+
+impl<C, U, K> DispatchOutboxEventsUseCase<C, U, K>
+where
+    C: ClaimOutboxEventsRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateOutboxEventRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        claim_outbox_events_repository: C,
+        update_outbox_event_repository: U,
+        clock_adapter: K,
+        batch_size: u32,
+        max_attempts: u32,
+        retry_delay_seconds: u64,
+    ) -> Self {
+        Self {
+            claim_outbox_events_repository,
+            update_outbox_event_repository,
+            clock_adapter,
+            domain_event_handlers: vec![],
+            batch_size,
+            max_attempts,
+            retry_delay_seconds,
+        }
+    }
+
+    /// Subscribes a handler to every event delivered from now on.
+    #[must_use]
+    pub fn register(mut self, domain_event_handler: Arc<dyn DomainEventHandlerPort>) -> Self {
+        self.domain_event_handlers.push(domain_event_handler);
+
+        self
+    }
+
+    /// Hands the event to every handler, even after one failed, and joins their errors.
+    async fn deliver(&self, outbox_event_entity: &OutboxEventEntity) -> Result<(), String> {
+        let mut errors = vec![];
+
+        for domain_event_handler in &self.domain_event_handlers {
+            if let Err(err) = domain_event_handler
+                .handle(outbox_event_entity.event.clone())
+                .await
+            {
+                errors.push(format!("{}: {err}", domain_event_handler.name()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    fn backoff_seconds(&self, attempts: u32) -> i64 {
+        let exponent = attempts.saturating_sub(1).min(MAX_BACKOFF_EXPONENT);
+
+        i64::try_from(self.retry_delay_seconds)
+            .unwrap_or(i64::MAX)
+            .saturating_mul(1 << exponent)
+    }
+}
+
+impl<C, U, K> DispatchOutboxEventsUseCasePort for DispatchOutboxEventsUseCase<C, U, K>
+where
+    C: ClaimOutboxEventsRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateOutboxEventRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self) -> DispatchOutboxEventsUseCaseFuture<'_> {
+        let future = async move {
+            let now = self.clock_adapter.now();
+
+            let outbox_event_entities = self
+                .claim_outbox_events_repository
+                .execute(
+                    now.unix_timestamp(),
+                    now.saturating_add_seconds(CLAIM_LEASE_SECONDS)
+                        .unix_timestamp(),
+                    self.batch_size,
+                )
+                .await
+                .map_err(DispatchOutboxEventsUseCaseError::DatabaseError)?;
+
+            let mut outbox_dispatch_report = OutboxDispatchReport {
+                claimed: outbox_event_entities.len(),
+                ..OutboxDispatchReport::default()
+            };
+
+            for mut outbox_event_entity in outbox_event_entities {
+                outbox_event_entity.attempts += 1;
+
+                match self.deliver(&outbox_event_entity).await {
+                    Ok(()) => {
+                        outbox_event_entity.status = OutboxEventStatus::Delivered;
+                        outbox_event_entity.last_error = None;
+                        outbox_dispatch_report.delivered += 1;
+                    }
+                    Err(error_message) if outbox_event_entity.attempts >= self.max_attempts => {
+                        tracing::error!(
+                            "Outbox event {} ({}) dead-lettered after {} attempt(s): {error_message}",
+                            outbox_event_entity.id,
+                            outbox_event_entity.event,
+                            outbox_event_entity.attempts
+                        );
+
+                        outbox_event_entity.status = OutboxEventStatus::DeadLettered;
+                        outbox_event_entity.last_error = Some(error_message);
+                        outbox_dispatch_report.dead_lettered += 1;
+                    }
+                    Err(error_message) => {
+                        tracing::warn!(
+                            "Outbox event {} ({}) failed on attempt {}: {error_message}",
+                            outbox_event_entity.id,
+                            outbox_event_entity.event,
+                            outbox_event_entity.attempts
+                        );
+
+                        outbox_event_entity.next_attempt_at = self
+                            .clock_adapter
+                            .now()
+                            .saturating_add_seconds(
+                                self.backoff_seconds(outbox_event_entity.attempts),
+                            )
+                            .unix_timestamp();
+                        outbox_event_entity.last_error = Some(error_message);
+                        outbox_dispatch_report.retried += 1;
+                    }
+                }
+
+                self.update_outbox_event_repository
+                    .execute(outbox_event_entity)
+                    .await
+                    .map_err(DispatchOutboxEventsUseCaseError::RepositoryError)?;
+            }
+
+            Ok(outbox_dispatch_report)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("DispatchOutboxEventsUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                events::domain_event_handler_port::{
+                    DomainEventHandlerError, DomainEventHandlerFuture, DomainEventHandlerPort,
+                },
+                repositories::outbox::{
+                    claim_outbox_events_repository_port::{
+                        ClaimOutboxEventsRepositoryError, ClaimOutboxEventsRepositoryFuture,
+                        ClaimOutboxEventsRepositoryPort,
+                    },
+                    update_outbox_event_repository_port::{
+                        UpdateOutboxEventRepositoryFuture, UpdateOutboxEventRepositoryPort,
+                    },
+                },
+            },
+            use_cases::outbox::dispatch_outbox_events_use_case::{
+                DispatchOutboxEventsUseCase, DispatchOutboxEventsUseCaseError,
+                DispatchOutboxEventsUseCasePort, OutboxDispatchReport,
+            },
+        },
+        domain::{
+            entities::outbox::outbox_event_entity::{OutboxEventEntity, OutboxEventStatus},
+            events::domain_event::DomainEvent,
+            value_objects::user::user_id::UserId,
+        },
+        infrastructure::adapters::clock::manual_clock_adapter::ManualClockAdapter,
+    };
+
+    const NOW: i64 = 1_695_996_669;
+    const USER_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+
+    mock! {
+        pub ClaimOutboxEventsRepository {}
+
+        impl ClaimOutboxEventsRepositoryPort for ClaimOutboxEventsRepository {
+            fn execute(
+                &self,
+                now: i64,
+                lease_until: i64,
+                limit: u32,
+            ) -> ClaimOutboxEventsRepositoryFuture<'_>;
+        }
+
+        impl Clone for ClaimOutboxEventsRepository {
+            fn clone(&self) -> Self {
+                MockClaimOutboxEventsRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateOutboxEventRepository {}
+
+        impl UpdateOutboxEventRepositoryPort for UpdateOutboxEventRepository {
+            fn execute(
+                &self,
+                outbox_event_entity: OutboxEventEntity,
+            ) -> UpdateOutboxEventRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateOutboxEventRepository {
+            fn clone(&self) -> Self {
+                MockUpdateOutboxEventRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub DomainEventHandler {}
+
+        impl DomainEventHandlerPort for DomainEventHandler {
+            fn name(&self) -> &'static str;
+            fn handle(&self, domain_event: DomainEvent) -> DomainEventHandlerFuture<'_>;
+        }
+    }
+
+    fn build_outbox_event_entity(attempts: u32) -> OutboxEventEntity {
+        let mut outbox_event_entity = OutboxEventEntity::new(
+            "d836bc7f-014e-4818-a97f-dd1bb1987b66".to_string(),
+            DomainEvent::PasswordChanged {
+                user_id: UserId::parse(USER_ID).unwrap(),
+            },
+            NOW - 60,
+        );
+
+        outbox_event_entity.attempts = attempts;
+
+        outbox_event_entity
+    }
+
+    fn build_claim_outbox_events_repository_mock(attempts: u32) -> MockClaimOutboxEventsRepository {
+        let mut claim_outbox_events_repository_mock = MockClaimOutboxEventsRepository::default();
+
+        claim_outbox_events_repository_mock
+            .expect_execute()
+            .times(1)
+            .returning(move |_, _, _| {
+                Box::pin(async move { Ok(vec![build_outbox_event_entity(attempts)]) })
+            });
+
+        claim_outbox_events_repository_mock
+    }
+
+    fn build_domain_event_handler_mock(
+        result: Result<(), DomainEventHandlerError>,
+    ) -> MockDomainEventHandler {
+        let mut domain_event_handler_mock = MockDomainEventHandler::default();
+
+        domain_event_handler_mock
+            .expect_name()
+            .return_const("welcome_email");
+
+        domain_event_handler_mock
+            .expect_handle()
+            .times(1)
+            .returning(move |_| {
+                let result = result.clone();
+
+                Box::pin(async move { result })
+            });
+
+        domain_event_handler_mock
+    }
+
+    #[tokio::test]
+    async fn should_deliver_claimed_events_to_every_handler() {
+        let mut claim_outbox_events_repository_mock = MockClaimOutboxEventsRepository::default();
+
+        claim_outbox_events_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|now, lease_until, limit| {
+                *now == NOW && *lease_until == NOW + 300 && *limit == 50
+            })
+            .returning(|_, _, _| Box::pin(async move { Ok(vec![build_outbox_event_entity(0)]) }));
+
+        let mut update_outbox_event_repository_mock = MockUpdateOutboxEventRepository::default();
+
+        update_outbox_event_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|outbox_event_entity| {
+                outbox_event_entity.status == OutboxEventStatus::Delivered
+                    && outbox_event_entity.attempts == 1
+                    && outbox_event_entity.last_error.is_none()
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let dispatch_outbox_events_use_case = DispatchOutboxEventsUseCase::new(
+            claim_outbox_events_repository_mock,
+            update_outbox_event_repository_mock,
+            ManualClockAdapter::new(NOW),
+            50,
+            3,
+            5,
+        )
+        .register(Arc::new(build_domain_event_handler_mock(Ok(()))))
+        .register(Arc::new(build_domain_event_handler_mock(Ok(()))));
+
+        let result = dispatch_outbox_events_use_case.perform().await;
+
+        assert_eq!(
+            result.unwrap(),
+            OutboxDispatchReport {
+                claimed: 1,
+                delivered: 1,
+                retried: 0,
+                dead_lettered: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_retry_with_backoff_if_a_handler_fails() {
+        let mut update_outbox_event_repository_mock = MockUpdateOutboxEventRepository::default();
+
+        update_outbox_event_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|outbox_event_entity| {
+                outbox_event_entity.status == OutboxEventStatus::Pending
+                    && outbox_event_entity.attempts == 2
+                    && outbox_event_entity.next_attempt_at == NOW + 10
+                    && outbox_event_entity.last_error.as_deref()
+                        == Some("welcome_email: handling error: smtp unavailable")
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let dispatch_outbox_events_use_case = DispatchOutboxEventsUseCase::new(
+            build_claim_outbox_events_repository_mock(1),
+            update_outbox_event_repository_mock,
+            ManualClockAdapter::new(NOW),
+            50,
+            3,
+            5,
+        )
+        .register(Arc::new(build_domain_event_handler_mock(Err(
+            DomainEventHandlerError::HandlingError {
+                message: "smtp unavailable".to_string(),
+            },
+        ))));
+
+        let result = dispatch_outbox_events_use_case.perform().await;
+
+        assert_eq!(result.unwrap().retried, 1);
+    }
+
+    #[tokio::test]
+    async fn should_dead_letter_event_once_max_attempts_are_reached() {
+        let mut update_outbox_event_repository_mock = MockUpdateOutboxEventRepository::default();
+
+        update_outbox_event_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|outbox_event_entity| {
+                outbox_event_entity.status == OutboxEventStatus::DeadLettered
+                    && outbox_event_entity.attempts == 3
+                    && outbox_event_entity.last_error.is_some()
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let dispatch_outbox_events_use_case = DispatchOutboxEventsUseCase::new(
+            build_claim_outbox_events_repository_mock(2),
+            update_outbox_event_repository_mock,
+            ManualClockAdapter::new(NOW),
+            50,
+            3,
+            5,
+        )
+        .register(Arc::new(build_domain_event_handler_mock(Err(
+            DomainEventHandlerError::HandlingError {
+                message: "smtp unavailable".to_string(),
+            },
+        ))));
+
+        let result = dispatch_outbox_events_use_case.perform().await;
+
+        assert_eq!(result.unwrap().dead_lettered, 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_claim_fails() {
+        let mut claim_outbox_events_repository_mock = MockClaimOutboxEventsRepository::default();
+
+        claim_outbox_events_repository_mock
+            .expect_execute()
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(ClaimOutboxEventsRepositoryError::ClaimError {
+                        message: "connection refused".to_string(),
+                    })
+                })
+            });
+
+        let mut update_outbox_event_repository_mock = MockUpdateOutboxEventRepository::default();
+
+        update_outbox_event_repository_mock
+            .expect_execute()
+            .times(0);
+
+        let dispatch_outbox_events_use_case = DispatchOutboxEventsUseCase::new(
+            claim_outbox_events_repository_mock,
+            update_outbox_event_repository_mock,
+            ManualClockAdapter::new(NOW),
+            50,
+            3,
+            5,
+        );
+
+        let result = dispatch_outbox_events_use_case.perform().await;
+
+        assert!(matches!(
+            result,
+            Err(DispatchOutboxEventsUseCaseError::DatabaseError(
+                ClaimOutboxEventsRepositoryError::ClaimError { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::application::{
+    dtos::user::avatar_dto::AvatarDto,
+    ports::{
+        file_storage::file_storage_port::{FileStorageError, FileStoragePort},
+        image_processing::image_processing_port::ImageProcessingPort,
+        repositories::user::get_user_by_id_repository_port::{
+            GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+        },
+    },
+    use_cases::user::upload_avatar_use_case::{AVATAR_SIZES, avatar_file_key},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetAvatarUseCaseError {
+    InvalidAvatarSize,
+    FileStorageError(FileStorageError),
+    DatabaseError(GetUserByIdRepositoryError),
+}
+
+impl std::fmt::Display for GetAvatarUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidAvatarSize => write!(
+                f,
+                "the avatar size must be one of: {}",
+                AVATAR_SIZES.map(|size| size.to_string()).join(", ")
+            ),
+            Self::FileStorageError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for GetAvatarUseCaseError {}
+
+pub type GetAvatarUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<AvatarDto>, GetAvatarUseCaseError>> + Send + 'a>>;
+
+pub trait GetAvatarUseCasePort: Send + Sync {
+    /// Returns the avatar thumbnail of the requested size, or the largest one when no size is
+    /// given. Returns `None` if the user does not exist or has no avatar.
+    fn perform(&self, user_id: String, size: Option<u32>) -> GetAvatarUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct GetAvatarUseCase<G, F, P> {
+    get_user_by_id_repository: G,
+    file_storage_adapter: F,
+    image_processing_adapter: P,
+}
+
+impl<G, F, P> GetAvatarUseCase<G, F, P>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    F: FileStoragePort + Send + Sync + Clone + 'static,
+    P: ImageProcessingPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        get_user_by_id_repository: G,
+        file_storage_adapter: F,
+        image_processing_adapter: P,
+    ) -> Self {
+        Self {
+            get_user_by_id_repository,
+            file_storage_adapter,
+            image_processing_adapter,
+        }
+    }
+}
+
+impl<G, F, P> GetAvatarUseCasePort for GetAvatarUseCase<G, F, P>
+where
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    F: FileStoragePort + Send + Sync + Clone + 'static,
+    P: ImageProcessingPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, user_id: String, size: Option<u32>) -> GetAvatarUseCaseFuture<'_> {
+        let future = async move {
+            let size = size.unwrap_or(AVATAR_SIZES[AVATAR_SIZES.len() - 1]);
+
+            if !AVATAR_SIZES.contains(&size) {
+                return Err(GetAvatarUseCaseError::InvalidAvatarSize);
+            }
+
+            let Some(user) = self
+                .get_user_by_id_repository
+                .execute(user_id)
+                .await
+                .map_err(GetAvatarUseCaseError::DatabaseError)?
+            else {
+                return Ok(None);
+            };
+
+            let (Some(avatar_key), Some(avatar_version)) =
+                (user.avatar_key.as_deref(), user.avatar_version())
+            else {
+                return Ok(None);
+            };
+
+            let content = self
+                .file_storage_adapter
+                .get(avatar_file_key(avatar_key, size))
+                .await
+                .map_err(GetAvatarUseCaseError::FileStorageError)?;
+
+            Ok(content.map(|content| {
+                AvatarDto::new(
+                    content,
+                    self.image_processing_adapter
+                        .thumbnail_content_type()
+                        .to_string(),
+                    format!("\"{avatar_version}-{size}\""),
+                )
+            }))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GetAvatarUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                file_storage::file_storage_port::FileStoragePort,
+                image_processing::image_processing_port::{
+                    ImageProcessingError, ImageProcessingPort,
+                },
+                repositories::user::get_user_by_id_repository_port::{
+                    GetUserByIdFuture, GetUserByIdRepositoryPort,
+                },
+            },
+            use_cases::user::get_avatar_use_case::{
+                GetAvatarUseCase, GetAvatarUseCaseError, GetAvatarUseCasePort,
+            },
+        },
+        domain::entities::user::user_entity::UserEntityBuilder,
+        infrastructure::adapters::file_storage::in_memory_file_storage_adapter::InMemoryFileStorageAdapter,
+    };
+
+    const USER_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub ImageProcessingAdapter {}
+
+        impl ImageProcessingPort for ImageProcessingAdapter {
+            fn sniff_content_type(&self, content: &[u8]) -> Option<&'static str>;
+            fn thumbnail_content_type(&self) -> &'static str;
+            fn thumbnails(
+                &self,
+                content: &[u8],
+                sizes: &[u32],
+            ) -> Result<Vec<Vec<u8>>, ImageProcessingError>;
+        }
+
+        impl Clone for ImageProcessingAdapter {
+            fn clone(&self) -> Self {
+                MockImageProcessingAdapter::new()
+            }
+        }
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        avatar_key: Option<&'static str>,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |id| {
+                Box::pin(async move {
+                    Ok(Some(
+                        UserEntityBuilder::default()
+                            .id(id)
+                            .first_name("John")
+                            .last_name("Doe")
+                            .email("johndoe@gmail.com")
+                            .password("hashed_password")
+                            .avatar_key(avatar_key.map(ToString::to_string))
+                            .build()
+                            .unwrap(),
+                    ))
+                })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    fn build_image_processing_adapter_mock() -> MockImageProcessingAdapter {
+        let mut image_processing_adapter_mock = MockImageProcessingAdapter::default();
+
+        image_processing_adapter_mock
+            .expect_thumbnail_content_type()
+            .return_const("image/png");
+
+        image_processing_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_return_requested_thumbnail() {
+        let file_storage_adapter = InMemoryFileStorageAdapter::new();
+
+        file_storage_adapter
+            .put(format!("avatars/{USER_ID}/v1/64"), vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        let get_avatar_use_case = GetAvatarUseCase::new(
+            build_get_user_by_id_repository_mock(Some(
+                "avatars/dba86129-90be-4409-a5a3-396db9335a57/v1",
+            )),
+            file_storage_adapter,
+            build_image_processing_adapter_mock(),
+        );
+
+        let result = get_avatar_use_case
+            .perform(USER_ID.to_string(), Some(64))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.content, vec![1, 2, 3]);
+        assert_eq!(result.content_type, "image/png");
+        assert_eq!(result.etag, "\"v1-64\"");
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_user_has_no_avatar() {
+        let get_avatar_use_case = GetAvatarUseCase::new(
+            build_get_user_by_id_repository_mock(None),
+            InMemoryFileStorageAdapter::new(),
+            build_image_processing_adapter_mock(),
+        );
+
+        let result = get_avatar_use_case
+            .perform(USER_ID.to_string(), None)
+            .await
+            .unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_size_is_not_generated() {
+        let get_avatar_use_case = GetAvatarUseCase::new(
+            build_get_user_by_id_repository_mock(None),
+            InMemoryFileStorageAdapter::new(),
+            build_image_processing_adapter_mock(),
+        );
+
+        let result = get_avatar_use_case
+            .perform(USER_ID.to_string(), Some(100))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            GetAvatarUseCaseError::InvalidAvatarSize
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        email_normalizer::email_normalizer_port::EmailNormalizerPort,
+        repositories::user::get_user_by_email_repository_port::{
+            GetUserByEmailRepositoryError, GetUserByEmailRepositoryPort,
+        },
+    },
+    domain::entities::user::user_entity::UserEntity,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GetUserByEmailUseCaseError {
+    RepositoryError(GetUserByEmailRepositoryError),
+}
+
+impl std::fmt::Display for GetUserByEmailUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for GetUserByEmailUseCaseError {}
+
+pub type GetUserByEmailUseCaseFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<Option<UserEntity>, GetUserByEmailUseCaseError>> + Send + 'a>,
+>;
+
+pub trait GetUserByEmailUseCasePort: Send + Sync {
+    fn perform(&self, email: String) -> GetUserByEmailUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct GetUserByEmailUseCase<G, N> {
+    get_user_by_email_repository: G,
+    email_normalizer_adapter: N,
+}
+
+impl<G, N> GetUserByEmailUseCase<G, N>
+where
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(get_user_by_email_repository: G, email_normalizer_adapter: N) -> Self {
+        Self {
+            get_user_by_email_repository,
+            email_normalizer_adapter,
+        }
+    }
+}
+
+impl<G, N> GetUserByEmailUseCasePort for GetUserByEmailUseCase<G, N>
+where
+    G: GetUserByEmailRepositoryPort + Send + Sync + Clone + 'static,
+    N: EmailNormalizerPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, email: String) -> GetUserByEmailUseCaseFuture<'_> {
+        let future = async move {
+            let user_entity = self
+                .get_user_by_email_repository
+                .execute(self.email_normalizer_adapter.normalize(&email))
+                .await
+                .map_err(GetUserByEmailUseCaseError::RepositoryError)?;
+
+            Ok(user_entity)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GetUserByEmailUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                email_normalizer::email_normalizer_port::EmailNormalizerPort,
+                repositories::user::get_user_by_email_repository_port::{
+                    GetUserByEmailRepositoryError, GetUserByEmailRepositoryFuture,
+                    GetUserByEmailRepositoryPort,
+                },
+            },
+            use_cases::user::get_user_by_email_use_case::{
+                GetUserByEmailUseCase, GetUserByEmailUseCaseError, GetUserByEmailUseCasePort,
+            },
+        },
+        domain::entities::user::user_entity::UserEntityBuilder,
+    };
+
+    mock! {
+        pub GetUserByEmailRepository {}
+
+        impl GetUserByEmailRepositoryPort for GetUserByEmailRepository {
+            fn execute(&self, email: String) -> GetUserByEmailRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetUserByEmailRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByEmailRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub EmailNormalizerAdapter {}
+
+        impl EmailNormalizerPort for EmailNormalizerAdapter {
+            fn normalize(&self, email: &str) -> String;
+        }
+
+        impl Clone for EmailNormalizerAdapter {
+            fn clone(&self) -> Self {
+                MockEmailNormalizerAdapter::new()
+            }
+        }
+    }
+
+    fn build_email_normalizer_adapter_mock() -> MockEmailNormalizerAdapter {
+        let mut email_normalizer_adapter_mock = MockEmailNormalizerAdapter::default();
+
+        email_normalizer_adapter_mock
+            .expect_normalize()
+            .returning(|email| email.trim().to_string());
+
+        email_normalizer_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_successfully_call_get_user_by_email_repository() {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .withf(|email| email == "johndoe@gmail.com")
+            .returning(|email| {
+                Box::pin(async move {
+                    let user_entity = UserEntityBuilder::default()
+                        .id("dba86129-90be-4409-a5a3-396db9335a57")
+                        .first_name("John")
+                        .last_name("Doe")
+                        .email(&email)
+                        .password("hashed_password")
+                        .build()
+                        .unwrap();
+
+                    Ok(Some(user_entity))
+                })
+            });
+
+        let get_user_by_email_use_case = GetUserByEmailUseCase::new(
+            get_user_by_email_repository_mock,
+            build_email_normalizer_adapter_mock(),
+        );
+
+        let result = get_user_by_email_use_case
+            .perform(" johndoe@gmail.com ".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            result.id.to_string(),
+            "dba86129-90be-4409-a5a3-396db9335a57"
+        );
+        assert_eq!(result.email.as_str(), "johndoe@gmail.com");
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_get_user_by_email_repository_fails() {
+        let mut get_user_by_email_repository_mock = MockGetUserByEmailRepository::default();
+
+        get_user_by_email_repository_mock
+            .expect_execute()
+            .returning(|_| {
+                Box::pin(async move {
+                    Err(GetUserByEmailRepositoryError::FindByEmailError {
+                        message: "find by e-mail error".to_string(),
+                    })
+                })
+            });
+
+        let get_user_by_email_use_case = GetUserByEmailUseCase::new(
+            get_user_by_email_repository_mock,
+            build_email_normalizer_adapter_mock(),
+        );
+
+        let result = get_user_by_email_use_case
+            .perform("johndoe@gmail.com".to_string())
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            GetUserByEmailUseCaseError::RepositoryError(
+                GetUserByEmailRepositoryError::FindByEmailError { message: _ }
+            )
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use std::pin::Pin;
 
+use tracing::Instrument;
+
 use crate::{
     application::ports::repositories::user::get_user_by_id_repository_port::{
         GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
@@ -50,7 +52,7 @@ where
     G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
 {
     fn perform(&self, id: String) -> GetUserByIdUseCaseFuture<'_> {
-        Box::pin(async move {
+        let future = async move {
             let user_entity = self
                 .get_user_by_id_repository
                 .execute(id)
@@ -58,7 +60,9 @@ where
                 .map_err(GetUserByIdUseCaseError::RepositoryError)?;
 
             Ok(user_entity)
-        })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("GetUserByIdUseCase::perform")))
     }
 }
 
@@ -109,7 +113,8 @@ mod tests {
                         .is_admin(false)
                         .created_at(1_695_996_669)
                         .updated_at(1_695_996_669)
-                        .build();
+                        .build()
+                        .unwrap();
 
                     Ok(Some(user_entity))
                 })
@@ -127,19 +132,19 @@ mod tests {
 
         let user = content.unwrap();
 
-        assert_eq!(user.id, "dba86129-90be-4409-a5a3-396db9335a57");
-        assert_eq!(user.first_name, "John");
-        assert_eq!(user.last_name, "Doe");
-        assert_eq!(user.email, "johndoe@gmail.com");
+        assert_eq!(user.id.to_string(), "dba86129-90be-4409-a5a3-396db9335a57");
+        assert_eq!(user.first_name.as_str(), "John");
+        assert_eq!(user.last_name.as_str(), "Doe");
+        assert_eq!(user.email.as_str(), "johndoe@gmail.com");
 
         assert_eq!(
-            user.password,
+            user.password.as_str(),
             "$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa."
         );
 
         assert!(!user.is_admin);
-        assert_eq!(user.created_at, 1_695_996_669);
-        assert_eq!(user.updated_at, 1_695_996_669);
+        assert_eq!(user.created_at.unix_timestamp(), 1_695_996_669);
+        assert_eq!(user.updated_at.unix_timestamp(), 1_695_996_669);
     }
 
     #[tokio::test]
@@ -0,0 +1,241 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::application::ports::user_search::user_search_port::{
+    UserSearchError, UserSearchPage, UserSearchPort, UserSearchQuery,
+};
+
+pub const DEFAULT_SEARCH_PAGE_SIZE: u32 = 20;
+pub const MAX_SEARCH_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SearchUsersUseCaseError {
+    EmptySearchTerm,
+    SearchError(UserSearchError),
+}
+
+impl std::fmt::Display for SearchUsersUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySearchTerm => write!(f, "the provided search term is empty"),
+            Self::SearchError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SearchUsersUseCaseError {}
+
+pub type SearchUsersUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserSearchPage, SearchUsersUseCaseError>> + Send + 'a>>;
+
+pub trait SearchUsersUseCasePort: Send + Sync {
+    fn perform(&self, user_search_query: UserSearchQuery) -> SearchUsersUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct SearchUsersUseCase<S> {
+    user_search_adapter: S,
+}
+
+impl<S> SearchUsersUseCase<S>
+where
+    S: UserSearchPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(user_search_adapter: S) -> Self {
+        Self {
+            user_search_adapter,
+        }
+    }
+}
+
+impl<S> SearchUsersUseCasePort for SearchUsersUseCase<S>
+where
+    S: UserSearchPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, user_search_query: UserSearchQuery) -> SearchUsersUseCaseFuture<'_> {
+        let future = async move {
+            let term = user_search_query.term.trim().to_string();
+
+            if term.is_empty() {
+                return Err(SearchUsersUseCaseError::EmptySearchTerm);
+            }
+
+            let page = user_search_query.page.max(1);
+            let per_page = user_search_query.per_page.clamp(1, MAX_SEARCH_PAGE_SIZE);
+
+            let user_search_page = self
+                .user_search_adapter
+                .search(UserSearchQuery::new(term, page, per_page))
+                .await
+                .map_err(SearchUsersUseCaseError::SearchError)?;
+
+            Ok(user_search_page)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("SearchUsersUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            ports::user_search::user_search_port::UserSearchQuery,
+            use_cases::user::search_users_use_case::{
+                MAX_SEARCH_PAGE_SIZE, SearchUsersUseCase, SearchUsersUseCaseError,
+                SearchUsersUseCasePort,
+            },
+        },
+        domain::entities::user::user_entity::{UserEntity, UserEntityBuilder},
+        infrastructure::repositories::user::in_memory_user_search_repository::InMemoryUserSearchRepository,
+    };
+
+    fn build_user(id: &str, first_name: &str, last_name: &str, email: &str) -> UserEntity {
+        UserEntityBuilder::default()
+            .id(id)
+            .first_name(first_name)
+            .last_name(last_name)
+            .email(email)
+            .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+            .is_admin(false)
+            .created_at(1_695_996_669)
+            .updated_at(1_695_996_669)
+            .build()
+            .unwrap()
+    }
+
+    fn build_repository() -> InMemoryUserSearchRepository {
+        InMemoryUserSearchRepository::new(vec![
+            build_user(
+                "dba86129-90be-4409-a5a3-396db9335a57",
+                "John",
+                "Doe",
+                "johndoe@gmail.com",
+            ),
+            build_user(
+                "d836bc7f-014e-4818-a97f-dd1bb1987b66",
+                "Jane",
+                "Doe",
+                "janedoe@outlook.com",
+            ),
+            build_user(
+                "5b0f3d0e-6f5c-4a8e-9a43-2b1c4f1f2a10",
+                "Alice",
+                "Smith",
+                "alice.smith@yahoo.com",
+            ),
+        ])
+    }
+
+    #[tokio::test]
+    async fn should_return_ranked_and_highlighted_users() {
+        let search_users_use_case = SearchUsersUseCase::new(build_repository());
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("john doe".to_string(), 1, 20))
+            .await;
+
+        assert!(result.is_ok());
+
+        let user_search_page = result.unwrap();
+
+        assert_eq!(user_search_page.total, 2);
+        assert_eq!(user_search_page.hits[0].user.first_name.as_str(), "John");
+        assert_eq!(
+            user_search_page.hits[0].highlights.first_name,
+            "<mark>John</mark>"
+        );
+        assert!(user_search_page.hits[0].rank > user_search_page.hits[1].rank);
+    }
+
+    #[tokio::test]
+    async fn should_match_misspelled_terms() {
+        let search_users_use_case = SearchUsersUseCase::new(build_repository());
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("smiht".to_string(), 1, 20))
+            .await;
+
+        assert!(result.is_ok());
+
+        let user_search_page = result.unwrap();
+
+        assert_eq!(user_search_page.total, 1);
+        assert_eq!(user_search_page.hits[0].user.last_name.as_str(), "Smith");
+    }
+
+    #[tokio::test]
+    async fn should_escape_user_content_in_highlights() {
+        let search_users_use_case =
+            SearchUsersUseCase::new(InMemoryUserSearchRepository::new(vec![build_user(
+                "dba86129-90be-4409-a5a3-396db9335a57",
+                "<img src=x onerror=alert(1)>",
+                "Doe",
+                "johndoe@gmail.com",
+            )]));
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("img".to_string(), 1, 20))
+            .await;
+
+        assert!(result.is_ok());
+
+        let user_search_page = result.unwrap();
+
+        assert_eq!(user_search_page.total, 1);
+        assert_eq!(
+            user_search_page.hits[0].highlights.first_name,
+            "&lt;<mark>img</mark> src=x onerror=alert(1)&gt;"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_paginate_results() {
+        let search_users_use_case = SearchUsersUseCase::new(build_repository());
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("doe".to_string(), 2, 1))
+            .await;
+
+        assert!(result.is_ok());
+
+        let user_search_page = result.unwrap();
+
+        assert_eq!(user_search_page.total, 2);
+        assert_eq!(user_search_page.hits.len(), 1);
+        assert_eq!(user_search_page.page, 2);
+        assert_eq!(user_search_page.per_page, 1);
+    }
+
+    #[tokio::test]
+    async fn should_clamp_pagination_parameters() {
+        let search_users_use_case = SearchUsersUseCase::new(build_repository());
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("doe".to_string(), 0, 10_000))
+            .await;
+
+        assert!(result.is_ok());
+
+        let user_search_page = result.unwrap();
+
+        assert_eq!(user_search_page.page, 1);
+        assert_eq!(user_search_page.per_page, MAX_SEARCH_PAGE_SIZE);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_search_term_is_empty() {
+        let search_users_use_case = SearchUsersUseCase::new(build_repository());
+
+        let result = search_users_use_case
+            .perform(UserSearchQuery::new("   ".to_string(), 1, 20))
+            .await;
+
+        assert!(result.is_err());
+
+        let error = result.unwrap_err();
+
+        assert!(matches!(error, SearchUsersUseCaseError::EmptySearchTerm));
+    }
+}
@@ -0,0 +1,510 @@
+use std::pin::Pin;
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        clock::clock_port::ClockPort,
+        file_storage::file_storage_port::{FileStorageError, FileStoragePort},
+        id_generator::id_generator_port::IdGeneratorPort,
+        image_processing::image_processing_port::{ImageProcessingError, ImageProcessingPort},
+        repositories::user::{
+            get_user_by_id_repository_port::{
+                GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
+            },
+            update_user_repository_port::{UpdateUserRepositoryError, UpdateUserRepositoryPort},
+        },
+    },
+    domain::{
+        entities::{
+            audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+            user::user_entity::UserEntity,
+        },
+        errors::user::user_errors::UserError,
+    },
+};
+
+pub const MAX_AVATAR_BYTES: usize = 2 * 1024 * 1024;
+
+/// Edge lengths, in pixels, of the square thumbnails generated for every avatar.
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 256];
+
+pub const ALLOWED_AVATAR_CONTENT_TYPES: [&str; 4] =
+    ["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// Returns the storage key of a single thumbnail of the avatar stored under `avatar_key`.
+#[must_use]
+pub fn avatar_file_key(avatar_key: &str, size: u32) -> String {
+    format!("{avatar_key}/{size}")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UploadAvatarUseCaseError {
+    AvatarTooLarge,
+    UnsupportedAvatarType,
+    UserError(UserError),
+    ImageProcessingError(ImageProcessingError),
+    FileStorageError(FileStorageError),
+    DatabaseError(GetUserByIdRepositoryError),
+    RepositoryError(UpdateUserRepositoryError),
+}
+
+impl std::fmt::Display for UploadAvatarUseCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AvatarTooLarge => write!(
+                f,
+                "the avatar must not be larger than {} MiB",
+                MAX_AVATAR_BYTES / 1024 / 1024
+            ),
+            Self::UnsupportedAvatarType => write!(
+                f,
+                "the avatar must be one of: {}",
+                ALLOWED_AVATAR_CONTENT_TYPES.join(", ")
+            ),
+            Self::UserError(error) => write!(f, "{error}"),
+            Self::ImageProcessingError(error) => write!(f, "{error}"),
+            Self::FileStorageError(error) => write!(f, "{error}"),
+            Self::DatabaseError(error) => write!(f, "{error}"),
+            Self::RepositoryError(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for UploadAvatarUseCaseError {}
+
+pub type UploadAvatarUseCaseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<UserEntity, UploadAvatarUseCaseError>> + Send + 'a>>;
+
+pub trait UploadAvatarUseCasePort: Send + Sync {
+    fn perform(&self, user_id: String, content: Vec<u8>) -> UploadAvatarUseCaseFuture<'_>;
+}
+
+#[derive(Clone)]
+pub struct UploadAvatarUseCase<P, F, I, G, U, K> {
+    image_processing_adapter: P,
+    file_storage_adapter: F,
+    id_generator_adapter: I,
+    get_user_by_id_repository: G,
+    update_user_repository: U,
+    clock_adapter: K,
+}
+
+impl<P, F, I, G, U, K> UploadAvatarUseCase<P, F, I, G, U, K>
+where
+    P: ImageProcessingPort + Send + Sync + Clone + 'static,
+    F: FileStoragePort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        image_processing_adapter: P,
+        file_storage_adapter: F,
+        id_generator_adapter: I,
+        get_user_by_id_repository: G,
+        update_user_repository: U,
+        clock_adapter: K,
+    ) -> Self {
+        Self {
+            image_processing_adapter,
+            file_storage_adapter,
+            id_generator_adapter,
+            get_user_by_id_repository,
+            update_user_repository,
+            clock_adapter,
+        }
+    }
+
+    /// Removes the thumbnails of a replaced avatar. Failures only leave orphaned files behind,
+    /// so they are not reported to the caller.
+    async fn delete_avatar(&self, avatar_key: &str) {
+        for size in AVATAR_SIZES {
+            let _ = self
+                .file_storage_adapter
+                .delete(avatar_file_key(avatar_key, size))
+                .await;
+        }
+    }
+}
+
+impl<P, F, I, G, U, K> UploadAvatarUseCasePort for UploadAvatarUseCase<P, F, I, G, U, K>
+where
+    P: ImageProcessingPort + Send + Sync + Clone + 'static,
+    F: FileStoragePort + Send + Sync + Clone + 'static,
+    I: IdGeneratorPort + Send + Sync + Clone + 'static,
+    G: GetUserByIdRepositoryPort + Send + Sync + Clone + 'static,
+    U: UpdateUserRepositoryPort + Send + Sync + Clone + 'static,
+    K: ClockPort + Send + Sync + Clone + 'static,
+{
+    fn perform(&self, user_id: String, content: Vec<u8>) -> UploadAvatarUseCaseFuture<'_> {
+        let future = async move {
+            if content.len() > MAX_AVATAR_BYTES {
+                return Err(UploadAvatarUseCaseError::AvatarTooLarge);
+            }
+
+            let is_allowed_type = self
+                .image_processing_adapter
+                .sniff_content_type(&content)
+                .is_some_and(|content_type| ALLOWED_AVATAR_CONTENT_TYPES.contains(&content_type));
+
+            if !is_allowed_type {
+                return Err(UploadAvatarUseCaseError::UnsupportedAvatarType);
+            }
+
+            let mut user = self
+                .get_user_by_id_repository
+                .execute(user_id)
+                .await
+                .map_err(UploadAvatarUseCaseError::DatabaseError)?
+                .ok_or(UploadAvatarUseCaseError::UserError(UserError::UserNotFound))?;
+
+            let thumbnails = self
+                .image_processing_adapter
+                .thumbnails(&content, &AVATAR_SIZES)
+                .map_err(UploadAvatarUseCaseError::ImageProcessingError)?;
+
+            let avatar_key = format!(
+                "avatars/{}/{}",
+                user.id,
+                self.id_generator_adapter.generate_id()
+            );
+
+            for (size, thumbnail) in AVATAR_SIZES.into_iter().zip(thumbnails) {
+                self.file_storage_adapter
+                    .put(avatar_file_key(&avatar_key, size), thumbnail)
+                    .await
+                    .map_err(UploadAvatarUseCaseError::FileStorageError)?;
+            }
+
+            let now = self.clock_adapter.now();
+            let previous_avatar_key = user.avatar_key.replace(avatar_key.clone());
+
+            user.updated_at = now;
+
+            let audit_entry_entity = AuditEntryEntity::new(
+                self.id_generator_adapter.generate_id(),
+                Some(user.id.to_string()),
+                AuditAction::AvatarUpdated,
+                user.id.to_string(),
+                now.unix_timestamp(),
+            );
+
+            let updated_user = match self
+                .update_user_repository
+                .execute(user, audit_entry_entity, vec![])
+                .await
+            {
+                Ok(updated_user) => updated_user,
+                Err(err) => {
+                    self.delete_avatar(&avatar_key).await;
+
+                    return Err(UploadAvatarUseCaseError::RepositoryError(err));
+                }
+            };
+
+            if let Some(previous_avatar_key) = previous_avatar_key {
+                self.delete_avatar(&previous_avatar_key).await;
+            }
+
+            Ok(updated_user)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("UploadAvatarUseCase::perform")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            ports::{
+                file_storage::file_storage_port::FileStoragePort,
+                id_generator::id_generator_port::IdGeneratorPort,
+                image_processing::image_processing_port::{
+                    ImageProcessingError, ImageProcessingPort,
+                },
+                repositories::user::{
+                    get_user_by_id_repository_port::{
+                        GetUserByIdFuture, GetUserByIdRepositoryPort,
+                    },
+                    update_user_repository_port::{
+                        UpdateUserRepositoryError, UpdateUserRepositoryFuture,
+                        UpdateUserRepositoryPort,
+                    },
+                },
+            },
+            use_cases::user::upload_avatar_use_case::{
+                MAX_AVATAR_BYTES, UploadAvatarUseCase, UploadAvatarUseCaseError,
+                UploadAvatarUseCasePort,
+            },
+        },
+        domain::entities::{
+            audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+            outbox::outbox_event_entity::OutboxEventEntity,
+            user::user_entity::{UserEntity, UserEntityBuilder},
+        },
+        infrastructure::adapters::{
+            clock::manual_clock_adapter::ManualClockAdapter,
+            file_storage::in_memory_file_storage_adapter::InMemoryFileStorageAdapter,
+        },
+    };
+
+    const USER_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+
+    mock! {
+        pub ImageProcessingAdapter {}
+
+        impl ImageProcessingPort for ImageProcessingAdapter {
+            fn sniff_content_type(&self, content: &[u8]) -> Option<&'static str>;
+            fn thumbnail_content_type(&self) -> &'static str;
+            fn thumbnails(
+                &self,
+                content: &[u8],
+                sizes: &[u32],
+            ) -> Result<Vec<Vec<u8>>, ImageProcessingError>;
+        }
+
+        impl Clone for ImageProcessingAdapter {
+            fn clone(&self) -> Self {
+                MockImageProcessingAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self {
+                MockIdGeneratorAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self {
+                MockGetUserByIdRepository::new()
+            }
+        }
+    }
+
+    mock! {
+        pub UpdateUserRepository {}
+
+        impl UpdateUserRepositoryPort for UpdateUserRepository {
+            fn execute(
+                &self,
+                user_entity: UserEntity,
+                audit_entry_entity: AuditEntryEntity,
+                outbox_event_entities: Vec<OutboxEventEntity>,
+            ) -> UpdateUserRepositoryFuture<'_>;
+        }
+
+        impl Clone for UpdateUserRepository {
+            fn clone(&self) -> Self {
+                MockUpdateUserRepository::new()
+            }
+        }
+    }
+
+    fn build_image_processing_adapter_mock(
+        content_type: Option<&'static str>,
+    ) -> MockImageProcessingAdapter {
+        let mut image_processing_adapter_mock = MockImageProcessingAdapter::default();
+
+        image_processing_adapter_mock
+            .expect_sniff_content_type()
+            .return_const(content_type);
+
+        image_processing_adapter_mock
+            .expect_thumbnails()
+            .returning(|_, sizes| Ok(sizes.iter().map(|size| vec![0; *size as usize]).collect()));
+
+        image_processing_adapter_mock
+    }
+
+    fn build_id_generator_adapter_mock() -> MockIdGeneratorAdapter {
+        let mut id_generator_adapter_mock = MockIdGeneratorAdapter::default();
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        id_generator_adapter_mock
+            .expect_generate_id()
+            .returning(move || format!("id-{}", counter.fetch_add(1, Ordering::SeqCst)));
+
+        id_generator_adapter_mock
+    }
+
+    fn build_get_user_by_id_repository_mock(
+        avatar_key: Option<String>,
+    ) -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock
+            .expect_execute()
+            .returning(move |id| {
+                let avatar_key = avatar_key.clone();
+
+                Box::pin(async move {
+                    Ok(Some(
+                        UserEntityBuilder::default()
+                            .id(id)
+                            .first_name("John")
+                            .last_name("Doe")
+                            .email("johndoe@gmail.com")
+                            .password("hashed_password")
+                            .avatar_key(avatar_key)
+                            .build()
+                            .unwrap(),
+                    ))
+                })
+            });
+
+        get_user_by_id_repository_mock
+    }
+
+    #[tokio::test]
+    async fn should_store_thumbnails_and_replace_previous_avatar() {
+        let file_storage_adapter = InMemoryFileStorageAdapter::new();
+        let previous_avatar_key = format!("avatars/{USER_ID}/previous");
+
+        for size in [64, 128, 256] {
+            file_storage_adapter
+                .put(format!("{previous_avatar_key}/{size}"), vec![1])
+                .await
+                .unwrap();
+        }
+
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .times(1)
+            .withf(|user_entity, audit_entry_entity, outbox_event_entities| {
+                outbox_event_entities.is_empty()
+                    && user_entity.avatar_key.as_deref() == Some(&format!("avatars/{USER_ID}/id-0"))
+                    && audit_entry_entity.action == AuditAction::AvatarUpdated
+            })
+            .returning(|user_entity, _, _| Box::pin(async move { Ok(user_entity) }));
+
+        let upload_avatar_use_case = UploadAvatarUseCase::new(
+            build_image_processing_adapter_mock(Some("image/png")),
+            file_storage_adapter.clone(),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(Some(previous_avatar_key)),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = upload_avatar_use_case
+            .perform(USER_ID.to_string(), vec![137, 80, 78, 71])
+            .await
+            .unwrap();
+
+        assert_eq!(result.avatar_version(), Some("id-0"));
+        assert_eq!(
+            file_storage_adapter.keys(),
+            vec![
+                format!("avatars/{USER_ID}/id-0/128"),
+                format!("avatars/{USER_ID}/id-0/256"),
+                format!("avatars/{USER_ID}/id-0/64"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_avatar_is_too_large() {
+        let upload_avatar_use_case = UploadAvatarUseCase::new(
+            build_image_processing_adapter_mock(Some("image/png")),
+            InMemoryFileStorageAdapter::new(),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(None),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = upload_avatar_use_case
+            .perform(USER_ID.to_string(), vec![0; MAX_AVATAR_BYTES + 1])
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            UploadAvatarUseCaseError::AvatarTooLarge
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_content_is_not_a_supported_image() {
+        let upload_avatar_use_case = UploadAvatarUseCase::new(
+            build_image_processing_adapter_mock(None),
+            InMemoryFileStorageAdapter::new(),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(None),
+            MockUpdateUserRepository::default(),
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = upload_avatar_use_case
+            .perform(USER_ID.to_string(), b"%PDF-1.7".to_vec())
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            UploadAvatarUseCaseError::UnsupportedAvatarType
+        );
+    }
+
+    #[tokio::test]
+    async fn should_remove_new_thumbnails_if_update_user_repository_fails() {
+        let file_storage_adapter = InMemoryFileStorageAdapter::new();
+        let mut update_user_repository_mock = MockUpdateUserRepository::default();
+
+        update_user_repository_mock
+            .expect_execute()
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(UpdateUserRepositoryError::UpdateError {
+                        message: "connection reset".to_string(),
+                    })
+                })
+            });
+
+        let upload_avatar_use_case = UploadAvatarUseCase::new(
+            build_image_processing_adapter_mock(Some("image/jpeg")),
+            file_storage_adapter.clone(),
+            build_id_generator_adapter_mock(),
+            build_get_user_by_id_repository_mock(None),
+            update_user_repository_mock,
+            ManualClockAdapter::new(1_695_996_669),
+        );
+
+        let result = upload_avatar_use_case
+            .perform(USER_ID.to_string(), vec![255, 216, 255])
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UploadAvatarUseCaseError::RepositoryError(_)
+        ));
+        assert!(file_storage_adapter.keys().is_empty());
+    }
+}
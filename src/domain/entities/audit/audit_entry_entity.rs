@@ -0,0 +1,80 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    UserPromoted,
+    UserDemoted,
+    UserSuspended,
+    UserUnsuspended,
+    PasswordResetForced,
+    SessionsRevoked,
+    PasswordChanged,
+    PasswordReset,
+    AvatarUpdated,
+}
+
+impl AuditAction {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserPromoted => "user.promoted",
+            Self::UserDemoted => "user.demoted",
+            Self::UserSuspended => "user.suspended",
+            Self::UserUnsuspended => "user.unsuspended",
+            Self::PasswordResetForced => "user.password_reset_forced",
+            Self::SessionsRevoked => "user.sessions_revoked",
+            Self::PasswordChanged => "user.password_changed",
+            Self::PasswordReset => "user.password_reset",
+            Self::AvatarUpdated => "user.avatar_updated",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "user.promoted" => Some(Self::UserPromoted),
+            "user.demoted" => Some(Self::UserDemoted),
+            "user.suspended" => Some(Self::UserSuspended),
+            "user.unsuspended" => Some(Self::UserUnsuspended),
+            "user.password_reset_forced" => Some(Self::PasswordResetForced),
+            "user.sessions_revoked" => Some(Self::SessionsRevoked),
+            "user.password_changed" => Some(Self::PasswordChanged),
+            "user.password_reset" => Some(Self::PasswordReset),
+            "user.avatar_updated" => Some(Self::AvatarUpdated),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntryEntity {
+    pub id: String,
+    /// `None` when the action was performed by an operator through the admin CLI.
+    pub actor_id: Option<String>,
+    pub action: AuditAction,
+    pub target_user_id: String,
+    pub created_at: i64,
+}
+
+impl AuditEntryEntity {
+    #[must_use]
+    pub const fn new(
+        id: String,
+        actor_id: Option<String>,
+        action: AuditAction,
+        target_user_id: String,
+        created_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            actor_id,
+            action,
+            target_user_id,
+            created_at,
+        }
+    }
+}
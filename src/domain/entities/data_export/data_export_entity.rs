@@ -0,0 +1,76 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl DataExportStatus {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "running" => Some(Self::Running),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DataExportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataExportEntity {
+    pub id: String,
+    pub user_id: String,
+    pub requested_by: String,
+    pub status: DataExportStatus,
+    pub archive: Option<Vec<u8>>,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+impl DataExportEntity {
+    /// Creates a pending export that has not been picked up by a background job yet.
+    #[must_use]
+    pub const fn pending(
+        id: String,
+        user_id: String,
+        requested_by: String,
+        created_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            requested_by,
+            status: DataExportStatus::Pending,
+            archive: None,
+            error_message: None,
+            created_at,
+            completed_at: None,
+        }
+    }
+
+    /// Exports are only visible to the user they describe, whoever requested them and admins.
+    #[must_use]
+    pub fn is_visible_to(&self, user_id: &str, is_admin: bool) -> bool {
+        is_admin || self.user_id == user_id || self.requested_by == user_id
+    }
+}
@@ -0,0 +1,69 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailDomainPolicyMode {
+    /// Only domains on the allowlist may register.
+    Allowlist,
+    /// Every domain may register except those on the denylist.
+    Denylist,
+}
+
+impl EmailDomainPolicyMode {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allowlist => "allowlist",
+            Self::Denylist => "denylist",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "allowlist" => Some(Self::Allowlist),
+            "denylist" => Some(Self::Denylist),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for EmailDomainPolicyMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailDomainPolicyEntity {
+    pub mode: EmailDomainPolicyMode,
+    pub block_disposable: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+    pub updated_by: Option<String>,
+    pub updated_at: i64,
+}
+
+impl EmailDomainPolicyEntity {
+    /// Whether the allowlist covers `domain`, either directly or through a parent domain.
+    #[must_use]
+    pub fn is_allowed(&self, domain: &str) -> bool {
+        Self::covers(&self.allowed_domains, domain)
+    }
+
+    /// Whether the denylist covers `domain`, either directly or through a parent domain.
+    #[must_use]
+    pub fn is_denied(&self, domain: &str) -> bool {
+        Self::covers(&self.denied_domains, domain)
+    }
+
+    /// Listing `acme.com` also covers `eu.acme.com`, but not `notacme.com`.
+    #[must_use]
+    pub fn covers(listed_domains: &[impl AsRef<str>], domain: &str) -> bool {
+        listed_domains.iter().any(|listed_domain| {
+            let listed_domain = listed_domain.as_ref();
+
+            domain == listed_domain
+                || domain
+                    .strip_suffix(listed_domain)
+                    .is_some_and(|subdomain| subdomain.ends_with('.'))
+        })
+    }
+}
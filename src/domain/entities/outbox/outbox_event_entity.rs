@@ -0,0 +1,67 @@
+use crate::domain::events::domain_event::DomainEvent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxEventStatus {
+    Pending,
+    Delivered,
+    /// Gave up after too many failed deliveries; kept for inspection and manual replay.
+    DeadLettered,
+}
+
+impl OutboxEventStatus {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::DeadLettered => "dead_lettered",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "delivered" => Some(Self::Delivered),
+            "dead_lettered" => Some(Self::DeadLettered),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for OutboxEventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A domain event waiting in the outbox, written in the same transaction as the change it
+/// describes so that it is delivered if and only if the change was committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxEventEntity {
+    pub id: String,
+    pub event: DomainEvent,
+    pub status: OutboxEventStatus,
+    /// Deliveries tried so far, successful or not.
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub occurred_at: i64,
+    /// Earliest time the event may be delivered (again).
+    pub next_attempt_at: i64,
+}
+
+impl OutboxEventEntity {
+    /// Records a pending event, due for delivery straight away.
+    #[must_use]
+    pub const fn new(id: String, event: DomainEvent, occurred_at: i64) -> Self {
+        Self {
+            id,
+            event,
+            status: OutboxEventStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            occurred_at,
+            next_attempt_at: occurred_at,
+        }
+    }
+}
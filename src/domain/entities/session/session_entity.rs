@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionEntity {
+    pub id: String,
+    pub user_id: String,
+    pub issued_at: i64,
+    pub expires_at: i64,
+}
+
+impl SessionEntity {
+    #[must_use]
+    pub const fn new(id: String, user_id: String, issued_at: i64, expires_at: i64) -> Self {
+        Self {
+            id,
+            user_id,
+            issued_at,
+            expires_at,
+        }
+    }
+}
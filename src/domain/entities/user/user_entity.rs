@@ -1,15 +1,73 @@
+use crate::domain::{
+    errors::value_object::value_object_errors::ValueObjectError,
+    value_objects::{
+        shared::timestamp::Timestamp,
+        user::{
+            email_address::EmailAddress, password_hash::PasswordHash, person_name::PersonName,
+            user_id::UserId,
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    Active,
+    Suspended,
+}
+
+impl UserStatus {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(Self::Active),
+            "suspended" => Some(Self::Suspended),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UserEntity {
-    pub id: String,
-    pub first_name: String,
-    pub last_name: String,
-    pub email: String,
-    pub password: String,
+    pub id: UserId,
+    pub first_name: PersonName,
+    pub last_name: PersonName,
+    pub email: EmailAddress,
+    pub password: PasswordHash,
     pub is_admin: bool,
-    pub created_at: i64,
-    pub updated_at: i64,
+    pub status: UserStatus,
+    pub password_reset_required: bool,
+    pub sessions_revoked_at: Option<Timestamp>,
+    /// Storage key prefix of the current avatar thumbnails, if the user uploaded one.
+    pub avatar_key: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl UserEntity {
+    /// Returns the version segment of the avatar storage key, which changes on every upload.
+    #[must_use]
+    pub fn avatar_version(&self) -> Option<&str> {
+        self.avatar_key
+            .as_deref()
+            .and_then(|avatar_key| avatar_key.rsplit('/').next())
+    }
 }
 
+/// Collects raw values and validates them all at once in `build`.
 #[derive(Debug, Clone)]
 pub struct UserEntityBuilder {
     id: String,
@@ -18,6 +76,10 @@ pub struct UserEntityBuilder {
     email: String,
     password: String,
     is_admin: bool,
+    status: UserStatus,
+    password_reset_required: bool,
+    sessions_revoked_at: Option<i64>,
+    avatar_key: Option<String>,
     created_at: i64,
     updated_at: i64,
 }
@@ -32,6 +94,10 @@ impl UserEntityBuilder {
             email: String::new(),
             password: String::new(),
             is_admin: false,
+            status: UserStatus::Active,
+            password_reset_required: false,
+            sessions_revoked_at: None,
+            avatar_key: None,
             created_at: 0,
             updated_at: 0,
         }
@@ -73,6 +139,30 @@ impl UserEntityBuilder {
         self
     }
 
+    #[must_use]
+    pub const fn status(mut self, status: UserStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn password_reset_required(mut self, password_reset_required: bool) -> Self {
+        self.password_reset_required = password_reset_required;
+        self
+    }
+
+    #[must_use]
+    pub const fn sessions_revoked_at(mut self, sessions_revoked_at: Option<i64>) -> Self {
+        self.sessions_revoked_at = sessions_revoked_at;
+        self
+    }
+
+    #[must_use]
+    pub fn avatar_key(mut self, avatar_key: Option<String>) -> Self {
+        self.avatar_key = avatar_key;
+        self
+    }
+
     #[must_use]
     pub fn created_at(mut self, created_at: impl Into<i64>) -> Self {
         self.created_at = created_at.into();
@@ -85,18 +175,27 @@ impl UserEntityBuilder {
         self
     }
 
-    #[must_use]
-    pub fn build(self) -> UserEntity {
-        UserEntity {
-            id: self.id,
-            first_name: self.first_name,
-            last_name: self.last_name,
-            email: self.email,
-            password: self.password,
+    /// # Errors
+    ///
+    /// Returns the `ValueObjectError` of the first value that is not valid.
+    pub fn build(self) -> Result<UserEntity, ValueObjectError> {
+        Ok(UserEntity {
+            id: UserId::parse(&self.id)?,
+            first_name: PersonName::parse(self.first_name)?,
+            last_name: PersonName::parse(self.last_name)?,
+            email: EmailAddress::parse(self.email)?,
+            password: PasswordHash::parse(self.password)?,
             is_admin: self.is_admin,
-            created_at: self.created_at,
-            updated_at: self.updated_at,
-        }
+            status: self.status,
+            password_reset_required: self.password_reset_required,
+            sessions_revoked_at: self
+                .sessions_revoked_at
+                .map(Timestamp::from_unix_timestamp)
+                .transpose()?,
+            avatar_key: self.avatar_key,
+            created_at: Timestamp::from_unix_timestamp(self.created_at)?,
+            updated_at: Timestamp::from_unix_timestamp(self.updated_at)?,
+        })
     }
 }
 
@@ -2,6 +2,11 @@
 pub enum UserError {
     PasswordsDoNotMatch,
     UserAlreadyExists,
+    UserNotFound,
+    UserSuspended,
+    PasswordResetRequired,
+    AdminPrivilegesRequired,
+    CannotTargetSelf,
 }
 
 impl std::fmt::Display for UserError {
@@ -11,6 +16,17 @@ impl std::fmt::Display for UserError {
             Self::UserAlreadyExists => {
                 write!(f, "an user is already registered with the given e-mail")
             }
+            Self::UserNotFound => write!(f, "no user with the provided id was found"),
+            Self::UserSuspended => write!(f, "the user account is suspended"),
+            Self::PasswordResetRequired => {
+                write!(f, "the user must reset the password before signing in")
+            }
+            Self::AdminPrivilegesRequired => {
+                write!(f, "the action requires administrator privileges")
+            }
+            Self::CannotTargetSelf => {
+                write!(f, "administrators cannot perform this action on themselves")
+            }
         }
     }
 }
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueObjectError {
+    InvalidUserId { value: String },
+    InvalidEmailAddress { value: String },
+    InvalidPasswordHash,
+    InvalidPersonName { value: String },
+    InvalidTimestamp { value: i64 },
+}
+
+impl std::fmt::Display for ValueObjectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUserId { value } => write!(f, "'{value}' is not a valid user id"),
+            Self::InvalidEmailAddress { value } => {
+                write!(f, "'{value}' is not a valid e-mail address")
+            }
+            // The value is left out so a hash never ends up in logs.
+            Self::InvalidPasswordHash => write!(f, "the password hash is not valid"),
+            Self::InvalidPersonName { value } => write!(f, "'{value}' is not a valid name"),
+            Self::InvalidTimestamp { value } => {
+                write!(f, "{value} is not a representable timestamp")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValueObjectError {}
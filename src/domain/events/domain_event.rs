@@ -0,0 +1,45 @@
+use crate::domain::value_objects::user::{email_address::EmailAddress, user_id::UserId};
+
+/// Something that happened to a user which other parts of the application may react to, such as
+/// sending a welcome e-mail once an account is registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEvent {
+    UserRegistered {
+        user_id: UserId,
+        email: EmailAddress,
+    },
+    UserSignedIn {
+        user_id: UserId,
+        session_id: String,
+    },
+    PasswordChanged {
+        user_id: UserId,
+    },
+}
+
+impl DomainEvent {
+    /// Stable identifier of the kind of event, stored next to its payload.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::UserRegistered { .. } => "user.registered",
+            Self::UserSignedIn { .. } => "user.signed_in",
+            Self::PasswordChanged { .. } => "user.password_changed",
+        }
+    }
+
+    #[must_use]
+    pub const fn user_id(&self) -> UserId {
+        match self {
+            Self::UserRegistered { user_id, .. }
+            | Self::UserSignedIn { user_id, .. }
+            | Self::PasswordChanged { user_id } => *user_id,
+        }
+    }
+}
+
+impl std::fmt::Display for DomainEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
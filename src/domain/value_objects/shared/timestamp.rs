@@ -0,0 +1,66 @@
+use time::OffsetDateTime;
+
+use crate::domain::errors::value_object::value_object_errors::ValueObjectError;
+
+/// A point in time, with the microsecond precision the database stores. The API only exposes
+/// whole seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(OffsetDateTime);
+
+impl Timestamp {
+    /// # Errors
+    ///
+    /// Returns `InvalidTimestamp` if the value is outside the supported date range.
+    pub fn from_unix_timestamp(value: i64) -> Result<Self, ValueObjectError> {
+        OffsetDateTime::from_unix_timestamp(value)
+            .map(Self)
+            .map_err(|_| ValueObjectError::InvalidTimestamp { value })
+    }
+
+    /// # Errors
+    ///
+    /// Returns `InvalidTimestamp` if the value is outside the supported date range.
+    pub fn from_unix_timestamp_micros(value: i64) -> Result<Self, ValueObjectError> {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(value) * 1_000)
+            .map(Self)
+            .map_err(|_| ValueObjectError::InvalidTimestamp { value })
+    }
+
+    #[must_use]
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.0.unix_timestamp()
+    }
+
+    #[must_use]
+    pub fn unix_timestamp_micros(&self) -> i64 {
+        // Every supported date fits: `i64` microseconds span almost 300 000 years.
+        i64::try_from(self.0.unix_timestamp_nanos() / 1_000).unwrap_or(i64::MAX)
+    }
+
+    #[must_use]
+    pub const fn as_offset_date_time(&self) -> OffsetDateTime {
+        self.0
+    }
+
+    /// The same point in time without its fraction of a second, as the API shows it.
+    #[must_use]
+    pub fn truncated_to_seconds(&self) -> OffsetDateTime {
+        self.0.replace_nanosecond(0).unwrap_or(self.0)
+    }
+
+    /// Moves the timestamp by the given seconds, stopping at the edges of the supported range.
+    #[must_use]
+    pub const fn saturating_add_seconds(&self, seconds: i64) -> Self {
+        Self(self.0.saturating_add(time::Duration::seconds(seconds)))
+    }
+}
+
+impl From<OffsetDateTime> for Timestamp {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        Self(
+            offset_date_time
+                .replace_nanosecond(offset_date_time.nanosecond() / 1_000 * 1_000)
+                .unwrap_or(offset_date_time),
+        )
+    }
+}
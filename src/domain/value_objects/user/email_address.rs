@@ -0,0 +1,88 @@
+use crate::domain::errors::value_object::value_object_errors::ValueObjectError;
+
+/// Longest e-mail the `users` table stores.
+const MAX_LENGTH: usize = 255;
+
+/// An e-mail with a local part and a domain. The exact format is enforced by the pattern
+/// matching port before users are created; this only rules out values that cannot be an address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    /// # Errors
+    ///
+    /// Returns `InvalidEmailAddress` if the value is too long, contains whitespace or lacks a
+    /// local part or a domain.
+    pub fn parse(value: impl Into<String>) -> Result<Self, ValueObjectError> {
+        let value = value.into();
+
+        let is_valid = value.chars().count() <= MAX_LENGTH
+            && !value.contains(char::is_whitespace)
+            && value
+                .rsplit_once('@')
+                .is_some_and(|(local_part, domain)| !local_part.is_empty() && !domain.is_empty());
+
+        if is_valid {
+            Ok(Self(value))
+        } else {
+            Err(ValueObjectError::InvalidEmailAddress { value })
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        errors::value_object::value_object_errors::ValueObjectError,
+        value_objects::user::email_address::EmailAddress,
+    };
+
+    #[test]
+    fn should_accept_addresses_with_local_part_and_domain() {
+        let email_address = EmailAddress::parse("john.doe@gmail.com").unwrap();
+
+        assert_eq!(email_address.as_str(), "john.doe@gmail.com");
+        assert_eq!(email_address.domain(), "gmail.com");
+    }
+
+    #[test]
+    fn should_reject_values_that_cannot_be_an_address() {
+        for value in [
+            "",
+            "johndoe",
+            "@gmail.com",
+            "johndoe@",
+            "john doe@gmail.com",
+        ] {
+            assert_eq!(
+                EmailAddress::parse(value),
+                Err(ValueObjectError::InvalidEmailAddress {
+                    value: value.to_string()
+                })
+            );
+        }
+
+        assert!(EmailAddress::parse(format!("{}@gmail.com", "a".repeat(250))).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::domain::errors::value_object::value_object_errors::ValueObjectError;
+
+/// The stored hash of a password, never the password itself. `Debug` redacts it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PasswordHash(String);
+
+impl PasswordHash {
+    /// # Errors
+    ///
+    /// Returns `InvalidPasswordHash` if the value is empty or contains whitespace.
+    pub fn parse(value: impl Into<String>) -> Result<Self, ValueObjectError> {
+        let value = value.into();
+
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(ValueObjectError::InvalidPasswordHash);
+        }
+
+        Ok(Self(value))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for PasswordHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PasswordHash(<redacted>)")
+    }
+}
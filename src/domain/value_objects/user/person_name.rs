@@ -0,0 +1,40 @@
+use crate::domain::errors::value_object::value_object_errors::ValueObjectError;
+
+/// Longest name the `users` table stores.
+const MAX_LENGTH: usize = 255;
+
+/// A first or last name, stored without surrounding whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonName(String);
+
+impl PersonName {
+    /// # Errors
+    ///
+    /// Returns `InvalidPersonName` if the value is blank or too long once trimmed.
+    pub fn parse(value: impl Into<String>) -> Result<Self, ValueObjectError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() || trimmed.chars().count() > MAX_LENGTH {
+            return Err(ValueObjectError::InvalidPersonName { value });
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Display for PersonName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
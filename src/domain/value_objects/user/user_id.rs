@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::domain::errors::value_object::value_object_errors::ValueObjectError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserId(Uuid);
+
+impl UserId {
+    /// # Errors
+    ///
+    /// Returns `InvalidUserId` if the value is not a UUID.
+    pub fn parse(value: &str) -> Result<Self, ValueObjectError> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| ValueObjectError::InvalidUserId {
+                value: value.to_string(),
+            })
+    }
+
+    #[must_use]
+    pub const fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for UserId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl PartialEq<str> for UserId {
+    fn eq(&self, other: &str) -> bool {
+        Uuid::parse_str(other).is_ok_and(|uuid| uuid == self.0)
+    }
+}
+
+impl PartialEq<String> for UserId {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
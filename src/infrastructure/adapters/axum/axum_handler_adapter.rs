@@ -1,19 +1,38 @@
 use axum::{
-    body::{Body, to_bytes},
-    extract::{Path, Request},
-    http::{Method, Response, StatusCode},
+    body::{Body, Bytes, to_bytes},
+    extract::{OriginalUri, Path, Query, Request},
+    http::{Method, Response},
 };
 
-use std::collections::HashMap;
+use http_body_util::LengthLimitError;
+use serde_json::{Value, json};
+use std::{collections::HashMap, sync::Arc};
+use tracing::Instrument;
 
-use crate::presentation::{
-    dtos::http::http_request_dto::HttpRequestDto,
-    ports::controller::controller_port::ControllerPort,
+use crate::{
+    application::dtos::auth::auth_principal_dto::AuthPrincipalDto,
+    presentation::{
+        dtos::http::{http_request_dto::HttpRequestDto, http_response_dto::HttpResponseDto},
+        errors::api_error::{ApiError, ApiErrorCode, PROBLEM_JSON_CONTENT_TYPE},
+        ports::{
+            controller::controller_port::ControllerPort,
+            request_body::request_body_port::RequestBodyPort,
+        },
+        validation::{validation_error::ValidationError, validation_schema::ValidationSchema},
+    },
 };
 
+/// Content types whose bodies are handed to controllers untouched instead of being parsed as JSON.
+const RAW_BODY_CONTENT_TYPES: [&str; 3] = ["text/csv", "application/x-ndjson", "application/jsonl"];
+
+/// Content types whose bodies are handed to controllers as bytes, since they may not be UTF-8.
+const BINARY_BODY_CONTENT_TYPES: [&str; 1] = ["multipart/form-data"];
+
 #[derive(Clone)]
 pub struct AxumHandlerAdapter<H> {
     handler: H,
+    /// Schema of the controller's body, built once since it compiles its patterns.
+    body_schema: Option<Arc<ValidationSchema>>,
 }
 
 impl<H> AxumHandlerAdapter<H>
@@ -21,8 +40,130 @@ where
     H: ControllerPort + Clone + Send + Sync,
 {
     #[must_use]
-    pub const fn new(handler: H) -> Self {
-        Self { handler }
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            body_schema: H::Body::schema().map(Arc::new),
+        }
+    }
+
+    /// Buffers the request body, answering with `413` when a body limit layer cut it short.
+    async fn read_body(body: Body, instance: &str) -> Result<Bytes, Response<Body>> {
+        to_bytes(body, usize::MAX).await.map_err(|err| {
+            let is_length_limit_error = std::error::Error::source(&err)
+                .is_some_and(<dyn std::error::Error>::is::<LengthLimitError>);
+
+            let api_error = if is_length_limit_error {
+                ApiError::new(
+                    ApiErrorCode::PayloadTooLarge,
+                    "the request body is too large",
+                )
+            } else {
+                ApiError::internal(&err)
+            };
+
+            api_error.into_response(Some(instance))
+        })
+    }
+
+    /// Turns the JSON body into the controller's body type: the body must be present and valid
+    /// JSON, satisfy the schema and then deserialize, with failures located by JSON pointer.
+    /// Controllers without a schema take no JSON body, so it is never parsed.
+    fn deserialize_body(&self, json_body: Option<&[u8]>) -> Result<H::Body, ApiError> {
+        let Some(body_schema) = &self.body_schema else {
+            return serde_json::from_value(Value::Null).map_err(|err| ApiError::internal(&err));
+        };
+
+        let Some(json_body) = json_body else {
+            return Err(ApiError::new(
+                ApiErrorCode::MissingRequestBody,
+                "a request body was not provided",
+            ));
+        };
+
+        let json_body = serde_json::from_slice::<Value>(json_body).map_err(|err| {
+            ApiError::new(
+                ApiErrorCode::MalformedRequestBody,
+                format!("the request body is not valid JSON: {err}"),
+            )
+        })?;
+
+        let validation_errors = body_schema.validate_value(&json_body);
+
+        if !validation_errors.is_empty() {
+            return Err(Self::invalid_body_error(&validation_errors));
+        }
+
+        serde_path_to_error::deserialize(json_body)
+            .map_err(|err| Self::invalid_body_error(&[ValidationError::from(err)]))
+    }
+
+    fn invalid_body_error(validation_errors: &[ValidationError]) -> ApiError {
+        let errors = validation_errors
+            .iter()
+            .map(ValidationError::to_json)
+            .collect::<Vec<_>>();
+
+        ApiError::new(
+            ApiErrorCode::InvalidRequestBody,
+            "some fields are not valid",
+        )
+        .with_extension("errors", json!(errors))
+    }
+
+    /// Short type name of the controller, such as `SignInController`, used to name its span.
+    fn controller_name() -> &'static str {
+        let type_name = std::any::type_name::<H>();
+        let type_path = type_name.split('<').next().unwrap_or(type_name);
+
+        type_path.rsplit("::").next().unwrap_or(type_path)
+    }
+
+    /// Converts the controller's response into an HTTP response, defaulting to JSON. Problem
+    /// details bodies get the request path as their `instance`.
+    fn build_response(http_response_dto: HttpResponseDto, instance: &str) -> Response<Body> {
+        let mut response_builder = Response::builder().status(http_response_dto.status_code);
+
+        let has_content_type = http_response_dto.headers.as_ref().is_some_and(|headers| {
+            headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("content-type"))
+        });
+
+        if !has_content_type {
+            response_builder = response_builder.header("content-type", "application/json");
+        }
+
+        let is_problem = http_response_dto.headers.as_ref().is_some_and(|headers| {
+            headers
+                .values()
+                .any(|value| value == PROBLEM_JSON_CONTENT_TYPE)
+        });
+
+        if let Some(headers) = http_response_dto.headers {
+            for (key, value) in headers {
+                response_builder = response_builder.header(key.as_str(), value.as_str());
+            }
+        }
+
+        if let Some(raw_body) = http_response_dto.raw_body {
+            return response_builder.body(Body::from(raw_body)).unwrap();
+        }
+
+        let body_string = http_response_dto.body.map_or_else(
+            || "{}".to_string(),
+            |mut body| {
+                if is_problem && let Value::Object(problem_details) = &mut body {
+                    problem_details
+                        .entry("instance")
+                        .or_insert_with(|| json!(instance));
+                }
+
+                body.to_string()
+            },
+        );
+
+        response_builder.body(Body::from(body_string)).unwrap()
     }
 
     /// Adapts an incoming HTTP request into the internal handler and returns an HTTP response.
@@ -50,6 +191,13 @@ where
         Path(request_params): Path<HashMap<String, String>>,
         request: Request<Body>,
     ) -> Response<Body> {
+        // Nested routers strip their prefix from the URI, so the path is read from the original.
+        let instance = request
+            .extensions()
+            .get::<OriginalUri>()
+            .map_or_else(|| request.uri().path(), |original_uri| original_uri.path())
+            .to_string();
+
         let method = match *request.method() {
             Method::GET => Method::GET,
             Method::POST => Method::POST,
@@ -57,59 +205,236 @@ where
             Method::PATCH => Method::PATCH,
             Method::DELETE => Method::DELETE,
             _ => {
-                return Response::builder()
-                    .status(StatusCode::BAD_REQUEST)
-                    .body(Body::from("Method type is invalid."))
-                    .unwrap();
+                let api_error = ApiError::new(
+                    ApiErrorCode::MethodNotAllowed,
+                    format!("the {} method is not supported", request.method()),
+                )
+                .with_extension("method", json!(request.method().as_str()));
+
+                return api_error.into_response(Some(&instance));
             }
         };
 
         let uri = request.uri().to_string();
 
-        let Ok(body_bytes) = to_bytes(request.into_body(), usize::MAX).await else {
-            return Response::builder()
-                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                .body(Body::from("Error reading request body."))
-                .unwrap();
+        let query_params = Query::<HashMap<String, String>>::try_from_uri(request.uri())
+            .ok()
+            .map(|Query(query_params)| query_params);
+
+        let principal = request.extensions().get::<AuthPrincipalDto>().cloned();
+
+        let headers = request
+            .headers()
+            .iter()
+            .filter_map(|(key, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (key.as_str().to_string(), value.to_string()))
+            })
+            .collect::<HashMap<String, String>>();
+
+        let is_raw_body = headers.get("content-type").is_some_and(|content_type| {
+            RAW_BODY_CONTENT_TYPES
+                .iter()
+                .any(|raw_content_type| content_type.starts_with(raw_content_type))
+        });
+
+        let is_binary_body = headers.get("content-type").is_some_and(|content_type| {
+            BINARY_BODY_CONTENT_TYPES
+                .iter()
+                .any(|binary_content_type| content_type.starts_with(binary_content_type))
+        });
+
+        let body_bytes = match Self::read_body(request.into_body(), &instance).await {
+            Ok(body_bytes) => body_bytes,
+            Err(response) => return response,
         };
 
-        let body_content = if body_bytes.is_empty() {
-            None
+        let mut raw_body = None;
+        let mut binary_body = None;
+        let mut json_body = None;
+
+        if body_bytes.is_empty() {
+            // Nothing to hand over; controllers that need a body answer with a problem.
+        } else if is_binary_body {
+            binary_body = Some(body_bytes.to_vec());
+        } else if is_raw_body {
+            let Ok(content) = String::from_utf8(body_bytes.to_vec()) else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MalformedRequestBody,
+                    "the request body is not valid UTF-8",
+                );
+
+                return api_error.into_response(Some(&instance));
+            };
+
+            raw_body = Some(content);
         } else {
-            match serde_json::from_slice(&body_bytes) {
-                Ok(json) => Some(json),
-                Err(_) => {
-                    return Response::builder()
-                        .status(StatusCode::BAD_REQUEST)
-                        .body(Body::from("Invalid JSON structurerror."))
-                        .unwrap();
-                }
-            }
+            json_body = Some(&body_bytes[..]);
+        }
+
+        let body = match self.deserialize_body(json_body) {
+            Ok(body) => body,
+            Err(api_error) => return api_error.into_response(Some(&instance)),
         };
 
         let http_request_dto = HttpRequestDto {
             method: method.to_string(),
             url: uri,
-            body: body_content,
+            body,
+            raw_body,
+            binary_body,
+            headers: Some(headers),
             params: Some(request_params),
+            query: query_params,
+            principal,
         };
 
-        let http_response_dto = self.handler.handle(http_request_dto).await;
+        let controller_span =
+            tracing::info_span!("controller", otel.name = Self::controller_name());
 
-        let body_string = http_response_dto
-            .body
-            .map_or_else(|| "{}".to_string(), |body| body.to_string());
+        let http_response_dto = self
+            .handler
+            .handle(http_request_dto)
+            .instrument(controller_span)
+            .await;
 
-        let mut response_builder = Response::builder()
-            .status(http_response_dto.status_code)
-            .header("content-type", "application/json");
+        Self::build_response(http_response_dto, &instance)
+    }
+}
 
-        if let Some(headers) = http_response_dto.headers {
-            for (key, value) in headers {
-                response_builder = response_builder.header(key.as_str(), value.as_str());
-            }
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        extract::{Path, Request},
+        http::{StatusCode, header},
+        routing::post,
+    };
+    use serde::Deserialize;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::{
+        infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
+        presentation::{
+            dtos::http::http_request_dto::HttpRequestDto,
+            helpers::http::http_response_helper::HttpResponseHelper,
+            ports::{
+                controller::controller_port::{ControllerFuture, ControllerPort},
+                request_body::request_body_port::RequestBodyPort,
+            },
+            validation::validation_schema::{FieldRules, ValidationSchema},
+        },
+    };
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ScoreBody {
+        display_name: String,
+        scores: Vec<u8>,
+    }
+
+    impl RequestBodyPort for ScoreBody {
+        fn schema() -> Option<ValidationSchema> {
+            Some(
+                ValidationSchema::new()
+                    .field("displayName", FieldRules::string().required())
+                    .field(
+                        "scores",
+                        FieldRules::array(FieldRules::unsigned_integer()).required(),
+                    ),
+            )
         }
+    }
 
-        response_builder.body(Body::from(body_string)).unwrap()
+    #[derive(Clone)]
+    struct ScoreController;
+
+    impl ControllerPort for ScoreController {
+        type Body = ScoreBody;
+
+        fn handle(&self, http_request_dto: HttpRequestDto<Self::Body>) -> ControllerFuture<'_> {
+            Box::pin(async move {
+                let score_body = http_request_dto.body;
+
+                HttpResponseHelper::new().ok(Some(json!({
+                    "displayName": score_body.display_name,
+                    "total": score_body.scores.iter().map(|score| u32::from(*score)).sum::<u32>()
+                })))
+            })
+        }
+    }
+
+    async fn send(body: &str) -> (StatusCode, Value) {
+        let handler_adapter = AxumHandlerAdapter::new(ScoreController);
+
+        let router = Router::new().route(
+            "/scores",
+            post(
+                move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                    handler_adapter.adapt_handler(path, request).await
+                },
+            ),
+        );
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/scores")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn should_hand_typed_body_to_controller() {
+        let (status, body) = send(r#"{ "displayName": "John", "scores": [10, 20] }"#).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "displayName": "John", "total": 30 }));
+    }
+
+    #[tokio::test]
+    async fn should_answer_missing_body_and_schema_errors() {
+        let (status, body) = send("").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "missing_request_body");
+        assert_eq!(body["instance"], "/scores");
+
+        let (status, body) = send(r#"{ "displayName": 10 }"#).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_request_body");
+        assert_eq!(body["errors"][0]["pointer"], "/displayName");
+        assert_eq!(body["errors"][0]["error"], "invalid_type");
+        assert_eq!(body["errors"][1]["pointer"], "/scores");
+        assert_eq!(body["errors"][1]["error"], "missing");
+    }
+
+    #[tokio::test]
+    async fn should_locate_deserialization_errors_by_pointer() {
+        let (status, body) = send(r#"{ "displayName": "John", "scores": [10, 300] }"#).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_request_body");
+        assert_eq!(body["errors"][0]["pointer"], "/scores/1");
+        assert_eq!(body["errors"][0]["error"], "invalid_value");
+        assert!(
+            body["errors"][0]["reason"]
+                .as_str()
+                .unwrap()
+                .contains("300")
+        );
     }
 }
@@ -1,4 +1,10 @@
-use crate::application::ports::hasher::hasher_port::{HasherError, HasherPort};
+use crate::{
+    application::ports::hasher::hasher_port::{HasherError, HasherPort},
+    domain::value_objects::user::password_hash::PasswordHash,
+};
+
+/// bcrypt only reads this many bytes of a password and silently ignores the rest.
+pub const MAX_PASSWORD_BYTES: usize = 72;
 
 #[derive(Clone)]
 pub struct BcryptAdapter;
@@ -11,13 +17,18 @@ impl BcryptAdapter {
 }
 
 impl HasherPort for BcryptAdapter {
-    fn hash(&self, password: &str) -> Result<String, HasherError> {
-        bcrypt::hash(password, bcrypt::DEFAULT_COST).map_err(|err| HasherError::HashingError {
-            message: err.to_string(),
-        })
+    fn hash(&self, password: &str) -> Result<PasswordHash, HasherError> {
+        // Refuses longer passwords instead of hashing a truncated copy of them.
+        bcrypt::non_truncating_hash(password, bcrypt::DEFAULT_COST)
+            .map_err(|err| err.to_string())
+            .and_then(|password_hash| {
+                PasswordHash::parse(password_hash).map_err(|err| err.to_string())
+            })
+            .map_err(|message| HasherError::HashingError { message })
     }
 
     fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError> {
+        // Still truncates, so that hashes made before long passwords were refused keep working.
         bcrypt::verify(password, password_hash).map_err(|err| HasherError::VerificationError {
             message: err.to_string(),
         })
@@ -0,0 +1,45 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{
+    application::ports::clock::clock_port::ClockPort,
+    domain::value_objects::shared::timestamp::Timestamp,
+};
+
+/// `ClockPort` whose time only moves when told to, meant for tests. Clones share the same time.
+#[derive(Clone)]
+pub struct ManualClockAdapter {
+    now: Arc<Mutex<Timestamp>>,
+}
+
+impl ManualClockAdapter {
+    /// Starts the clock at the given Unix timestamp, in seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp is outside the range `Timestamp` supports.
+    #[must_use]
+    pub fn new(now: i64) -> Self {
+        let now =
+            Timestamp::from_unix_timestamp(now).expect("the clock must start at a valid time");
+
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    pub fn set(&self, now: Timestamp) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    pub fn advance(&self, seconds: i64) {
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *now = now.saturating_add_seconds(seconds);
+    }
+}
+
+impl ClockPort for ManualClockAdapter {
+    fn now(&self) -> Timestamp {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,28 @@
+use time::OffsetDateTime;
+
+use crate::{
+    application::ports::clock::clock_port::ClockPort,
+    domain::value_objects::shared::timestamp::Timestamp,
+};
+
+#[derive(Clone)]
+pub struct SystemClockAdapter;
+
+impl SystemClockAdapter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ClockPort for SystemClockAdapter {
+    fn now(&self) -> Timestamp {
+        Timestamp::from(OffsetDateTime::now_utc())
+    }
+}
+
+impl Default for SystemClockAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
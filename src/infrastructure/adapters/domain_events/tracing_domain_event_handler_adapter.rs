@@ -0,0 +1,36 @@
+use crate::{
+    application::ports::events::domain_event_handler_port::{
+        DomainEventHandlerFuture, DomainEventHandlerPort,
+    },
+    domain::events::domain_event::DomainEvent,
+};
+
+/// Logs every delivered event. Handlers with real side effects, such as sending a welcome e-mail
+/// on `UserRegistered`, are registered next to it in the outbox dispatcher factory.
+#[derive(Clone, Default)]
+pub struct TracingDomainEventHandlerAdapter;
+
+impl DomainEventHandlerPort for TracingDomainEventHandlerAdapter {
+    fn name(&self) -> &'static str {
+        "tracing"
+    }
+
+    fn handle(&self, domain_event: DomainEvent) -> DomainEventHandlerFuture<'_> {
+        Box::pin(async move {
+            match domain_event {
+                DomainEvent::UserRegistered { user_id, .. } => {
+                    tracing::info!("User {user_id} registered.");
+                }
+                DomainEvent::UserSignedIn {
+                    user_id,
+                    session_id,
+                } => tracing::info!("User {user_id} signed in, session {session_id}."),
+                DomainEvent::PasswordChanged { user_id } => {
+                    tracing::info!("User {user_id} changed their password.");
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::{
+        email_domain_policy::email_domain_policy_port::{
+            EmailDomainPolicyError, EmailDomainPolicyFuture, EmailDomainPolicyPort,
+        },
+        repositories::email_domain_policy::get_email_domain_policy_repository_port::GetEmailDomainPolicyRepositoryPort,
+    },
+    domain::entities::email_domain_policy::email_domain_policy_entity::{
+        EmailDomainPolicyEntity, EmailDomainPolicyMode,
+    },
+    infrastructure::config::app_config::EmailDomainPolicyConfig,
+};
+
+/// Domains of disposable e-mail providers, bundled so the check works without any setup.
+static DISPOSABLE_DOMAINS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+    include_str!("../../../../data/disposable_email_domains.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+});
+
+struct CachedPolicy {
+    email_domain_policy_entity: EmailDomainPolicyEntity,
+    loaded_at: Instant,
+}
+
+/// Checks e-mail domains against the policy stored in the database, which is kept in memory for
+/// the configured time so sign-ups do not query it every time.
+#[derive(Clone)]
+pub struct CachedEmailDomainPolicyAdapter<G> {
+    get_email_domain_policy_repository: G,
+    cache_ttl: Duration,
+    cached_policy: Arc<Mutex<Option<CachedPolicy>>>,
+}
+
+impl<G> CachedEmailDomainPolicyAdapter<G>
+where
+    G: GetEmailDomainPolicyRepositoryPort + Clone + Send + Sync,
+{
+    #[must_use]
+    pub fn new(
+        get_email_domain_policy_repository: G,
+        email_domain_policy_config: &EmailDomainPolicyConfig,
+    ) -> Self {
+        Self {
+            get_email_domain_policy_repository,
+            cache_ttl: email_domain_policy_config.cache_ttl(),
+            cached_policy: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn policy(&self) -> Result<EmailDomainPolicyEntity, EmailDomainPolicyError> {
+        if let Some(cached_policy) = self
+            .cached_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .filter(|cached_policy| cached_policy.loaded_at.elapsed() < self.cache_ttl)
+        {
+            return Ok(cached_policy.email_domain_policy_entity.clone());
+        }
+
+        let email_domain_policy_entity = self
+            .get_email_domain_policy_repository
+            .execute()
+            .await
+            .map_err(|err| EmailDomainPolicyError::LoadError {
+                message: err.to_string(),
+            })?;
+
+        *self
+            .cached_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(CachedPolicy {
+            email_domain_policy_entity: email_domain_policy_entity.clone(),
+            loaded_at: Instant::now(),
+        });
+
+        Ok(email_domain_policy_entity)
+    }
+
+    /// The denylist wins over everything, and an allowlisted domain is trusted even when it is
+    /// a known disposable provider.
+    fn evaluate(
+        email_domain_policy_entity: &EmailDomainPolicyEntity,
+        domain: &str,
+    ) -> Result<(), EmailDomainPolicyError> {
+        if email_domain_policy_entity.is_denied(domain) {
+            return Err(EmailDomainPolicyError::DomainDenied {
+                domain: domain.to_string(),
+            });
+        }
+
+        if email_domain_policy_entity.is_allowed(domain) {
+            return Ok(());
+        }
+
+        if email_domain_policy_entity.mode == EmailDomainPolicyMode::Allowlist {
+            return Err(EmailDomainPolicyError::DomainNotAllowed {
+                domain: domain.to_string(),
+            });
+        }
+
+        if email_domain_policy_entity.block_disposable
+            && EmailDomainPolicyEntity::covers(&DISPOSABLE_DOMAINS, domain)
+        {
+            return Err(EmailDomainPolicyError::DisposableDomain {
+                domain: domain.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<G> EmailDomainPolicyPort for CachedEmailDomainPolicyAdapter<G>
+where
+    G: GetEmailDomainPolicyRepositoryPort + Clone + Send + Sync,
+{
+    fn check(&self, email: &str) -> EmailDomainPolicyFuture<'_> {
+        let domain = email
+            .rsplit_once('@')
+            .map_or(email, |(_, domain)| domain)
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+
+        let future = async move {
+            let email_domain_policy_entity = self.policy().await?;
+
+            Self::evaluate(&email_domain_policy_entity, &domain)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("CachedEmailDomainPolicyAdapter::check")))
+    }
+
+    fn invalidate(&self) {
+        *self
+            .cached_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockall::mock;
+
+    use crate::{
+        application::ports::{
+            email_domain_policy::email_domain_policy_port::{
+                EmailDomainPolicyError, EmailDomainPolicyPort,
+            },
+            repositories::email_domain_policy::get_email_domain_policy_repository_port::{
+                GetEmailDomainPolicyRepositoryError, GetEmailDomainPolicyRepositoryFuture,
+                GetEmailDomainPolicyRepositoryPort,
+            },
+        },
+        domain::entities::email_domain_policy::email_domain_policy_entity::{
+            EmailDomainPolicyEntity, EmailDomainPolicyMode,
+        },
+        infrastructure::{
+            adapters::email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            config::app_config::EmailDomainPolicyConfig,
+        },
+    };
+
+    mock! {
+        pub GetEmailDomainPolicyRepository {}
+
+        impl GetEmailDomainPolicyRepositoryPort for GetEmailDomainPolicyRepository {
+            fn execute(&self) -> GetEmailDomainPolicyRepositoryFuture<'_>;
+        }
+
+        impl Clone for GetEmailDomainPolicyRepository {
+            fn clone(&self) -> Self {
+                MockGetEmailDomainPolicyRepository::new()
+            }
+        }
+    }
+
+    fn build_policy(mode: EmailDomainPolicyMode) -> EmailDomainPolicyEntity {
+        EmailDomainPolicyEntity {
+            mode,
+            block_disposable: true,
+            allowed_domains: vec!["acme.com".to_string(), "mailinator.com".to_string()],
+            denied_domains: vec!["eu.acme.com".to_string()],
+            updated_by: None,
+            updated_at: 1_695_996_669,
+        }
+    }
+
+    fn build_adapter(
+        mode: EmailDomainPolicyMode,
+        expected_loads: usize,
+        cache_ttl_seconds: u64,
+    ) -> CachedEmailDomainPolicyAdapter<MockGetEmailDomainPolicyRepository> {
+        let mut get_email_domain_policy_repository_mock =
+            MockGetEmailDomainPolicyRepository::default();
+
+        get_email_domain_policy_repository_mock
+            .expect_execute()
+            .times(expected_loads)
+            .returning(move || Box::pin(async move { Ok(build_policy(mode)) }));
+
+        CachedEmailDomainPolicyAdapter::new(
+            get_email_domain_policy_repository_mock,
+            &EmailDomainPolicyConfig { cache_ttl_seconds },
+        )
+    }
+
+    #[tokio::test]
+    async fn should_only_accept_allowlisted_domains_in_allowlist_mode() {
+        let adapter = build_adapter(EmailDomainPolicyMode::Allowlist, 1, 60);
+
+        assert_eq!(adapter.check("john.doe@acme.com").await, Ok(()));
+        assert_eq!(adapter.check("john.doe@sales.ACME.com").await, Ok(()));
+        assert_eq!(
+            adapter.check("john.doe@gmail.com").await,
+            Err(EmailDomainPolicyError::DomainNotAllowed {
+                domain: "gmail.com".to_string()
+            })
+        );
+        assert_eq!(
+            adapter.check("john.doe@notacme.com").await,
+            Err(EmailDomainPolicyError::DomainNotAllowed {
+                domain: "notacme.com".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refuse_denylisted_and_disposable_domains_in_denylist_mode() {
+        let adapter = build_adapter(EmailDomainPolicyMode::Denylist, 1, 60);
+
+        assert_eq!(adapter.check("john.doe@gmail.com").await, Ok(()));
+        assert_eq!(
+            adapter.check("john.doe@eu.acme.com").await,
+            Err(EmailDomainPolicyError::DomainDenied {
+                domain: "eu.acme.com".to_string()
+            })
+        );
+        assert_eq!(
+            adapter.check("john.doe@yopmail.com").await,
+            Err(EmailDomainPolicyError::DisposableDomain {
+                domain: "yopmail.com".to_string()
+            })
+        );
+        // Allowlisted domains are exempt from the disposable check.
+        assert_eq!(adapter.check("john.doe@mailinator.com").await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_reload_policy_once_invalidated_or_expired() {
+        let adapter = build_adapter(EmailDomainPolicyMode::Denylist, 2, 60);
+
+        adapter.check("john.doe@gmail.com").await.unwrap();
+        adapter.check("john.doe@gmail.com").await.unwrap();
+        adapter.invalidate();
+        adapter.check("john.doe@gmail.com").await.unwrap();
+
+        let uncached_adapter = build_adapter(EmailDomainPolicyMode::Denylist, 2, 0);
+
+        uncached_adapter.check("john.doe@gmail.com").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        uncached_adapter.check("john.doe@gmail.com").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_policy_cannot_be_loaded() {
+        let mut get_email_domain_policy_repository_mock =
+            MockGetEmailDomainPolicyRepository::default();
+
+        get_email_domain_policy_repository_mock
+            .expect_execute()
+            .returning(|| {
+                Box::pin(async {
+                    Err(GetEmailDomainPolicyRepositoryError::FindError {
+                        message: "connection refused".to_string(),
+                    })
+                })
+            });
+
+        let adapter = CachedEmailDomainPolicyAdapter::new(
+            get_email_domain_policy_repository_mock,
+            &EmailDomainPolicyConfig {
+                cache_ttl_seconds: 60,
+            },
+        );
+
+        assert!(matches!(
+            adapter.check("john.doe@gmail.com").await,
+            Err(EmailDomainPolicyError::LoadError { .. })
+        ));
+    }
+}
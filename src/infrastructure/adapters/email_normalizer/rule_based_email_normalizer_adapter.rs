@@ -0,0 +1,125 @@
+use crate::{
+    application::ports::email_normalizer::email_normalizer_port::EmailNormalizerPort,
+    infrastructure::config::app_config::EmailNormalizationConfig,
+};
+
+/// Providers that deliver `name+tag@domain` to `name@domain`.
+const SUBADDRESSING_DOMAINS: [&str; 9] = [
+    "gmail.com",
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "icloud.com",
+    "me.com",
+    "protonmail.com",
+    "proton.me",
+    "fastmail.com",
+];
+
+/// Providers among the above that also ignore dots in the local part.
+const DOT_INSENSITIVE_DOMAINS: [&str; 1] = ["gmail.com"];
+
+/// Trims e-mails and lowercases their domain, which is case-insensitive. The local part keeps
+/// its case, as the database compares e-mails case-insensitively anyway.
+#[derive(Clone)]
+pub struct RuleBasedEmailNormalizerAdapter {
+    provider_rules: bool,
+}
+
+impl RuleBasedEmailNormalizerAdapter {
+    #[must_use]
+    pub const fn new(email_normalization_config: &EmailNormalizationConfig) -> Self {
+        Self {
+            provider_rules: email_normalization_config.provider_rules,
+        }
+    }
+
+    fn apply_provider_rules(local_part: &str, domain: &str) -> (String, String) {
+        let domain = if domain == "googlemail.com" {
+            "gmail.com"
+        } else {
+            domain
+        };
+
+        if !SUBADDRESSING_DOMAINS.contains(&domain) {
+            return (local_part.to_string(), domain.to_string());
+        }
+
+        // These providers treat the local part case-insensitively too.
+        let mut local_part = local_part.to_lowercase();
+
+        if let Some((name, _)) = local_part.split_once('+') {
+            local_part = name.to_string();
+        }
+
+        if DOT_INSENSITIVE_DOMAINS.contains(&domain) {
+            local_part.retain(|character| character != '.');
+        }
+
+        (local_part, domain.to_string())
+    }
+}
+
+impl EmailNormalizerPort for RuleBasedEmailNormalizerAdapter {
+    fn normalize(&self, email: &str) -> String {
+        let email = email.trim();
+
+        let Some((local_part, domain)) = email.rsplit_once('@') else {
+            return email.to_string();
+        };
+
+        let domain = domain.trim_end_matches('.').to_lowercase();
+
+        if !self.provider_rules {
+            return format!("{local_part}@{domain}");
+        }
+
+        let (local_part, domain) = Self::apply_provider_rules(local_part, &domain);
+
+        format!("{local_part}@{domain}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::email_normalizer::email_normalizer_port::EmailNormalizerPort,
+        infrastructure::{
+            adapters::email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            config::app_config::EmailNormalizationConfig,
+        },
+    };
+
+    #[test]
+    fn should_trim_and_lowercase_the_domain_only() {
+        let adapter = RuleBasedEmailNormalizerAdapter::new(&EmailNormalizationConfig {
+            provider_rules: false,
+        });
+
+        assert_eq!(
+            adapter.normalize("  John.Doe+News@Gmail.COM "),
+            "John.Doe+News@gmail.com"
+        );
+        assert_eq!(adapter.normalize("not-an-email"), "not-an-email");
+    }
+
+    #[test]
+    fn should_fold_provider_aliases_when_enabled() {
+        let adapter = RuleBasedEmailNormalizerAdapter::new(&EmailNormalizationConfig {
+            provider_rules: true,
+        });
+
+        assert_eq!(
+            adapter.normalize("John.Doe+News@GoogleMail.com"),
+            "johndoe@gmail.com"
+        );
+        assert_eq!(
+            adapter.normalize("John.Doe+News@outlook.com"),
+            "john.doe@outlook.com"
+        );
+        assert_eq!(
+            adapter.normalize("John.Doe+News@acme.com"),
+            "John.Doe+News@acme.com"
+        );
+    }
+}
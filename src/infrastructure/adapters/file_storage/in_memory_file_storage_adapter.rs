@@ -0,0 +1,71 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::application::ports::file_storage::file_storage_port::{
+    DeleteFileFuture, FileStorageError, FileStoragePort, GetFileFuture, PutFileFuture,
+};
+
+/// `FileStoragePort` backed by a shared map, meant for tests. Clones share the same files.
+#[derive(Clone, Default)]
+pub struct InMemoryFileStorageAdapter {
+    files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl InMemoryFileStorageAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the stored keys in lexicographic order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread panicked while holding the lock.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.files.lock().unwrap().keys().cloned().collect();
+
+        keys.sort();
+
+        keys
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, Vec<u8>>>, String> {
+        self.files.lock().map_err(|err| err.to_string())
+    }
+}
+
+impl FileStoragePort for InMemoryFileStorageAdapter {
+    fn put(&self, key: String, content: Vec<u8>) -> PutFileFuture<'_> {
+        Box::pin(async move {
+            self.lock()
+                .map_err(|message| FileStorageError::WriteError { message })?
+                .insert(key, content);
+
+            Ok(())
+        })
+    }
+
+    fn get(&self, key: String) -> GetFileFuture<'_> {
+        Box::pin(async move {
+            Ok(self
+                .lock()
+                .map_err(|message| FileStorageError::ReadError { message })?
+                .get(&key)
+                .cloned())
+        })
+    }
+
+    fn delete(&self, key: String) -> DeleteFileFuture<'_> {
+        Box::pin(async move {
+            self.lock()
+                .map_err(|message| FileStorageError::DeleteError { message })?
+                .remove(&key);
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::application::ports::{
+    file_storage::file_storage_port::{
+        DeleteFileFuture, FileStorageError, FileStoragePort, GetFileFuture, PutFileFuture,
+    },
+    health::health_check_port::{
+        HealthCheckFuture, HealthCheckMetrics, HealthCheckPort, HealthCheckResult,
+    },
+};
+
+/// Written and removed again by the health check to prove the root directory is writable.
+const HEALTH_CHECK_PROBE_KEY: &str = ".health-check";
+
+/// Stores files below a root directory on the local filesystem, mapping each key segment to a
+/// directory level.
+#[derive(Clone)]
+pub struct LocalFileStorageAdapter {
+    root_directory: Arc<PathBuf>,
+}
+
+impl LocalFileStorageAdapter {
+    #[must_use]
+    pub fn new(root_directory: impl Into<PathBuf>) -> Self {
+        Self {
+            root_directory: Arc::new(root_directory.into()),
+        }
+    }
+
+    /// Resolves a key to a path, rejecting keys that could escape the root directory.
+    fn resolve(&self, key: &str) -> Result<PathBuf, FileStorageError> {
+        let relative_path = Path::new(key);
+
+        let is_valid = !key.is_empty()
+            && relative_path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_valid {
+            return Err(FileStorageError::InvalidKey {
+                key: key.to_string(),
+            });
+        }
+
+        Ok(self.root_directory.join(relative_path))
+    }
+}
+
+impl FileStoragePort for LocalFileStorageAdapter {
+    fn put(&self, key: String, content: Vec<u8>) -> PutFileFuture<'_> {
+        Box::pin(async move {
+            let path = self.resolve(&key)?;
+
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.map_err(|err| {
+                    FileStorageError::WriteError {
+                        message: err.to_string(),
+                    }
+                })?;
+            }
+
+            tokio::fs::write(&path, content)
+                .await
+                .map_err(|err| FileStorageError::WriteError {
+                    message: err.to_string(),
+                })
+        })
+    }
+
+    fn get(&self, key: String) -> GetFileFuture<'_> {
+        Box::pin(async move {
+            let path = self.resolve(&key)?;
+
+            match tokio::fs::read(&path).await {
+                Ok(content) => Ok(Some(content)),
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(FileStorageError::ReadError {
+                    message: err.to_string(),
+                }),
+            }
+        })
+    }
+
+    fn delete(&self, key: String) -> DeleteFileFuture<'_> {
+        Box::pin(async move {
+            let path = self.resolve(&key)?;
+
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(FileStorageError::DeleteError {
+                    message: err.to_string(),
+                }),
+            }
+        })
+    }
+}
+
+impl HealthCheckPort for LocalFileStorageAdapter {
+    fn name(&self) -> &'static str {
+        "storage"
+    }
+
+    fn check(&self) -> HealthCheckFuture<'_> {
+        Box::pin(async move {
+            let probe_result = async {
+                self.put(HEALTH_CHECK_PROBE_KEY.to_string(), vec![]).await?;
+                self.delete(HEALTH_CHECK_PROBE_KEY.to_string()).await
+            }
+            .await;
+
+            match probe_result {
+                Ok(()) => HealthCheckResult::up(HealthCheckMetrics::new()),
+                Err(err) => HealthCheckResult::down(err.to_string()),
+            }
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::Cursor;
+
+use image::{ImageFormat, ImageReader, Limits, imageops::FilterType};
+
+use crate::application::ports::image_processing::image_processing_port::{
+    ImageProcessingError, ImageProcessingPort,
+};
+
+/// Largest width or height accepted when decoding, which bounds the memory a crafted image can
+/// make the decoder allocate.
+const MAX_IMAGE_DIMENSION: u32 = 4096;
+
+#[derive(Clone)]
+pub struct ImageAdapter;
+
+impl ImageAdapter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ImageProcessingPort for ImageAdapter {
+    fn sniff_content_type(&self, content: &[u8]) -> Option<&'static str> {
+        match image::guess_format(content).ok()? {
+            ImageFormat::Png => Some("image/png"),
+            ImageFormat::Jpeg => Some("image/jpeg"),
+            ImageFormat::Gif => Some("image/gif"),
+            ImageFormat::WebP => Some("image/webp"),
+            _ => None,
+        }
+    }
+
+    fn thumbnail_content_type(&self) -> &'static str {
+        "image/png"
+    }
+
+    fn thumbnails(
+        &self,
+        content: &[u8],
+        sizes: &[u32],
+    ) -> Result<Vec<Vec<u8>>, ImageProcessingError> {
+        let mut limits = Limits::default();
+
+        limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+        limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+
+        let mut image_reader = ImageReader::new(Cursor::new(content))
+            .with_guessed_format()
+            .map_err(|err| ImageProcessingError::DecodeError {
+                message: err.to_string(),
+            })?;
+
+        image_reader.limits(limits);
+
+        let image = image_reader
+            .decode()
+            .map_err(|err| ImageProcessingError::DecodeError {
+                message: err.to_string(),
+            })?;
+
+        sizes
+            .iter()
+            .map(|size| {
+                let thumbnail = image.resize_to_fill(*size, *size, FilterType::Lanczos3);
+                let mut encoded = Cursor::new(Vec::new());
+
+                thumbnail
+                    .write_to(&mut encoded, ImageFormat::Png)
+                    .map_err(|err| ImageProcessingError::EncodeError {
+                        message: err.to_string(),
+                    })?;
+
+                Ok(encoded.into_inner())
+            })
+            .collect()
+    }
+}
+
+impl Default for ImageAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,234 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use serde_json::Value;
+
+use crate::application::ports::localization::localization_port::LocalizationPort;
+
+/// Locale of the messages written in the code, used when negotiation finds nothing better.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Catalogs are embedded so a deployment can never miss one; keys are matched case-insensitively
+/// against `Accept-Language` tags.
+const CATALOG_SOURCES: [(&str, &str); 2] = [
+    ("pt-BR", include_str!("../../../../locales/pt-BR.json")),
+    ("es", include_str!("../../../../locales/es.json")),
+];
+
+/// Messages per locale, flattened into dotted keys such as `problems.user_not_found.title`.
+static CATALOGS: LazyLock<HashMap<&'static str, HashMap<String, String>>> = LazyLock::new(|| {
+    CATALOG_SOURCES
+        .iter()
+        .map(|(locale, source)| {
+            let catalog = serde_json::from_str::<Value>(source)
+                .unwrap_or_else(|err| panic!("the {locale} message catalog is invalid: {err}"));
+
+            let mut messages = HashMap::new();
+
+            flatten_catalog("", &catalog, &mut messages);
+
+            (*locale, messages)
+        })
+        .collect()
+});
+
+fn flatten_catalog(prefix: &str, value: &Value, messages: &mut HashMap<String, String>) {
+    match value {
+        Value::Object(members) => {
+            for (key, member) in members {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                flatten_catalog(&key, member, messages);
+            }
+        }
+        Value::String(message) => {
+            messages.insert(prefix.to_string(), message.clone());
+        }
+        _ => {}
+    }
+}
+
+#[derive(Clone)]
+pub struct JsonCatalogLocalizationAdapter;
+
+impl JsonCatalogLocalizationAdapter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Finds the supported locale for a single language tag, first by exact match and then by
+    /// primary language, so `pt` and `pt-PT` are both served the `pt-BR` catalog.
+    fn match_locale(language_tag: &str) -> Option<&'static str> {
+        let supported_locales = || {
+            std::iter::once(DEFAULT_LOCALE).chain(CATALOG_SOURCES.iter().map(|(locale, _)| *locale))
+        };
+
+        let primary_language = |tag: &str| tag.split('-').next().unwrap_or(tag).to_lowercase();
+
+        supported_locales()
+            .find(|locale| locale.eq_ignore_ascii_case(language_tag))
+            .or_else(|| {
+                supported_locales()
+                    .find(|locale| primary_language(locale) == primary_language(language_tag))
+            })
+    }
+
+    /// Parses `Accept-Language` into its tags, ordered by descending quality. Tags with `q=0`
+    /// are refused by the caller, so they are dropped.
+    fn parse_accept_language(accept_language: &str) -> Vec<&str> {
+        let mut language_tags = accept_language
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';').map(str::trim);
+                let language_tag = parts.next().filter(|tag| !tag.is_empty())?;
+
+                let quality = parts
+                    .find_map(|parameter| parameter.strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+
+                (quality > 0.0).then_some((language_tag, quality))
+            })
+            .collect::<Vec<_>>();
+
+        // A stable sort keeps the caller's order between tags of equal quality.
+        language_tags.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+
+        language_tags.into_iter().map(|(tag, _)| tag).collect()
+    }
+}
+
+impl LocalizationPort for JsonCatalogLocalizationAdapter {
+    fn negotiate_locale(&self, accept_language: Option<&str>) -> String {
+        accept_language
+            .map(Self::parse_accept_language)
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(|language_tag| {
+                if language_tag == "*" {
+                    return Some(DEFAULT_LOCALE);
+                }
+
+                Self::match_locale(language_tag)
+            })
+            .unwrap_or(DEFAULT_LOCALE)
+            .to_string()
+    }
+
+    fn translate(&self, locale: &str, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let mut template = CATALOGS.get(locale)?.get(key)?.as_str();
+        let mut message = String::with_capacity(template.len());
+
+        while let Some((text, rest)) = template.split_once('{') {
+            let (name, rest) = rest.split_once('}')?;
+
+            // An unfilled placeholder would leak into the response, so English is kept instead.
+            let (_, value) = args.iter().find(|(arg_name, _)| *arg_name == name)?;
+
+            message.push_str(text);
+            message.push_str(value);
+            template = rest;
+        }
+
+        message.push_str(template);
+
+        Some(message)
+    }
+}
+
+impl Default for JsonCatalogLocalizationAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        application::ports::localization::localization_port::LocalizationPort,
+        infrastructure::adapters::json_catalog::json_catalog_localization_adapter::{
+            CATALOGS, DEFAULT_LOCALE, JsonCatalogLocalizationAdapter,
+        },
+    };
+
+    #[test]
+    fn should_negotiate_locale_by_quality_and_primary_language() {
+        let localization_adapter = JsonCatalogLocalizationAdapter::new();
+
+        assert_eq!(
+            localization_adapter.negotiate_locale(Some("fr-FR, es;q=0.8, pt;q=0.9")),
+            "pt-BR"
+        );
+        assert_eq!(
+            localization_adapter.negotiate_locale(Some("es-MX,es;q=0.9")),
+            "es"
+        );
+        assert_eq!(
+            localization_adapter.negotiate_locale(Some("pt-BR;q=0, en;q=0.5")),
+            "en"
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_default_locale() {
+        let localization_adapter = JsonCatalogLocalizationAdapter::new();
+
+        assert_eq!(localization_adapter.negotiate_locale(None), DEFAULT_LOCALE);
+        assert_eq!(
+            localization_adapter.negotiate_locale(Some("de, fr;q=0.5")),
+            DEFAULT_LOCALE
+        );
+        assert_eq!(
+            localization_adapter.negotiate_locale(Some("not a ; valid header")),
+            DEFAULT_LOCALE
+        );
+    }
+
+    #[test]
+    fn should_translate_with_placeholders() {
+        let localization_adapter = JsonCatalogLocalizationAdapter::new();
+
+        let message = localization_adapter.translate(
+            "pt-BR",
+            "validation.missing",
+            &[("pointer", "/email".to_string())],
+        );
+
+        assert_eq!(message.as_deref(), Some("o campo '/email' é obrigatório"));
+    }
+
+    #[test]
+    fn should_not_translate_default_locale_or_unfilled_placeholders() {
+        let localization_adapter = JsonCatalogLocalizationAdapter::new();
+
+        assert!(
+            localization_adapter
+                .translate(
+                    DEFAULT_LOCALE,
+                    "validation.missing",
+                    &[("pointer", "/email".to_string())]
+                )
+                .is_none()
+        );
+        assert!(
+            localization_adapter
+                .translate("es", "validation.missing", &[])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn should_have_same_keys_in_every_catalog() {
+        let key_sets = CATALOGS
+            .values()
+            .map(|messages| messages.keys().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+
+        assert!(key_sets.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+}
@@ -1,79 +1,218 @@
-use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode, errors::ErrorKind,
+};
 use serde::{Deserialize, Serialize};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
 
-use crate::application::ports::auth::auth_port::{AuthError, AuthPort};
+use crate::{
+    application::ports::{
+        auth::auth_port::{AuthClaims, AuthError, AuthPort, AuthToken},
+        clock::clock_port::ClockPort,
+    },
+    domain::value_objects::shared::timestamp::Timestamp,
+};
+
+/// Seconds a token is still accepted after it expired, to absorb clock drift between hosts.
+const EXPIRATION_LEEWAY_SECONDS: i64 = 5;
 
 #[derive(Debug, Serialize, Deserialize)]
-#[allow(clippy::cast_possible_truncation)]
 struct Claims {
     sub: String,
-    exp: usize,
+    iat: i64,
+    exp: i64,
+    /// `iat` in microseconds. Missing from tokens issued before it was introduced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iat_us: Option<i64>,
 }
 
 #[derive(Clone)]
-pub struct JsonWebTokenAdapter;
+pub struct JsonWebTokenAdapter<C> {
+    secret: Arc<str>,
+    token_ttl_seconds: u64,
+    clock_adapter: C,
+}
 
-impl JsonWebTokenAdapter {
+impl<C> JsonWebTokenAdapter<C>
+where
+    C: ClockPort,
+{
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub fn new(secret: &str, token_ttl_seconds: u64, clock_adapter: C) -> Self {
+        Self {
+            secret: Arc::from(secret),
+            token_ttl_seconds,
+            clock_adapter,
+        }
     }
 }
 
-impl AuthPort for JsonWebTokenAdapter {
-    fn generate_auth_token(&self, user_id: &str) -> Result<String, AuthError> {
-        let expiration = usize::try_from(
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-                + 120,
-        )
-        .map_err(|_| AuthError::UnexpectedError)?;
+impl<C> AuthPort for JsonWebTokenAdapter<C>
+where
+    C: ClockPort,
+{
+    fn generate_auth_token(&self, user_id: &str) -> Result<AuthToken, AuthError> {
+        let now = self.clock_adapter.now();
+        let issued_at = now.unix_timestamp();
+
+        let token_ttl_seconds =
+            i64::try_from(self.token_ttl_seconds).map_err(|_| AuthError::UnexpectedError)?;
 
         let claims = Claims {
             sub: user_id.to_string(),
-            exp: expiration,
+            iat: issued_at,
+            exp: issued_at + token_ttl_seconds,
+            iat_us: Some(now.unix_timestamp_micros()),
         };
 
         let auth_token = encode(
             &Header::default(),
             &claims,
-            &EncodingKey::from_secret("asdasdas".as_ref()),
+            &EncodingKey::from_secret(self.secret.as_bytes()),
         )
         .map_err(|err| AuthError::GenerateTokenError {
             message: err.to_string(),
         })?;
 
-        Ok(auth_token)
+        Ok(AuthToken {
+            value: auth_token,
+            issued_at: claims.iat,
+            expires_at: claims.exp,
+        })
     }
 
-    fn verify_auth_token(&self, token: &str) -> Result<(), AuthError> {
+    fn verify_auth_token(&self, token: &str) -> Result<AuthClaims, AuthError> {
         let mut validation = Validation::new(Algorithm::HS256);
 
-        validation.leeway = 5;
-        validation.validate_exp = true;
+        // Expiry is checked against the clock port below rather than the system time.
+        validation.validate_exp = false;
 
-        decode::<Claims>(
+        let token_data = decode::<Claims>(
             token,
-            &DecodingKey::from_secret("asdasdas".as_ref()),
+            &DecodingKey::from_secret(self.secret.as_bytes()),
             &validation,
         )
-        .map(|_| ())
         .map_err(|err| match err.kind() {
-            jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthError::ExpiredTokenError,
-            jsonwebtoken::errors::ErrorKind::InvalidToken => AuthError::InvalidTokenError,
-            _ => {
-                println!("{err}");
+            ErrorKind::ExpiredSignature => AuthError::ExpiredTokenError,
+            // Failures caused by our own keys or crypto, not by what the client sent.
+            ErrorKind::InvalidEcdsaKey
+            | ErrorKind::InvalidRsaKey(_)
+            | ErrorKind::RsaFailedSigning
+            | ErrorKind::InvalidAlgorithmName
+            | ErrorKind::InvalidKeyFormat
+            | ErrorKind::MissingAlgorithm
+            | ErrorKind::Crypto(_) => {
+                tracing::error!("Unexpected error while verifying an authentication token: {err}.");
+
                 AuthError::UnexpectedError
             }
+            _ => AuthError::InvalidTokenError,
+        })?;
+
+        if token_data.claims.exp + EXPIRATION_LEEWAY_SECONDS
+            < self.clock_adapter.now().unix_timestamp()
+        {
+            return Err(AuthError::ExpiredTokenError);
+        }
+
+        let issued_at = token_data
+            .claims
+            .iat_us
+            .map_or_else(
+                || Timestamp::from_unix_timestamp(token_data.claims.iat),
+                Timestamp::from_unix_timestamp_micros,
+            )
+            .map_err(|_| AuthError::InvalidTokenError)?;
+
+        Ok(AuthClaims {
+            user_id: token_data.claims.sub,
+            issued_at,
         })
     }
 }
 
-impl Default for JsonWebTokenAdapter {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::auth::auth_port::{AuthError, AuthPort},
+        domain::value_objects::shared::timestamp::Timestamp,
+        infrastructure::adapters::{
+            clock::manual_clock_adapter::ManualClockAdapter,
+            jsonwebtoken::jsonwebtoken_adapter::JsonWebTokenAdapter,
+        },
+    };
+
+    const USER_ID: &str = "dba86129-90be-4409-a5a3-396db9335a57";
+
+    #[test]
+    fn should_issue_token_at_clock_time() {
+        let clock_adapter = ManualClockAdapter::new(1_695_996_669);
+        let auth_adapter = JsonWebTokenAdapter::new("secret", 3600, clock_adapter);
+
+        let auth_token = auth_adapter.generate_auth_token(USER_ID).unwrap();
+
+        assert_eq!(auth_token.issued_at, 1_695_996_669);
+        assert_eq!(auth_token.expires_at, 1_695_996_669 + 3600);
+
+        let auth_claims = auth_adapter.verify_auth_token(&auth_token.value).unwrap();
+
+        assert_eq!(auth_claims.user_id, USER_ID);
+        assert_eq!(auth_claims.issued_at.unix_timestamp(), 1_695_996_669);
+    }
+
+    #[test]
+    fn should_keep_sub_second_issue_time() {
+        let clock_adapter = ManualClockAdapter::new(1_695_996_669);
+        let auth_adapter = JsonWebTokenAdapter::new("secret", 3600, clock_adapter.clone());
+
+        clock_adapter.set(Timestamp::from_unix_timestamp_micros(1_695_996_669_250_000).unwrap());
+
+        let auth_token = auth_adapter.generate_auth_token(USER_ID).unwrap();
+        let auth_claims = auth_adapter.verify_auth_token(&auth_token.value).unwrap();
+
+        assert_eq!(auth_token.issued_at, 1_695_996_669);
+        assert_eq!(
+            auth_claims.issued_at.unix_timestamp_micros(),
+            1_695_996_669_250_000
+        );
+    }
+
+    #[test]
+    fn should_reject_tokens_the_client_tampered_with() {
+        let clock_adapter = ManualClockAdapter::new(1_695_996_669);
+        let auth_adapter = JsonWebTokenAdapter::new("secret", 3600, clock_adapter.clone());
+        let other_auth_adapter = JsonWebTokenAdapter::new("other-secret", 3600, clock_adapter);
+
+        let forged_token = other_auth_adapter.generate_auth_token(USER_ID).unwrap();
+
+        for token in [
+            forged_token.value.as_str(),
+            "not-a-jwt",
+            "a.b.c",
+            "e30.e30.e30",
+        ] {
+            assert_eq!(
+                auth_adapter.verify_auth_token(token),
+                Err(AuthError::InvalidTokenError),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_token_once_expired() {
+        let clock_adapter = ManualClockAdapter::new(1_695_996_669);
+        let auth_adapter = JsonWebTokenAdapter::new("secret", 3600, clock_adapter.clone());
+
+        let auth_token = auth_adapter.generate_auth_token(USER_ID).unwrap();
+
+        // Still accepted within the leeway.
+        clock_adapter.advance(3605);
+        assert!(auth_adapter.verify_auth_token(&auth_token.value).is_ok());
+
+        clock_adapter.advance(1);
+        assert_eq!(
+            auth_adapter.verify_auth_token(&auth_token.value),
+            Err(AuthError::ExpiredTokenError)
+        );
     }
 }
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use opentelemetry::global;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::application::ports::tracing::trace_context_port::TraceContextPort;
+
+/// Reads and writes trace context with the globally installed OpenTelemetry propagator.
+#[derive(Clone)]
+pub struct OpenTelemetryTraceContextAdapter;
+
+impl TraceContextPort for OpenTelemetryTraceContextAdapter {
+    fn continue_trace(&self, span: &Span, headers: &HashMap<String, String>) {
+        let parent_context =
+            global::get_text_map_propagator(|propagator| propagator.extract(headers));
+
+        if let Err(err) = span.set_parent(parent_context) {
+            tracing::debug!("Unable to continue the incoming trace: {err}.");
+        }
+    }
+
+    fn trace_headers(&self, span: &Span) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&span.context(), &mut headers);
+        });
+
+        headers.retain(|_, value| !value.is_empty());
+
+        headers
+    }
+}
@@ -0,0 +1,422 @@
+/// Common passwords and words, most common first. Guessing one takes as many attempts as its
+/// rank, as an attacker tries them in this order.
+const COMMON_PASSWORDS: [&str; 64] = [
+    "password",
+    "123456",
+    "qwerty",
+    "111111",
+    "iloveyou",
+    "admin",
+    "welcome",
+    "monkey",
+    "dragon",
+    "football",
+    "letmein",
+    "abc123",
+    "login",
+    "princess",
+    "sunshine",
+    "master",
+    "shadow",
+    "baseball",
+    "superman",
+    "trustno1",
+    "hello",
+    "freedom",
+    "whatever",
+    "qazwsx",
+    "michael",
+    "charlie",
+    "jordan",
+    "jennifer",
+    "hunter",
+    "soccer",
+    "batman",
+    "starwars",
+    "secret",
+    "summer",
+    "winter",
+    "spring",
+    "autumn",
+    "love",
+    "money",
+    "computer",
+    "internet",
+    "google",
+    "flower",
+    "cookie",
+    "pokemon",
+    "london",
+    "samsung",
+    "liverpool",
+    "chelsea",
+    "arsenal",
+    "banana",
+    "orange",
+    "purple",
+    "chocolate",
+    "changeme",
+    "default",
+    "access",
+    "user",
+    "guest",
+    "test",
+    "pass",
+    "senha",
+    "contrasena",
+    "azerty",
+];
+
+/// Rows of a QWERTY keyboard; runs along them are among the first patterns attackers try.
+const KEYBOARD_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Character substitutions undone before looking passwords up in the dictionary.
+const LEET_SUBSTITUTIONS: [(char, char); 9] = [
+    ('@', 'a'),
+    ('4', 'a'),
+    ('3', 'e'),
+    ('1', 'i'),
+    ('!', 'i'),
+    ('0', 'o'),
+    ('$', 's'),
+    ('5', 's'),
+    ('7', 't'),
+];
+
+/// Guesses per character of a stretch no pattern explains.
+const BRUTEFORCE_CARDINALITY_LOG10: f64 = 1.0;
+
+/// Guesses an attacker spends on every extra pattern before growing the sequence, as in zxcvbn.
+const MIN_GUESSES_BEFORE_GROWING_SEQUENCE_LOG10: f64 = 4.0;
+
+/// `log10` of the guesses separating one score from the next: 10^3, 10^6, 10^8 and 10^10.
+const SCORE_THRESHOLDS_LOG10: [f64; 4] = [3.0, 6.0, 8.0, 10.0];
+
+/// A stretch of the password explained by a pattern, with the `log10` of the guesses needed to
+/// find it.
+struct PatternMatch {
+    start: usize,
+    end: usize,
+    guesses_log10: f64,
+}
+
+/// Estimates how hard a password is to guess, following zxcvbn.
+///
+/// The password is split into dictionary words, repeats, sequences, keyboard runs and years, and
+/// the split needing the fewest guesses decides the score.
+pub struct PasswordStrengthEstimator;
+
+impl PasswordStrengthEstimator {
+    /// Scores `password` from 0, guessable in under a thousand attempts, to 4. `user_inputs`
+    /// such as the user's e-mail and names are tried first, as an attacker would.
+    #[must_use]
+    pub fn score(password: &str, user_inputs: &[&str]) -> u8 {
+        let guesses_log10 = Self::guesses_log10(password, user_inputs);
+
+        let score = SCORE_THRESHOLDS_LOG10
+            .iter()
+            .take_while(|threshold| guesses_log10 >= **threshold)
+            .count();
+
+        u8::try_from(score).unwrap_or(u8::MAX)
+    }
+
+    fn guesses_log10(password: &str, user_inputs: &[&str]) -> f64 {
+        let characters = password.chars().collect::<Vec<_>>();
+        let length = characters.len();
+
+        if length == 0 {
+            return 0.0;
+        }
+
+        let mut pattern_matches = vec![];
+
+        Self::dictionary_matches(&characters, user_inputs, &mut pattern_matches);
+        Self::repeat_matches(&characters, &mut pattern_matches);
+        Self::sequence_matches(&characters, &mut pattern_matches);
+        Self::keyboard_matches(&characters, &mut pattern_matches);
+        Self::year_matches(&characters, &mut pattern_matches);
+
+        // best[end][count] is the cheapest way to explain the first `end` characters with
+        // `count` matches, where a bruteforced stretch also counts as one match.
+        let mut best = vec![vec![f64::INFINITY; length + 1]; length + 1];
+        best[0][0] = 0.0;
+
+        for end in 1..=length {
+            for start in 0..end {
+                #[allow(clippy::cast_precision_loss)]
+                let bruteforce_log10 = (end - start) as f64 * BRUTEFORCE_CARDINALITY_LOG10;
+
+                Self::relax(&mut best, start, end, bruteforce_log10);
+            }
+
+            for pattern_match in pattern_matches.iter().filter(|m| m.end == end) {
+                Self::relax(
+                    &mut best,
+                    pattern_match.start,
+                    end,
+                    pattern_match.guesses_log10,
+                );
+            }
+        }
+
+        best[length]
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, product_log10)| product_log10.is_finite())
+            .map(|(count, product_log10)| {
+                // Guesses are count! * product, plus the attempts spent on shorter sequences.
+                #[allow(clippy::cast_precision_loss)]
+                let factorial_log10 = (1..=count).map(|n| (n as f64).log10()).sum::<f64>();
+                #[allow(clippy::cast_precision_loss)]
+                let additive_log10 = (count - 1) as f64 * MIN_GUESSES_BEFORE_GROWING_SEQUENCE_LOG10;
+
+                Self::add_log10(factorial_log10 + product_log10, additive_log10)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn relax(best: &mut [Vec<f64>], start: usize, end: usize, guesses_log10: f64) {
+        for count in 0..end {
+            let candidate = best[start][count] + guesses_log10;
+
+            if candidate < best[end][count + 1] {
+                best[end][count + 1] = candidate;
+            }
+        }
+    }
+
+    /// `log10(10^a + 10^b)`, computed without leaving logarithms.
+    fn add_log10(a: f64, b: f64) -> f64 {
+        let (high, low) = if a > b { (a, b) } else { (b, a) };
+
+        high + 10_f64.powf(low - high).ln_1p() / std::f64::consts::LN_10
+    }
+
+    fn dictionary_matches(
+        characters: &[char],
+        user_inputs: &[&str],
+        pattern_matches: &mut Vec<PatternMatch>,
+    ) {
+        let lowercase = characters
+            .iter()
+            .map(|character| character.to_lowercase().next().unwrap_or(*character))
+            .collect::<Vec<_>>();
+
+        let unleeted = lowercase
+            .iter()
+            .map(|character| {
+                LEET_SUBSTITUTIONS
+                    .iter()
+                    .find(|(leet, _)| leet == character)
+                    .map_or(*character, |(_, letter)| *letter)
+            })
+            .collect::<Vec<_>>();
+
+        let user_words = user_inputs
+            .iter()
+            .flat_map(|user_input| user_input.split(|character: char| !character.is_alphanumeric()))
+            .filter(|word| word.chars().count() >= 3)
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>();
+
+        let dictionary = user_words
+            .iter()
+            .map(String::as_str)
+            .chain(COMMON_PASSWORDS)
+            .enumerate()
+            .map(|(index, word)| (index + 1, word.chars().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        for (rank, word) in &dictionary {
+            for start in 0..characters.len() {
+                let end = start + word.len();
+
+                if end > characters.len() {
+                    break;
+                }
+
+                let is_leet = unleeted[start..end] == word[..] && lowercase[start..end] != word[..];
+                let is_reversed = unleeted[start..end].iter().rev().eq(word.iter());
+
+                if !is_leet && !is_reversed && lowercase[start..end] != word[..] {
+                    continue;
+                }
+
+                let has_uppercase = characters[start..end]
+                    .iter()
+                    .any(|character| character.is_uppercase());
+
+                // Each variation at most doubles the guesses for the word.
+                let variations = [has_uppercase, is_leet, is_reversed]
+                    .into_iter()
+                    .filter(|is_variation| *is_variation)
+                    .count();
+
+                #[allow(clippy::cast_precision_loss)]
+                let guesses_log10 =
+                    (variations as f64).mul_add(2_f64.log10(), (*rank as f64).log10());
+
+                pattern_matches.push(PatternMatch {
+                    start,
+                    end,
+                    guesses_log10,
+                });
+            }
+        }
+    }
+
+    fn repeat_matches(characters: &[char], pattern_matches: &mut Vec<PatternMatch>) {
+        for (start, end) in Self::runs(characters, |previous, current| previous == current) {
+            #[allow(clippy::cast_precision_loss)]
+            let guesses_log10 = 1.0 + ((end - start) as f64).log10();
+
+            pattern_matches.push(PatternMatch {
+                start,
+                end,
+                guesses_log10,
+            });
+        }
+    }
+
+    fn sequence_matches(characters: &[char], pattern_matches: &mut Vec<PatternMatch>) {
+        for delta in [1_i64, -1] {
+            let is_step = |previous: char, current: char| {
+                i64::from(u32::from(current)) - i64::from(u32::from(previous)) == delta
+            };
+
+            for (start, end) in Self::runs(characters, is_step) {
+                let first = characters[start];
+
+                let base: f64 = if "aAzZ019".contains(first) {
+                    4.0
+                } else if first.is_ascii_digit() {
+                    10.0
+                } else {
+                    26.0
+                };
+
+                #[allow(clippy::cast_precision_loss)]
+                let guesses_log10 = base.log10()
+                    + ((end - start) as f64).log10()
+                    + f64::from(u8::from(delta < 0)) * 2_f64.log10();
+
+                pattern_matches.push(PatternMatch {
+                    start,
+                    end,
+                    guesses_log10,
+                });
+            }
+        }
+    }
+
+    fn keyboard_matches(characters: &[char], pattern_matches: &mut Vec<PatternMatch>) {
+        let lowercase = characters
+            .iter()
+            .map(char::to_ascii_lowercase)
+            .collect::<Vec<_>>();
+
+        for row in KEYBOARD_ROWS {
+            let reversed_row = row.chars().rev().collect::<String>();
+
+            for start in 0..characters.len() {
+                for end in (start + 4)..=characters.len().min(start + row.len()) {
+                    let run = lowercase[start..end].iter().collect::<String>();
+
+                    if !row.contains(&run) && !reversed_row.contains(&run) {
+                        continue;
+                    }
+
+                    #[allow(clippy::cast_precision_loss)]
+                    let guesses_log10 = 40_f64.log10() + ((end - start) as f64).log10();
+
+                    pattern_matches.push(PatternMatch {
+                        start,
+                        end,
+                        guesses_log10,
+                    });
+                }
+            }
+        }
+    }
+
+    fn year_matches(characters: &[char], pattern_matches: &mut Vec<PatternMatch>) {
+        for start in 0..characters.len().saturating_sub(3) {
+            let year = characters[start..start + 4].iter().collect::<String>();
+
+            if year
+                .parse::<u16>()
+                .is_ok_and(|year| (1900..=2099).contains(&year))
+            {
+                pattern_matches.push(PatternMatch {
+                    start,
+                    end: start + 4,
+                    guesses_log10: 200_f64.log10(),
+                });
+            }
+        }
+    }
+
+    /// Maximal runs of at least three characters where every pair of neighbours satisfies
+    /// `continues`.
+    fn runs(characters: &[char], continues: impl Fn(char, char) -> bool) -> Vec<(usize, usize)> {
+        let mut runs = vec![];
+        let mut start = 0;
+
+        for end in 1..=characters.len() {
+            if end < characters.len() && continues(characters[end - 1], characters[end]) {
+                continue;
+            }
+
+            if end - start >= 3 {
+                runs.push((start, end));
+            }
+
+            start = end;
+        }
+
+        runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::adapters::password_policy::password_strength_estimator::PasswordStrengthEstimator;
+
+    #[test]
+    fn should_score_patterns_low() {
+        for password in [
+            "password",
+            "P@ssw0rd",
+            "qwertyuiop",
+            "aaaaaaaaaaaa",
+            "abcdefgh1234",
+            "password2024",
+        ] {
+            assert!(
+                PasswordStrengthEstimator::score(password, &[]) <= 1,
+                "{password} should be weak"
+            );
+        }
+    }
+
+    #[test]
+    fn should_score_long_unpredictable_passwords_high() {
+        assert_eq!(
+            PasswordStrengthEstimator::score("correct horse battery staple", &[]),
+            4
+        );
+        assert_eq!(PasswordStrengthEstimator::score("Xk9#vR2!qLm7", &[]), 4);
+    }
+
+    #[test]
+    fn should_treat_user_inputs_as_dictionary_words() {
+        let user_inputs = ["john.doe@gmail.com", "John", "Doe"];
+
+        assert!(
+            PasswordStrengthEstimator::score("JohnDoe", &user_inputs)
+                < PasswordStrengthEstimator::score("JohnDoe", &[])
+        );
+    }
+}
@@ -0,0 +1,434 @@
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use sha1::{Digest, Sha1};
+
+use crate::{
+    application::ports::password_policy::password_policy_port::{
+        PasswordPolicyError, PasswordPolicyFuture, PasswordPolicyPort, PasswordPolicyViolation,
+    },
+    infrastructure::{
+        adapters::{
+            bcrypt::bcrypt_adapter::MAX_PASSWORD_BYTES,
+            password_policy::password_strength_estimator::PasswordStrengthEstimator,
+        },
+        config::app_config::PasswordPolicyConfig,
+    },
+};
+
+/// Personal info shorter than this is too likely to appear by chance to be rejected.
+const MIN_PERSONAL_INFO_LENGTH: usize = 3;
+
+/// Hex digits of the SHA-1 prefix naming each file of a partitioned breached passwords list.
+const PARTITION_PREFIX_LENGTH: usize = 5;
+
+type Sha1Digest = [u8; 20];
+
+enum BreachedPasswords {
+    None,
+    /// Every hash of a single file, held in memory; meant for short lists.
+    Loaded(HashSet<Sha1Digest>),
+    /// A directory with one `PREFIX.txt` file of `SUFFIX[:COUNT]` lines per 5 hex digit prefix,
+    /// the layout the Have I Been Pwned downloader produces. Only the file matching the
+    /// password is read, so a full dump costs neither memory nor startup time.
+    Partitioned(PathBuf),
+}
+
+/// Checks passwords against the configured rules. The breached password hashes are loaded once
+/// and shared between clones.
+#[derive(Clone)]
+pub struct RuleBasedPasswordPolicyAdapter {
+    password_policy_config: PasswordPolicyConfig,
+    breached_passwords: Arc<BreachedPasswords>,
+}
+
+impl RuleBasedPasswordPolicyAdapter {
+    /// Builds the adapter, loading the breached passwords file when one is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `std::io::Error` if the file cannot be read or a line is not a SHA-1 hash.
+    pub fn new(password_policy_config: PasswordPolicyConfig) -> Result<Self, std::io::Error> {
+        let breached_passwords = match &password_policy_config.breached_passwords_file {
+            Some(path) if path.is_dir() => BreachedPasswords::Partitioned(path.clone()),
+            Some(path) => BreachedPasswords::Loaded(Self::load_breached_password_hashes(path)?),
+            None => BreachedPasswords::None,
+        };
+
+        Ok(Self {
+            password_policy_config,
+            breached_passwords: Arc::new(breached_passwords),
+        })
+    }
+
+    /// Streams one `HASH[:COUNT]` per line, the format Have I Been Pwned distributes.
+    fn load_breached_password_hashes(path: &Path) -> Result<HashSet<Sha1Digest>, std::io::Error> {
+        let file = File::open(path).map_err(|err| {
+            std::io::Error::new(
+                err.kind(),
+                format!(
+                    "cannot read the breached passwords file '{}': {err}",
+                    path.display()
+                ),
+            )
+        })?;
+
+        let mut breached_password_hashes = HashSet::new();
+
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let hash = line.split(':').next().unwrap_or_default().trim();
+
+            let digest = Self::decode_sha1(hash).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "line {} of the breached passwords file '{}' is not a SHA-1 hash",
+                        index + 1,
+                        path.display()
+                    ),
+                )
+            })?;
+
+            breached_password_hashes.insert(digest);
+        }
+
+        Ok(breached_password_hashes)
+    }
+
+    fn decode_sha1(hash: &str) -> Option<Sha1Digest> {
+        if hash.len() != 40 || !hash.is_ascii() {
+            return None;
+        }
+
+        let mut digest = [0; 20];
+
+        for (index, byte) in digest.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hash[index * 2..index * 2 + 2], 16).ok()?;
+        }
+
+        Some(digest)
+    }
+
+    /// Looks the hash up in the file of its prefix; a missing file means no breached password
+    /// shares that prefix.
+    fn is_in_partition(directory: &Path, digest: &Sha1Digest) -> Result<bool, std::io::Error> {
+        let hash = digest.iter().fold(String::new(), |mut hash, byte| {
+            let _ = write!(hash, "{byte:02X}");
+
+            hash
+        });
+        let (prefix, suffix) = hash.split_at(PARTITION_PREFIX_LENGTH);
+
+        let file = match File::open(directory.join(format!("{prefix}.txt"))) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(err),
+        };
+
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let line_suffix = line.split(':').next().unwrap_or_default().trim();
+
+            if line_suffix.eq_ignore_ascii_case(suffix) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn contains_personal_info(password: &str, personal_info: &[&str]) -> bool {
+        let password = password.to_lowercase();
+
+        personal_info
+            .iter()
+            .flat_map(|value| {
+                // An e-mail is also matched by its local part, the bit users tend to reuse.
+                let local_part = value.split_once('@').map(|(local_part, _)| local_part);
+
+                std::iter::once(*value).chain(local_part)
+            })
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| value.chars().count() >= MIN_PERSONAL_INFO_LENGTH)
+            .any(|value| password.contains(&value))
+    }
+
+    async fn is_breached(&self, digest: Sha1Digest) -> bool {
+        match &*self.breached_passwords {
+            BreachedPasswords::None => false,
+            BreachedPasswords::Loaded(breached_password_hashes) => {
+                breached_password_hashes.contains(&digest)
+            }
+            BreachedPasswords::Partitioned(directory) => {
+                let partition_directory = directory.clone();
+
+                // Scanning the prefix file is blocking I/O, kept off the runtime workers.
+                tokio::task::spawn_blocking(move || {
+                    Self::is_in_partition(&partition_directory, &digest)
+                })
+                .await
+                .unwrap_or_else(|err| Err(std::io::Error::other(err)))
+                .unwrap_or_else(|err| {
+                    // Failing open: an unreadable list must not lock everyone out of sign-up.
+                    tracing::error!(
+                        "Cannot read the breached passwords in '{}': {err}.",
+                        directory.display()
+                    );
+
+                    false
+                })
+            }
+        }
+    }
+}
+
+impl PasswordPolicyPort for RuleBasedPasswordPolicyAdapter {
+    fn validate(&self, password: &str, personal_info: &[&str]) -> PasswordPolicyFuture<'_> {
+        let password_policy_config = &self.password_policy_config;
+        let length = password.chars().count();
+        let mut violations = vec![];
+
+        if length < password_policy_config.min_length {
+            violations.push(PasswordPolicyViolation::TooShort {
+                min_length: password_policy_config.min_length,
+            });
+        }
+
+        if length > password_policy_config.max_length {
+            violations.push(PasswordPolicyViolation::TooLong {
+                max_length: password_policy_config.max_length,
+            });
+        } else if password.len() > MAX_PASSWORD_BYTES {
+            violations.push(PasswordPolicyViolation::TooManyBytes {
+                max_bytes: MAX_PASSWORD_BYTES,
+            });
+        }
+
+        for character_class in &password_policy_config.required_character_classes {
+            if !password
+                .chars()
+                .any(|character| character_class.contains(character))
+            {
+                violations.push(PasswordPolicyViolation::MissingCharacterClass {
+                    character_class: *character_class,
+                });
+            }
+        }
+
+        let score = PasswordStrengthEstimator::score(password, personal_info);
+
+        if score < password_policy_config.min_strength_score {
+            violations.push(PasswordPolicyViolation::TooWeak {
+                score,
+                min_score: password_policy_config.min_strength_score,
+            });
+        }
+
+        if Self::contains_personal_info(password, personal_info) {
+            violations.push(PasswordPolicyViolation::ContainsPersonalInfo);
+        }
+
+        let digest: Sha1Digest = Sha1::digest(password.as_bytes()).into();
+
+        Box::pin(async move {
+            if self.is_breached(digest).await {
+                violations.push(PasswordPolicyViolation::Breached);
+            }
+
+            if violations.is_empty() {
+                Ok(())
+            } else {
+                Err(PasswordPolicyError { violations })
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{
+        application::ports::password_policy::password_policy_port::{
+            CharacterClass, PasswordPolicyPort, PasswordPolicyViolation,
+        },
+        infrastructure::{
+            adapters::password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            config::app_config::PasswordPolicyConfig,
+        },
+    };
+
+    fn build_password_policy_config(
+        breached_passwords_file: Option<PathBuf>,
+    ) -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 12,
+            max_length: 64,
+            required_character_classes: vec![CharacterClass::Uppercase, CharacterClass::Digit],
+            min_strength_score: 3,
+            breached_passwords_file,
+        }
+    }
+
+    fn write_breached_passwords_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "rust-tdd-rest-api-{name}-{}.txt",
+            std::process::id()
+        ));
+
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[tokio::test]
+    async fn should_accept_password_satisfying_every_rule() {
+        let password_policy_adapter =
+            RuleBasedPasswordPolicyAdapter::new(build_password_policy_config(None)).unwrap();
+
+        assert!(
+            password_policy_adapter
+                .validate(
+                    "Vinho tinto é ótimo 4 mim",
+                    &["john@gmail.com", "John", "Doe"]
+                )
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_every_violated_rule() {
+        let password_policy_adapter =
+            RuleBasedPasswordPolicyAdapter::new(build_password_policy_config(None)).unwrap();
+
+        let err = password_policy_adapter
+            .validate("johnny", &["johnny@gmail.com", "Johnny", "Doe"])
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.violations,
+            vec![
+                PasswordPolicyViolation::TooShort { min_length: 12 },
+                PasswordPolicyViolation::MissingCharacterClass {
+                    character_class: CharacterClass::Uppercase
+                },
+                PasswordPolicyViolation::MissingCharacterClass {
+                    character_class: CharacterClass::Digit
+                },
+                PasswordPolicyViolation::TooWeak {
+                    score: 0,
+                    min_score: 3
+                },
+                PasswordPolicyViolation::ContainsPersonalInfo,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_reject_password_bcrypt_would_truncate() {
+        let password_policy_adapter =
+            RuleBasedPasswordPolicyAdapter::new(build_password_policy_config(None)).unwrap();
+
+        // 40 characters, but 74 bytes once encoded.
+        let password = "Ação Pão 9 €€ 漢字仮名 ÉÈÊËĀĂĄ 🦀🦀 Ünïcödé Žž";
+
+        let err = password_policy_adapter
+            .validate(password, &[])
+            .await
+            .unwrap_err();
+
+        assert!(password.chars().count() <= 64);
+        assert!(
+            err.violations
+                .contains(&PasswordPolicyViolation::TooManyBytes { max_bytes: 72 })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_reject_breached_password() {
+        // SHA-1 of "Correct Horse Battery 9".
+        let path = write_breached_passwords_file(
+            "breached",
+            "0000000000000000000000000000000000000000:3\nB4D541F98B1F93D4AFE4E6D62B6B83BCB662FEFB:12\n",
+        );
+
+        let password_policy_adapter =
+            RuleBasedPasswordPolicyAdapter::new(build_password_policy_config(Some(path.clone())))
+                .unwrap();
+
+        std::fs::remove_file(path).unwrap();
+
+        let err = password_policy_adapter
+            .validate("Correct Horse Battery 9", &[])
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.violations, vec![PasswordPolicyViolation::Breached]);
+    }
+
+    #[tokio::test]
+    async fn should_look_up_breached_password_in_its_prefix_file() {
+        let directory = std::env::temp_dir().join(format!(
+            "rust-tdd-rest-api-breached-partitioned-{}",
+            std::process::id()
+        ));
+
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // Suffix of the SHA-1 of "Correct Horse Battery 9", whose prefix is B4D54.
+        std::fs::write(
+            directory.join("B4D54.txt"),
+            "0000000000000000000000000000000000A:1\r\n1F98B1F93D4AFE4E6D62B6B83BCB662FEFB:12\r\n",
+        )
+        .unwrap();
+
+        let password_policy_adapter = RuleBasedPasswordPolicyAdapter::new(
+            build_password_policy_config(Some(directory.clone())),
+        )
+        .unwrap();
+
+        let breached_result = password_policy_adapter
+            .validate("Correct Horse Battery 9", &[])
+            .await;
+        let other_result = password_policy_adapter
+            .validate("Vinho tinto é ótimo 4 mim", &[])
+            .await;
+
+        std::fs::remove_dir_all(directory).unwrap();
+
+        assert_eq!(
+            breached_result.unwrap_err().violations,
+            vec![PasswordPolicyViolation::Breached]
+        );
+        assert!(other_result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_on_malformed_breached_passwords_file() {
+        let path = write_breached_passwords_file("malformed", "not-a-hash:1\n");
+
+        let result =
+            RuleBasedPasswordPolicyAdapter::new(build_password_policy_config(Some(path.clone())));
+
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            result.err().map(|err| err.kind()),
+            Some(std::io::ErrorKind::InvalidData)
+        );
+    }
+}
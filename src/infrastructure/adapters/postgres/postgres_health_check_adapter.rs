@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::application::ports::health::health_check_port::{
+    HealthCheckFuture, HealthCheckMetrics, HealthCheckPort, HealthCheckResult,
+};
+
+/// Round-trips a trivial query through the connection pool and reports its usage.
+#[derive(Clone)]
+pub struct PostgresHealthCheckAdapter {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl PostgresHealthCheckAdapter {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl HealthCheckPort for PostgresHealthCheckAdapter {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    fn check(&self) -> HealthCheckFuture<'_> {
+        Box::pin(async move {
+            if let Err(err) = sqlx::query("SELECT 1")
+                .execute(self.database_pool.as_ref())
+                .await
+            {
+                return HealthCheckResult::down(err.to_string());
+            }
+
+            HealthCheckResult::up(HealthCheckMetrics::from([
+                ("pool_size", u64::from(self.database_pool.size())),
+                ("pool_idle", self.database_pool.num_idle() as u64),
+                (
+                    "pool_max",
+                    u64::from(self.database_pool.options().get_max_connections()),
+                ),
+            ]))
+        })
+    }
+}
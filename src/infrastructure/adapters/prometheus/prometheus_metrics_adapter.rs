@@ -0,0 +1,261 @@
+use std::{sync::Arc, time::Duration};
+
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, PullingGauge, Registry,
+    TextEncoder,
+};
+use sqlx::{Pool, Postgres};
+
+use crate::application::ports::metrics::metrics_port::{MetricsError, MetricsPort};
+
+/// Buckets for bcrypt, whose cost factor puts a single operation in the hundreds of milliseconds.
+const PASSWORD_HASHING_BUCKETS: [f64; 10] =
+    [0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+struct PrometheusMetrics {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    sign_ins_total: IntCounterVec,
+    sign_ups_total: IntCounterVec,
+    password_hashing_duration_seconds: HistogramVec,
+    token_verification_failures_total: IntCounterVec,
+    rate_limit_rejections_total: IntCounter,
+}
+
+/// Keeps every metric in a dedicated registry; clones share it.
+#[derive(Clone)]
+pub struct PrometheusMetricsAdapter {
+    metrics: Arc<PrometheusMetrics>,
+}
+
+impl PrometheusMetricsAdapter {
+    /// Creates and registers every metric the application records.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MetricsError` if a metric definition is invalid.
+    pub fn new() -> Result<Self, MetricsError> {
+        let registry = Registry::new();
+
+        let http_requests_total = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests served."),
+            &["method", "route", "status"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time spent serving HTTP requests.",
+            ),
+            &["method", "route", "status"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let sign_ins_total = IntCounterVec::new(
+            Opts::new("auth_sign_ins_total", "Sign-in attempts by outcome."),
+            &["outcome"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let sign_ups_total = IntCounterVec::new(
+            Opts::new("auth_sign_ups_total", "Sign-up attempts by outcome."),
+            &["outcome"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let password_hashing_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "password_hashing_duration_seconds",
+                "Time spent hashing and verifying passwords.",
+            )
+            .buckets(PASSWORD_HASHING_BUCKETS.to_vec()),
+            &["operation"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let token_verification_failures_total = IntCounterVec::new(
+            Opts::new(
+                "auth_token_verification_failures_total",
+                "Rejected authentication tokens by reason.",
+            ),
+            &["reason"],
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let rate_limit_rejections_total = IntCounter::new(
+            "rate_limit_rejections_total",
+            "Requests rejected by the rate limiter.",
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        registry
+            .register(Box::new(http_requests_total.clone()))
+            .and_then(|()| registry.register(Box::new(http_request_duration_seconds.clone())))
+            .and_then(|()| registry.register(Box::new(sign_ins_total.clone())))
+            .and_then(|()| registry.register(Box::new(sign_ups_total.clone())))
+            .and_then(|()| registry.register(Box::new(password_hashing_duration_seconds.clone())))
+            .and_then(|()| registry.register(Box::new(token_verification_failures_total.clone())))
+            .and_then(|()| registry.register(Box::new(rate_limit_rejections_total.clone())))
+            .map_err(|err| Self::registration_error(&err))?;
+
+        Ok(Self {
+            metrics: Arc::new(PrometheusMetrics {
+                registry,
+                http_requests_total,
+                http_request_duration_seconds,
+                sign_ins_total,
+                sign_ups_total,
+                password_hashing_duration_seconds,
+                token_verification_failures_total,
+                rate_limit_rejections_total,
+            }),
+        })
+    }
+
+    /// Exposes the utilization of `database_pool`, read at scrape time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MetricsError` if the pool gauges were already registered.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn register_database_pool(
+        &self,
+        database_pool: &Arc<Pool<Postgres>>,
+    ) -> Result<(), MetricsError> {
+        let pool = database_pool.clone();
+        let connections = PullingGauge::new(
+            "db_pool_connections",
+            "Open connections in the database pool, idle or in use.",
+            Box::new(move || f64::from(pool.size())),
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let pool = database_pool.clone();
+        let idle_connections = PullingGauge::new(
+            "db_pool_idle_connections",
+            "Idle connections in the database pool.",
+            Box::new(move || pool.num_idle() as f64),
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let pool = database_pool.clone();
+        let max_connections = PullingGauge::new(
+            "db_pool_max_connections",
+            "Maximum number of connections the database pool may open.",
+            Box::new(move || f64::from(pool.options().get_max_connections())),
+        )
+        .map_err(|err| Self::registration_error(&err))?;
+
+        let registry = &self.metrics.registry;
+
+        registry
+            .register(Box::new(connections))
+            .and_then(|()| registry.register(Box::new(idle_connections)))
+            .and_then(|()| registry.register(Box::new(max_connections)))
+            .map_err(|err| Self::registration_error(&err))
+    }
+
+    fn registration_error(err: &prometheus::Error) -> MetricsError {
+        MetricsError::RegistrationError {
+            message: err.to_string(),
+        }
+    }
+}
+
+impl MetricsPort for PrometheusMetricsAdapter {
+    fn observe_http_request(
+        &self,
+        method: &str,
+        route: &str,
+        status_code: u16,
+        duration: Duration,
+    ) {
+        let status = status_code.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.metrics
+            .http_requests_total
+            .with_label_values(&labels)
+            .inc();
+        self.metrics
+            .http_request_duration_seconds
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_sign_in(&self, outcome: &str) {
+        self.metrics
+            .sign_ins_total
+            .with_label_values(&[outcome])
+            .inc();
+    }
+
+    fn record_sign_up(&self, outcome: &str) {
+        self.metrics
+            .sign_ups_total
+            .with_label_values(&[outcome])
+            .inc();
+    }
+
+    fn observe_password_hashing(&self, operation: &str, duration: Duration) {
+        self.metrics
+            .password_hashing_duration_seconds
+            .with_label_values(&[operation])
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_token_verification_failure(&self, reason: &str) {
+        self.metrics
+            .token_verification_failures_total
+            .with_label_values(&[reason])
+            .inc();
+    }
+
+    fn record_rate_limit_rejection(&self) {
+        self.metrics.rate_limit_rejections_total.inc();
+    }
+
+    fn export(&self) -> Result<String, MetricsError> {
+        TextEncoder::new()
+            .encode_to_string(&self.metrics.registry.gather())
+            .map_err(|err| MetricsError::ExportError {
+                message: err.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        application::ports::metrics::metrics_port::MetricsPort,
+        infrastructure::adapters::prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+    };
+
+    #[test]
+    fn should_export_recorded_metrics_with_their_labels() {
+        let metrics_adapter = PrometheusMetricsAdapter::new().unwrap();
+
+        metrics_adapter.observe_http_request(
+            "GET",
+            "/api/v1/users/{id}",
+            200,
+            Duration::from_millis(5),
+        );
+        metrics_adapter.record_sign_in("invalid_credentials");
+        metrics_adapter.record_rate_limit_rejection();
+
+        let exported_metrics = metrics_adapter.export().unwrap();
+
+        assert!(exported_metrics.contains(
+            r#"http_requests_total{method="GET",route="/api/v1/users/{id}",status="200"} 1"#
+        ));
+        assert!(
+            exported_metrics.contains(r#"auth_sign_ins_total{outcome="invalid_credentials"} 1"#)
+        );
+        assert!(exported_metrics.contains("rate_limit_rejections_total 1"));
+    }
+}
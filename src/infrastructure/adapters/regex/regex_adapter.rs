@@ -22,20 +22,11 @@ impl PatternMatchingPort for RegexAdapter {
         Ok(regex.is_match(email))
     }
 
-    fn is_valid_email_domain(&self, email: &str) -> Result<bool, PatternMatchingError> {
-        let regex = Regex::new(
-            r"^[a-zA-Z0-9._%+-]+@(gmail\.com|outlook\.com|yahoo\.com|hotmail\.com|live\.com)$",
-        )
-        .map_err(|_| PatternMatchingError::InvalidRegex)?;
-
-        Ok(regex.is_match(email))
-    }
-
-    fn is_valid_password(&self, password: &str) -> Result<bool, PatternMatchingError> {
-        let regex = Regex::new(r"^[A-Za-z\d!@#$%^&*]{12,}$")
+    fn is_valid_password_hash(&self, password_hash: &str) -> Result<bool, PatternMatchingError> {
+        let regex = Regex::new(r"^\$2[abxy]\$\d{2}\$[./A-Za-z0-9]{53}$")
             .map_err(|_| PatternMatchingError::InvalidRegex)?;
 
-        Ok(regex.is_match(password))
+        Ok(regex.is_match(password_hash))
     }
 
     fn is_valid_uuid(&self, uuid: &str) -> Result<bool, PatternMatchingError> {
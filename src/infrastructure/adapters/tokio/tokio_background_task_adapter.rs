@@ -0,0 +1,52 @@
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use tokio::task::JoinSet;
+
+/// Keeps track of detached work, such as data export generation, so shutdown can wait for it
+/// instead of dropping it halfway through.
+#[derive(Clone, Default)]
+pub struct TokioBackgroundTaskAdapter {
+    tasks: Arc<Mutex<JoinSet<()>>>,
+}
+
+impl TokioBackgroundTaskAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spawn(&self, task: impl Future<Output = ()> + Send + 'static) {
+        let mut tasks = self.tasks.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Reap finished tasks so the set only grows with work that is still running.
+        while tasks.try_join_next().is_some() {}
+
+        tasks.spawn(task);
+    }
+
+    /// Waits up to `timeout` for every running task, then aborts the rest and returns how many
+    /// were aborted.
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        let mut tasks =
+            std::mem::take(&mut *self.tasks.lock().unwrap_or_else(PoisonError::into_inner));
+
+        let all_finished = tokio::time::timeout(timeout, async {
+            while tasks.join_next().await.is_some() {}
+        })
+        .await
+        .is_ok();
+
+        if all_finished {
+            return 0;
+        }
+
+        let aborted_tasks = tasks.len();
+
+        tasks.shutdown().await;
+
+        aborted_tasks
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    application::{
+        ports::job::data_export_job_port::DataExportJobPort,
+        use_cases::data_export::generate_data_export_use_case::GenerateDataExportUseCasePort,
+    },
+    infrastructure::adapters::tokio::tokio_background_task_adapter::TokioBackgroundTaskAdapter,
+};
+
+/// Runs data export generation on the Tokio runtime, detached from the request that asked for it.
+#[derive(Clone)]
+pub struct TokioDataExportJobAdapter<G> {
+    background_task_adapter: TokioBackgroundTaskAdapter,
+    generate_data_export_use_case: G,
+}
+
+impl<G> TokioDataExportJobAdapter<G>
+where
+    G: GenerateDataExportUseCasePort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        background_task_adapter: TokioBackgroundTaskAdapter,
+        generate_data_export_use_case: G,
+    ) -> Self {
+        Self {
+            background_task_adapter,
+            generate_data_export_use_case,
+        }
+    }
+}
+
+impl<G> DataExportJobPort for TokioDataExportJobAdapter<G>
+where
+    G: GenerateDataExportUseCasePort + Send + Sync + Clone + 'static,
+{
+    fn enqueue(&self, data_export_id: String) {
+        let generate_data_export_use_case = self.generate_data_export_use_case.clone();
+
+        self.background_task_adapter.spawn(async move {
+            match generate_data_export_use_case
+                .perform(data_export_id.clone())
+                .await
+            {
+                Ok(data_export_entity) => tracing::info!(
+                    "Data export {} finished with status {}.",
+                    data_export_entity.id,
+                    data_export_entity.status
+                ),
+                Err(err) => tracing::error!("Data export {data_export_id} failed: {err}"),
+            }
+        });
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::outbox::dispatch_outbox_events_use_case::DispatchOutboxEventsUseCasePort,
+    infrastructure::adapters::tokio::{
+        tokio_background_task_adapter::TokioBackgroundTaskAdapter,
+        tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter,
+    },
+};
+
+/// Polls the outbox on the Tokio runtime until shutdown. Batches are dispatched back to back
+/// while events are due, then the outbox is polled again every `poll_interval`.
+#[derive(Clone)]
+pub struct TokioOutboxDispatcherAdapter<D> {
+    background_task_adapter: TokioBackgroundTaskAdapter,
+    shutdown_signal_adapter: TokioShutdownSignalAdapter,
+    dispatch_outbox_events_use_case: D,
+    poll_interval: Duration,
+}
+
+impl<D> TokioOutboxDispatcherAdapter<D>
+where
+    D: DispatchOutboxEventsUseCasePort + Send + Sync + Clone + 'static,
+{
+    pub const fn new(
+        background_task_adapter: TokioBackgroundTaskAdapter,
+        shutdown_signal_adapter: TokioShutdownSignalAdapter,
+        dispatch_outbox_events_use_case: D,
+        poll_interval: Duration,
+    ) -> Self {
+        Self {
+            background_task_adapter,
+            shutdown_signal_adapter,
+            dispatch_outbox_events_use_case,
+            poll_interval,
+        }
+    }
+
+    /// Spawns the polling loop as a background task, so shutdown lets the batch in flight finish.
+    pub fn start(&self) {
+        let dispatcher = self.clone();
+
+        self.background_task_adapter.spawn(async move {
+            loop {
+                let has_claimed_events =
+                    match dispatcher.dispatch_outbox_events_use_case.perform().await {
+                        Ok(outbox_dispatch_report) => {
+                            if outbox_dispatch_report.claimed > 0 {
+                                tracing::debug!(
+                                    "Outbox dispatch: {} delivered, {} retried, {} dead-lettered.",
+                                    outbox_dispatch_report.delivered,
+                                    outbox_dispatch_report.retried,
+                                    outbox_dispatch_report.dead_lettered
+                                );
+                            }
+
+                            outbox_dispatch_report.claimed > 0
+                        }
+                        Err(err) => {
+                            tracing::error!("Outbox dispatch failed: {err}");
+
+                            false
+                        }
+                    };
+
+                if dispatcher.shutdown_signal_adapter.is_triggered() {
+                    break;
+                }
+
+                if has_claimed_events {
+                    continue;
+                }
+
+                tokio::select! {
+                    () = dispatcher.shutdown_signal_adapter.wait() => break,
+                    () = tokio::time::sleep(dispatcher.poll_interval) => {}
+                }
+            }
+
+            tracing::info!("Outbox dispatcher stopped.");
+        });
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+use crate::application::ports::health::health_check_port::{
+    HealthCheckFuture, HealthCheckMetrics, HealthCheckPort, HealthCheckResult,
+};
+
+/// Broadcasts the moment the server starts shutting down to everything that has to react to it:
+/// the HTTP server stops accepting connections and readiness starts failing.
+#[derive(Clone)]
+pub struct TokioShutdownSignalAdapter {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl TokioShutdownSignalAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(false);
+
+        Self {
+            sender: Arc::new(sender),
+        }
+    }
+
+    /// Starts the shutdown; triggering it more than once has no further effect.
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Resolves once `trigger` has been called, immediately if it already was.
+    pub async fn wait(&self) {
+        let mut receiver = self.sender.subscribe();
+
+        // The sender lives as long as `self`, so the channel cannot close while waiting.
+        let _ = receiver.wait_for(|is_triggered| *is_triggered).await;
+    }
+
+    /// Triggers the shutdown when the process receives SIGINT (Ctrl+C) or SIGTERM.
+    pub fn listen_for_os_signals(&self) {
+        let shutdown_signal = self.clone();
+
+        tokio::spawn(async move {
+            let signal_name = wait_for_os_signal().await;
+
+            tracing::info!("Received {signal_name}, shutting down gracefully.");
+
+            shutdown_signal.trigger();
+        });
+    }
+}
+
+impl Default for TokioShutdownSignalAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthCheckPort for TokioShutdownSignalAdapter {
+    fn name(&self) -> &'static str {
+        "shutdown"
+    }
+
+    fn check(&self) -> HealthCheckFuture<'_> {
+        Box::pin(async move {
+            if self.is_triggered() {
+                HealthCheckResult::down("server is shutting down")
+            } else {
+                HealthCheckResult::up(HealthCheckMetrics::new())
+            }
+        })
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_os_signal() -> &'static str {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let Ok(mut sigterm) = signal(SignalKind::terminate()) else {
+        tracing::warn!("Unable to listen for SIGTERM, only SIGINT triggers a graceful shutdown.");
+
+        let _ = tokio::signal::ctrl_c().await;
+
+        return "SIGINT";
+    };
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+        _ = sigterm.recv() => "SIGTERM",
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_os_signal() -> &'static str {
+    let _ = tokio::signal::ctrl_c().await;
+
+    "Ctrl+C"
+}
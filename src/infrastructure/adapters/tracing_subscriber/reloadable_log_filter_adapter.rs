@@ -0,0 +1,209 @@
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use tracing_subscriber::{EnvFilter, Registry, reload};
+
+use crate::application::{
+    dtos::admin::log_filter_dto::LogFilterDto,
+    ports::logging::log_filter_port::{LogFilterError, LogFilterPort},
+};
+
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+#[derive(Default)]
+struct LogFilterState {
+    /// Bumped on every change so a revert timer only fires if nothing happened since.
+    generation: u64,
+    /// Directives to restore when the pending revert timer fires.
+    revert_to: Option<String>,
+}
+
+/// Swaps the `EnvFilter` of the global subscriber through its reload handle.
+#[derive(Clone)]
+pub struct ReloadableLogFilterAdapter {
+    filter_handle: LogFilterHandle,
+    state: Arc<Mutex<LogFilterState>>,
+}
+
+impl ReloadableLogFilterAdapter {
+    #[must_use]
+    pub fn new(filter_handle: LogFilterHandle) -> Self {
+        Self {
+            filter_handle,
+            state: Arc::new(Mutex::new(LogFilterState::default())),
+        }
+    }
+
+    fn current_directives(&self) -> Result<String, LogFilterError> {
+        self.filter_handle
+            .with_current(ToString::to_string)
+            .map_err(|err| LogFilterError::ReloadError {
+                message: err.to_string(),
+            })
+    }
+
+    fn reload(&self, env_filter: EnvFilter) -> Result<(), LogFilterError> {
+        self.filter_handle
+            .reload(env_filter)
+            .map_err(|err| LogFilterError::ReloadError {
+                message: err.to_string(),
+            })
+    }
+
+    // The state lock is held across reloads so a revert timer cannot undo a newer change.
+    #[allow(clippy::significant_drop_tightening)]
+    fn schedule_revert(&self, generation: u64, revert_to: String, revert_after: Duration) {
+        let log_filter_adapter = self.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(revert_after).await;
+
+            let mut state = log_filter_adapter
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            if state.generation != generation {
+                return;
+            }
+
+            state.revert_to = None;
+
+            // The directives were active before, so they always parse.
+            let env_filter = EnvFilter::new(&revert_to);
+
+            match log_filter_adapter.reload(env_filter) {
+                Ok(()) => tracing::info!("Log filter directives reverted to '{revert_to}'."),
+                Err(err) => tracing::error!("Unable to revert the log filter directives: {err}."),
+            }
+        });
+    }
+}
+
+impl LogFilterPort for ReloadableLogFilterAdapter {
+    #[allow(clippy::significant_drop_tightening)]
+    fn replace_directives(
+        &self,
+        directives: &str,
+        revert_after: Option<Duration>,
+    ) -> Result<LogFilterDto, LogFilterError> {
+        let env_filter = EnvFilter::builder().parse(directives).map_err(|err| {
+            LogFilterError::InvalidDirectives {
+                message: err.to_string(),
+            }
+        })?;
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let previous_directives = self.current_directives()?;
+
+        self.reload(env_filter)?;
+
+        state.generation += 1;
+
+        // A change made while a revert is pending still restores the original directives, unless
+        // it is permanent, in which case there is nothing left to revert.
+        let revert_to = revert_after.map(|revert_after| {
+            let revert_to = state
+                .revert_to
+                .take()
+                .unwrap_or_else(|| previous_directives.clone());
+
+            self.schedule_revert(state.generation, revert_to.clone(), revert_after);
+
+            revert_to
+        });
+
+        state.revert_to.clone_from(&revert_to);
+
+        Ok(LogFilterDto {
+            directives: self.current_directives()?,
+            previous_directives,
+            revert_to,
+            revert_after_seconds: revert_after.map(|revert_after| revert_after.as_secs()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tracing_subscriber::{EnvFilter, reload};
+
+    use crate::{
+        application::ports::logging::log_filter_port::{LogFilterError, LogFilterPort},
+        infrastructure::adapters::tracing_subscriber::reloadable_log_filter_adapter::ReloadableLogFilterAdapter,
+    };
+
+    const DIRECTIVES: &str = "info,rust_tdd_rest_api::infrastructure=trace";
+
+    #[tokio::test]
+    async fn should_replace_directives_and_revert_them_after_the_delay() {
+        let (_filter_layer, filter_handle) = reload::Layer::new(EnvFilter::new("debug"));
+        let log_filter_adapter = ReloadableLogFilterAdapter::new(filter_handle);
+
+        let log_filter_dto = log_filter_adapter
+            .replace_directives(DIRECTIVES, Some(Duration::from_millis(50)))
+            .unwrap();
+
+        assert_eq!(
+            log_filter_dto.directives,
+            log_filter_adapter.current_directives().unwrap()
+        );
+        assert!(
+            log_filter_dto
+                .directives
+                .contains("rust_tdd_rest_api::infrastructure=trace")
+        );
+        assert_eq!(log_filter_dto.previous_directives, "debug");
+        assert_eq!(log_filter_dto.revert_to.as_deref(), Some("debug"));
+
+        log_filter_adapter
+            .replace_directives("warn", Some(Duration::from_millis(100)))
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(75)).await;
+
+        assert_eq!(log_filter_adapter.current_directives().unwrap(), "warn");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(log_filter_adapter.current_directives().unwrap(), "debug");
+    }
+
+    #[tokio::test]
+    async fn should_cancel_pending_revert_on_permanent_change() {
+        let (_filter_layer, filter_handle) = reload::Layer::new(EnvFilter::new("debug"));
+        let log_filter_adapter = ReloadableLogFilterAdapter::new(filter_handle);
+
+        log_filter_adapter
+            .replace_directives("trace", Some(Duration::from_millis(50)))
+            .unwrap();
+
+        let log_filter_dto = log_filter_adapter.replace_directives("warn", None).unwrap();
+
+        assert_eq!(log_filter_dto.previous_directives, "trace");
+        assert_eq!(log_filter_dto.revert_to, None);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(log_filter_adapter.current_directives().unwrap(), "warn");
+    }
+
+    #[test]
+    fn should_reject_invalid_directives_and_keep_current_filter() {
+        let (_filter_layer, filter_handle) = reload::Layer::new(EnvFilter::new("debug"));
+        let log_filter_adapter = ReloadableLogFilterAdapter::new(filter_handle);
+
+        let result = log_filter_adapter.replace_directives("rust_tdd_rest_api=loud", None);
+
+        assert!(matches!(
+            result,
+            Err(LogFilterError::InvalidDirectives { .. })
+        ));
+        assert_eq!(log_filter_adapter.current_directives().unwrap(), "debug");
+    }
+}
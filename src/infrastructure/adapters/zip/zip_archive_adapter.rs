@@ -0,0 +1,222 @@
+use std::io::{Cursor, Write};
+
+use serde_json::{Value, json};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+use crate::application::{
+    dtos::data_export::user_data_export_dto::UserDataExportDto,
+    ports::archive::archive_port::{ArchiveError, ArchivePort},
+};
+
+#[derive(Clone)]
+pub struct ZipArchiveAdapter;
+
+impl ZipArchiveAdapter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    fn format_timestamp(timestamp: i64) -> String {
+        OffsetDateTime::from_unix_timestamp(timestamp)
+            .ok()
+            .and_then(|date_time| date_time.format(&Rfc3339).ok())
+            .unwrap_or_default()
+    }
+
+    fn escape_csv_field(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    fn build_csv(header: &[&str], rows: Vec<Vec<String>>) -> String {
+        let mut csv = header.join(",");
+        csv.push_str("\r\n");
+
+        for row in rows {
+            let fields: Vec<String> = row
+                .iter()
+                .map(|field| Self::escape_csv_field(field))
+                .collect();
+
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+
+        csv
+    }
+
+    /// Builds the JSON document of the archive. The password hash is deliberately left out.
+    fn build_json(user_data_export_dto: &UserDataExportDto) -> Value {
+        let user = &user_data_export_dto.user;
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        json!({
+            "profile": {
+                "id": user.id.to_string(),
+                "first_name": user.first_name.as_str(),
+                "last_name": user.last_name.as_str(),
+                "email": user.email.as_str(),
+                "is_admin": user.is_admin,
+                "status": user.status.as_str(),
+                "password_reset_required": user.password_reset_required,
+                "sessions_revoked_at": user
+                    .sessions_revoked_at
+                    .map(|sessions_revoked_at| Self::format_timestamp(sessions_revoked_at.unix_timestamp())),
+                "created_at": Self::format_timestamp(user.created_at.unix_timestamp()),
+                "updated_at": Self::format_timestamp(user.updated_at.unix_timestamp()),
+            },
+            "sessions": user_data_export_dto.sessions.iter().map(|session| json!({
+                "id": session.id,
+                "signed_in_at": Self::format_timestamp(session.issued_at),
+                "token_issued_at": Self::format_timestamp(session.issued_at),
+                "token_expires_at": Self::format_timestamp(session.expires_at),
+                "active": session.expires_at > now
+                    && user.sessions_revoked_at.is_none_or(|revoked_at| session.issued_at > revoked_at.unix_timestamp()),
+            })).collect::<Vec<Value>>(),
+            "audit_entries": user_data_export_dto.audit_entries.iter().map(|audit_entry| json!({
+                "id": audit_entry.id,
+                "actor_id": audit_entry.actor_id,
+                "action": audit_entry.action.as_str(),
+                "target_user_id": audit_entry.target_user_id,
+                "created_at": Self::format_timestamp(audit_entry.created_at),
+            })).collect::<Vec<Value>>(),
+        })
+    }
+
+    fn json_field(value: &Value) -> String {
+        match value {
+            Value::Null => String::new(),
+            Value::String(string) => string.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    fn json_rows(values: &Value, columns: &[&str]) -> Vec<Vec<String>> {
+        values
+            .as_array()
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|value| {
+                        columns
+                            .iter()
+                            .map(|column| Self::json_field(&value[*column]))
+                            .collect()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl ArchivePort for ZipArchiveAdapter {
+    fn content_type(&self) -> &'static str {
+        "application/zip"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "zip"
+    }
+
+    fn build_user_data_archive(
+        &self,
+        user_data_export_dto: &UserDataExportDto,
+    ) -> Result<Vec<u8>, ArchiveError> {
+        const PROFILE_COLUMNS: [&str; 10] = [
+            "id",
+            "first_name",
+            "last_name",
+            "email",
+            "is_admin",
+            "status",
+            "password_reset_required",
+            "sessions_revoked_at",
+            "created_at",
+            "updated_at",
+        ];
+        const SESSION_COLUMNS: [&str; 5] = [
+            "id",
+            "signed_in_at",
+            "token_issued_at",
+            "token_expires_at",
+            "active",
+        ];
+        const AUDIT_ENTRY_COLUMNS: [&str; 5] =
+            ["id", "actor_id", "action", "target_user_id", "created_at"];
+
+        let document = Self::build_json(user_data_export_dto);
+
+        let json = serde_json::to_vec_pretty(&document).map_err(|err| {
+            ArchiveError::SerializationError {
+                message: err.to_string(),
+            }
+        })?;
+
+        let files = [
+            ("user.json", json),
+            (
+                "profile.csv",
+                Self::build_csv(
+                    &PROFILE_COLUMNS,
+                    Self::json_rows(
+                        &Value::Array(vec![document["profile"].clone()]),
+                        &PROFILE_COLUMNS,
+                    ),
+                )
+                .into_bytes(),
+            ),
+            (
+                "sessions.csv",
+                Self::build_csv(
+                    &SESSION_COLUMNS,
+                    Self::json_rows(&document["sessions"], &SESSION_COLUMNS),
+                )
+                .into_bytes(),
+            ),
+            (
+                "audit_entries.csv",
+                Self::build_csv(
+                    &AUDIT_ENTRY_COLUMNS,
+                    Self::json_rows(&document["audit_entries"], &AUDIT_ENTRY_COLUMNS),
+                )
+                .into_bytes(),
+            ),
+        ];
+
+        let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        for (file_name, content) in files {
+            zip_writer.start_file(file_name, options).map_err(|err| {
+                ArchiveError::CompressionError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            zip_writer
+                .write_all(&content)
+                .map_err(|err| ArchiveError::CompressionError {
+                    message: err.to_string(),
+                })?;
+        }
+
+        let cursor = zip_writer
+            .finish()
+            .map_err(|err| ArchiveError::CompressionError {
+                message: err.to_string(),
+            })?;
+
+        Ok(cursor.into_inner())
+    }
+}
+
+impl Default for ZipArchiveAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,145 @@
+use std::{io::BufRead, pin::Pin, sync::Arc};
+
+use crate::{
+    application::use_cases::admin::operator_user_action_use_case::OperatorUserAction,
+    infrastructure::{
+        adapters::{
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+        },
+        config::{
+            app_config::{ResolvedSettings, redact_setting_value},
+            config_args::ConfigArgs,
+            config_loader::ConfigLoader,
+        },
+        factories::cli::admin::admin_cli_controller_factory::AdminCliControllerFactory,
+        gateways::database::database_gateway::DatabaseGateway,
+        repositories::email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+    },
+    presentation::cli::cli_args::AdminCommand,
+};
+
+pub type AdminFuture = Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>>>>;
+
+pub trait AdminBootstrapPort {
+    fn run(&self) -> AdminFuture;
+}
+
+pub struct AdminBootstrap {
+    config_args: ConfigArgs,
+    admin_command: AdminCommand,
+}
+
+impl AdminBootstrap {
+    #[must_use]
+    pub const fn new(config_args: ConfigArgs, admin_command: AdminCommand) -> Self {
+        Self {
+            config_args,
+            admin_command,
+        }
+    }
+
+    fn print_settings(settings: &ResolvedSettings) {
+        let key_width = settings.keys().map(|key| key.len()).max().unwrap_or(0);
+
+        for (key, resolved_setting) in settings {
+            println!(
+                "{key:<key_width$} = {} ({})",
+                redact_setting_value(key, &resolved_setting.value),
+                resolved_setting.origin
+            );
+        }
+    }
+
+    /// Uses the `--password` flag when given, otherwise reads a single line from standard input.
+    fn read_password(password: Option<String>) -> Result<String, Box<dyn std::error::Error>> {
+        if let Some(password) = password {
+            return Ok(password);
+        }
+
+        eprint!("Password: ");
+
+        let mut password = String::new();
+
+        std::io::stdin().lock().read_line(&mut password)?;
+
+        Ok(password.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+impl AdminBootstrapPort for AdminBootstrap {
+    fn run(&self) -> AdminFuture {
+        let config_args = self.config_args.clone();
+        let admin_command = self.admin_command.clone();
+
+        Box::pin(async move {
+            let (app_config, settings) = ConfigLoader::new().resolve_from_process(&config_args)?;
+
+            if matches!(admin_command, AdminCommand::ShowConfig) {
+                Self::print_settings(&settings);
+
+                return Ok(());
+            }
+
+            let database_gateway = DatabaseGateway::new(app_config.database);
+            let database_pool = Arc::new(database_gateway.initialize_pool().await?);
+            let password_policy_adapter =
+                RuleBasedPasswordPolicyAdapter::new(app_config.password_policy)?;
+            let email_domain_policy_adapter = CachedEmailDomainPolicyAdapter::new(
+                GetEmailDomainPolicyRepository::new(database_pool.clone()),
+                &app_config.email_domain_policy,
+            );
+            let admin_cli_controller = AdminCliControllerFactory::new(
+                database_pool,
+                password_policy_adapter,
+                email_domain_policy_adapter,
+                RuleBasedEmailNormalizerAdapter::new(&app_config.email_normalization),
+            )
+            .build();
+
+            let output = match admin_command {
+                AdminCommand::CreateAdmin {
+                    first_name,
+                    last_name,
+                    email,
+                    password,
+                } => {
+                    let password = Self::read_password(password)?;
+
+                    admin_cli_controller
+                        .create_admin(first_name, last_name, email, password)
+                        .await?
+                }
+                AdminCommand::Promote { user } => {
+                    admin_cli_controller
+                        .perform(user.selector(), OperatorUserAction::GrantAdmin)
+                        .await?
+                }
+                AdminCommand::ResetPassword { user, password } => {
+                    let new_password = Self::read_password(password)?;
+
+                    admin_cli_controller
+                        .perform(
+                            user.selector(),
+                            OperatorUserAction::ResetPassword { new_password },
+                        )
+                        .await?
+                }
+                AdminCommand::RevokeSessions { user } => {
+                    admin_cli_controller
+                        .perform(user.selector(), OperatorUserAction::RevokeSessions)
+                        .await?
+                }
+                AdminCommand::ShowUser { user } => {
+                    admin_cli_controller.show_user(user.selector()).await?
+                }
+                AdminCommand::ShowConfig => unreachable!("handled before connecting"),
+            };
+
+            println!("{output}");
+
+            Ok(())
+        })
+    }
+}
@@ -1,24 +1,77 @@
-use std::{net::SocketAddr, pin::Pin, sync::Arc};
+use std::{pin::Pin, sync::Arc};
 
+use axum::Router;
+use sqlx::{Pool, Postgres};
 use tokio::net::TcpListener;
 
 use crate::{
     infrastructure::{
-        factories::controller::{
-            auth::{
-                sign_in_controller_factory::SignInControllerFactory,
-                sign_up_controller_factory::SignUpControllerFactory,
+        adapters::{
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+            tokio::{
+                tokio_background_task_adapter::TokioBackgroundTaskAdapter,
+                tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter,
             },
-            user::get_user_by_id_controller_factory::GetUserByIdControllerFactory,
+            tracing_subscriber::reloadable_log_filter_adapter::ReloadableLogFilterAdapter,
         },
-        gateways::database::database_gateway::DatabaseGateway,
+        config::{app_config::AppConfig, config_args::ConfigArgs, config_loader::ConfigLoader},
+        factories::{
+            controller::{
+                admin::{
+                    admin_user_action_controller_factory::AdminUserActionControllerFactory,
+                    change_log_level_controller_factory::ChangeLogLevelControllerFactory,
+                    get_email_domain_policy_controller_factory::GetEmailDomainPolicyControllerFactory,
+                    import_users_controller_factory::ImportUsersControllerFactory,
+                    update_email_domain_policy_controller_factory::UpdateEmailDomainPolicyControllerFactory,
+                },
+                auth::{
+                    change_password_controller_factory::ChangePasswordControllerFactory,
+                    sign_in_controller_factory::SignInControllerFactory,
+                    sign_up_controller_factory::SignUpControllerFactory,
+                },
+                data_export::{
+                    download_data_export_controller_factory::DownloadDataExportControllerFactory,
+                    export_user_data_controller_factory::ExportUserDataControllerFactory,
+                    get_data_export_controller_factory::GetDataExportControllerFactory,
+                },
+                health::{
+                    liveness_controller_factory::LivenessControllerFactory,
+                    readiness_controller_factory::ReadinessControllerFactory,
+                },
+                metrics::get_metrics_controller_factory::GetMetricsControllerFactory,
+                user::{
+                    get_avatar_controller_factory::GetAvatarControllerFactory,
+                    get_user_by_id_controller_factory::GetUserByIdControllerFactory,
+                    search_users_controller_factory::SearchUsersControllerFactory,
+                    upload_avatar_controller_factory::UploadAvatarControllerFactory,
+                },
+            },
+            job::outbox_dispatcher_factory::OutboxDispatcherFactory,
+            middleware::{
+                auth::auth_middleware_factory::AuthMiddlewareFactory,
+                localization::localization_middleware_factory::LocalizationMiddlewareFactory,
+                request_id::request_id_middleware_factory::RequestIdMiddlewareFactory,
+                tracing::trace_context_middleware_factory::TraceContextMiddlewareFactory,
+            },
+        },
+        gateways::{
+            database::{database_gateway::DatabaseGateway, database_migrator::DatabaseMigrator},
+            http::http_server_gateway::HttpServerGateway,
+            telemetry::telemetry_gateway::TelemetryGateway,
+        },
+        repositories::email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
     },
     presentation::{
         ports::router::router_port::RouterPort, routers::core::core_router::CoreRouter,
     },
 };
 
-pub struct ApiBootstrap;
+pub struct ApiBootstrap {
+    config_args: ConfigArgs,
+}
 
 pub type SetupFuture = Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>>>>;
 
@@ -28,103 +81,279 @@ pub trait ApiBootstrapPort {
 
 impl ApiBootstrap {
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub const fn new(config_args: ConfigArgs) -> Self {
+        Self { config_args }
+    }
+
+    /// Wires every controller through its factory and mounts them on the core router.
+    #[allow(clippy::too_many_lines)]
+    fn build_router(
+        database_pool: Arc<Pool<Postgres>>,
+        app_config: &Arc<AppConfig>,
+        shutdown_signal_adapter: &TokioShutdownSignalAdapter,
+        background_task_adapter: &TokioBackgroundTaskAdapter,
+        metrics_adapter: &PrometheusMetricsAdapter,
+        log_filter_adapter: &ReloadableLogFilterAdapter,
+        password_policy_adapter: &RuleBasedPasswordPolicyAdapter,
+    ) -> Router {
+        let auth_middleware_factory = AuthMiddlewareFactory::new(
+            database_pool.clone(),
+            app_config.clone(),
+            metrics_adapter.clone(),
+        );
+        let auth_middleware = auth_middleware_factory.build();
+
+        let trace_context_middleware_factory = TraceContextMiddlewareFactory::new();
+        let trace_context_middleware = trace_context_middleware_factory.build();
+
+        let request_id_middleware_factory = RequestIdMiddlewareFactory::new();
+        let request_id_middleware = request_id_middleware_factory.build();
+
+        let localization_middleware_factory = LocalizationMiddlewareFactory::new();
+        let localization_middleware = localization_middleware_factory.build();
+
+        // A single adapter so that policy updates invalidate the cache every consumer reads from.
+        let email_domain_policy_adapter = CachedEmailDomainPolicyAdapter::new(
+            GetEmailDomainPolicyRepository::new(database_pool.clone()),
+            &app_config.email_domain_policy,
+        );
+        let email_normalizer_adapter =
+            RuleBasedEmailNormalizerAdapter::new(&app_config.email_normalization);
+
+        let sign_up_controller_factory = SignUpControllerFactory::new(
+            database_pool.clone(),
+            metrics_adapter.clone(),
+            password_policy_adapter.clone(),
+            email_domain_policy_adapter.clone(),
+            email_normalizer_adapter.clone(),
+        );
+        let sign_up_controller = sign_up_controller_factory.build();
+
+        let sign_in_controller_factory = SignInControllerFactory::new(
+            database_pool.clone(),
+            app_config.clone(),
+            metrics_adapter.clone(),
+            email_normalizer_adapter.clone(),
+        );
+        let sign_in_controller = sign_in_controller_factory.build();
+
+        let change_password_controller_factory = ChangePasswordControllerFactory::new(
+            database_pool.clone(),
+            metrics_adapter.clone(),
+            password_policy_adapter.clone(),
+            email_normalizer_adapter.clone(),
+        );
+
+        let change_password_controller = change_password_controller_factory.build();
+
+        let get_user_by_id_controller_factory =
+            GetUserByIdControllerFactory::new(database_pool.clone());
+
+        let get_user_by_id_controller = get_user_by_id_controller_factory.build();
+
+        let search_users_controller_factory =
+            SearchUsersControllerFactory::new(database_pool.clone());
+
+        let search_users_controller = search_users_controller_factory.build();
+
+        let upload_avatar_controller_factory =
+            UploadAvatarControllerFactory::new(database_pool.clone(), app_config.clone());
+
+        let upload_avatar_controller = upload_avatar_controller_factory.build();
+
+        let get_avatar_controller_factory =
+            GetAvatarControllerFactory::new(database_pool.clone(), app_config.clone());
+
+        let get_avatar_controller = get_avatar_controller_factory.build();
+
+        let admin_user_action_controller_factory =
+            AdminUserActionControllerFactory::new(database_pool.clone());
+
+        let admin_user_action_controller = admin_user_action_controller_factory.build();
+
+        let import_users_controller_factory = ImportUsersControllerFactory::new(
+            database_pool.clone(),
+            password_policy_adapter.clone(),
+            email_domain_policy_adapter.clone(),
+            email_normalizer_adapter,
+        );
+
+        let import_users_controller = import_users_controller_factory.build();
+
+        let change_log_level_controller_factory =
+            ChangeLogLevelControllerFactory::new(database_pool.clone(), log_filter_adapter.clone());
+
+        let change_log_level_controller = change_log_level_controller_factory.build();
+
+        let get_email_domain_policy_controller_factory =
+            GetEmailDomainPolicyControllerFactory::new(database_pool.clone());
+
+        let get_email_domain_policy_controller = get_email_domain_policy_controller_factory.build();
+
+        let update_email_domain_policy_controller_factory =
+            UpdateEmailDomainPolicyControllerFactory::new(
+                database_pool.clone(),
+                email_domain_policy_adapter,
+            );
+
+        let update_email_domain_policy_controller =
+            update_email_domain_policy_controller_factory.build();
+
+        let export_user_data_controller_factory = ExportUserDataControllerFactory::new(
+            database_pool.clone(),
+            background_task_adapter.clone(),
+        );
+
+        let export_user_data_controller = export_user_data_controller_factory.build();
+
+        let get_data_export_controller_factory =
+            GetDataExportControllerFactory::new(database_pool.clone());
+
+        let get_data_export_controller = get_data_export_controller_factory.build();
+
+        let download_data_export_controller_factory =
+            DownloadDataExportControllerFactory::new(database_pool.clone());
+
+        let download_data_export_controller = download_data_export_controller_factory.build();
+
+        let liveness_controller_factory = LivenessControllerFactory::new();
+        let liveness_controller = liveness_controller_factory.build();
+
+        let readiness_controller_factory = ReadinessControllerFactory::new(
+            database_pool,
+            app_config.clone(),
+            shutdown_signal_adapter.clone(),
+        );
+
+        let readiness_controller = readiness_controller_factory.build();
+
+        let get_metrics_controller_factory =
+            GetMetricsControllerFactory::new(metrics_adapter.clone());
+        let get_metrics_controller = get_metrics_controller_factory.build();
+
+        let core_router = CoreRouter::new(
+            app_config.rate_limit.clone(),
+            app_config.cors.clone(),
+            metrics_adapter.clone(),
+            trace_context_middleware,
+            request_id_middleware,
+            localization_middleware,
+            auth_middleware,
+            sign_up_controller,
+            sign_in_controller,
+            change_password_controller,
+            get_user_by_id_controller,
+            search_users_controller,
+            upload_avatar_controller,
+            get_avatar_controller,
+            admin_user_action_controller,
+            import_users_controller,
+            change_log_level_controller,
+            get_email_domain_policy_controller,
+            update_email_domain_policy_controller,
+            export_user_data_controller,
+            get_data_export_controller,
+            download_data_export_controller,
+            liveness_controller,
+            readiness_controller,
+            get_metrics_controller,
+        );
+
+        core_router.register_routes()
     }
 }
 
 impl ApiBootstrapPort for ApiBootstrap {
     fn setup(&self) -> SetupFuture {
-        Box::pin(async move {
-            tracing_subscriber::fmt()
-                .with_max_level(tracing::Level::DEBUG)
-                .init();
-
-            match dotenvy::dotenv() {
-                Ok(_) => tracing::info!("Environment file successfully loaded."),
-                Err(err) => {
-                    tracing::error!("{}", &err.to_string());
+        let config_args = self.config_args.clone();
 
-                    std::process::exit(1)
-                }
-            }
+        Box::pin(async move {
+            let app_config = Arc::new(ConfigLoader::new().load_from_process(&config_args)?);
 
-            let database_gateway = DatabaseGateway;
+            let telemetry_gateway =
+                TelemetryGateway::new(app_config.telemetry.clone(), app_config.log.clone());
+            let telemetry_guard = telemetry_gateway.initialize()?;
 
-            let database_pool = Arc::new(database_gateway.initialize_pool().await.unwrap_or_else(
-                |err| {
-                    tracing::error!("{}", &err.to_string());
+            tracing::info!("Configuration successfully loaded.");
 
-                    std::process::exit(1)
-                },
-            ));
+            let database_gateway = DatabaseGateway::new(app_config.database.clone());
+            let database_pool = Arc::new(database_gateway.initialize_pool().await?);
 
             tracing::info!("Database pool successfully initialized.");
 
-            let server_host = std::env::var("SERVER_HOST").unwrap_or_else(|err| {
-                tracing::error!("{}", &err.to_string());
-
-                std::process::exit(1)
-            });
+            let metrics_adapter = PrometheusMetricsAdapter::new()?;
 
-            let server_port = std::env::var("SERVER_PORT").unwrap_or_else(|err| {
-                tracing::error!("{}", &err.to_string());
+            metrics_adapter.register_database_pool(&database_pool)?;
 
-                std::process::exit(1)
-            });
+            let password_policy_adapter =
+                RuleBasedPasswordPolicyAdapter::new(app_config.password_policy.clone())?;
 
-            let server_address = format!("{server_host}:{server_port}");
+            if app_config.database.auto_migrate {
+                let database_migrator = DatabaseMigrator::new(database_pool.clone());
+                let applied_migrations = database_migrator.up().await?;
 
-            let tcp_listener = TcpListener::bind(server_address.clone())
-                .await
-                .map_err(|err| {
-                    tracing::error!("{}", &err.to_string());
+                tracing::info!(
+                    "{} pending migration(s) successfully applied.",
+                    applied_migrations.len()
+                );
+            }
 
-                    std::process::exit(1)
-                })?;
+            let server_address = app_config.server.address();
+            let tcp_listener = TcpListener::bind(&server_address).await?;
 
             let server_started_message =
                 format!("Server successfully started at '{server_address}'.");
 
             tracing::info!("{}", server_started_message);
 
-            let sign_up_controller_factory = SignUpControllerFactory::new(database_pool.clone());
-            let sign_up_controller = sign_up_controller_factory.build();
+            let shutdown_signal_adapter = TokioShutdownSignalAdapter::new();
+            let background_task_adapter = TokioBackgroundTaskAdapter::new();
 
-            let sign_in_controller_factory = SignInControllerFactory::new(database_pool.clone());
-            let sign_in_controller = sign_in_controller_factory.build();
+            shutdown_signal_adapter.listen_for_os_signals();
 
-            let get_user_by_id_controller_factory =
-                GetUserByIdControllerFactory::new(database_pool);
+            let outbox_dispatcher_factory = OutboxDispatcherFactory::new(
+                database_pool.clone(),
+                app_config.clone(),
+                background_task_adapter.clone(),
+                shutdown_signal_adapter.clone(),
+            );
 
-            let get_user_by_id_controller = get_user_by_id_controller_factory.build();
+            outbox_dispatcher_factory.build().start();
 
-            let core_router = CoreRouter::new(
-                sign_up_controller,
-                sign_in_controller,
-                get_user_by_id_controller,
+            let axum_router = Self::build_router(
+                database_pool.clone(),
+                &app_config,
+                &shutdown_signal_adapter,
+                &background_task_adapter,
+                &metrics_adapter,
+                &telemetry_guard.log_filter_adapter(),
+                &password_policy_adapter,
             );
 
-            let axum_router = core_router.register_routes();
+            let shutdown_timeout = app_config.server.shutdown_timeout();
+            let http_server_gateway = HttpServerGateway::new(shutdown_timeout);
 
-            axum::serve(
-                tcp_listener,
-                axum_router.into_make_service_with_connect_info::<SocketAddr>(),
-            )
-            .await
-            .unwrap_or_else(|err| {
-                tracing::error!("{}", &err.to_string());
+            http_server_gateway
+                .serve(tcp_listener, axum_router, shutdown_signal_adapter)
+                .await?;
 
-                std::process::exit(1)
-            });
+            tracing::info!("Server stopped accepting requests, waiting for background jobs.");
+
+            let aborted_tasks = background_task_adapter.drain(shutdown_timeout).await;
+
+            if aborted_tasks > 0 {
+                tracing::warn!(
+                    "{aborted_tasks} background job(s) did not complete within the shutdown timeout and were aborted."
+                );
+            }
+
+            database_pool.close().await;
+
+            tracing::info!("Database pool closed, shutdown complete.");
+
+            telemetry_guard.shutdown();
 
             Ok(())
         })
     }
 }
-
-impl Default for ApiBootstrap {
-    fn default() -> Self {
-        Self::new()
-    }
-}
@@ -0,0 +1,85 @@
+use std::{pin::Pin, sync::Arc};
+
+use crate::{
+    infrastructure::{
+        config::{config_args::ConfigArgs, config_loader::ConfigLoader},
+        gateways::database::{
+            database_gateway::DatabaseGateway,
+            database_migrator::{DatabaseMigrator, MigrationStatus},
+        },
+    },
+    presentation::cli::cli_args::MigrateCommand,
+};
+
+pub type MigrateFuture = Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>>>>;
+
+pub trait MigrateBootstrapPort {
+    fn run(&self) -> MigrateFuture;
+}
+
+pub struct MigrateBootstrap {
+    config_args: ConfigArgs,
+    migrate_command: MigrateCommand,
+}
+
+impl MigrateBootstrap {
+    #[must_use]
+    pub const fn new(config_args: ConfigArgs, migrate_command: MigrateCommand) -> Self {
+        Self {
+            config_args,
+            migrate_command,
+        }
+    }
+
+    fn print_migrations(heading: &str, migration_statuses: &[MigrationStatus]) {
+        println!("{heading}");
+
+        if migration_statuses.is_empty() {
+            println!("  (none)");
+        }
+
+        for migration_status in migration_statuses {
+            println!(
+                "  {:<9} {} {}",
+                migration_status.state.as_str(),
+                migration_status.version,
+                migration_status.description
+            );
+        }
+    }
+}
+
+impl MigrateBootstrapPort for MigrateBootstrap {
+    fn run(&self) -> MigrateFuture {
+        let config_args = self.config_args.clone();
+        let migrate_command = self.migrate_command;
+
+        Box::pin(async move {
+            let app_config = ConfigLoader::new().load_from_process(&config_args)?;
+
+            let database_gateway = DatabaseGateway::new(app_config.database);
+            let database_pool = Arc::new(database_gateway.initialize_pool().await?);
+            let database_migrator = DatabaseMigrator::new(database_pool);
+
+            match migrate_command {
+                MigrateCommand::Up => {
+                    let applied_migrations = database_migrator.up().await?;
+
+                    Self::print_migrations("Applied migrations:", &applied_migrations);
+                }
+                MigrateCommand::Down { steps } => {
+                    let reverted_migrations = database_migrator.down(steps).await?;
+
+                    Self::print_migrations("Reverted migrations:", &reverted_migrations);
+                }
+                MigrateCommand::Status => {
+                    let migration_statuses = database_migrator.status().await?;
+
+                    Self::print_migrations("Migrations:", &migration_statuses);
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,811 @@
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr, time::Duration};
+
+use crate::{
+    application::ports::password_policy::password_policy_port::CharacterClass,
+    infrastructure::adapters::bcrypt::bcrypt_adapter::MAX_PASSWORD_BYTES,
+};
+
+/// A setting the application understands and the environment variable that overrides it.
+///
+/// Settings without a default must be provided by one of the configuration sources.
+pub struct SettingDefinition {
+    pub key: &'static str,
+    pub env_var: &'static str,
+    pub default: Option<&'static str>,
+}
+
+pub const SETTING_DEFINITIONS: [SettingDefinition; 28] = [
+    SettingDefinition {
+        key: "server.host",
+        env_var: "SERVER_HOST",
+        default: Some("127.0.0.1"),
+    },
+    SettingDefinition {
+        key: "server.port",
+        env_var: "SERVER_PORT",
+        default: Some("3000"),
+    },
+    SettingDefinition {
+        key: "server.shutdown_timeout_seconds",
+        env_var: "SERVER_SHUTDOWN_TIMEOUT_SECONDS",
+        default: Some("30"),
+    },
+    SettingDefinition {
+        key: "database.url",
+        env_var: "DATABASE_URL",
+        default: None,
+    },
+    SettingDefinition {
+        key: "database.max_connections",
+        env_var: "DATABASE_MAX_CONNECTIONS",
+        default: Some("5"),
+    },
+    SettingDefinition {
+        key: "database.auto_migrate",
+        env_var: "DATABASE_AUTO_MIGRATE",
+        default: Some("false"),
+    },
+    SettingDefinition {
+        key: "auth.jwt_secret",
+        env_var: "JWT_SECRET",
+        default: None,
+    },
+    SettingDefinition {
+        key: "auth.token_ttl_seconds",
+        env_var: "JWT_TTL_SECONDS",
+        default: Some("120"),
+    },
+    SettingDefinition {
+        key: "rate_limit.replenish_interval_seconds",
+        env_var: "RATE_LIMIT_REPLENISH_INTERVAL_SECONDS",
+        default: Some("2"),
+    },
+    SettingDefinition {
+        key: "rate_limit.burst_size",
+        env_var: "RATE_LIMIT_BURST_SIZE",
+        default: Some("5"),
+    },
+    SettingDefinition {
+        key: "cors.allowed_origins",
+        env_var: "CORS_ALLOWED_ORIGINS",
+        default: Some("*"),
+    },
+    SettingDefinition {
+        key: "storage.directory",
+        env_var: "FILE_STORAGE_DIR",
+        default: Some("storage"),
+    },
+    SettingDefinition {
+        key: "health.check_timeout_ms",
+        env_var: "HEALTH_CHECK_TIMEOUT_MS",
+        default: Some("2000"),
+    },
+    SettingDefinition {
+        key: "log.level",
+        env_var: "LOG_LEVEL",
+        default: Some("debug"),
+    },
+    SettingDefinition {
+        key: "log.format",
+        env_var: "LOG_FORMAT",
+        default: Some("text"),
+    },
+    SettingDefinition {
+        key: "telemetry.otlp_endpoint",
+        env_var: "OTEL_EXPORTER_OTLP_ENDPOINT",
+        default: Some(""),
+    },
+    SettingDefinition {
+        key: "telemetry.service_name",
+        env_var: "OTEL_SERVICE_NAME",
+        default: Some("rust-tdd-rest-api"),
+    },
+    SettingDefinition {
+        key: "password_policy.min_length",
+        env_var: "PASSWORD_MIN_LENGTH",
+        default: Some("12"),
+    },
+    SettingDefinition {
+        key: "password_policy.max_length",
+        env_var: "PASSWORD_MAX_LENGTH",
+        default: Some("64"),
+    },
+    SettingDefinition {
+        key: "password_policy.required_character_classes",
+        env_var: "PASSWORD_REQUIRED_CHARACTER_CLASSES",
+        default: Some(""),
+    },
+    SettingDefinition {
+        key: "password_policy.min_strength_score",
+        env_var: "PASSWORD_MIN_STRENGTH_SCORE",
+        default: Some("3"),
+    },
+    SettingDefinition {
+        key: "password_policy.breached_passwords_file",
+        env_var: "PASSWORD_BREACHED_PASSWORDS_FILE",
+        default: Some(""),
+    },
+    SettingDefinition {
+        key: "email_domain_policy.cache_ttl_seconds",
+        env_var: "EMAIL_DOMAIN_POLICY_CACHE_TTL_SECONDS",
+        default: Some("60"),
+    },
+    SettingDefinition {
+        key: "email_normalization.provider_rules",
+        env_var: "EMAIL_NORMALIZATION_PROVIDER_RULES",
+        default: Some("false"),
+    },
+    SettingDefinition {
+        key: "outbox.poll_interval_ms",
+        env_var: "OUTBOX_POLL_INTERVAL_MS",
+        default: Some("1000"),
+    },
+    SettingDefinition {
+        key: "outbox.batch_size",
+        env_var: "OUTBOX_BATCH_SIZE",
+        default: Some("50"),
+    },
+    SettingDefinition {
+        key: "outbox.max_attempts",
+        env_var: "OUTBOX_MAX_ATTEMPTS",
+        default: Some("8"),
+    },
+    SettingDefinition {
+        key: "outbox.retry_delay_seconds",
+        env_var: "OUTBOX_RETRY_DELAY_SECONDS",
+        default: Some("5"),
+    },
+];
+
+/// Minimum length of the JWT signing secret, matching the HS256 key size.
+pub const MIN_JWT_SECRET_LENGTH: usize = 32;
+
+#[must_use]
+pub fn find_setting_definition(key: &str) -> Option<&'static SettingDefinition> {
+    SETTING_DEFINITIONS
+        .iter()
+        .find(|setting_definition| setting_definition.key == key)
+}
+
+/// Masks credentials so the effective configuration can be printed or logged.
+#[must_use]
+pub fn redact_setting_value(key: &str, value: &str) -> String {
+    match key {
+        "auth.jwt_secret" => REDACTED.to_string(),
+        "database.url" => redact_url_password(value),
+        _ => value.to_string(),
+    }
+}
+
+const REDACTED: &str = "********";
+
+fn redact_url_password(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+
+    let Some(userinfo_end) = rest[..authority_end].rfind('@') else {
+        return url.to_string();
+    };
+
+    match rest[..userinfo_end].split_once(':') {
+        Some((user, _)) => format!("{scheme}://{user}:{REDACTED}{}", &rest[userinfo_end..]),
+        None => url.to_string(),
+    }
+}
+
+/// Where the effective value of a setting came from, reported alongside validation errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    Default,
+    File { path: PathBuf },
+    Environment { variable: &'static str },
+    CommandLine,
+}
+
+impl std::fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "built-in default"),
+            Self::File { path } => write!(f, "file '{}'", path.display()),
+            Self::Environment { variable } => write!(f, "environment variable {variable}"),
+            Self::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedSetting {
+    pub value: String,
+    pub origin: ConfigOrigin,
+}
+
+pub type ResolvedSettings = BTreeMap<&'static str, ResolvedSetting>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub key: String,
+    pub message: String,
+    pub origin: Option<ConfigOrigin>,
+}
+
+impl ConfigIssue {
+    #[must_use]
+    pub fn new(key: &str, message: impl Into<String>, origin: Option<ConfigOrigin>) -> Self {
+        Self {
+            key: key.to_string(),
+            message: message.into(),
+            origin,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.origin {
+            Some(origin) => write!(f, "{}: {} (from {origin})", self.key, self.message),
+            None => write!(f, "{}: {}", self.key, self.message),
+        }
+    }
+}
+
+/// Every problem found while loading the configuration, so they can all be fixed in one go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppConfigError {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl std::fmt::Display for AppConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the configuration is invalid ({} issue(s)):",
+            self.issues.len()
+        )?;
+
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for AppConfigError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    /// How long in-flight requests, then background jobs, may run once shutdown starts.
+    pub shutdown_timeout_seconds: u64,
+}
+
+impl ServerConfig {
+    #[must_use]
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    #[must_use]
+    pub const fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_seconds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+    /// Applies pending migrations when the server starts.
+    pub auto_migrate: bool,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthConfig {
+    pub jwt_secret: String,
+    pub token_ttl_seconds: u64,
+}
+
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("jwt_secret", &"[redacted]")
+            .field("token_ttl_seconds", &self.token_ttl_seconds)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Seconds it takes for one request of the burst allowance to be replenished.
+    pub replenish_interval_seconds: u64,
+    pub burst_size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsConfig {
+    /// Either `["*"]` or a list of exact origins such as `https://app.example.com`.
+    pub allowed_origins: Vec<String>,
+}
+
+impl CorsConfig {
+    #[must_use]
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.iter().any(|origin| origin == "*")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageConfig {
+    pub directory: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthConfig {
+    /// Upper bound for each readiness check; a slower dependency is reported as down.
+    pub check_timeout_ms: u64,
+}
+
+impl HealthConfig {
+    #[must_use]
+    pub const fn check_timeout(&self) -> Duration {
+        Duration::from_millis(self.check_timeout_ms)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogConfig {
+    pub level: String,
+    /// Either `text` for humans or `json`, one object per line, for log aggregators.
+    pub format: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    /// OTLP/gRPC collector spans are exported to; when absent spans are only propagated.
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicyConfig {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub required_character_classes: Vec<CharacterClass>,
+    /// Lowest accepted strength estimate, from 0 to 4.
+    pub min_strength_score: u8,
+    /// SHA-1 hashes of breached passwords as distributed by Have I Been Pwned: either a file of
+    /// `HASH[:COUNT]` lines, or a directory of `PREFIX.txt` files of `SUFFIX[:COUNT]` lines, one
+    /// per 5 hex digit prefix. When absent no breach check is made.
+    pub breached_passwords_file: Option<PathBuf>,
+}
+
+/// Highest score the password strength estimate can give.
+pub const MAX_PASSWORD_STRENGTH_SCORE: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailDomainPolicyConfig {
+    /// How long each instance reuses the stored policy before reading it again, which bounds how
+    /// long an admin's change takes to reach the other instances.
+    pub cache_ttl_seconds: u64,
+}
+
+impl EmailDomainPolicyConfig {
+    #[must_use]
+    pub const fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_seconds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailNormalizationConfig {
+    /// Whether to also fold provider-specific aliases, such as dots and `+tags` in Gmail
+    /// addresses, into the address they deliver to.
+    pub provider_rules: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxConfig {
+    /// How long the dispatcher waits before looking for due events once the outbox is drained.
+    pub poll_interval_ms: u64,
+    pub batch_size: u32,
+    /// Deliveries tried before an event is dead-lettered.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled after every further failure.
+    pub retry_delay_seconds: u64,
+}
+
+impl OutboxConfig {
+    #[must_use]
+    pub const fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub rate_limit: RateLimitConfig,
+    pub cors: CorsConfig,
+    pub storage: StorageConfig,
+    pub health: HealthConfig,
+    pub log: LogConfig,
+    pub telemetry: TelemetryConfig,
+    pub password_policy: PasswordPolicyConfig,
+    pub email_domain_policy: EmailDomainPolicyConfig,
+    pub email_normalization: EmailNormalizationConfig,
+    pub outbox: OutboxConfig,
+}
+
+impl AppConfig {
+    /// Builds the typed configuration out of the merged settings, validating every value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppConfigError` listing every missing or invalid setting.
+    pub fn from_settings(settings: &ResolvedSettings) -> Result<Self, AppConfigError> {
+        let mut reader = SettingsReader::new(settings);
+
+        let host = reader.string("server.host");
+        let port = reader.parse::<u16>("server.port", "a port number between 0 and 65535");
+        let shutdown_timeout_seconds =
+            reader.parse::<u64>("server.shutdown_timeout_seconds", "a non-negative integer");
+
+        let database_url = reader.string("database.url");
+        reader.check(
+            "database.url",
+            database_url.starts_with("postgres://") || database_url.starts_with("postgresql://"),
+            "must be a postgres:// or postgresql:// connection string",
+        );
+
+        let max_connections = reader.parse::<u32>("database.max_connections", "a positive integer");
+        reader.check(
+            "database.max_connections",
+            max_connections > 0,
+            "must be at least 1",
+        );
+
+        let auto_migrate = reader.parse::<bool>("database.auto_migrate", "either true or false");
+
+        let jwt_secret = reader.string("auth.jwt_secret");
+        reader.check(
+            "auth.jwt_secret",
+            jwt_secret.len() >= MIN_JWT_SECRET_LENGTH,
+            format!("must be at least {MIN_JWT_SECRET_LENGTH} characters long"),
+        );
+
+        let token_ttl_seconds = reader.parse::<u64>("auth.token_ttl_seconds", "a positive integer");
+        reader.check(
+            "auth.token_ttl_seconds",
+            token_ttl_seconds > 0,
+            "must be at least 1",
+        );
+
+        let rate_limit = read_rate_limit_config(&mut reader);
+
+        let allowed_origins = reader.list("cors.allowed_origins");
+        reader.check(
+            "cors.allowed_origins",
+            allowed_origins == ["*"]
+                || allowed_origins.iter().all(|origin| is_valid_origin(origin)),
+            "must be '*' or a comma-separated list of http(s) origins",
+        );
+
+        let directory = reader.string("storage.directory");
+
+        let check_timeout_ms = reader.parse::<u64>("health.check_timeout_ms", "a positive integer");
+        reader.check(
+            "health.check_timeout_ms",
+            check_timeout_ms > 0,
+            "must be at least 1",
+        );
+
+        let log = read_log_config(&mut reader);
+        let telemetry = read_telemetry_config(&mut reader);
+        let password_policy = read_password_policy_config(&mut reader);
+        let cache_ttl_seconds = reader.parse::<u64>(
+            "email_domain_policy.cache_ttl_seconds",
+            "a non-negative integer",
+        );
+        let provider_rules =
+            reader.parse::<bool>("email_normalization.provider_rules", "either true or false");
+        let outbox = read_outbox_config(&mut reader);
+
+        reader.finish()?;
+
+        Ok(Self {
+            server: ServerConfig {
+                host,
+                port,
+                shutdown_timeout_seconds,
+            },
+            database: DatabaseConfig {
+                url: database_url,
+                max_connections,
+                auto_migrate,
+            },
+            auth: AuthConfig {
+                jwt_secret,
+                token_ttl_seconds,
+            },
+            rate_limit,
+            cors: CorsConfig { allowed_origins },
+            storage: StorageConfig {
+                directory: PathBuf::from(directory),
+            },
+            health: HealthConfig { check_timeout_ms },
+            log,
+            telemetry,
+            password_policy,
+            email_domain_policy: EmailDomainPolicyConfig { cache_ttl_seconds },
+            email_normalization: EmailNormalizationConfig { provider_rules },
+            outbox,
+        })
+    }
+}
+
+fn read_rate_limit_config(reader: &mut SettingsReader<'_>) -> RateLimitConfig {
+    let replenish_interval_seconds = reader.parse::<u64>(
+        "rate_limit.replenish_interval_seconds",
+        "a positive integer",
+    );
+    reader.check(
+        "rate_limit.replenish_interval_seconds",
+        replenish_interval_seconds > 0,
+        "must be at least 1",
+    );
+
+    let burst_size = reader.parse::<u32>("rate_limit.burst_size", "a positive integer");
+    reader.check(
+        "rate_limit.burst_size",
+        burst_size > 0,
+        "must be at least 1",
+    );
+
+    RateLimitConfig {
+        replenish_interval_seconds,
+        burst_size,
+    }
+}
+
+fn read_log_config(reader: &mut SettingsReader<'_>) -> LogConfig {
+    let level = reader.string("log.level").to_ascii_lowercase();
+    reader.check(
+        "log.level",
+        ["trace", "debug", "info", "warn", "error"].contains(&level.as_str()),
+        "must be one of: trace, debug, info, warn, error",
+    );
+
+    let format = reader.string("log.format").to_ascii_lowercase();
+    reader.check(
+        "log.format",
+        ["text", "json"].contains(&format.as_str()),
+        "must be one of: text, json",
+    );
+
+    LogConfig { level, format }
+}
+
+fn read_telemetry_config(reader: &mut SettingsReader<'_>) -> TelemetryConfig {
+    let otlp_endpoint = reader.optional_string("telemetry.otlp_endpoint");
+    reader.check(
+        "telemetry.otlp_endpoint",
+        otlp_endpoint.as_deref().is_none_or(|endpoint| {
+            endpoint.starts_with("http://") || endpoint.starts_with("https://")
+        }),
+        "must be an http(s) URL such as http://localhost:4317",
+    );
+
+    let service_name = reader.string("telemetry.service_name");
+
+    TelemetryConfig {
+        otlp_endpoint,
+        service_name,
+    }
+}
+
+fn read_password_policy_config(reader: &mut SettingsReader<'_>) -> PasswordPolicyConfig {
+    let min_length = reader.parse::<usize>("password_policy.min_length", "a positive integer");
+    reader.check(
+        "password_policy.min_length",
+        min_length > 0,
+        "must be at least 1",
+    );
+
+    let max_length = reader.parse::<usize>("password_policy.max_length", "a positive integer");
+    reader.check(
+        "password_policy.max_length",
+        max_length >= min_length,
+        "must not be lower than password_policy.min_length",
+    );
+    reader.check(
+        "password_policy.max_length",
+        max_length <= MAX_PASSWORD_BYTES,
+        format!("must be at most {MAX_PASSWORD_BYTES}, the longest password bcrypt hashes in full"),
+    );
+
+    let character_classes = reader
+        .optional_string("password_policy.required_character_classes")
+        .unwrap_or_default();
+
+    let required_character_classes = character_classes
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(CharacterClass::from_str)
+        .collect::<Result<Vec<_>, _>>();
+
+    reader.check(
+        "password_policy.required_character_classes",
+        required_character_classes.is_ok(),
+        "must be a comma-separated list of: lowercase, uppercase, digit, symbol",
+    );
+
+    let min_strength_score = reader.parse::<u8>(
+        "password_policy.min_strength_score",
+        "an integer between 0 and 4",
+    );
+    reader.check(
+        "password_policy.min_strength_score",
+        min_strength_score <= MAX_PASSWORD_STRENGTH_SCORE,
+        "must be between 0 and 4",
+    );
+
+    let breached_passwords_file = reader
+        .optional_string("password_policy.breached_passwords_file")
+        .map(PathBuf::from);
+
+    PasswordPolicyConfig {
+        min_length,
+        max_length,
+        required_character_classes: required_character_classes.unwrap_or_default(),
+        min_strength_score,
+        breached_passwords_file,
+    }
+}
+
+fn read_outbox_config(reader: &mut SettingsReader<'_>) -> OutboxConfig {
+    let poll_interval_ms = reader.parse::<u64>("outbox.poll_interval_ms", "a positive integer");
+    reader.check(
+        "outbox.poll_interval_ms",
+        poll_interval_ms > 0,
+        "must be at least 1",
+    );
+
+    let batch_size = reader.parse::<u32>("outbox.batch_size", "a positive integer");
+    reader.check("outbox.batch_size", batch_size > 0, "must be at least 1");
+
+    let max_attempts = reader.parse::<u32>("outbox.max_attempts", "a positive integer");
+    reader.check(
+        "outbox.max_attempts",
+        max_attempts > 0,
+        "must be at least 1",
+    );
+
+    let retry_delay_seconds =
+        reader.parse::<u64>("outbox.retry_delay_seconds", "a non-negative integer");
+
+    OutboxConfig {
+        poll_interval_ms,
+        batch_size,
+        max_attempts,
+        retry_delay_seconds,
+    }
+}
+
+fn is_valid_origin(origin: &str) -> bool {
+    (origin.starts_with("http://") || origin.starts_with("https://"))
+        && !origin.ends_with('/')
+        && !origin.chars().any(char::is_whitespace)
+}
+
+/// Reads settings while collecting issues instead of stopping at the first one.
+struct SettingsReader<'a> {
+    settings: &'a ResolvedSettings,
+    issues: Vec<ConfigIssue>,
+}
+
+impl<'a> SettingsReader<'a> {
+    const fn new(settings: &'a ResolvedSettings) -> Self {
+        Self {
+            settings,
+            issues: vec![],
+        }
+    }
+
+    fn origin(&self, key: &str) -> Option<ConfigOrigin> {
+        self.settings
+            .get(key)
+            .map(|resolved_setting| resolved_setting.origin.clone())
+    }
+
+    fn string(&mut self, key: &'static str) -> String {
+        match self.settings.get(key) {
+            Some(resolved_setting) if !resolved_setting.value.trim().is_empty() => {
+                resolved_setting.value.trim().to_string()
+            }
+            _ => {
+                let env_var = find_setting_definition(key)
+                    .map_or("", |setting_definition| setting_definition.env_var);
+
+                let issue = ConfigIssue::new(
+                    key,
+                    format!("is required; set it in the config file or via {env_var}"),
+                    self.origin(key),
+                );
+
+                self.issues.push(issue);
+
+                String::new()
+            }
+        }
+    }
+
+    /// Reads a setting that may be left empty to turn the related feature off.
+    fn optional_string(&self, key: &'static str) -> Option<String> {
+        self.settings
+            .get(key)
+            .map(|resolved_setting| resolved_setting.value.trim())
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    }
+
+    fn parse<T>(&mut self, key: &'static str, expected: &str) -> T
+    where
+        T: FromStr + Default,
+    {
+        let value = self.string(key);
+
+        if value.is_empty() {
+            return T::default();
+        }
+
+        value.parse::<T>().unwrap_or_else(|_| {
+            let issue = ConfigIssue::new(
+                key,
+                format!("'{value}' is not {expected}"),
+                self.origin(key),
+            );
+
+            self.issues.push(issue);
+
+            T::default()
+        })
+    }
+
+    fn list(&mut self, key: &'static str) -> Vec<String> {
+        self.string(key)
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Records an issue for a value that parsed but is out of range. Keys that already failed
+    /// to parse are skipped so each setting is reported once.
+    fn check(&mut self, key: &'static str, is_valid: bool, message: impl Into<String>) {
+        let already_reported = self.issues.iter().any(|issue| issue.key == key);
+
+        if !is_valid && !already_reported {
+            let issue = ConfigIssue::new(key, message, self.origin(key));
+
+            self.issues.push(issue);
+        }
+    }
+
+    fn finish(self) -> Result<(), AppConfigError> {
+        if self.issues.is_empty() {
+            return Ok(());
+        }
+
+        Err(AppConfigError {
+            issues: self.issues,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+/// Command-line flags that feed the configuration loader. They take precedence over the config
+/// file and environment variables.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ConfigArgs {
+    /// Path to a TOML configuration file.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Address the HTTP server binds to.
+    #[arg(long, global = true)]
+    pub host: Option<String>,
+
+    /// Port the HTTP server listens on.
+    #[arg(long, global = true)]
+    pub port: Option<String>,
+
+    /// Postgres connection string.
+    #[arg(long, global = true, value_name = "URL")]
+    pub database_url: Option<String>,
+
+    /// Overrides any setting by key, e.g. `--set rate_limit.burst_size=10`.
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE", value_parser = parse_override)]
+    pub overrides: Vec<(String, String)>,
+}
+
+impl ConfigArgs {
+    /// Returns every flag as a `(setting key, value)` pair; dedicated flags win over `--set`.
+    #[must_use]
+    pub fn setting_overrides(&self) -> Vec<(String, String)> {
+        let mut overrides = self.overrides.clone();
+
+        let dedicated_flags = [
+            ("server.host", &self.host),
+            ("server.port", &self.port),
+            ("database.url", &self.database_url),
+        ];
+
+        for (key, value) in dedicated_flags {
+            if let Some(value) = value {
+                overrides.push((key.to_string(), value.clone()));
+            }
+        }
+
+        overrides
+    }
+}
+
+fn parse_override(raw_override: &str) -> Result<(String, String), String> {
+    raw_override
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("'{raw_override}' must have the form KEY=VALUE"))
+}
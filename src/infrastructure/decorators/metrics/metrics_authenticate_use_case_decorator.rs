@@ -0,0 +1,55 @@
+use crate::application::{
+    ports::{auth::auth_port::AuthError, metrics::metrics_port::MetricsPort},
+    use_cases::auth::authenticate_use_case::{
+        AuthenticateUseCaseError, AuthenticateUseCaseFuture, AuthenticateUseCasePort,
+    },
+};
+
+/// Counts tokens the wrapped use case rejects, labelled by the `AuthError` variant.
+#[derive(Clone)]
+pub struct MetricsAuthenticateUseCaseDecorator<U, M> {
+    authenticate_use_case: U,
+    metrics_adapter: M,
+}
+
+impl<U, M> MetricsAuthenticateUseCaseDecorator<U, M>
+where
+    U: AuthenticateUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    pub const fn new(authenticate_use_case: U, metrics_adapter: M) -> Self {
+        Self {
+            authenticate_use_case,
+            metrics_adapter,
+        }
+    }
+
+    const fn failure_reason(err: &AuthError) -> &'static str {
+        match err {
+            AuthError::GenerateTokenError { .. } => "generate_token_error",
+            AuthError::InvalidTokenError => "invalid_token",
+            AuthError::ExpiredTokenError => "expired_token",
+            AuthError::RevokedTokenError => "revoked_token",
+            AuthError::UnexpectedError => "unexpected_error",
+        }
+    }
+}
+
+impl<U, M> AuthenticateUseCasePort for MetricsAuthenticateUseCaseDecorator<U, M>
+where
+    U: AuthenticateUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    fn perform(&self, auth_token: String) -> AuthenticateUseCaseFuture<'_> {
+        Box::pin(async move {
+            let result = self.authenticate_use_case.perform(auth_token).await;
+
+            if let Err(AuthenticateUseCaseError::AuthError(err)) = &result {
+                self.metrics_adapter
+                    .record_token_verification_failure(Self::failure_reason(err));
+            }
+
+            result
+        })
+    }
+}
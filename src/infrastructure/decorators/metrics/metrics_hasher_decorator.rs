@@ -0,0 +1,55 @@
+use std::time::Instant;
+
+use crate::{
+    application::ports::{
+        hasher::hasher_port::{HasherError, HasherPort},
+        metrics::metrics_port::MetricsPort,
+    },
+    domain::value_objects::user::password_hash::PasswordHash,
+};
+
+/// Times every call to the wrapped hasher.
+#[derive(Clone)]
+pub struct MetricsHasherDecorator<H, M> {
+    hasher_adapter: H,
+    metrics_adapter: M,
+}
+
+impl<H, M> MetricsHasherDecorator<H, M>
+where
+    H: HasherPort + Clone,
+    M: MetricsPort + Clone,
+{
+    pub const fn new(hasher_adapter: H, metrics_adapter: M) -> Self {
+        Self {
+            hasher_adapter,
+            metrics_adapter,
+        }
+    }
+}
+
+impl<H, M> HasherPort for MetricsHasherDecorator<H, M>
+where
+    H: HasherPort + Clone,
+    M: MetricsPort + Clone,
+{
+    fn hash(&self, password: &str) -> Result<PasswordHash, HasherError> {
+        let started_at = Instant::now();
+        let result = self.hasher_adapter.hash(password);
+
+        self.metrics_adapter
+            .observe_password_hashing("hash", started_at.elapsed());
+
+        result
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool, HasherError> {
+        let started_at = Instant::now();
+        let result = self.hasher_adapter.verify(password, password_hash);
+
+        self.metrics_adapter
+            .observe_password_hashing("verify", started_at.elapsed());
+
+        result
+    }
+}
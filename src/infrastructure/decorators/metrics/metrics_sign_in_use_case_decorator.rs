@@ -0,0 +1,66 @@
+use crate::{
+    application::{
+        dtos::auth::sign_in_dto::SignInDto,
+        ports::metrics::metrics_port::MetricsPort,
+        use_cases::auth::sign_in_use_case::{
+            SignInUseCaseError, SignInUseCaseFuture, SignInUseCasePort,
+        },
+    },
+    domain::errors::user::user_errors::UserError,
+};
+
+/// Counts sign-in attempts of the wrapped use case by outcome.
+#[derive(Clone)]
+pub struct MetricsSignInUseCaseDecorator<U, M> {
+    sign_in_use_case: U,
+    metrics_adapter: M,
+}
+
+impl<U, M> MetricsSignInUseCaseDecorator<U, M>
+where
+    U: SignInUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    pub const fn new(sign_in_use_case: U, metrics_adapter: M) -> Self {
+        Self {
+            sign_in_use_case,
+            metrics_adapter,
+        }
+    }
+
+    const fn failure_reason(err: &SignInUseCaseError) -> &'static str {
+        match err {
+            SignInUseCaseError::UserError(UserError::UserSuspended) => "user_suspended",
+            SignInUseCaseError::UserError(UserError::PasswordResetRequired) => {
+                "password_reset_required"
+            }
+            SignInUseCaseError::UserError(_) => "user_error",
+            SignInUseCaseError::HasherError(_) => "hasher_error",
+            SignInUseCaseError::AuthError(_) => "token_error",
+            SignInUseCaseError::DatabaseError(_)
+            | SignInUseCaseError::SessionRepositoryError(_) => "repository_error",
+        }
+    }
+}
+
+impl<U, M> SignInUseCasePort for MetricsSignInUseCaseDecorator<U, M>
+where
+    U: SignInUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    fn perform(&self, sign_in_dto: SignInDto) -> SignInUseCaseFuture<'_> {
+        Box::pin(async move {
+            let result = self.sign_in_use_case.perform(sign_in_dto).await;
+
+            let outcome = match &result {
+                Ok(Some(_)) => "success",
+                Ok(None) => "invalid_credentials",
+                Err(err) => Self::failure_reason(err),
+            };
+
+            self.metrics_adapter.record_sign_in(outcome);
+
+            result
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    application::{
+        dtos::auth::sign_up_dto::SignUpDto,
+        ports::{
+            email_domain_policy::email_domain_policy_port::EmailDomainPolicyError,
+            metrics::metrics_port::MetricsPort,
+        },
+        use_cases::auth::sign_up_use_case::{
+            SignUpUseCaseError, SignUpUseCaseFuture, SignUpUseCasePort,
+        },
+    },
+    domain::errors::user::user_errors::UserError,
+};
+
+/// Counts sign-up attempts of the wrapped use case by outcome.
+#[derive(Clone)]
+pub struct MetricsSignUpUseCaseDecorator<U, M> {
+    sign_up_use_case: U,
+    metrics_adapter: M,
+}
+
+impl<U, M> MetricsSignUpUseCaseDecorator<U, M>
+where
+    U: SignUpUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    pub const fn new(sign_up_use_case: U, metrics_adapter: M) -> Self {
+        Self {
+            sign_up_use_case,
+            metrics_adapter,
+        }
+    }
+
+    const fn failure_reason(err: &SignUpUseCaseError) -> &'static str {
+        match err {
+            SignUpUseCaseError::UserError(UserError::UserAlreadyExists) => "user_already_exists",
+            SignUpUseCaseError::UserError(UserError::PasswordsDoNotMatch) => {
+                "passwords_do_not_match"
+            }
+            SignUpUseCaseError::UserError(_) => "user_error",
+            SignUpUseCaseError::EmailDomainPolicyError(EmailDomainPolicyError::LoadError {
+                ..
+            }) => "email_domain_policy_error",
+            SignUpUseCaseError::EmailDomainPolicyError(_) => "invalid_email_domain",
+            SignUpUseCaseError::HasherError(_) => "hasher_error",
+            SignUpUseCaseError::ValidationError(_) => "validation_error",
+            SignUpUseCaseError::DatabaseError(_) => "database_error",
+            SignUpUseCaseError::RepositoryError(_) => "repository_error",
+        }
+    }
+}
+
+impl<U, M> SignUpUseCasePort for MetricsSignUpUseCaseDecorator<U, M>
+where
+    U: SignUpUseCasePort + Clone,
+    M: MetricsPort + Clone,
+{
+    fn perform(&self, sign_up_dto: SignUpDto) -> SignUpUseCaseFuture<'_> {
+        Box::pin(async move {
+            let result = self.sign_up_use_case.perform(sign_up_dto).await;
+
+            let outcome = match &result {
+                Ok(_) => "success",
+                Err(err) => Self::failure_reason(err),
+            };
+
+            self.metrics_adapter.record_sign_up(outcome);
+
+            result
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::{
+        admin::operator_user_action_use_case::OperatorUserActionUseCase,
+        auth::sign_up_use_case::SignUpUseCase,
+        user::{
+            get_user_by_email_use_case::GetUserByEmailUseCase,
+            get_user_by_id_use_case::GetUserByIdUseCase,
+        },
+    },
+    infrastructure::{
+        adapters::{
+            bcrypt::bcrypt_adapter::BcryptAdapter, clock::system_clock_adapter::SystemClockAdapter,
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            regex::regex_adapter::RegexAdapter, uuid::uuid_adapter::UuidAdapter,
+        },
+        repositories::{
+            email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+            user::{
+                create_user_repository::CreateUserRepository,
+                get_user_by_email_repository::GetUserByEmailRepository,
+                get_user_by_id_repository::GetUserByIdRepository,
+                update_user_repository::UpdateUserRepository,
+            },
+        },
+    },
+    presentation::cli::admin::admin_cli_controller::AdminCliController,
+};
+
+pub type AdminCliControllerAdapter = AdminCliController<
+    RegexAdapter,
+    RuleBasedPasswordPolicyAdapter,
+    SignUpUseCase<
+        BcryptAdapter,
+        UuidAdapter,
+        CreateUserRepository,
+        GetUserByEmailRepository,
+        CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        RuleBasedEmailNormalizerAdapter,
+        SystemClockAdapter,
+    >,
+    OperatorUserActionUseCase<
+        BcryptAdapter,
+        UuidAdapter,
+        GetUserByIdRepository,
+        UpdateUserRepository,
+        SystemClockAdapter,
+    >,
+    GetUserByIdUseCase<GetUserByIdRepository>,
+    GetUserByEmailUseCase<GetUserByEmailRepository, RuleBasedEmailNormalizerAdapter>,
+>;
+
+pub struct AdminCliControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+    email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+    email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+}
+
+impl AdminCliControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+        email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            password_policy_adapter,
+            email_domain_policy_adapter,
+            email_normalizer_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> AdminCliControllerAdapter {
+        let pattern_matching_adapter = RegexAdapter;
+        let hasher_adapter = BcryptAdapter;
+        let id_generator_adapter = UuidAdapter;
+        let create_user_repository = CreateUserRepository::new(self.database_pool.clone());
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+        let update_user_repository = UpdateUserRepository::new(self.database_pool.clone());
+
+        let get_user_by_email_repository =
+            GetUserByEmailRepository::new(self.database_pool.clone());
+
+        let sign_up_use_case = SignUpUseCase::new(
+            hasher_adapter.clone(),
+            id_generator_adapter.clone(),
+            create_user_repository,
+            get_user_by_email_repository.clone(),
+            self.email_domain_policy_adapter.clone(),
+            self.email_normalizer_adapter.clone(),
+            SystemClockAdapter,
+        );
+
+        let operator_user_action_use_case = OperatorUserActionUseCase::new(
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_id_repository.clone(),
+            update_user_repository,
+            SystemClockAdapter,
+        );
+
+        AdminCliController::new(
+            pattern_matching_adapter,
+            self.password_policy_adapter.clone(),
+            sign_up_use_case,
+            operator_user_action_use_case,
+            GetUserByIdUseCase::new(get_user_by_id_repository),
+            GetUserByEmailUseCase::new(
+                get_user_by_email_repository,
+                self.email_normalizer_adapter.clone(),
+            ),
+        )
+    }
+}
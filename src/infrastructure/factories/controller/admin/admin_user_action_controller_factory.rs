@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::admin::admin_user_action_use_case::AdminUserActionUseCase,
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter, regex::regex_adapter::RegexAdapter,
+            uuid::uuid_adapter::UuidAdapter,
+        },
+        repositories::user::{
+            get_user_by_id_repository::GetUserByIdRepository,
+            update_user_repository::UpdateUserRepository,
+        },
+    },
+    presentation::{
+        controllers::admin::admin_user_action_controller::AdminUserActionController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct AdminUserActionControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl AdminUserActionControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> AdminUserActionController<
+        RegexAdapter,
+        AdminUserActionUseCase<
+            UuidAdapter,
+            GetUserByIdRepository,
+            UpdateUserRepository,
+            SystemClockAdapter,
+        >,
+    > {
+        let pattern_matching_adapter = RegexAdapter;
+        let id_generator_adapter = UuidAdapter;
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+        let update_user_repository = UpdateUserRepository::new(self.database_pool.clone());
+
+        let admin_user_action_use_case = AdminUserActionUseCase::new(
+            id_generator_adapter,
+            get_user_by_id_repository,
+            update_user_repository,
+            SystemClockAdapter,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        AdminUserActionController::new(
+            pattern_matching_adapter,
+            admin_user_action_use_case,
+            http_response_helper,
+        )
+    }
+}
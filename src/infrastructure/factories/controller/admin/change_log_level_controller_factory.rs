@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::admin::change_log_level_use_case::ChangeLogLevelUseCase,
+    infrastructure::{
+        adapters::tracing_subscriber::reloadable_log_filter_adapter::ReloadableLogFilterAdapter,
+        repositories::user::get_user_by_id_repository::GetUserByIdRepository,
+    },
+    presentation::{
+        controllers::admin::change_log_level_controller::ChangeLogLevelController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub type ChangeLogLevelControllerAdapter = ChangeLogLevelController<
+    ChangeLogLevelUseCase<GetUserByIdRepository, ReloadableLogFilterAdapter>,
+>;
+
+pub struct ChangeLogLevelControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    log_filter_adapter: ReloadableLogFilterAdapter,
+}
+
+impl ChangeLogLevelControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        log_filter_adapter: ReloadableLogFilterAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            log_filter_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> ChangeLogLevelControllerAdapter {
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+
+        let change_log_level_use_case =
+            ChangeLogLevelUseCase::new(get_user_by_id_repository, self.log_filter_adapter.clone());
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        ChangeLogLevelController::new(change_log_level_use_case, http_response_helper)
+    }
+}
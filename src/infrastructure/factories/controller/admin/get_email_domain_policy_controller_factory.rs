@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::admin::get_email_domain_policy_use_case::GetEmailDomainPolicyUseCase,
+    infrastructure::repositories::{
+        email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+        user::get_user_by_id_repository::GetUserByIdRepository,
+    },
+    presentation::{
+        controllers::admin::get_email_domain_policy_controller::GetEmailDomainPolicyController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub type GetEmailDomainPolicyControllerAdapter = GetEmailDomainPolicyController<
+    GetEmailDomainPolicyUseCase<GetUserByIdRepository, GetEmailDomainPolicyRepository>,
+>;
+
+pub struct GetEmailDomainPolicyControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl GetEmailDomainPolicyControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> GetEmailDomainPolicyControllerAdapter {
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+
+        let get_email_domain_policy_repository =
+            GetEmailDomainPolicyRepository::new(self.database_pool.clone());
+
+        let get_email_domain_policy_use_case = GetEmailDomainPolicyUseCase::new(
+            get_user_by_id_repository,
+            get_email_domain_policy_repository,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        GetEmailDomainPolicyController::new(get_email_domain_policy_use_case, http_response_helper)
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::admin::import_users_use_case::ImportUsersUseCase,
+    infrastructure::{
+        adapters::{
+            bcrypt::bcrypt_adapter::BcryptAdapter, clock::system_clock_adapter::SystemClockAdapter,
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            regex::regex_adapter::RegexAdapter, uuid::uuid_adapter::UuidAdapter,
+        },
+        repositories::{
+            email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+            user::{
+                create_user_repository::CreateUserRepository,
+                get_user_by_email_repository::GetUserByEmailRepository,
+            },
+        },
+    },
+    presentation::{
+        controllers::admin::import_users_controller::ImportUsersController,
+        helpers::{
+            http::http_response_helper::HttpResponseHelper,
+            import::user_import_helper::UserImportHelper,
+        },
+    },
+};
+
+pub type ImportUsersControllerAdapter = ImportUsersController<
+    ImportUsersUseCase<
+        RegexAdapter,
+        RuleBasedPasswordPolicyAdapter,
+        CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        RuleBasedEmailNormalizerAdapter,
+        BcryptAdapter,
+        UuidAdapter,
+        GetUserByEmailRepository,
+        CreateUserRepository,
+        SystemClockAdapter,
+    >,
+>;
+
+pub struct ImportUsersControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+    email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+    email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+}
+
+impl ImportUsersControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+        email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            password_policy_adapter,
+            email_domain_policy_adapter,
+            email_normalizer_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> ImportUsersControllerAdapter {
+        let pattern_matching_adapter = RegexAdapter;
+        let hasher_adapter = BcryptAdapter;
+        let id_generator_adapter = UuidAdapter;
+
+        let get_user_by_email_repository =
+            GetUserByEmailRepository::new(self.database_pool.clone());
+
+        let create_user_repository = CreateUserRepository::new(self.database_pool.clone());
+
+        let import_users_use_case = ImportUsersUseCase::new(
+            pattern_matching_adapter,
+            self.password_policy_adapter.clone(),
+            self.email_domain_policy_adapter.clone(),
+            self.email_normalizer_adapter.clone(),
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_email_repository,
+            create_user_repository,
+            SystemClockAdapter,
+        );
+
+        let user_import_helper = UserImportHelper::new();
+        let http_response_helper = HttpResponseHelper::new();
+
+        ImportUsersController::new(
+            user_import_helper,
+            import_users_use_case,
+            http_response_helper,
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::admin::update_email_domain_policy_use_case::UpdateEmailDomainPolicyUseCase,
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter,
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+        },
+        repositories::{
+            email_domain_policy::{
+                get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+                update_email_domain_policy_repository::UpdateEmailDomainPolicyRepository,
+            },
+            user::get_user_by_id_repository::GetUserByIdRepository,
+        },
+    },
+    presentation::{
+        controllers::admin::update_email_domain_policy_controller::UpdateEmailDomainPolicyController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub type UpdateEmailDomainPolicyControllerAdapter = UpdateEmailDomainPolicyController<
+    UpdateEmailDomainPolicyUseCase<
+        GetUserByIdRepository,
+        UpdateEmailDomainPolicyRepository,
+        CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        SystemClockAdapter,
+    >,
+>;
+
+pub struct UpdateEmailDomainPolicyControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+}
+
+impl UpdateEmailDomainPolicyControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+    ) -> Self {
+        Self {
+            database_pool,
+            email_domain_policy_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> UpdateEmailDomainPolicyControllerAdapter {
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+
+        let update_email_domain_policy_repository =
+            UpdateEmailDomainPolicyRepository::new(self.database_pool.clone());
+
+        // The adapter is shared with the sign-up flow, so invalidating it here is seen there.
+        let update_email_domain_policy_use_case = UpdateEmailDomainPolicyUseCase::new(
+            get_user_by_id_repository,
+            update_email_domain_policy_repository,
+            self.email_domain_policy_adapter.clone(),
+            SystemClockAdapter,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        UpdateEmailDomainPolicyController::new(
+            update_email_domain_policy_use_case,
+            http_response_helper,
+        )
+    }
+}
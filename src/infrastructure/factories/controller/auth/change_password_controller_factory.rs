@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::auth::change_password_use_case::ChangePasswordUseCase,
+    infrastructure::{
+        adapters::{
+            bcrypt::bcrypt_adapter::BcryptAdapter, clock::system_clock_adapter::SystemClockAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+            uuid::uuid_adapter::UuidAdapter,
+        },
+        decorators::metrics::metrics_hasher_decorator::MetricsHasherDecorator,
+        repositories::user::{
+            get_user_by_email_repository::GetUserByEmailRepository,
+            update_user_repository::UpdateUserRepository,
+        },
+    },
+    presentation::{
+        controllers::auth::change_password::change_password_controller::ChangePasswordController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub type ChangePasswordControllerAdapter = ChangePasswordController<
+    RuleBasedPasswordPolicyAdapter,
+    ChangePasswordUseCase<
+        MetricsHasherDecorator<BcryptAdapter, PrometheusMetricsAdapter>,
+        UuidAdapter,
+        GetUserByEmailRepository,
+        UpdateUserRepository,
+        RuleBasedEmailNormalizerAdapter,
+        SystemClockAdapter,
+    >,
+>;
+
+pub struct ChangePasswordControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    metrics_adapter: PrometheusMetricsAdapter,
+    password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+    email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+}
+
+impl ChangePasswordControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        metrics_adapter: PrometheusMetricsAdapter,
+        password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+        email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            metrics_adapter,
+            password_policy_adapter,
+            email_normalizer_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> ChangePasswordControllerAdapter {
+        let hasher_adapter =
+            MetricsHasherDecorator::new(BcryptAdapter, self.metrics_adapter.clone());
+        let id_generator_adapter = UuidAdapter;
+
+        let get_user_by_email_repository =
+            GetUserByEmailRepository::new(self.database_pool.clone());
+
+        let update_user_repository = UpdateUserRepository::new(self.database_pool.clone());
+
+        let change_password_use_case = ChangePasswordUseCase::new(
+            hasher_adapter,
+            id_generator_adapter,
+            get_user_by_email_repository,
+            update_user_repository,
+            self.email_normalizer_adapter.clone(),
+            SystemClockAdapter,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        ChangePasswordController::new(
+            self.password_policy_adapter.clone(),
+            change_password_use_case,
+            http_response_helper,
+        )
+    }
+}
@@ -6,54 +6,99 @@ use crate::{
     application::use_cases::auth::sign_in_use_case::SignInUseCase,
     infrastructure::{
         adapters::{
-            bcrypt::bcrypt_adapter::BcryptAdapter,
+            bcrypt::bcrypt_adapter::BcryptAdapter, clock::system_clock_adapter::SystemClockAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
             jsonwebtoken::jsonwebtoken_adapter::JsonWebTokenAdapter,
-            regex::regex_adapter::RegexAdapter,
+            prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+            regex::regex_adapter::RegexAdapter, uuid::uuid_adapter::UuidAdapter,
+        },
+        config::app_config::AppConfig,
+        decorators::metrics::{
+            metrics_hasher_decorator::MetricsHasherDecorator,
+            metrics_sign_in_use_case_decorator::MetricsSignInUseCaseDecorator,
+        },
+        repositories::{
+            session::create_session_repository::CreateSessionRepository,
+            user::get_user_by_email_repository::GetUserByEmailRepository,
         },
-        repositories::user::get_user_by_email_repository::GetUserByEmailRepository,
     },
     presentation::{
-        controllers::auth::{
-            sign_in::sign_in_controller::SignInController,
-            sign_in::sign_in_validator::SignInValidator,
-        },
+        controllers::auth::sign_in::sign_in_controller::SignInController,
         helpers::http::{
             http_body_helper::HttpBodyHelper, http_response_helper::HttpResponseHelper,
         },
     },
 };
 
+pub type SignInControllerAdapter = SignInController<
+    RegexAdapter,
+    MetricsSignInUseCaseDecorator<
+        SignInUseCase<
+            MetricsHasherDecorator<BcryptAdapter, PrometheusMetricsAdapter>,
+            JsonWebTokenAdapter<SystemClockAdapter>,
+            UuidAdapter,
+            GetUserByEmailRepository,
+            CreateSessionRepository,
+            RuleBasedEmailNormalizerAdapter,
+        >,
+        PrometheusMetricsAdapter,
+    >,
+>;
+
 pub struct SignInControllerFactory {
     database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+    metrics_adapter: PrometheusMetricsAdapter,
+    email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
 }
 
 impl SignInControllerFactory {
     #[must_use]
-    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
-        Self { database_pool }
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        app_config: Arc<AppConfig>,
+        metrics_adapter: PrometheusMetricsAdapter,
+        email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            app_config,
+            metrics_adapter,
+            email_normalizer_adapter,
+        }
     }
 
     #[must_use]
-    pub fn build(
-        &self,
-    ) -> SignInController<
-        SignInValidator,
-        RegexAdapter,
-        SignInUseCase<BcryptAdapter, JsonWebTokenAdapter, GetUserByEmailRepository>,
-    > {
-        let hasher_adapter = BcryptAdapter;
-        let auth_adapter = JsonWebTokenAdapter;
+    pub fn build(&self) -> SignInControllerAdapter {
+        let hasher_adapter =
+            MetricsHasherDecorator::new(BcryptAdapter, self.metrics_adapter.clone());
+        let auth_adapter = JsonWebTokenAdapter::new(
+            &self.app_config.auth.jwt_secret,
+            self.app_config.auth.token_ttl_seconds,
+            SystemClockAdapter,
+        );
         let pattern_matching_adapter = RegexAdapter;
+        let id_generator_adapter = UuidAdapter;
 
         let get_user_by_email_repository =
             GetUserByEmailRepository::new(self.database_pool.clone());
 
+        let create_session_repository = CreateSessionRepository::new(self.database_pool.clone());
+
+        let sign_in_use_case = SignInUseCase::new(
+            hasher_adapter,
+            auth_adapter,
+            id_generator_adapter,
+            get_user_by_email_repository,
+            create_session_repository,
+            self.email_normalizer_adapter.clone(),
+        );
+
         let sign_in_use_case =
-            SignInUseCase::new(hasher_adapter, auth_adapter, get_user_by_email_repository);
+            MetricsSignInUseCaseDecorator::new(sign_in_use_case, self.metrics_adapter.clone());
 
-        let sign_in_validator = SignInValidator;
         let http_response_helper = HttpResponseHelper::new();
-        let http_body_helper = HttpBodyHelper::new(sign_in_validator, http_response_helper.clone());
+        let http_body_helper = HttpBodyHelper::new(http_response_helper.clone());
 
         SignInController::new(
             http_body_helper,
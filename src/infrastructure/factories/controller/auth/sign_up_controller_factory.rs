@@ -6,45 +6,81 @@ use crate::{
     application::use_cases::auth::sign_up_use_case::SignUpUseCase,
     infrastructure::{
         adapters::{
-            bcrypt::bcrypt_adapter::BcryptAdapter, regex::regex_adapter::RegexAdapter,
-            uuid::uuid_adapter::UuidAdapter,
+            bcrypt::bcrypt_adapter::BcryptAdapter, clock::system_clock_adapter::SystemClockAdapter,
+            email_domain_policy::cached_email_domain_policy_adapter::CachedEmailDomainPolicyAdapter,
+            email_normalizer::rule_based_email_normalizer_adapter::RuleBasedEmailNormalizerAdapter,
+            password_policy::rule_based_password_policy_adapter::RuleBasedPasswordPolicyAdapter,
+            prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+            regex::regex_adapter::RegexAdapter, uuid::uuid_adapter::UuidAdapter,
         },
-        repositories::user::{
-            create_user_repository::CreateUserRepository,
-            get_user_by_email_repository::GetUserByEmailRepository,
+        decorators::metrics::{
+            metrics_hasher_decorator::MetricsHasherDecorator,
+            metrics_sign_up_use_case_decorator::MetricsSignUpUseCaseDecorator,
+        },
+        repositories::{
+            email_domain_policy::get_email_domain_policy_repository::GetEmailDomainPolicyRepository,
+            user::{
+                create_user_repository::CreateUserRepository,
+                get_user_by_email_repository::GetUserByEmailRepository,
+            },
         },
     },
     presentation::{
-        controllers::auth::sign_up::{
-            sign_up_controller::SignUpController, sign_up_validator::SignUpValidator,
-        },
+        controllers::auth::sign_up::sign_up_controller::SignUpController,
         helpers::http::{
             http_body_helper::HttpBodyHelper, http_response_helper::HttpResponseHelper,
         },
     },
 };
 
+pub type SignUpControllerAdapter = SignUpController<
+    RegexAdapter,
+    RuleBasedPasswordPolicyAdapter,
+    MetricsSignUpUseCaseDecorator<
+        SignUpUseCase<
+            MetricsHasherDecorator<BcryptAdapter, PrometheusMetricsAdapter>,
+            UuidAdapter,
+            CreateUserRepository,
+            GetUserByEmailRepository,
+            CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+            RuleBasedEmailNormalizerAdapter,
+            SystemClockAdapter,
+        >,
+        PrometheusMetricsAdapter,
+    >,
+>;
+
 pub struct SignUpControllerFactory {
     database_pool: Arc<Pool<Postgres>>,
+    metrics_adapter: PrometheusMetricsAdapter,
+    password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+    email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+    email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
 }
 
 impl SignUpControllerFactory {
     #[must_use]
-    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
-        Self { database_pool }
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        metrics_adapter: PrometheusMetricsAdapter,
+        password_policy_adapter: RuleBasedPasswordPolicyAdapter,
+        email_domain_policy_adapter: CachedEmailDomainPolicyAdapter<GetEmailDomainPolicyRepository>,
+        email_normalizer_adapter: RuleBasedEmailNormalizerAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            metrics_adapter,
+            password_policy_adapter,
+            email_domain_policy_adapter,
+            email_normalizer_adapter,
+        }
     }
 
     #[must_use]
-    pub fn build(
-        &self,
-    ) -> SignUpController<
-        SignUpValidator,
-        RegexAdapter,
-        SignUpUseCase<BcryptAdapter, UuidAdapter, CreateUserRepository, GetUserByEmailRepository>,
-    > {
-        let sign_up_validator = SignUpValidator;
+    pub fn build(&self) -> SignUpControllerAdapter {
         let pattern_matching_adapter = RegexAdapter;
-        let hasher_adapter = BcryptAdapter;
+        let hasher_adapter =
+            MetricsHasherDecorator::new(BcryptAdapter, self.metrics_adapter.clone());
         let id_generator_adapter = UuidAdapter;
         let create_user_repository = CreateUserRepository::new(self.database_pool.clone());
 
@@ -52,18 +88,25 @@ impl SignUpControllerFactory {
             GetUserByEmailRepository::new(self.database_pool.clone());
 
         let http_response_helper = HttpResponseHelper::new();
-        let http_body_helper = HttpBodyHelper::new(sign_up_validator, http_response_helper.clone());
+        let http_body_helper = HttpBodyHelper::new(http_response_helper.clone());
 
         let sign_up_use_case = SignUpUseCase::new(
             hasher_adapter,
             id_generator_adapter,
             create_user_repository,
             get_user_by_email_repository,
+            self.email_domain_policy_adapter.clone(),
+            self.email_normalizer_adapter.clone(),
+            SystemClockAdapter,
         );
 
+        let sign_up_use_case =
+            MetricsSignUpUseCaseDecorator::new(sign_up_use_case, self.metrics_adapter.clone());
+
         SignUpController::new(
             http_body_helper,
             pattern_matching_adapter,
+            self.password_policy_adapter.clone(),
             sign_up_use_case,
             http_response_helper,
         )
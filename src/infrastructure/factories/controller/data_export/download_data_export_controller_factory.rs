@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::data_export::download_data_export_use_case::DownloadDataExportUseCase,
+    infrastructure::{
+        adapters::{
+            regex::regex_adapter::RegexAdapter, zip::zip_archive_adapter::ZipArchiveAdapter,
+        },
+        repositories::data_export::get_data_export_by_id_repository::GetDataExportByIdRepository,
+    },
+    presentation::{
+        controllers::data_export::download_data_export_controller::DownloadDataExportController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct DownloadDataExportControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl DownloadDataExportControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> DownloadDataExportController<
+        RegexAdapter,
+        DownloadDataExportUseCase<GetDataExportByIdRepository, ZipArchiveAdapter>,
+    > {
+        let pattern_matching_adapter = RegexAdapter;
+        let archive_adapter = ZipArchiveAdapter;
+        let get_data_export_by_id_repository =
+            GetDataExportByIdRepository::new(self.database_pool.clone());
+
+        let download_data_export_use_case =
+            DownloadDataExportUseCase::new(get_data_export_by_id_repository, archive_adapter);
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        DownloadDataExportController::new(
+            pattern_matching_adapter,
+            download_data_export_use_case,
+            http_response_helper,
+        )
+    }
+}
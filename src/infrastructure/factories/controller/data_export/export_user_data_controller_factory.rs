@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::data_export::{
+        generate_data_export_use_case::GenerateDataExportUseCase,
+        request_data_export_use_case::RequestDataExportUseCase,
+    },
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter,
+            regex::regex_adapter::RegexAdapter,
+            tokio::{
+                tokio_background_task_adapter::TokioBackgroundTaskAdapter,
+                tokio_data_export_job_adapter::TokioDataExportJobAdapter,
+            },
+            uuid::uuid_adapter::UuidAdapter,
+            zip::zip_archive_adapter::ZipArchiveAdapter,
+        },
+        repositories::{
+            data_export::{
+                create_data_export_repository::CreateDataExportRepository,
+                get_data_export_by_id_repository::GetDataExportByIdRepository,
+                update_data_export_repository::UpdateDataExportRepository,
+            },
+            user::get_user_data_repository::GetUserDataRepository,
+        },
+    },
+    presentation::{
+        controllers::data_export::export_user_data_controller::ExportUserDataController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+type DataExportJobAdapter = TokioDataExportJobAdapter<
+    GenerateDataExportUseCase<
+        GetDataExportByIdRepository,
+        GetUserDataRepository,
+        ZipArchiveAdapter,
+        UpdateDataExportRepository,
+        SystemClockAdapter,
+    >,
+>;
+
+pub struct ExportUserDataControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    background_task_adapter: TokioBackgroundTaskAdapter,
+}
+
+impl ExportUserDataControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        background_task_adapter: TokioBackgroundTaskAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            background_task_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> ExportUserDataController<
+        RegexAdapter,
+        RequestDataExportUseCase<
+            UuidAdapter,
+            GetUserDataRepository,
+            ZipArchiveAdapter,
+            CreateDataExportRepository,
+            DataExportJobAdapter,
+            SystemClockAdapter,
+        >,
+    > {
+        let pattern_matching_adapter = RegexAdapter;
+        let id_generator_adapter = UuidAdapter;
+        let archive_adapter = ZipArchiveAdapter;
+
+        let get_user_data_repository = GetUserDataRepository::new(self.database_pool.clone());
+        let get_data_export_by_id_repository =
+            GetDataExportByIdRepository::new(self.database_pool.clone());
+        let create_data_export_repository =
+            CreateDataExportRepository::new(self.database_pool.clone());
+        let update_data_export_repository =
+            UpdateDataExportRepository::new(self.database_pool.clone());
+
+        let generate_data_export_use_case = GenerateDataExportUseCase::new(
+            get_data_export_by_id_repository,
+            get_user_data_repository.clone(),
+            archive_adapter.clone(),
+            update_data_export_repository,
+            SystemClockAdapter,
+        );
+
+        let data_export_job_adapter = TokioDataExportJobAdapter::new(
+            self.background_task_adapter.clone(),
+            generate_data_export_use_case,
+        );
+
+        let request_data_export_use_case = RequestDataExportUseCase::new(
+            id_generator_adapter,
+            get_user_data_repository,
+            archive_adapter,
+            create_data_export_repository,
+            data_export_job_adapter,
+            SystemClockAdapter,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        ExportUserDataController::new(
+            pattern_matching_adapter,
+            request_data_export_use_case,
+            http_response_helper,
+        )
+    }
+}
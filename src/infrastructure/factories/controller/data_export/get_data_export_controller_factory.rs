@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::data_export::get_data_export_use_case::GetDataExportUseCase,
+    infrastructure::{
+        adapters::regex::regex_adapter::RegexAdapter,
+        repositories::data_export::get_data_export_by_id_repository::GetDataExportByIdRepository,
+    },
+    presentation::{
+        controllers::data_export::get_data_export_controller::GetDataExportController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct GetDataExportControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl GetDataExportControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> GetDataExportController<RegexAdapter, GetDataExportUseCase<GetDataExportByIdRepository>>
+    {
+        let pattern_matching_adapter = RegexAdapter;
+        let get_data_export_by_id_repository =
+            GetDataExportByIdRepository::new(self.database_pool.clone());
+
+        let get_data_export_use_case = GetDataExportUseCase::new(get_data_export_by_id_repository);
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        GetDataExportController::new(
+            pattern_matching_adapter,
+            get_data_export_use_case,
+            http_response_helper,
+        )
+    }
+}
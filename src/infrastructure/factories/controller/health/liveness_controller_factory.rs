@@ -0,0 +1,24 @@
+use crate::presentation::{
+    controllers::health::liveness_controller::LivenessController,
+    helpers::http::http_response_helper::HttpResponseHelper,
+};
+
+pub struct LivenessControllerFactory;
+
+impl LivenessControllerFactory {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[must_use]
+    pub const fn build(&self) -> LivenessController {
+        LivenessController::new(HttpResponseHelper::new())
+    }
+}
+
+impl Default for LivenessControllerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
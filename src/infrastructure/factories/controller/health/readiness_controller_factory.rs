@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::health::check_readiness_use_case::CheckReadinessUseCase,
+    infrastructure::{
+        adapters::{
+            file_storage::local_file_storage_adapter::LocalFileStorageAdapter,
+            postgres::postgres_health_check_adapter::PostgresHealthCheckAdapter,
+            tokio::tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter,
+        },
+        config::app_config::AppConfig,
+    },
+    presentation::{
+        controllers::health::readiness_controller::ReadinessController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct ReadinessControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+    shutdown_signal_adapter: TokioShutdownSignalAdapter,
+}
+
+impl ReadinessControllerFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        app_config: Arc<AppConfig>,
+        shutdown_signal_adapter: TokioShutdownSignalAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            app_config,
+            shutdown_signal_adapter,
+        }
+    }
+
+    /// Registers a health check for every dependency the server is configured with.
+    #[must_use]
+    pub fn build(&self) -> ReadinessController<CheckReadinessUseCase> {
+        let postgres_health_check_adapter =
+            PostgresHealthCheckAdapter::new(self.database_pool.clone());
+
+        let file_storage_adapter =
+            LocalFileStorageAdapter::new(self.app_config.storage.directory.clone());
+
+        let check_readiness_use_case =
+            CheckReadinessUseCase::new(self.app_config.health.check_timeout())
+                .register(Arc::new(self.shutdown_signal_adapter.clone()))
+                .register(Arc::new(postgres_health_check_adapter))
+                .register(Arc::new(file_storage_adapter));
+
+        ReadinessController::new(check_readiness_use_case, HttpResponseHelper::new())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    infrastructure::adapters::prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+    presentation::{
+        controllers::metrics::get_metrics_controller::GetMetricsController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct GetMetricsControllerFactory {
+    metrics_adapter: PrometheusMetricsAdapter,
+}
+
+impl GetMetricsControllerFactory {
+    #[must_use]
+    pub const fn new(metrics_adapter: PrometheusMetricsAdapter) -> Self {
+        Self { metrics_adapter }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> GetMetricsController<PrometheusMetricsAdapter> {
+        GetMetricsController::new(self.metrics_adapter.clone(), HttpResponseHelper::new())
+    }
+}
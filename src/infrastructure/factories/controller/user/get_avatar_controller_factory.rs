@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::user::get_avatar_use_case::GetAvatarUseCase,
+    infrastructure::{
+        adapters::{
+            file_storage::local_file_storage_adapter::LocalFileStorageAdapter,
+            image::image_adapter::ImageAdapter, regex::regex_adapter::RegexAdapter,
+        },
+        config::app_config::AppConfig,
+        repositories::user::get_user_by_id_repository::GetUserByIdRepository,
+    },
+    presentation::{
+        controllers::user::get_avatar_controller::GetAvatarController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+type GetAvatarUseCaseAdapter =
+    GetAvatarUseCase<GetUserByIdRepository, LocalFileStorageAdapter, ImageAdapter>;
+
+pub struct GetAvatarControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+}
+
+impl GetAvatarControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>, app_config: Arc<AppConfig>) -> Self {
+        Self {
+            database_pool,
+            app_config,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> GetAvatarController<RegexAdapter, GetAvatarUseCaseAdapter> {
+        let pattern_matching_adapter = RegexAdapter;
+        let file_storage_adapter = LocalFileStorageAdapter::new(&self.app_config.storage.directory);
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+        let image_processing_adapter = ImageAdapter::new();
+
+        let get_avatar_use_case = GetAvatarUseCase::new(
+            get_user_by_id_repository,
+            file_storage_adapter,
+            image_processing_adapter,
+        );
+
+        let http_response_helper = HttpResponseHelper::new();
+
+        GetAvatarController::new(
+            pattern_matching_adapter,
+            get_avatar_use_case,
+            http_response_helper,
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::user::search_users_use_case::SearchUsersUseCase,
+    infrastructure::repositories::user::search_users_repository::SearchUsersRepository,
+    presentation::{
+        controllers::user::search_users_controller::SearchUsersController,
+        helpers::http::http_response_helper::HttpResponseHelper,
+    },
+};
+
+pub struct SearchUsersControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl SearchUsersControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> SearchUsersController<SearchUsersUseCase<SearchUsersRepository>> {
+        let search_users_repository = SearchUsersRepository::new(self.database_pool.clone());
+        let search_users_use_case = SearchUsersUseCase::new(search_users_repository);
+        let http_response_helper = HttpResponseHelper::new();
+
+        SearchUsersController::new(search_users_use_case, http_response_helper)
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::user::upload_avatar_use_case::UploadAvatarUseCase,
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter,
+            file_storage::local_file_storage_adapter::LocalFileStorageAdapter,
+            image::image_adapter::ImageAdapter, uuid::uuid_adapter::UuidAdapter,
+        },
+        config::app_config::AppConfig,
+        repositories::user::{
+            get_user_by_id_repository::GetUserByIdRepository,
+            update_user_repository::UpdateUserRepository,
+        },
+    },
+    presentation::{
+        controllers::user::upload_avatar_controller::UploadAvatarController,
+        helpers::{
+            http::http_response_helper::HttpResponseHelper,
+            multipart::multipart_helper::MultipartHelper,
+        },
+    },
+};
+
+type UploadAvatarUseCaseAdapter = UploadAvatarUseCase<
+    ImageAdapter,
+    LocalFileStorageAdapter,
+    UuidAdapter,
+    GetUserByIdRepository,
+    UpdateUserRepository,
+    SystemClockAdapter,
+>;
+
+pub struct UploadAvatarControllerFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+}
+
+impl UploadAvatarControllerFactory {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>, app_config: Arc<AppConfig>) -> Self {
+        Self {
+            database_pool,
+            app_config,
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> UploadAvatarController<UploadAvatarUseCaseAdapter> {
+        let image_processing_adapter = ImageAdapter::new();
+        let id_generator_adapter = UuidAdapter::new();
+        let file_storage_adapter = LocalFileStorageAdapter::new(&self.app_config.storage.directory);
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+        let update_user_repository = UpdateUserRepository::new(self.database_pool.clone());
+
+        let upload_avatar_use_case = UploadAvatarUseCase::new(
+            image_processing_adapter,
+            file_storage_adapter,
+            id_generator_adapter,
+            get_user_by_id_repository,
+            update_user_repository,
+            SystemClockAdapter,
+        );
+
+        let multipart_helper = MultipartHelper::new();
+        let http_response_helper = HttpResponseHelper::new();
+
+        UploadAvatarController::new(
+            multipart_helper,
+            upload_avatar_use_case,
+            http_response_helper,
+        )
+    }
+}
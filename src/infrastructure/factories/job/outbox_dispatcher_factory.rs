@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::outbox::dispatch_outbox_events_use_case::DispatchOutboxEventsUseCase,
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter,
+            domain_events::tracing_domain_event_handler_adapter::TracingDomainEventHandlerAdapter,
+            tokio::{
+                tokio_background_task_adapter::TokioBackgroundTaskAdapter,
+                tokio_outbox_dispatcher_adapter::TokioOutboxDispatcherAdapter,
+                tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter,
+            },
+        },
+        config::app_config::AppConfig,
+        repositories::outbox::{
+            claim_outbox_events_repository::ClaimOutboxEventsRepository,
+            update_outbox_event_repository::UpdateOutboxEventRepository,
+        },
+    },
+};
+
+pub struct OutboxDispatcherFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+    background_task_adapter: TokioBackgroundTaskAdapter,
+    shutdown_signal_adapter: TokioShutdownSignalAdapter,
+}
+
+impl OutboxDispatcherFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        app_config: Arc<AppConfig>,
+        background_task_adapter: TokioBackgroundTaskAdapter,
+        shutdown_signal_adapter: TokioShutdownSignalAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            app_config,
+            background_task_adapter,
+            shutdown_signal_adapter,
+        }
+    }
+
+    /// Registers every in-process handler domain events are delivered to.
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> TokioOutboxDispatcherAdapter<
+        DispatchOutboxEventsUseCase<
+            ClaimOutboxEventsRepository,
+            UpdateOutboxEventRepository,
+            SystemClockAdapter,
+        >,
+    > {
+        let outbox_config = &self.app_config.outbox;
+
+        let dispatch_outbox_events_use_case = DispatchOutboxEventsUseCase::new(
+            ClaimOutboxEventsRepository::new(self.database_pool.clone()),
+            UpdateOutboxEventRepository::new(self.database_pool.clone()),
+            SystemClockAdapter,
+            outbox_config.batch_size,
+            outbox_config.max_attempts,
+            outbox_config.retry_delay_seconds,
+        )
+        .register(Arc::new(TracingDomainEventHandlerAdapter));
+
+        TokioOutboxDispatcherAdapter::new(
+            self.background_task_adapter.clone(),
+            self.shutdown_signal_adapter.clone(),
+            dispatch_outbox_events_use_case,
+            outbox_config.poll_interval(),
+        )
+    }
+}
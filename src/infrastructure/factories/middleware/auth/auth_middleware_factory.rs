@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+
+use crate::{
+    application::use_cases::auth::authenticate_use_case::AuthenticateUseCase,
+    infrastructure::{
+        adapters::{
+            clock::system_clock_adapter::SystemClockAdapter,
+            jsonwebtoken::jsonwebtoken_adapter::JsonWebTokenAdapter,
+            prometheus::prometheus_metrics_adapter::PrometheusMetricsAdapter,
+        },
+        config::app_config::AppConfig,
+        decorators::metrics::metrics_authenticate_use_case_decorator::MetricsAuthenticateUseCaseDecorator,
+        repositories::user::get_user_by_id_repository::GetUserByIdRepository,
+    },
+    presentation::middlewares::auth::auth_middleware::AuthMiddleware,
+};
+
+pub struct AuthMiddlewareFactory {
+    database_pool: Arc<Pool<Postgres>>,
+    app_config: Arc<AppConfig>,
+    metrics_adapter: PrometheusMetricsAdapter,
+}
+
+impl AuthMiddlewareFactory {
+    #[must_use]
+    pub const fn new(
+        database_pool: Arc<Pool<Postgres>>,
+        app_config: Arc<AppConfig>,
+        metrics_adapter: PrometheusMetricsAdapter,
+    ) -> Self {
+        Self {
+            database_pool,
+            app_config,
+            metrics_adapter,
+        }
+    }
+
+    #[must_use]
+    pub fn build(
+        &self,
+    ) -> AuthMiddleware<
+        MetricsAuthenticateUseCaseDecorator<
+            AuthenticateUseCase<JsonWebTokenAdapter<SystemClockAdapter>, GetUserByIdRepository>,
+            PrometheusMetricsAdapter,
+        >,
+    > {
+        let auth_adapter = JsonWebTokenAdapter::new(
+            &self.app_config.auth.jwt_secret,
+            self.app_config.auth.token_ttl_seconds,
+            SystemClockAdapter,
+        );
+        let get_user_by_id_repository = GetUserByIdRepository::new(self.database_pool.clone());
+        let authenticate_use_case =
+            AuthenticateUseCase::new(auth_adapter, get_user_by_id_repository);
+
+        let authenticate_use_case = MetricsAuthenticateUseCaseDecorator::new(
+            authenticate_use_case,
+            self.metrics_adapter.clone(),
+        );
+
+        AuthMiddleware::new(authenticate_use_case)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    infrastructure::adapters::json_catalog::json_catalog_localization_adapter::JsonCatalogLocalizationAdapter,
+    presentation::middlewares::localization::localization_middleware::LocalizationMiddleware,
+};
+
+pub struct LocalizationMiddlewareFactory;
+
+impl LocalizationMiddlewareFactory {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[must_use]
+    pub const fn build(&self) -> LocalizationMiddleware<JsonCatalogLocalizationAdapter> {
+        LocalizationMiddleware::new(JsonCatalogLocalizationAdapter)
+    }
+}
+
+impl Default for LocalizationMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
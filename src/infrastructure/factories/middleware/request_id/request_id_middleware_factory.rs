@@ -0,0 +1,24 @@
+use crate::{
+    infrastructure::adapters::uuid::uuid_adapter::UuidAdapter,
+    presentation::middlewares::request_id::request_id_middleware::RequestIdMiddleware,
+};
+
+pub struct RequestIdMiddlewareFactory;
+
+impl RequestIdMiddlewareFactory {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[must_use]
+    pub const fn build(&self) -> RequestIdMiddleware<UuidAdapter> {
+        RequestIdMiddleware::new(UuidAdapter)
+    }
+}
+
+impl Default for RequestIdMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    infrastructure::adapters::opentelemetry::opentelemetry_trace_context_adapter::OpenTelemetryTraceContextAdapter,
+    presentation::middlewares::tracing::trace_context_middleware::TraceContextMiddleware,
+};
+
+pub struct TraceContextMiddlewareFactory;
+
+impl TraceContextMiddlewareFactory {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[must_use]
+    pub const fn build(&self) -> TraceContextMiddleware<OpenTelemetryTraceContextAdapter> {
+        TraceContextMiddleware::new(OpenTelemetryTraceContextAdapter)
+    }
+}
+
+impl Default for TraceContextMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
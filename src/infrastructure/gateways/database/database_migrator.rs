@@ -0,0 +1,179 @@
+use std::{pin::Pin, sync::Arc};
+
+use sqlx::{
+    Pool, Postgres,
+    migrate::{Migrate, Migrator},
+};
+
+/// Migrations under `migrations/`, embedded into the binary at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+#[derive(Debug)]
+pub enum DatabaseMigrationError {
+    Migrate { message: String },
+}
+
+impl std::fmt::Display for DatabaseMigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Migrate { message } => {
+                write!(
+                    f,
+                    "An error occurred while running database migrations: {message}.",
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DatabaseMigrationError {}
+
+impl From<sqlx::Error> for DatabaseMigrationError {
+    fn from(err: sqlx::Error) -> Self {
+        Self::Migrate {
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<sqlx::migrate::MigrateError> for DatabaseMigrationError {
+    fn from(err: sqlx::migrate::MigrateError) -> Self {
+        Self::Migrate {
+            message: err.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    /// Applied, but the embedded SQL no longer matches what was run against the database.
+    Modified,
+}
+
+impl MigrationState {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Applied => "applied",
+            Self::Pending => "pending",
+            Self::Modified => "modified",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub state: MigrationState,
+}
+
+pub type MigrationStatusFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<MigrationStatus>, DatabaseMigrationError>> + 'a>>;
+
+pub struct DatabaseMigrator {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl DatabaseMigrator {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    /// Lists every embedded migration along with whether it has been applied.
+    #[must_use]
+    pub fn status(&self) -> MigrationStatusFuture<'_> {
+        Box::pin(async move {
+            let mut connection = self.database_pool.acquire().await?;
+
+            connection.ensure_migrations_table().await?;
+
+            let applied_migrations = connection.list_applied_migrations().await?;
+
+            let migration_statuses = MIGRATOR
+                .iter()
+                .filter(|migration| !migration.migration_type.is_down_migration())
+                .map(|migration| {
+                    let applied_migration = applied_migrations
+                        .iter()
+                        .find(|applied_migration| applied_migration.version == migration.version);
+
+                    let state = match applied_migration {
+                        None => MigrationState::Pending,
+                        Some(applied_migration)
+                            if applied_migration.checksum != migration.checksum =>
+                        {
+                            MigrationState::Modified
+                        }
+                        Some(_) => MigrationState::Applied,
+                    };
+
+                    MigrationStatus {
+                        version: migration.version,
+                        description: migration.description.to_string(),
+                        state,
+                    }
+                })
+                .collect();
+
+            Ok(migration_statuses)
+        })
+    }
+
+    /// Applies every pending migration and returns the ones that were applied.
+    #[must_use]
+    pub fn up(&self) -> MigrationStatusFuture<'_> {
+        Box::pin(async move {
+            let applied_migrations = self
+                .status()
+                .await?
+                .into_iter()
+                .filter(|migration_status| migration_status.state == MigrationState::Pending)
+                .map(|migration_status| MigrationStatus {
+                    state: MigrationState::Applied,
+                    ..migration_status
+                })
+                .collect::<Vec<_>>();
+
+            MIGRATOR.run(self.database_pool.as_ref()).await?;
+
+            Ok(applied_migrations)
+        })
+    }
+
+    /// Reverts the `steps` most recently applied migrations and returns them, newest first.
+    #[must_use]
+    pub fn down(&self, steps: usize) -> MigrationStatusFuture<'_> {
+        Box::pin(async move {
+            let applied_migrations = self
+                .status()
+                .await?
+                .into_iter()
+                .filter(|migration_status| migration_status.state != MigrationState::Pending)
+                .collect::<Vec<_>>();
+
+            let kept_migrations = applied_migrations.len().saturating_sub(steps);
+
+            let target_version = kept_migrations
+                .checked_sub(1)
+                .map_or(0, |index| applied_migrations[index].version);
+
+            MIGRATOR
+                .undo(self.database_pool.as_ref(), target_version)
+                .await?;
+
+            Ok(applied_migrations
+                .into_iter()
+                .skip(kept_migrations)
+                .rev()
+                .map(|migration_status| MigrationStatus {
+                    state: MigrationState::Pending,
+                    ..migration_status
+                })
+                .collect())
+        })
+    }
+}
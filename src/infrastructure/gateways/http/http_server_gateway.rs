@@ -0,0 +1,195 @@
+use std::{net::SocketAddr, pin::Pin, time::Duration};
+
+use axum::Router;
+use tokio::net::TcpListener;
+
+use crate::infrastructure::adapters::tokio::tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter;
+
+#[derive(Debug)]
+pub enum HttpServerError {
+    Serve { message: String },
+}
+
+impl std::fmt::Display for HttpServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serve { message } => {
+                write!(
+                    f,
+                    "An error occurred while serving HTTP requests: {message}."
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HttpServerError {}
+
+impl From<std::io::Error> for HttpServerError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Serve {
+            message: err.to_string(),
+        }
+    }
+}
+
+pub type ServeFuture = Pin<Box<dyn Future<Output = Result<(), HttpServerError>> + Send>>;
+
+pub struct HttpServerGateway {
+    shutdown_timeout: Duration,
+}
+
+impl HttpServerGateway {
+    #[must_use]
+    pub const fn new(shutdown_timeout: Duration) -> Self {
+        Self { shutdown_timeout }
+    }
+
+    /// Serves `router` until `shutdown_signal` is triggered. From then on no new connection is
+    /// accepted and in-flight requests get up to the shutdown timeout to complete. Past that it
+    /// returns anyway, leaving the remaining connections to be dropped with the runtime.
+    #[must_use]
+    pub fn serve(
+        &self,
+        tcp_listener: TcpListener,
+        router: Router,
+        shutdown_signal: TokioShutdownSignalAdapter,
+    ) -> ServeFuture {
+        let shutdown_timeout = self.shutdown_timeout;
+
+        Box::pin(async move {
+            let graceful_shutdown_signal = shutdown_signal.clone();
+
+            let server = axum::serve(
+                tcp_listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(async move { graceful_shutdown_signal.wait().await });
+
+            let mut server = std::pin::pin!(server.into_future());
+
+            tokio::select! {
+                result = &mut server => return Ok(result?),
+                () = shutdown_signal.wait() => {}
+            }
+
+            tracing::info!(
+                "Draining in-flight requests for up to {} second(s).",
+                shutdown_timeout.as_secs()
+            );
+
+            if let Ok(result) = tokio::time::timeout(shutdown_timeout, server).await {
+                result?;
+            } else {
+                tracing::warn!(
+                    "In-flight requests did not complete within the shutdown timeout, giving up on them."
+                );
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{Router, routing::get};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::infrastructure::{
+        adapters::tokio::tokio_shutdown_signal_adapter::TokioShutdownSignalAdapter,
+        gateways::http::http_server_gateway::HttpServerGateway,
+    };
+
+    fn build_router(request_duration: Duration) -> Router {
+        Router::new().route(
+            "/slow",
+            get(move || async move {
+                tokio::time::sleep(request_duration).await;
+
+                "done"
+            }),
+        )
+    }
+
+    /// Sends a bare HTTP/1.1 request and returns the raw response, or `None` if the connection
+    /// was closed without one.
+    async fn send_request(mut tcp_stream: TcpStream) -> Option<String> {
+        tcp_stream
+            .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+
+        tcp_stream.read_to_string(&mut response).await.ok()?;
+
+        Some(response).filter(|response| !response.is_empty())
+    }
+
+    #[tokio::test]
+    async fn should_complete_in_flight_request_and_refuse_new_connections_on_shutdown() {
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+        let shutdown_signal = TokioShutdownSignalAdapter::new();
+
+        let server = tokio::spawn(HttpServerGateway::new(Duration::from_secs(5)).serve(
+            tcp_listener,
+            build_router(Duration::from_millis(300)),
+            shutdown_signal.clone(),
+        ));
+
+        let in_flight_request =
+            tokio::spawn(send_request(TcpStream::connect(address).await.unwrap()));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        shutdown_signal.trigger();
+
+        let response = in_flight_request.await.unwrap().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("done"));
+
+        tokio::time::timeout(Duration::from_secs(1), server)
+            .await
+            .expect("server should stop once drained")
+            .unwrap()
+            .unwrap();
+
+        assert!(TcpStream::connect(address).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_stop_waiting_for_requests_still_running_after_shutdown_timeout() {
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+        let shutdown_signal = TokioShutdownSignalAdapter::new();
+
+        let server = tokio::spawn(HttpServerGateway::new(Duration::from_millis(100)).serve(
+            tcp_listener,
+            build_router(Duration::from_secs(30)),
+            shutdown_signal.clone(),
+        ));
+
+        let in_flight_request =
+            tokio::spawn(send_request(TcpStream::connect(address).await.unwrap()));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        shutdown_signal.trigger();
+
+        tokio::time::timeout(Duration::from_secs(1), server)
+            .await
+            .expect("server should stop at the shutdown timeout")
+            .unwrap()
+            .unwrap();
+
+        assert!(!in_flight_request.is_finished());
+
+        in_flight_request.abort();
+    }
+}
@@ -0,0 +1,170 @@
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    Resource,
+    propagation::TraceContextPropagator,
+    trace::{SdkTracer, SdkTracerProvider},
+};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{
+    EnvFilter, layer::SubscriberExt, registry::LookupSpan, reload, util::SubscriberInitExt,
+};
+
+use crate::infrastructure::{
+    adapters::tracing_subscriber::reloadable_log_filter_adapter::ReloadableLogFilterAdapter,
+    config::app_config::{LogConfig, TelemetryConfig},
+    gateways::telemetry::redacting_writer::RedactingMakeWriter,
+};
+
+/// Name of the instrumentation scope every span is recorded under.
+const TRACER_NAME: &str = "rust-tdd-rest-api";
+
+#[derive(Debug)]
+pub enum TelemetryError {
+    ExporterError { message: String },
+    SubscriberError { message: String },
+}
+
+impl std::fmt::Display for TelemetryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExporterError { message } => {
+                write!(
+                    f,
+                    "An error occurred while creating the span exporter: {message}."
+                )
+            }
+            Self::SubscriberError { message } => {
+                write!(
+                    f,
+                    "An error occurred while installing the subscriber: {message}."
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TelemetryError {}
+
+/// Flushes the spans still buffered for export; keep it alive until the server stops.
+pub struct TelemetryGuard {
+    tracer_provider: SdkTracerProvider,
+    log_filter_adapter: ReloadableLogFilterAdapter,
+}
+
+impl TelemetryGuard {
+    /// Changes the log filter of the installed subscriber at runtime.
+    #[must_use]
+    pub fn log_filter_adapter(&self) -> ReloadableLogFilterAdapter {
+        self.log_filter_adapter.clone()
+    }
+
+    pub fn shutdown(self) {
+        if let Err(err) = self.tracer_provider.shutdown() {
+            tracing::warn!("Unable to flush pending spans: {err}.");
+        }
+    }
+}
+
+pub struct TelemetryGateway {
+    telemetry_config: TelemetryConfig,
+    log_config: LogConfig,
+}
+
+impl TelemetryGateway {
+    #[must_use]
+    pub const fn new(telemetry_config: TelemetryConfig, log_config: LogConfig) -> Self {
+        Self {
+            telemetry_config,
+            log_config,
+        }
+    }
+
+    /// Installs the global `tracing` subscriber, which writes redacted logs in the configured
+    /// format behind a filter that can be swapped at runtime, and turns spans into OpenTelemetry
+    /// spans. Spans are exported over OTLP when an
+    /// endpoint is configured; otherwise they are only used to propagate the trace context. SQL
+    /// statements are logged by `sqlx` as events of the repository span that ran them.
+    ///
+    /// Must be called from within the Tokio runtime, which the OTLP exporter runs on.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TelemetryError` if the exporter cannot be created or a subscriber is already
+    /// installed.
+    pub fn initialize(&self) -> Result<TelemetryGuard, TelemetryError> {
+        let env_filter = EnvFilter::builder()
+            .parse(&self.log_config.level)
+            .map_err(|err| TelemetryError::SubscriberError {
+                message: err.to_string(),
+            })?;
+
+        let (filter_layer, filter_handle) = reload::Layer::new(env_filter);
+
+        let tracer_provider = self.build_tracer_provider()?;
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        global::set_tracer_provider(tracer_provider.clone());
+
+        let is_json_format = self.log_config.format == "json";
+
+        let json_layer = is_json_format.then(|| {
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(RedactingMakeWriter::new(std::io::stdout))
+        });
+
+        let text_layer = (!is_json_format).then(|| {
+            tracing_subscriber::fmt::layer().with_writer(RedactingMakeWriter::new(std::io::stdout))
+        });
+
+        tracing_subscriber::registry()
+            .with(filter_layer)
+            .with(json_layer)
+            .with(text_layer)
+            .with(Self::tracing_layer(&tracer_provider))
+            .try_init()
+            .map_err(|err| TelemetryError::SubscriberError {
+                message: err.to_string(),
+            })?;
+
+        Ok(TelemetryGuard {
+            tracer_provider,
+            log_filter_adapter: ReloadableLogFilterAdapter::new(filter_handle),
+        })
+    }
+
+    fn build_tracer_provider(&self) -> Result<SdkTracerProvider, TelemetryError> {
+        let resource = Resource::builder()
+            .with_service_name(self.telemetry_config.service_name.clone())
+            .build();
+
+        let tracer_provider_builder = SdkTracerProvider::builder().with_resource(resource);
+
+        let Some(otlp_endpoint) = &self.telemetry_config.otlp_endpoint else {
+            return Ok(tracer_provider_builder.build());
+        };
+
+        let span_exporter = SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(otlp_endpoint)
+            .build()
+            .map_err(|err| TelemetryError::ExporterError {
+                message: err.to_string(),
+            })?;
+
+        Ok(tracer_provider_builder
+            .with_batch_exporter(span_exporter)
+            .build())
+    }
+
+    /// Bridges `tracing` spans to spans of `tracer_provider`.
+    #[must_use]
+    pub fn tracing_layer<S>(tracer_provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, SdkTracer>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(TRACER_NAME))
+    }
+}
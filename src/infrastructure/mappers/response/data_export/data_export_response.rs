@@ -0,0 +1,43 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DataExportResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub requested_by: Uuid,
+    pub status: String,
+    pub error_message: Option<String>,
+    pub download_url: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub completed_at: Option<OffsetDateTime>,
+}
+
+impl TryFrom<DataExportEntity> for DataExportResponse {
+    type Error = ModelError;
+
+    fn try_from(data_export_entity: DataExportEntity) -> Result<Self, Self::Error> {
+        let download_url = (data_export_entity.status == DataExportStatus::Completed)
+            .then(|| format!("/me/exports/{}/archive", data_export_entity.id));
+
+        Ok(Self {
+            id: parse_uuid("id", &data_export_entity.id)?,
+            user_id: parse_uuid("user_id", &data_export_entity.user_id)?,
+            requested_by: parse_uuid("requested_by", &data_export_entity.requested_by)?,
+            status: data_export_entity.status.to_string(),
+            error_message: data_export_entity.error_message,
+            download_url,
+            created_at: parse_timestamp("created_at", data_export_entity.created_at)?,
+            completed_at: data_export_entity
+                .completed_at
+                .map(|completed_at| parse_timestamp("completed_at", completed_at))
+                .transpose()?,
+        })
+    }
+}
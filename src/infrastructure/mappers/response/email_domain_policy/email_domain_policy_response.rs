@@ -0,0 +1,37 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::email_domain_policy::email_domain_policy_entity::EmailDomainPolicyEntity,
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EmailDomainPolicyResponse {
+    pub mode: String,
+    pub block_disposable: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TryFrom<EmailDomainPolicyEntity> for EmailDomainPolicyResponse {
+    type Error = ModelError;
+
+    fn try_from(email_domain_policy_entity: EmailDomainPolicyEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mode: email_domain_policy_entity.mode.to_string(),
+            block_disposable: email_domain_policy_entity.block_disposable,
+            allowed_domains: email_domain_policy_entity.allowed_domains,
+            denied_domains: email_domain_policy_entity.denied_domains,
+            updated_by: email_domain_policy_entity
+                .updated_by
+                .as_deref()
+                .map(|updated_by| parse_uuid("updated_by", updated_by))
+                .transpose()?,
+            updated_at: parse_timestamp("updated_at", email_domain_policy_entity.updated_at)?,
+        })
+    }
+}
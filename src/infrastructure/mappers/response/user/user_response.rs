@@ -11,22 +11,30 @@ pub struct UserResponse {
     pub last_name: String,
     pub email: String,
     pub is_admin: bool,
+    pub status: String,
+    pub password_reset_required: bool,
+    pub avatar_url: Option<String>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
 
 impl From<UserEntity> for UserResponse {
     fn from(user_entity: UserEntity) -> Self {
+        let avatar_url = user_entity
+            .avatar_version()
+            .map(|avatar_version| format!("/users/{}/avatar?v={avatar_version}", user_entity.id));
+
         Self {
-            id: Uuid::parse_str(&user_entity.id).expect("Invalid UUID"),
-            first_name: user_entity.first_name,
-            last_name: user_entity.last_name,
-            email: user_entity.email,
+            id: user_entity.id.as_uuid(),
+            first_name: user_entity.first_name.into_inner(),
+            last_name: user_entity.last_name.into_inner(),
+            email: user_entity.email.into_inner(),
             is_admin: user_entity.is_admin,
-            created_at: OffsetDateTime::from_unix_timestamp(user_entity.created_at)
-                .expect("Invalid created_at timestamp"),
-            updated_at: OffsetDateTime::from_unix_timestamp(user_entity.updated_at)
-                .expect("Invalid updated_at timestamp"),
+            status: user_entity.status.to_string(),
+            password_reset_required: user_entity.password_reset_required,
+            avatar_url,
+            created_at: user_entity.created_at.truncated_to_seconds(),
+            updated_at: user_entity.updated_at.truncated_to_seconds(),
         }
     }
 }
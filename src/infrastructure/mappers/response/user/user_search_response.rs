@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+use crate::{
+    application::ports::user_search::user_search_port::{UserSearchHit, UserSearchPage},
+    infrastructure::mappers::response::user::user_response::UserResponse,
+};
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UserSearchHighlightsResponse {
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UserSearchHitResponse {
+    pub user: UserResponse,
+    pub rank: f32,
+    pub highlights: UserSearchHighlightsResponse,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PaginationResponse {
+    pub page: u32,
+    pub per_page: u32,
+    pub total: u64,
+    pub total_pages: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UserSearchResponse {
+    pub users: Vec<UserSearchHitResponse>,
+    pub pagination: PaginationResponse,
+}
+
+impl From<UserSearchHit> for UserSearchHitResponse {
+    fn from(user_search_hit: UserSearchHit) -> Self {
+        Self {
+            user: UserResponse::from(user_search_hit.user),
+            rank: user_search_hit.rank,
+            highlights: UserSearchHighlightsResponse {
+                first_name: user_search_hit.highlights.first_name,
+                last_name: user_search_hit.highlights.last_name,
+                email: user_search_hit.highlights.email,
+            },
+        }
+    }
+}
+
+impl From<UserSearchPage> for UserSearchResponse {
+    fn from(user_search_page: UserSearchPage) -> Self {
+        Self {
+            users: user_search_page
+                .hits
+                .into_iter()
+                .map(UserSearchHitResponse::from)
+                .collect(),
+            pagination: PaginationResponse {
+                page: user_search_page.page,
+                per_page: user_search_page.per_page,
+                total: user_search_page.total,
+                total_pages: user_search_page
+                    .total
+                    .div_ceil(u64::from(user_search_page.per_page.max(1))),
+            },
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::entities::audit::audit_entry_entity::{AuditAction, AuditEntryEntity},
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(sqlx::FromRow)]
+pub struct AuditEntryModel {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: String,
+    pub target_user_id: Uuid,
+    pub created_at: OffsetDateTime,
+}
+
+impl TryFrom<AuditEntryEntity> for AuditEntryModel {
+    type Error = ModelError;
+
+    fn try_from(entity: AuditEntryEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("id", &entity.id)?,
+            actor_id: entity
+                .actor_id
+                .map(|actor_id| parse_uuid("actor_id", &actor_id))
+                .transpose()?,
+            action: entity.action.as_str().to_string(),
+            target_user_id: parse_uuid("target_user_id", &entity.target_user_id)?,
+            created_at: parse_timestamp("created_at", entity.created_at)?,
+        })
+    }
+}
+
+/// Fails on rows this version cannot read, such as an action it no longer knows.
+impl TryFrom<AuditEntryModel> for AuditEntryEntity {
+    type Error = ModelError;
+
+    fn try_from(audit_entry_model: AuditEntryModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: audit_entry_model.id.to_string(),
+            actor_id: audit_entry_model
+                .actor_id
+                .map(|actor_id| actor_id.to_string()),
+            action: AuditAction::parse(&audit_entry_model.action).ok_or_else(|| {
+                ModelError::UnknownValue {
+                    field: "action",
+                    value: audit_entry_model.action.clone(),
+                }
+            })?,
+            target_user_id: audit_entry_model.target_user_id.to_string(),
+            created_at: audit_entry_model.created_at.unix_timestamp(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::types::Uuid;
+    use time::OffsetDateTime;
+
+    use crate::{
+        domain::entities::audit::audit_entry_entity::AuditEntryEntity,
+        infrastructure::models::{
+            audit::audit_entry_model::AuditEntryModel, errors::model_errors::ModelError,
+        },
+    };
+
+    #[test]
+    fn should_return_error_if_stored_action_is_unknown() {
+        let audit_entry_model = AuditEntryModel {
+            id: Uuid::parse_str("d836bc7f-014e-4818-a97f-dd1bb1987b66").unwrap(),
+            actor_id: None,
+            action: "user.renamed".to_string(),
+            target_user_id: Uuid::parse_str("dba86129-90be-4409-a5a3-396db9335a57").unwrap(),
+            created_at: OffsetDateTime::from_unix_timestamp(1_695_996_669).unwrap(),
+        };
+
+        let result = AuditEntryEntity::try_from(audit_entry_model);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ModelError::UnknownValue {
+                field: "action",
+                value: "user.renamed".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::entities::data_export::data_export_entity::{DataExportEntity, DataExportStatus},
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(sqlx::FromRow)]
+pub struct DataExportModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub requested_by: Uuid,
+    pub status: String,
+    pub archive: Option<Vec<u8>>,
+    pub error_message: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub completed_at: Option<OffsetDateTime>,
+}
+
+impl From<DataExportModel> for DataExportEntity {
+    fn from(data_export_model: DataExportModel) -> Self {
+        Self {
+            id: data_export_model.id.to_string(),
+            user_id: data_export_model.user_id.to_string(),
+            requested_by: data_export_model.requested_by.to_string(),
+            // The column is constrained to known statuses; anything else is reported as failed.
+            status: DataExportStatus::parse(&data_export_model.status)
+                .unwrap_or(DataExportStatus::Failed),
+            archive: data_export_model.archive,
+            error_message: data_export_model.error_message,
+            created_at: data_export_model.created_at.unix_timestamp(),
+            completed_at: data_export_model
+                .completed_at
+                .map(OffsetDateTime::unix_timestamp),
+        }
+    }
+}
+
+impl TryFrom<DataExportEntity> for DataExportModel {
+    type Error = ModelError;
+
+    fn try_from(entity: DataExportEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("id", &entity.id)?,
+            user_id: parse_uuid("user_id", &entity.user_id)?,
+            requested_by: parse_uuid("requested_by", &entity.requested_by)?,
+            status: entity.status.as_str().to_string(),
+            archive: entity.archive,
+            error_message: entity.error_message,
+            created_at: parse_timestamp("created_at", entity.created_at)?,
+            completed_at: entity
+                .completed_at
+                .map(|completed_at| parse_timestamp("completed_at", completed_at))
+                .transpose()?,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::entities::email_domain_policy::email_domain_policy_entity::{
+        EmailDomainPolicyEntity, EmailDomainPolicyMode,
+    },
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+pub const ALLOW_LIST: &str = "allow";
+pub const DENY_LIST: &str = "deny";
+
+#[derive(sqlx::FromRow)]
+pub struct EmailDomainPolicyModel {
+    pub mode: String,
+    pub block_disposable: bool,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct EmailDomainRuleModel {
+    pub domain: String,
+    pub list: String,
+}
+
+impl EmailDomainRuleModel {
+    /// One row per listed domain, allowlist first.
+    #[must_use]
+    pub fn from_policy(entity: &EmailDomainPolicyEntity) -> Vec<Self> {
+        let allowed = entity
+            .allowed_domains
+            .iter()
+            .map(|domain| (domain, ALLOW_LIST));
+        let denied = entity
+            .denied_domains
+            .iter()
+            .map(|domain| (domain, DENY_LIST));
+
+        allowed
+            .chain(denied)
+            .map(|(domain, list)| Self {
+                domain: domain.clone(),
+                list: list.to_string(),
+            })
+            .collect()
+    }
+}
+
+impl From<(EmailDomainPolicyModel, Vec<EmailDomainRuleModel>)> for EmailDomainPolicyEntity {
+    fn from(
+        (email_domain_policy_model, email_domain_rule_models): (
+            EmailDomainPolicyModel,
+            Vec<EmailDomainRuleModel>,
+        ),
+    ) -> Self {
+        let domains = |list: &str| {
+            email_domain_rule_models
+                .iter()
+                .filter(|rule| rule.list == list)
+                .map(|rule| rule.domain.clone())
+                .collect::<Vec<_>>()
+        };
+
+        Self {
+            // The column is constrained to known modes; anything else falls back to the
+            // permissive default.
+            mode: EmailDomainPolicyMode::parse(&email_domain_policy_model.mode)
+                .unwrap_or(EmailDomainPolicyMode::Denylist),
+            block_disposable: email_domain_policy_model.block_disposable,
+            allowed_domains: domains(ALLOW_LIST),
+            denied_domains: domains(DENY_LIST),
+            updated_by: email_domain_policy_model
+                .updated_by
+                .map(|updated_by| updated_by.to_string()),
+            updated_at: email_domain_policy_model.updated_at.unix_timestamp(),
+        }
+    }
+}
+
+impl TryFrom<&EmailDomainPolicyEntity> for EmailDomainPolicyModel {
+    type Error = ModelError;
+
+    fn try_from(entity: &EmailDomainPolicyEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mode: entity.mode.as_str().to_string(),
+            block_disposable: entity.block_disposable,
+            updated_by: entity
+                .updated_by
+                .as_deref()
+                .map(|updated_by| parse_uuid("updated_by", updated_by))
+                .transpose()?,
+            updated_at: parse_timestamp("updated_at", entity.updated_at)?,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+/// A value that cannot be converted between an entity and its database row or response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelError {
+    InvalidUuid { field: &'static str, value: String },
+    InvalidTimestamp { field: &'static str, value: i64 },
+    UnknownValue { field: &'static str, value: String },
+}
+
+impl std::fmt::Display for ModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUuid { field, value } => write!(f, "{field} '{value}' is not a UUID"),
+            Self::InvalidTimestamp { field, value } => {
+                write!(f, "{field} {value} is not a representable timestamp")
+            }
+            Self::UnknownValue { field, value } => write!(f, "{field} '{value}' is not known"),
+        }
+    }
+}
+
+impl std::error::Error for ModelError {}
+
+/// # Errors
+///
+/// Returns `InvalidUuid` if the value is not a UUID.
+pub fn parse_uuid(field: &'static str, value: &str) -> Result<Uuid, ModelError> {
+    Uuid::parse_str(value).map_err(|_| ModelError::InvalidUuid {
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// # Errors
+///
+/// Returns `InvalidTimestamp` if the value is outside the supported date range.
+pub fn parse_timestamp(field: &'static str, value: i64) -> Result<OffsetDateTime, ModelError> {
+    OffsetDateTime::from_unix_timestamp(value)
+        .map_err(|_| ModelError::InvalidTimestamp { field, value })
+}
@@ -0,0 +1,164 @@
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::{
+        entities::outbox::outbox_event_entity::{OutboxEventEntity, OutboxEventStatus},
+        events::domain_event::DomainEvent,
+        value_objects::user::{email_address::EmailAddress, user_id::UserId},
+    },
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(sqlx::FromRow)]
+pub struct OutboxEventModel {
+    pub id: Uuid,
+    pub event_type: String,
+    pub payload: Value,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub occurred_at: OffsetDateTime,
+    pub next_attempt_at: OffsetDateTime,
+}
+
+#[derive(Deserialize)]
+struct UserRegisteredPayload {
+    user_id: Uuid,
+    email: String,
+}
+
+#[derive(Deserialize)]
+struct UserSignedInPayload {
+    user_id: Uuid,
+    session_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct PasswordChangedPayload {
+    user_id: Uuid,
+}
+
+fn encode_payload(event: &DomainEvent) -> Value {
+    match event {
+        DomainEvent::UserRegistered { user_id, email } => json!({
+            "user_id": user_id.to_string(),
+            "email": email.as_str(),
+        }),
+        DomainEvent::UserSignedIn {
+            user_id,
+            session_id,
+        } => json!({
+            "user_id": user_id.to_string(),
+            "session_id": session_id,
+        }),
+        DomainEvent::PasswordChanged { user_id } => json!({
+            "user_id": user_id.to_string(),
+        }),
+    }
+}
+
+fn decode_payload(event_type: &str, payload: Value) -> Result<DomainEvent, String> {
+    let invalid_payload = |err: serde_json::Error| format!("invalid {event_type} payload: {err}");
+
+    match event_type {
+        "user.registered" => {
+            let payload = serde_json::from_value::<UserRegisteredPayload>(payload)
+                .map_err(invalid_payload)?;
+
+            Ok(DomainEvent::UserRegistered {
+                user_id: UserId::from(payload.user_id),
+                email: EmailAddress::parse(payload.email).map_err(|err| err.to_string())?,
+            })
+        }
+        "user.signed_in" => {
+            let payload =
+                serde_json::from_value::<UserSignedInPayload>(payload).map_err(invalid_payload)?;
+
+            Ok(DomainEvent::UserSignedIn {
+                user_id: UserId::from(payload.user_id),
+                session_id: payload.session_id.to_string(),
+            })
+        }
+        "user.password_changed" => {
+            let payload = serde_json::from_value::<PasswordChangedPayload>(payload)
+                .map_err(invalid_payload)?;
+
+            Ok(DomainEvent::PasswordChanged {
+                user_id: UserId::from(payload.user_id),
+            })
+        }
+        _ => Err(format!("unknown event type '{event_type}'")),
+    }
+}
+
+impl TryFrom<OutboxEventEntity> for OutboxEventModel {
+    type Error = ModelError;
+
+    fn try_from(entity: OutboxEventEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("id", &entity.id)?,
+            event_type: entity.event.name().to_string(),
+            payload: encode_payload(&entity.event),
+            status: entity.status.as_str().to_string(),
+            attempts: i32::try_from(entity.attempts).unwrap_or(i32::MAX),
+            last_error: entity.last_error,
+            occurred_at: parse_timestamp("occurred_at", entity.occurred_at)?,
+            next_attempt_at: parse_timestamp("next_attempt_at", entity.next_attempt_at)?,
+        })
+    }
+}
+
+/// Fails on rows this version cannot read, such as an event type it no longer knows.
+impl TryFrom<OutboxEventModel> for OutboxEventEntity {
+    type Error = String;
+
+    fn try_from(outbox_event_model: OutboxEventModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: outbox_event_model.id.to_string(),
+            event: decode_payload(&outbox_event_model.event_type, outbox_event_model.payload)?,
+            status: OutboxEventStatus::parse(&outbox_event_model.status)
+                .ok_or_else(|| format!("unknown status '{}'", outbox_event_model.status))?,
+            attempts: u32::try_from(outbox_event_model.attempts).unwrap_or_default(),
+            last_error: outbox_event_model.last_error,
+            occurred_at: outbox_event_model.occurred_at.unix_timestamp(),
+            next_attempt_at: outbox_event_model.next_attempt_at.unix_timestamp(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            entities::outbox::outbox_event_entity::OutboxEventEntity,
+            events::domain_event::DomainEvent, value_objects::user::user_id::UserId,
+        },
+        infrastructure::models::{
+            errors::model_errors::ModelError, outbox::outbox_event_model::OutboxEventModel,
+        },
+    };
+
+    #[test]
+    fn should_return_error_if_event_id_is_not_a_uuid() {
+        let outbox_event_entity = OutboxEventEntity::new(
+            "not-a-uuid".to_string(),
+            DomainEvent::PasswordChanged {
+                user_id: UserId::parse("dba86129-90be-4409-a5a3-396db9335a57").unwrap(),
+            },
+            1_695_996_669,
+        );
+
+        let result = OutboxEventModel::try_from(outbox_event_entity);
+
+        assert_eq!(
+            result.err(),
+            Some(ModelError::InvalidUuid {
+                field: "id",
+                value: "not-a-uuid".to_string(),
+            })
+        );
+    }
+}
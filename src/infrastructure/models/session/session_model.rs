@@ -0,0 +1,39 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::entities::session::session_entity::SessionEntity,
+    infrastructure::models::errors::model_errors::{ModelError, parse_timestamp, parse_uuid},
+};
+
+#[derive(sqlx::FromRow)]
+pub struct SessionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub issued_at: OffsetDateTime,
+    pub expires_at: OffsetDateTime,
+}
+
+impl From<SessionModel> for SessionEntity {
+    fn from(session_model: SessionModel) -> Self {
+        Self {
+            id: session_model.id.to_string(),
+            user_id: session_model.user_id.to_string(),
+            issued_at: session_model.issued_at.unix_timestamp(),
+            expires_at: session_model.expires_at.unix_timestamp(),
+        }
+    }
+}
+
+impl TryFrom<SessionEntity> for SessionModel {
+    type Error = ModelError;
+
+    fn try_from(entity: SessionEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("id", &entity.id)?,
+            user_id: parse_uuid("user_id", &entity.user_id)?,
+            issued_at: parse_timestamp("issued_at", entity.issued_at)?,
+            expires_at: parse_timestamp("expires_at", entity.expires_at)?,
+        })
+    }
+}
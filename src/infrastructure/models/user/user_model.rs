@@ -1,7 +1,17 @@
 use sqlx::types::Uuid;
 use time::OffsetDateTime;
 
-use crate::domain::entities::user::user_entity::UserEntity;
+use crate::domain::{
+    entities::user::user_entity::{UserEntity, UserStatus},
+    errors::value_object::value_object_errors::ValueObjectError,
+    value_objects::{
+        shared::timestamp::Timestamp,
+        user::{
+            email_address::EmailAddress, password_hash::PasswordHash, person_name::PersonName,
+            user_id::UserId,
+        },
+    },
+};
 
 #[derive(sqlx::FromRow)]
 pub struct UserModel {
@@ -11,38 +21,54 @@ pub struct UserModel {
     pub email: String,
     pub password: String,
     pub is_admin: bool,
+    pub status: String,
+    pub password_reset_required: bool,
+    pub sessions_revoked_at: Option<OffsetDateTime>,
+    pub avatar_key: Option<String>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
 
-impl From<UserModel> for UserEntity {
-    fn from(user_model: UserModel) -> Self {
-        Self {
-            id: user_model.id.to_string(),
-            first_name: user_model.first_name,
-            last_name: user_model.last_name,
-            email: user_model.email,
-            password: user_model.password,
+/// Fails when a stored row breaks a rule the domain enforces, e.g. a blank name written by hand.
+impl TryFrom<UserModel> for UserEntity {
+    type Error = ValueObjectError;
+
+    fn try_from(user_model: UserModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: UserId::from(user_model.id),
+            first_name: PersonName::parse(user_model.first_name)?,
+            last_name: PersonName::parse(user_model.last_name)?,
+            email: EmailAddress::parse(user_model.email)?,
+            password: PasswordHash::parse(user_model.password)?,
             is_admin: user_model.is_admin,
-            created_at: user_model.created_at.unix_timestamp(),
-            updated_at: user_model.updated_at.unix_timestamp(),
-        }
+            // The column is constrained to known statuses; anything else fails closed.
+            status: UserStatus::parse(&user_model.status).unwrap_or(UserStatus::Suspended),
+            password_reset_required: user_model.password_reset_required,
+            sessions_revoked_at: user_model.sessions_revoked_at.map(Timestamp::from),
+            avatar_key: user_model.avatar_key,
+            created_at: Timestamp::from(user_model.created_at),
+            updated_at: Timestamp::from(user_model.updated_at),
+        })
     }
 }
 
 impl From<UserEntity> for UserModel {
     fn from(entity: UserEntity) -> Self {
         Self {
-            id: Uuid::parse_str(&entity.id).expect("Invalid UUID in UserEntity"),
-            first_name: entity.first_name,
-            last_name: entity.last_name,
-            email: entity.email,
-            password: entity.password,
+            id: entity.id.as_uuid(),
+            first_name: entity.first_name.into_inner(),
+            last_name: entity.last_name.into_inner(),
+            email: entity.email.into_inner(),
+            password: entity.password.into_inner(),
             is_admin: entity.is_admin,
-            created_at: OffsetDateTime::from_unix_timestamp(entity.created_at)
-                .expect("Invalid timestamp in UserEntity"),
-            updated_at: OffsetDateTime::from_unix_timestamp(entity.updated_at)
-                .expect("Invalid timestamp in UserEntity"),
+            status: entity.status.as_str().to_string(),
+            password_reset_required: entity.password_reset_required,
+            sessions_revoked_at: entity
+                .sessions_revoked_at
+                .map(|sessions_revoked_at| sessions_revoked_at.as_offset_date_time()),
+            avatar_key: entity.avatar_key,
+            created_at: entity.created_at.as_offset_date_time(),
+            updated_at: entity.updated_at.as_offset_date_time(),
         }
     }
 }
@@ -0,0 +1,62 @@
+use sqlx::types::Uuid;
+use time::OffsetDateTime;
+
+use crate::{
+    application::ports::user_search::user_search_port::{UserSearchHighlights, UserSearchHit},
+    domain::{
+        entities::user::user_entity::UserEntity,
+        errors::value_object::value_object_errors::ValueObjectError,
+    },
+    infrastructure::models::user::user_model::UserModel,
+};
+
+#[derive(sqlx::FromRow)]
+pub struct UserSearchModel {
+    pub id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub password: String,
+    pub is_admin: bool,
+    pub status: String,
+    pub password_reset_required: bool,
+    pub sessions_revoked_at: Option<OffsetDateTime>,
+    pub avatar_key: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub rank: f32,
+    pub first_name_highlight: String,
+    pub last_name_highlight: String,
+    pub email_highlight: String,
+}
+
+impl TryFrom<UserSearchModel> for UserSearchHit {
+    type Error = ValueObjectError;
+
+    fn try_from(user_search_model: UserSearchModel) -> Result<Self, Self::Error> {
+        let user_model = UserModel {
+            id: user_search_model.id,
+            first_name: user_search_model.first_name,
+            last_name: user_search_model.last_name,
+            email: user_search_model.email,
+            password: user_search_model.password,
+            is_admin: user_search_model.is_admin,
+            status: user_search_model.status,
+            password_reset_required: user_search_model.password_reset_required,
+            sessions_revoked_at: user_search_model.sessions_revoked_at,
+            avatar_key: user_search_model.avatar_key,
+            created_at: user_search_model.created_at,
+            updated_at: user_search_model.updated_at,
+        };
+
+        Ok(Self {
+            user: UserEntity::try_from(user_model)?,
+            rank: user_search_model.rank,
+            highlights: UserSearchHighlights {
+                first_name: user_search_model.first_name_highlight,
+                last_name: user_search_model.last_name_highlight,
+                email: user_search_model.email_highlight,
+            },
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::data_export::create_data_export_repository_port::{
+        CreateDataExportRepositoryError, CreateDataExportRepositoryFuture,
+        CreateDataExportRepositoryPort,
+    },
+    domain::entities::data_export::data_export_entity::DataExportEntity,
+    infrastructure::models::data_export::data_export_model::DataExportModel,
+};
+
+#[derive(Clone)]
+pub struct CreateDataExportRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl CreateDataExportRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl CreateDataExportRepositoryPort for CreateDataExportRepository {
+    fn execute(
+        &self,
+        data_export_entity: DataExportEntity,
+    ) -> CreateDataExportRepositoryFuture<'_> {
+        let future = async move {
+            let data_export_model =
+                DataExportModel::try_from(data_export_entity).map_err(|err| {
+                    CreateDataExportRepositoryError::InsertError {
+                        message: err.to_string(),
+                    }
+                })?;
+
+            let created_data_export = sqlx::query_as!(
+                DataExportModel,
+                r#"
+                INSERT INTO data_exports (id, user_id, requested_by, status, archive, error_message, created_at, completed_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING *
+                "#,
+                data_export_model.id,
+                data_export_model.user_id,
+                data_export_model.requested_by,
+                data_export_model.status,
+                data_export_model.archive,
+                data_export_model.error_message,
+                data_export_model.created_at,
+                data_export_model.completed_at,
+            )
+            .fetch_one(&*self.database_pool)
+            .await
+            .map_err(|err| CreateDataExportRepositoryError::InsertError {
+                message: err.to_string(),
+            })?;
+
+            Ok(created_data_export.into())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "CreateDataExportRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "INSERT",
+            db.collection.name = "data_exports",
+        )))
+    }
+}
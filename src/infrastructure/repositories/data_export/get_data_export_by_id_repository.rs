@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::{
+    application::ports::repositories::data_export::get_data_export_by_id_repository_port::{
+        GetDataExportByIdRepositoryError, GetDataExportByIdRepositoryFuture,
+        GetDataExportByIdRepositoryPort,
+    },
+    infrastructure::models::data_export::data_export_model::DataExportModel,
+};
+
+#[derive(Clone)]
+pub struct GetDataExportByIdRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl GetDataExportByIdRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl GetDataExportByIdRepositoryPort for GetDataExportByIdRepository {
+    fn execute(&self, id: String) -> GetDataExportByIdRepositoryFuture<'_> {
+        let future = async move {
+            let data_export_uuid = Uuid::parse_str(&id).map_err(|_| {
+                GetDataExportByIdRepositoryError::FindByIdError {
+                    message: "Invalid UUID format".to_string(),
+                }
+            })?;
+
+            let data_export_model = sqlx::query_as!(
+                DataExportModel,
+                "SELECT * FROM data_exports WHERE id = $1",
+                data_export_uuid
+            )
+            .fetch_optional(&*self.database_pool)
+            .await
+            .map_err(|err| GetDataExportByIdRepositoryError::FindByIdError {
+                message: err.to_string(),
+            })?;
+
+            Ok(data_export_model.map(Into::into))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetDataExportByIdRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "data_exports",
+        )))
+    }
+}
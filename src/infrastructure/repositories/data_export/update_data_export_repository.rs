@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::data_export::update_data_export_repository_port::{
+        UpdateDataExportRepositoryError, UpdateDataExportRepositoryFuture,
+        UpdateDataExportRepositoryPort,
+    },
+    domain::entities::data_export::data_export_entity::DataExportEntity,
+    infrastructure::models::data_export::data_export_model::DataExportModel,
+};
+
+#[derive(Clone)]
+pub struct UpdateDataExportRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl UpdateDataExportRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl UpdateDataExportRepositoryPort for UpdateDataExportRepository {
+    fn execute(
+        &self,
+        data_export_entity: DataExportEntity,
+    ) -> UpdateDataExportRepositoryFuture<'_> {
+        let future = async move {
+            let data_export_model =
+                DataExportModel::try_from(data_export_entity).map_err(|err| {
+                    UpdateDataExportRepositoryError::UpdateError {
+                        message: err.to_string(),
+                    }
+                })?;
+
+            let updated_data_export = sqlx::query_as!(
+                DataExportModel,
+                r#"
+                UPDATE data_exports
+                SET status = $2,
+                    archive = $3,
+                    error_message = $4,
+                    completed_at = $5
+                WHERE id = $1
+                RETURNING *
+                "#,
+                data_export_model.id,
+                data_export_model.status,
+                data_export_model.archive,
+                data_export_model.error_message,
+                data_export_model.completed_at,
+            )
+            .fetch_optional(&*self.database_pool)
+            .await
+            .map_err(|err| UpdateDataExportRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?
+            .ok_or(UpdateDataExportRepositoryError::DataExportNotFound)?;
+
+            Ok(updated_data_export.into())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "UpdateDataExportRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "UPDATE",
+            db.collection.name = "data_exports",
+        )))
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::email_domain_policy::get_email_domain_policy_repository_port::{
+        GetEmailDomainPolicyRepositoryError, GetEmailDomainPolicyRepositoryFuture,
+        GetEmailDomainPolicyRepositoryPort,
+    },
+    infrastructure::models::email_domain_policy::email_domain_policy_model::{
+        EmailDomainPolicyModel, EmailDomainRuleModel,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetEmailDomainPolicyRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl GetEmailDomainPolicyRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl GetEmailDomainPolicyRepositoryPort for GetEmailDomainPolicyRepository {
+    fn execute(&self) -> GetEmailDomainPolicyRepositoryFuture<'_> {
+        let future = async move {
+            let email_domain_policy_model = sqlx::query_as!(
+                EmailDomainPolicyModel,
+                "SELECT mode, block_disposable, updated_by, updated_at FROM email_domain_policy"
+            )
+            .fetch_one(&*self.database_pool)
+            .await
+            .map_err(|err| GetEmailDomainPolicyRepositoryError::FindError {
+                message: err.to_string(),
+            })?;
+
+            let email_domain_rule_models = sqlx::query_as!(
+                EmailDomainRuleModel,
+                "SELECT domain, list FROM email_domain_rules ORDER BY domain"
+            )
+            .fetch_all(&*self.database_pool)
+            .await
+            .map_err(|err| GetEmailDomainPolicyRepositoryError::FindError {
+                message: err.to_string(),
+            })?;
+
+            Ok((email_domain_policy_model, email_domain_rule_models).into())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetEmailDomainPolicyRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "email_domain_policy",
+        )))
+    }
+}
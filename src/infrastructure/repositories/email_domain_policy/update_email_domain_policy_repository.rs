@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::email_domain_policy::update_email_domain_policy_repository_port::{
+        UpdateEmailDomainPolicyRepositoryError, UpdateEmailDomainPolicyRepositoryFuture,
+        UpdateEmailDomainPolicyRepositoryPort,
+    },
+    domain::entities::email_domain_policy::email_domain_policy_entity::EmailDomainPolicyEntity,
+    infrastructure::models::email_domain_policy::email_domain_policy_model::{
+        EmailDomainPolicyModel, EmailDomainRuleModel,
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdateEmailDomainPolicyRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl UpdateEmailDomainPolicyRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl UpdateEmailDomainPolicyRepositoryPort for UpdateEmailDomainPolicyRepository {
+    fn execute(
+        &self,
+        email_domain_policy_entity: EmailDomainPolicyEntity,
+    ) -> UpdateEmailDomainPolicyRepositoryFuture<'_> {
+        let future = async move {
+            let email_domain_policy_model =
+                EmailDomainPolicyModel::try_from(&email_domain_policy_entity).map_err(|err| {
+                    UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                        message: err.to_string(),
+                    }
+                })?;
+            let (domains, lists): (Vec<_>, Vec<_>) =
+                EmailDomainRuleModel::from_policy(&email_domain_policy_entity)
+                    .into_iter()
+                    .map(|rule| (rule.domain, rule.list))
+                    .unzip();
+
+            let mut transaction = self.database_pool.begin().await.map_err(|err| {
+                UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            let updated_email_domain_policy = sqlx::query_as!(
+                EmailDomainPolicyModel,
+                r#"
+                UPDATE email_domain_policy
+                SET mode = $1,
+                    block_disposable = $2,
+                    updated_by = $3,
+                    updated_at = $4
+                RETURNING mode, block_disposable, updated_by, updated_at
+                "#,
+                email_domain_policy_model.mode,
+                email_domain_policy_model.block_disposable,
+                email_domain_policy_model.updated_by,
+                email_domain_policy_model.updated_at,
+            )
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|err| UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?;
+
+            sqlx::query!("DELETE FROM email_domain_rules")
+                .execute(&mut *transaction)
+                .await
+                .map_err(|err| UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                    message: err.to_string(),
+                })?;
+
+            let email_domain_rule_models = sqlx::query_as!(
+                EmailDomainRuleModel,
+                r#"
+                INSERT INTO email_domain_rules (domain, list)
+                SELECT * FROM UNNEST($1::VARCHAR[], $2::VARCHAR[])
+                RETURNING domain AS "domain!", list AS "list!"
+                "#,
+                &domains,
+                &lists,
+            )
+            .fetch_all(&mut *transaction)
+            .await
+            .map_err(|err| UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?;
+
+            transaction.commit().await.map_err(|err| {
+                UpdateEmailDomainPolicyRepositoryError::UpdateError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            Ok((updated_email_domain_policy, email_domain_rule_models).into())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "UpdateEmailDomainPolicyRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "UPDATE",
+            db.collection.name = "email_domain_policy",
+        )))
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres, types::Uuid};
+use time::OffsetDateTime;
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::outbox::claim_outbox_events_repository_port::{
+        ClaimOutboxEventsRepositoryError, ClaimOutboxEventsRepositoryFuture,
+        ClaimOutboxEventsRepositoryPort,
+    },
+    domain::entities::outbox::outbox_event_entity::{OutboxEventEntity, OutboxEventStatus},
+    infrastructure::models::outbox::outbox_event_model::OutboxEventModel,
+};
+
+#[derive(Clone)]
+pub struct ClaimOutboxEventsRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl ClaimOutboxEventsRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    fn to_timestamp(
+        unix_timestamp: i64,
+    ) -> Result<OffsetDateTime, ClaimOutboxEventsRepositoryError> {
+        OffsetDateTime::from_unix_timestamp(unix_timestamp).map_err(|err| {
+            ClaimOutboxEventsRepositoryError::ClaimError {
+                message: err.to_string(),
+            }
+        })
+    }
+
+    /// Retrying an event this version cannot read would never succeed, so it is dead-lettered
+    /// straight away instead of being handed to the dispatcher.
+    async fn dead_letter(
+        &self,
+        outbox_event_id: Uuid,
+        event_type: &str,
+        error_message: &str,
+    ) -> Result<(), ClaimOutboxEventsRepositoryError> {
+        tracing::error!(
+            "Outbox event {} ({}) dead-lettered, it cannot be read: {error_message}",
+            outbox_event_id,
+            event_type
+        );
+
+        sqlx::query!(
+            r#"
+            UPDATE outbox_events
+            SET status = $2,
+                last_error = $3
+            WHERE id = $1
+            "#,
+            outbox_event_id,
+            OutboxEventStatus::DeadLettered.as_str(),
+            error_message,
+        )
+        .execute(&*self.database_pool)
+        .await
+        .map_err(|err| ClaimOutboxEventsRepositoryError::ClaimError {
+            message: err.to_string(),
+        })?;
+
+        Ok(())
+    }
+}
+
+impl ClaimOutboxEventsRepositoryPort for ClaimOutboxEventsRepository {
+    fn execute(
+        &self,
+        now: i64,
+        lease_until: i64,
+        limit: u32,
+    ) -> ClaimOutboxEventsRepositoryFuture<'_> {
+        let future = async move {
+            // `SKIP LOCKED` lets several instances claim disjoint batches concurrently.
+            let mut claimed_events = sqlx::query_as!(
+                OutboxEventModel,
+                r#"
+                UPDATE outbox_events
+                SET next_attempt_at = $2
+                WHERE id IN (
+                    SELECT id
+                    FROM outbox_events
+                    WHERE status = 'pending' AND next_attempt_at <= $1
+                    ORDER BY occurred_at
+                    LIMIT $3
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING *
+                "#,
+                Self::to_timestamp(now)?,
+                Self::to_timestamp(lease_until)?,
+                i64::from(limit),
+            )
+            .fetch_all(&*self.database_pool)
+            .await
+            .map_err(|err| ClaimOutboxEventsRepositoryError::ClaimError {
+                message: err.to_string(),
+            })?;
+
+            claimed_events.sort_by_key(|outbox_event_model| outbox_event_model.occurred_at);
+
+            let mut outbox_event_entities = Vec::with_capacity(claimed_events.len());
+
+            for outbox_event_model in claimed_events {
+                let outbox_event_id = outbox_event_model.id;
+                let event_type = outbox_event_model.event_type.clone();
+
+                match OutboxEventEntity::try_from(outbox_event_model) {
+                    Ok(outbox_event_entity) => outbox_event_entities.push(outbox_event_entity),
+                    Err(error_message) => {
+                        self.dead_letter(outbox_event_id, &event_type, &error_message)
+                            .await?;
+                    }
+                }
+            }
+
+            Ok(outbox_event_entities)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "ClaimOutboxEventsRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "UPDATE",
+            db.collection.name = "outbox_events",
+        )))
+    }
+}
@@ -0,0 +1,45 @@
+use sqlx::PgConnection;
+
+use crate::{
+    domain::entities::outbox::outbox_event_entity::OutboxEventEntity,
+    infrastructure::models::outbox::outbox_event_model::OutboxEventModel,
+};
+
+/// Appends events to the outbox through the connection of a transaction another repository has
+/// opened, so they are committed or rolled back together with the change they describe.
+pub struct CreateOutboxEventsRepository;
+
+impl CreateOutboxEventsRepository {
+    /// # Errors
+    ///
+    /// Returns the database error of the first insert that failed, or an encode error for an
+    /// event that cannot be stored; the caller is expected to roll back its transaction.
+    pub async fn execute(
+        connection: &mut PgConnection,
+        outbox_event_entities: Vec<OutboxEventEntity>,
+    ) -> Result<(), sqlx::Error> {
+        for outbox_event_entity in outbox_event_entities {
+            let outbox_event_model = OutboxEventModel::try_from(outbox_event_entity)
+                .map_err(|err| sqlx::Error::Encode(Box::new(err)))?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO outbox_events (id, event_type, payload, status, attempts, last_error, occurred_at, next_attempt_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                outbox_event_model.id,
+                outbox_event_model.event_type,
+                outbox_event_model.payload,
+                outbox_event_model.status,
+                outbox_event_model.attempts,
+                outbox_event_model.last_error,
+                outbox_event_model.occurred_at,
+                outbox_event_model.next_attempt_at,
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
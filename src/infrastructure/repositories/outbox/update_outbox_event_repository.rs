@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::outbox::update_outbox_event_repository_port::{
+        UpdateOutboxEventRepositoryError, UpdateOutboxEventRepositoryFuture,
+        UpdateOutboxEventRepositoryPort,
+    },
+    domain::entities::outbox::outbox_event_entity::OutboxEventEntity,
+    infrastructure::models::outbox::outbox_event_model::OutboxEventModel,
+};
+
+#[derive(Clone)]
+pub struct UpdateOutboxEventRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl UpdateOutboxEventRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl UpdateOutboxEventRepositoryPort for UpdateOutboxEventRepository {
+    fn execute(
+        &self,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> UpdateOutboxEventRepositoryFuture<'_> {
+        let future = async move {
+            let outbox_event_model =
+                OutboxEventModel::try_from(outbox_event_entity).map_err(|err| {
+                    UpdateOutboxEventRepositoryError::UpdateError {
+                        message: err.to_string(),
+                    }
+                })?;
+
+            let query_result = sqlx::query!(
+                r#"
+                UPDATE outbox_events
+                SET status = $2,
+                    attempts = $3,
+                    last_error = $4,
+                    next_attempt_at = $5
+                WHERE id = $1
+                "#,
+                outbox_event_model.id,
+                outbox_event_model.status,
+                outbox_event_model.attempts,
+                outbox_event_model.last_error,
+                outbox_event_model.next_attempt_at,
+            )
+            .execute(&*self.database_pool)
+            .await
+            .map_err(|err| UpdateOutboxEventRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?;
+
+            if query_result.rows_affected() == 0 {
+                return Err(UpdateOutboxEventRepositoryError::OutboxEventNotFound);
+            }
+
+            Ok(())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "UpdateOutboxEventRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "UPDATE",
+            db.collection.name = "outbox_events",
+        )))
+    }
+}
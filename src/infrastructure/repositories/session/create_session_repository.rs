@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::session::create_session_repository_port::{
+        CreateSessionRepositoryError, CreateSessionRepositoryFuture, CreateSessionRepositoryPort,
+    },
+    domain::entities::{
+        outbox::outbox_event_entity::OutboxEventEntity, session::session_entity::SessionEntity,
+    },
+    infrastructure::{
+        models::session::session_model::SessionModel,
+        repositories::outbox::create_outbox_events_repository::CreateOutboxEventsRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateSessionRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl CreateSessionRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl CreateSessionRepositoryPort for CreateSessionRepository {
+    fn execute(
+        &self,
+        session_entity: SessionEntity,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> CreateSessionRepositoryFuture<'_> {
+        let future = async move {
+            let session_model = SessionModel::try_from(session_entity).map_err(|err| {
+                CreateSessionRepositoryError::InsertError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            let mut transaction = self.database_pool.begin().await.map_err(|err| {
+                CreateSessionRepositoryError::InsertError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            let created_session = sqlx::query_as!(
+                SessionModel,
+                r#"
+                INSERT INTO user_sessions (id, user_id, issued_at, expires_at)
+                VALUES ($1, $2, $3, $4)
+                RETURNING *
+                "#,
+                session_model.id,
+                session_model.user_id,
+                session_model.issued_at,
+                session_model.expires_at,
+            )
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|err| CreateSessionRepositoryError::InsertError {
+                message: err.to_string(),
+            })?;
+
+            CreateOutboxEventsRepository::execute(&mut transaction, vec![outbox_event_entity])
+                .await
+                .map_err(|err| CreateSessionRepositoryError::InsertError {
+                    message: err.to_string(),
+                })?;
+
+            transaction.commit().await.map_err(|err| {
+                CreateSessionRepositoryError::InsertError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            Ok(created_session.into())
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "CreateSessionRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "INSERT",
+            db.collection.name = "user_sessions",
+        )))
+    }
+}
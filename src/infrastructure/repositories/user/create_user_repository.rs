@@ -1,13 +1,20 @@
 use std::sync::Arc;
 
-use sqlx::{Pool, Postgres};
+use sqlx::{PgExecutor, Pool, Postgres};
+use tracing::Instrument;
 
 use crate::{
     application::ports::repositories::user::create_user_repository_port::{
         CreateUserRepositoryError, CreateUserRepositoryFuture, CreateUserRepositoryPort,
+        CreateUsersRepositoryFuture,
+    },
+    domain::entities::{
+        outbox::outbox_event_entity::OutboxEventEntity, user::user_entity::UserEntity,
+    },
+    infrastructure::{
+        models::user::user_model::UserModel,
+        repositories::outbox::create_outbox_events_repository::CreateOutboxEventsRepository,
     },
-    domain::entities::user::user_entity::UserEntity,
-    infrastructure::models::user::user_model::UserModel,
 };
 
 #[derive(Clone)]
@@ -20,38 +27,125 @@ impl CreateUserRepository {
     pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
         Self { database_pool }
     }
+
+    /// Tells apart the failures callers react to: duplicates, an unreachable database and
+    /// queries that ran out of time.
+    fn map_error(err: sqlx::Error) -> CreateUserRepositoryError {
+        match err {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                CreateUserRepositoryError::UniqueViolation {
+                    constraint: db_err.constraint().unwrap_or_default().to_string(),
+                }
+            }
+            // 57014 is `query_canceled`, raised when `statement_timeout` is hit.
+            sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("57014") => {
+                CreateUserRepositoryError::TimeoutError {
+                    message: db_err.to_string(),
+                }
+            }
+            err @ sqlx::Error::PoolTimedOut => CreateUserRepositoryError::TimeoutError {
+                message: err.to_string(),
+            },
+            err @ (sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed) => CreateUserRepositoryError::ConnectionError {
+                message: err.to_string(),
+            },
+            err => CreateUserRepositoryError::InsertError {
+                message: err.to_string(),
+            },
+        }
+    }
+
+    async fn insert<'e, E>(
+        executor: E,
+        user_entity: UserEntity,
+    ) -> Result<UserEntity, CreateUserRepositoryError>
+    where
+        E: PgExecutor<'e>,
+    {
+        let user_model = UserModel::from(user_entity);
+
+        let created_user = sqlx::query_as!(
+            UserModel,
+            r#"
+                INSERT INTO users (id, first_name, last_name, email, password, is_admin, status, password_reset_required, sessions_revoked_at, avatar_key, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                RETURNING *
+                "#,
+            user_model.id,
+            user_model.first_name,
+            user_model.last_name,
+            user_model.email,
+            user_model.password,
+            user_model.is_admin,
+            user_model.status,
+            user_model.password_reset_required,
+            user_model.sessions_revoked_at,
+            user_model.avatar_key,
+            user_model.created_at,
+            user_model.updated_at,
+        )
+        .fetch_one(executor)
+        .await
+        .map_err(Self::map_error)?;
+
+        UserEntity::try_from(created_user).map_err(|err| CreateUserRepositoryError::InsertError {
+            message: err.to_string(),
+        })
+    }
 }
 
 impl CreateUserRepositoryPort for CreateUserRepository {
-    fn execute(&self, user_entity: UserEntity) -> CreateUserRepositoryFuture<'_> {
+    fn execute(
+        &self,
+        user_entity: UserEntity,
+        outbox_event_entity: OutboxEventEntity,
+    ) -> CreateUserRepositoryFuture<'_> {
         Box::pin(async move {
-            let user_model = UserModel::from(user_entity);
+            let mut transaction = self.database_pool.begin().await.map_err(Self::map_error)?;
 
-            let created_user = sqlx::query_as!(
-                UserModel,
-                r#"
-                INSERT INTO users (id, first_name, last_name, email, password, is_admin, created_at, updated_at)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-                RETURNING *
-                "#,
-                user_model.id,
-                user_model.first_name,
-                user_model.last_name,
-                user_model.email,
-                user_model.password,
-                user_model.is_admin,
-                user_model.created_at,
-                user_model.updated_at,
-            )
-            .fetch_one(&*self.database_pool)
-            .await
-            .map_err(|err| CreateUserRepositoryError::InsertError {
-                message: err.to_string(),
-            })?;
+            let created_user = Self::insert(&mut *transaction, user_entity).await?;
 
-            let user_entity = created_user.into();
+            CreateOutboxEventsRepository::execute(&mut transaction, vec![outbox_event_entity])
+                .await
+                .map_err(Self::map_error)?;
 
-            Ok(user_entity)
+            transaction.commit().await.map_err(Self::map_error)?;
+
+            Ok(created_user)
         })
     }
+
+    fn execute_batch(
+        &self,
+        user_entities: Vec<UserEntity>,
+        outbox_event_entities: Vec<OutboxEventEntity>,
+    ) -> CreateUsersRepositoryFuture<'_> {
+        let future = async move {
+            let mut transaction = self.database_pool.begin().await.map_err(Self::map_error)?;
+
+            let mut created_users = Vec::with_capacity(user_entities.len());
+
+            for user_entity in user_entities {
+                created_users.push(Self::insert(&mut *transaction, user_entity).await?);
+            }
+
+            CreateOutboxEventsRepository::execute(&mut transaction, outbox_event_entities)
+                .await
+                .map_err(Self::map_error)?;
+
+            transaction.commit().await.map_err(Self::map_error)?;
+
+            Ok(created_users)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "CreateUserRepository::execute_batch",
+            db.system.name = "postgresql",
+            db.operation.name = "INSERT",
+            db.collection.name = "users",
+        )))
+    }
 }
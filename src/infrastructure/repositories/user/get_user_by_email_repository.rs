@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use sqlx::{Pool, Postgres};
+use tracing::Instrument;
 
 use crate::{
     application::ports::repositories::user::get_user_by_email_repository_port::{
         GetUserByEmailRepositoryError, GetUserByEmailRepositoryFuture, GetUserByEmailRepositoryPort,
     },
+    domain::entities::user::user_entity::UserEntity,
     infrastructure::models::user::user_model::UserModel,
 };
 
@@ -23,18 +25,33 @@ impl GetUserByEmailRepository {
 
 impl GetUserByEmailRepositoryPort for GetUserByEmailRepository {
     fn execute(&self, email: String) -> GetUserByEmailRepositoryFuture<'_> {
-        Box::pin(async move {
-            let user_model =
-                sqlx::query_as!(UserModel, "SELECT * FROM users WHERE email = $1", email)
-                    .fetch_optional(&*self.database_pool)
-                    .await
-                    .map_err(|err| GetUserByEmailRepositoryError::FindByEmailError {
-                        message: err.to_string(),
-                    })?;
+        let future = async move {
+            let user_model = sqlx::query_as!(
+                UserModel,
+                "SELECT * FROM users WHERE email = $1::CITEXT",
+                email
+            )
+            .fetch_optional(&*self.database_pool)
+            .await
+            .map_err(|err| GetUserByEmailRepositoryError::FindByEmailError {
+                message: err.to_string(),
+            })?;
 
-            let user_entity = user_model.map(Into::into);
+            let user_entity = user_model
+                .map(UserEntity::try_from)
+                .transpose()
+                .map_err(|err| GetUserByEmailRepositoryError::FindByEmailError {
+                    message: err.to_string(),
+                })?;
 
             Ok(user_entity)
-        })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetUserByEmailRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "users",
+        )))
     }
 }
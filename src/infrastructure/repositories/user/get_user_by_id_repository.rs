@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use sqlx::{Pool, Postgres};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use crate::{
     application::ports::repositories::user::get_user_by_id_repository_port::{
         GetUserByIdFuture, GetUserByIdRepositoryError, GetUserByIdRepositoryPort,
     },
+    domain::entities::user::user_entity::UserEntity,
     infrastructure::models::user::user_model::UserModel,
 };
 
@@ -24,7 +26,7 @@ impl GetUserByIdRepository {
 
 impl GetUserByIdRepositoryPort for GetUserByIdRepository {
     fn execute(&self, id: String) -> GetUserByIdFuture<'_> {
-        Box::pin(async move {
+        let future = async move {
             let user_uuid =
                 Uuid::parse_str(&id).map_err(|_| GetUserByIdRepositoryError::FindByIdError {
                     message: "Invalid UUID format".to_string(),
@@ -38,9 +40,21 @@ impl GetUserByIdRepositoryPort for GetUserByIdRepository {
                         message: err.to_string(),
                     })?;
 
-            let user_entity = user_model.map(Into::into);
+            let user_entity = user_model
+                .map(UserEntity::try_from)
+                .transpose()
+                .map_err(|err| GetUserByIdRepositoryError::FindByIdError {
+                    message: err.to_string(),
+                })?;
 
             Ok(user_entity)
-        })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetUserByIdRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "users",
+        )))
     }
 }
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::data_export::user_data_export_dto::UserDataExportDto,
+        ports::repositories::user::get_user_data_repository_port::{
+            CountUserRecordsFuture, GetUserDataRepositoryError, GetUserDataRepositoryFuture,
+            GetUserDataRepositoryPort,
+        },
+    },
+    domain::entities::{
+        audit::audit_entry_entity::AuditEntryEntity, user::user_entity::UserEntity,
+    },
+    infrastructure::models::{
+        audit::audit_entry_model::AuditEntryModel, session::session_model::SessionModel,
+        user::user_model::UserModel,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetUserDataRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl GetUserDataRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+
+    fn parse_user_id(user_id: &str) -> Result<Uuid, GetUserDataRepositoryError> {
+        Uuid::parse_str(user_id).map_err(|_| GetUserDataRepositoryError::FindError {
+            message: "Invalid UUID format".to_string(),
+        })
+    }
+}
+
+impl GetUserDataRepositoryPort for GetUserDataRepository {
+    fn count_records(&self, user_id: String) -> CountUserRecordsFuture<'_> {
+        let future = async move {
+            let user_uuid = Self::parse_user_id(&user_id)?;
+
+            let record_count = sqlx::query_scalar!(
+                r#"
+                SELECT
+                    (SELECT COUNT(*) FROM user_sessions WHERE user_id = users.id)
+                    + (SELECT COUNT(*) FROM audit_entries WHERE actor_id = users.id OR target_user_id = users.id)
+                    AS "record_count!"
+                FROM users
+                WHERE id = $1
+                "#,
+                user_uuid
+            )
+            .fetch_optional(&*self.database_pool)
+            .await
+            .map_err(|err| GetUserDataRepositoryError::FindError {
+                message: err.to_string(),
+            })?;
+
+            Ok(record_count.map(|record_count| u64::try_from(record_count).unwrap_or_default()))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetUserDataRepository::count_records",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "users",
+        )))
+    }
+
+    fn execute(&self, user_id: String) -> GetUserDataRepositoryFuture<'_> {
+        let future = async move {
+            let user_uuid = Self::parse_user_id(&user_id)?;
+
+            let mut transaction = self.database_pool.begin().await.map_err(|err| {
+                GetUserDataRepositoryError::FindError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            let Some(user_model) =
+                sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = $1", user_uuid)
+                    .fetch_optional(&mut *transaction)
+                    .await
+                    .map_err(|err| GetUserDataRepositoryError::FindError {
+                        message: err.to_string(),
+                    })?
+            else {
+                return Ok(None);
+            };
+
+            let session_models = sqlx::query_as!(
+                SessionModel,
+                "SELECT * FROM user_sessions WHERE user_id = $1 ORDER BY issued_at DESC",
+                user_uuid
+            )
+            .fetch_all(&mut *transaction)
+            .await
+            .map_err(|err| GetUserDataRepositoryError::FindError {
+                message: err.to_string(),
+            })?;
+
+            let audit_entry_models = sqlx::query_as!(
+                AuditEntryModel,
+                r#"
+                SELECT * FROM audit_entries
+                WHERE actor_id = $1 OR target_user_id = $1
+                ORDER BY created_at DESC
+                "#,
+                user_uuid
+            )
+            .fetch_all(&mut *transaction)
+            .await
+            .map_err(|err| GetUserDataRepositoryError::FindError {
+                message: err.to_string(),
+            })?;
+
+            transaction
+                .commit()
+                .await
+                .map_err(|err| GetUserDataRepositoryError::FindError {
+                    message: err.to_string(),
+                })?;
+
+            Ok(Some(UserDataExportDto::new(
+                UserEntity::try_from(user_model).map_err(|err| {
+                    GetUserDataRepositoryError::FindError {
+                        message: err.to_string(),
+                    }
+                })?,
+                session_models.into_iter().map(Into::into).collect(),
+                audit_entry_models
+                    .into_iter()
+                    .map(AuditEntryEntity::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|err| GetUserDataRepositoryError::FindError {
+                        message: err.to_string(),
+                    })?,
+            )))
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "GetUserDataRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "users",
+        )))
+    }
+}
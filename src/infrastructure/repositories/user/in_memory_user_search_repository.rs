@@ -0,0 +1,210 @@
+use std::{collections::HashSet, sync::Arc};
+
+use tracing::Instrument;
+
+use crate::{
+    application::ports::user_search::user_search_port::{
+        UserSearchFuture, UserSearchHighlights, UserSearchHit, UserSearchPage, UserSearchPort,
+        UserSearchQuery,
+    },
+    domain::entities::user::user_entity::UserEntity,
+};
+
+/// Default `pg_trgm.similarity_threshold`, the cut-off of the `%` operator.
+const SIMILARITY_THRESHOLD: f32 = 0.3;
+const HIGHLIGHT_START: &str = "<mark>";
+const HIGHLIGHT_STOP: &str = "</mark>";
+
+/// In-memory `UserSearchPort` that matches the same way as the Postgres implementation.
+///
+/// A user is found when every search word is one of their tokens, or when the whole term is
+/// `pg_trgm`-similar to their first name, last name or e-mail. Ranking only approximates
+/// `ts_rank`.
+#[derive(Clone)]
+pub struct InMemoryUserSearchRepository {
+    users: Arc<Vec<UserEntity>>,
+}
+
+impl InMemoryUserSearchRepository {
+    #[must_use]
+    pub fn new(users: Vec<UserEntity>) -> Self {
+        Self {
+            users: Arc::new(users),
+        }
+    }
+
+    fn words(value: &str) -> impl Iterator<Item = &str> {
+        value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+    }
+
+    /// Same trigram set as `pg_trgm`: lowercased words padded with two leading and one trailing
+    /// space.
+    fn trigrams(value: &str) -> HashSet<String> {
+        Self::words(&value.to_lowercase())
+            .flat_map(|word| {
+                let padded: Vec<char> = format!("  {word} ").chars().collect();
+
+                padded
+                    .windows(3)
+                    .map(|window| window.iter().collect())
+                    .collect::<Vec<String>>()
+            })
+            .collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn similarity(left: &str, right: &str) -> f32 {
+        let left_trigrams = Self::trigrams(left);
+        let right_trigrams = Self::trigrams(right);
+        let shared = left_trigrams.intersection(&right_trigrams).count();
+        let total = left_trigrams.union(&right_trigrams).count();
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        shared as f32 / total as f32
+    }
+
+    /// Tokens of the `simple` text search configuration: names split into words, e-mails kept
+    /// whole.
+    fn tokens(user: &UserEntity) -> Vec<String> {
+        Self::words(user.first_name.as_str())
+            .chain(Self::words(user.last_name.as_str()))
+            .map(str::to_lowercase)
+            .chain(std::iter::once(user.email.as_str().to_lowercase()))
+            .collect()
+    }
+
+    fn escape_html(value: &str, escaped: &mut String) {
+        for character in value.chars() {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                _ => escaped.push(character),
+            }
+        }
+    }
+
+    /// Escapes `value` for HTML and wraps the words matching a search word in `<mark>`.
+    fn highlight(value: &str, terms: &[String]) -> String {
+        let mut highlighted = String::with_capacity(value.len());
+        let mut word_start: Option<usize> = None;
+
+        let push_word = |highlighted: &mut String, word: &str| {
+            if terms.iter().any(|term| word.to_lowercase() == *term) {
+                highlighted.push_str(HIGHLIGHT_START);
+                Self::escape_html(word, highlighted);
+                highlighted.push_str(HIGHLIGHT_STOP);
+            } else {
+                Self::escape_html(word, highlighted);
+            }
+        };
+
+        for (index, character) in value.char_indices() {
+            if character.is_alphanumeric() {
+                word_start.get_or_insert(index);
+            } else {
+                if let Some(start) = word_start.take() {
+                    push_word(&mut highlighted, &value[start..index]);
+                }
+
+                Self::escape_html(&character.to_string(), &mut highlighted);
+            }
+        }
+
+        if let Some(start) = word_start {
+            push_word(&mut highlighted, &value[start..]);
+        }
+
+        highlighted
+    }
+
+    /// E-mails are a single token, so they are highlighted whole or not at all.
+    fn highlight_email(email: &str, terms: &[String]) -> String {
+        let mut highlighted = String::with_capacity(email.len());
+
+        if terms.contains(&email.to_lowercase()) {
+            highlighted.push_str(HIGHLIGHT_START);
+            Self::escape_html(email, &mut highlighted);
+            highlighted.push_str(HIGHLIGHT_STOP);
+        } else {
+            Self::escape_html(email, &mut highlighted);
+        }
+
+        highlighted
+    }
+
+    /// Returns `None` when the user does not match, mirroring the `WHERE` clause of the Postgres
+    /// query.
+    fn rank(user: &UserEntity, term: &str, terms: &[String]) -> Option<f32> {
+        let tokens = Self::tokens(user);
+        let is_text_match = terms.iter().all(|term| tokens.contains(term));
+
+        let similarity = [
+            user.first_name.as_str(),
+            user.last_name.as_str(),
+            user.email.as_str(),
+        ]
+        .into_iter()
+        .map(|value| Self::similarity(value, term))
+        .fold(0.0, f32::max);
+
+        (is_text_match || similarity >= SIMILARITY_THRESHOLD)
+            .then(|| f32::from(u8::from(is_text_match)) + similarity)
+    }
+}
+
+impl UserSearchPort for InMemoryUserSearchRepository {
+    fn search(&self, query: UserSearchQuery) -> UserSearchFuture<'_> {
+        let future = async move {
+            let terms: Vec<String> = query
+                .term
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .collect();
+
+            let mut hits: Vec<UserSearchHit> = self
+                .users
+                .iter()
+                .filter_map(|user| {
+                    let rank = Self::rank(user, &query.term, &terms)?;
+
+                    Some(UserSearchHit {
+                        user: user.clone(),
+                        rank,
+                        highlights: UserSearchHighlights {
+                            first_name: Self::highlight(user.first_name.as_str(), &terms),
+                            last_name: Self::highlight(user.last_name.as_str(), &terms),
+                            email: Self::highlight_email(user.email.as_str(), &terms),
+                        },
+                    })
+                })
+                .collect();
+
+            hits.sort_by(|left, right| right.rank.total_cmp(&left.rank));
+
+            let total = hits.len() as u64;
+
+            let hits = hits
+                .into_iter()
+                .skip(query.offset() as usize)
+                .take(query.per_page as usize)
+                .collect();
+
+            Ok(UserSearchPage {
+                hits,
+                total,
+                page: query.page,
+                per_page: query.per_page,
+            })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!("InMemoryUserSearchRepository::search")))
+    }
+}
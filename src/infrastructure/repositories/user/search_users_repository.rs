@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::user_search::user_search_port::{
+        UserSearchError, UserSearchFuture, UserSearchHit, UserSearchPage, UserSearchPort,
+        UserSearchQuery,
+    },
+    infrastructure::models::user::user_search_model::UserSearchModel,
+};
+
+#[derive(Clone)]
+pub struct SearchUsersRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl SearchUsersRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl UserSearchPort for SearchUsersRepository {
+    fn search(&self, query: UserSearchQuery) -> UserSearchFuture<'_> {
+        let future = async move {
+            let total = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "total!"
+                FROM users
+                WHERE to_tsvector('simple', first_name || ' ' || last_name || ' ' || email)
+                        @@ websearch_to_tsquery('simple', $1)
+                    OR first_name % $1
+                    OR last_name % $1
+                    OR email % $1
+                "#,
+                query.term,
+            )
+            .fetch_one(&*self.database_pool)
+            .await
+            .map_err(|err| UserSearchError::SearchError {
+                message: err.to_string(),
+            })?;
+
+            let user_search_models = sqlx::query_as!(
+                UserSearchModel,
+                r#"
+                SELECT
+                    users.id,
+                    users.first_name,
+                    users.last_name,
+                    users.email,
+                    users.password,
+                    users.is_admin,
+                    users.status,
+                    users.password_reset_required,
+                    users.sessions_revoked_at,
+                    users.avatar_key,
+                    users.created_at,
+                    users.updated_at,
+                    (
+                        ts_rank(
+                            to_tsvector('simple', users.first_name || ' ' || users.last_name || ' ' || users.email),
+                            search.ts_query
+                        )
+                        + GREATEST(
+                            similarity(users.first_name, search.term),
+                            similarity(users.last_name, search.term),
+                            similarity(users.email, search.term)
+                        )
+                    )::REAL AS "rank!",
+                    ts_headline('simple', html_escape(users.first_name), search.ts_query, 'StartSel=<mark>, StopSel=</mark>, HighlightAll=true') AS "first_name_highlight!",
+                    ts_headline('simple', html_escape(users.last_name), search.ts_query, 'StartSel=<mark>, StopSel=</mark>, HighlightAll=true') AS "last_name_highlight!",
+                    ts_headline('simple', html_escape(users.email), search.ts_query, 'StartSel=<mark>, StopSel=</mark>, HighlightAll=true') AS "email_highlight!"
+                FROM users, (SELECT websearch_to_tsquery('simple', $1) AS ts_query, $1::TEXT AS term) AS search
+                WHERE to_tsvector('simple', users.first_name || ' ' || users.last_name || ' ' || users.email)
+                        @@ search.ts_query
+                    OR users.first_name % search.term
+                    OR users.last_name % search.term
+                    OR users.email % search.term
+                ORDER BY "rank!" DESC, users.created_at DESC
+                LIMIT $2 OFFSET $3
+                "#,
+                query.term,
+                i64::from(query.per_page),
+                i64::from(query.offset()),
+            )
+            .fetch_all(&*self.database_pool)
+            .await
+            .map_err(|err| UserSearchError::SearchError {
+                message: err.to_string(),
+            })?;
+
+            let hits = user_search_models
+                .into_iter()
+                .map(UserSearchHit::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| UserSearchError::SearchError {
+                    message: err.to_string(),
+                })?;
+
+            Ok(UserSearchPage {
+                hits,
+                total: u64::try_from(total).unwrap_or_default(),
+                page: query.page,
+                per_page: query.per_page,
+            })
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "SearchUsersRepository::search",
+            db.system.name = "postgresql",
+            db.operation.name = "SELECT",
+            db.collection.name = "users",
+        )))
+    }
+}
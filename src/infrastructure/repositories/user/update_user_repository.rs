@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::repositories::user::update_user_repository_port::{
+        UpdateUserRepositoryError, UpdateUserRepositoryFuture, UpdateUserRepositoryPort,
+    },
+    domain::entities::{
+        audit::audit_entry_entity::AuditEntryEntity,
+        outbox::outbox_event_entity::OutboxEventEntity, user::user_entity::UserEntity,
+    },
+    infrastructure::{
+        models::{audit::audit_entry_model::AuditEntryModel, user::user_model::UserModel},
+        repositories::outbox::create_outbox_events_repository::CreateOutboxEventsRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdateUserRepository {
+    database_pool: Arc<Pool<Postgres>>,
+}
+
+impl UpdateUserRepository {
+    #[must_use]
+    pub const fn new(database_pool: Arc<Pool<Postgres>>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl UpdateUserRepositoryPort for UpdateUserRepository {
+    fn execute(
+        &self,
+        user_entity: UserEntity,
+        audit_entry_entity: AuditEntryEntity,
+        outbox_event_entities: Vec<OutboxEventEntity>,
+    ) -> UpdateUserRepositoryFuture<'_> {
+        let future = async move {
+            let user_model = UserModel::from(user_entity);
+            let audit_entry_model =
+                AuditEntryModel::try_from(audit_entry_entity).map_err(|err| {
+                    UpdateUserRepositoryError::UpdateError {
+                        message: err.to_string(),
+                    }
+                })?;
+
+            let mut transaction = self.database_pool.begin().await.map_err(|err| {
+                UpdateUserRepositoryError::UpdateError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            let updated_user = sqlx::query_as!(
+                UserModel,
+                r#"
+                UPDATE users
+                SET first_name = $2,
+                    last_name = $3,
+                    email = $4,
+                    password = $5,
+                    is_admin = $6,
+                    status = $7,
+                    password_reset_required = $8,
+                    sessions_revoked_at = $9,
+                    avatar_key = $10,
+                    updated_at = $11
+                WHERE id = $1
+                RETURNING *
+                "#,
+                user_model.id,
+                user_model.first_name,
+                user_model.last_name,
+                user_model.email,
+                user_model.password,
+                user_model.is_admin,
+                user_model.status,
+                user_model.password_reset_required,
+                user_model.sessions_revoked_at,
+                user_model.avatar_key,
+                user_model.updated_at,
+            )
+            .fetch_optional(&mut *transaction)
+            .await
+            .map_err(|err| UpdateUserRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?
+            .ok_or(UpdateUserRepositoryError::UserNotFound)?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO audit_entries (id, actor_id, action, target_user_id, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                audit_entry_model.id,
+                audit_entry_model.actor_id,
+                audit_entry_model.action,
+                audit_entry_model.target_user_id,
+                audit_entry_model.created_at,
+            )
+            .execute(&mut *transaction)
+            .await
+            .map_err(|err| UpdateUserRepositoryError::UpdateError {
+                message: err.to_string(),
+            })?;
+
+            CreateOutboxEventsRepository::execute(&mut transaction, outbox_event_entities)
+                .await
+                .map_err(|err| UpdateUserRepositoryError::UpdateError {
+                    message: err.to_string(),
+                })?;
+
+            transaction
+                .commit()
+                .await
+                .map_err(|err| UpdateUserRepositoryError::UpdateError {
+                    message: err.to_string(),
+                })?;
+
+            let user_entity = UserEntity::try_from(updated_user).map_err(|err| {
+                UpdateUserRepositoryError::UpdateError {
+                    message: err.to_string(),
+                }
+            })?;
+
+            Ok(user_entity)
+        };
+
+        Box::pin(future.instrument(tracing::info_span!(
+            "UpdateUserRepository::execute",
+            db.system.name = "postgresql",
+            db.operation.name = "UPDATE",
+            db.collection.name = "users",
+        )))
+    }
+}
@@ -2,10 +2,41 @@
 #![warn(clippy::pedantic)]
 #![warn(clippy::nursery)]
 
-use crate::infrastructure::boostrap::api::api_boostrap::{ApiBootstrap, ApiBootstrapPort};
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::{
+    infrastructure::boostrap::{
+        admin::admin_boostrap::{AdminBootstrap, AdminBootstrapPort},
+        api::api_boostrap::{ApiBootstrap, ApiBootstrapPort},
+        migrate::migrate_boostrap::{MigrateBootstrap, MigrateBootstrapPort},
+    },
+    presentation::cli::cli_args::{CliArgs, Command},
+};
 
 pub mod domain {
     pub mod entities {
+        pub mod audit {
+            pub mod audit_entry_entity;
+        }
+
+        pub mod data_export {
+            pub mod data_export_entity;
+        }
+
+        pub mod email_domain_policy {
+            pub mod email_domain_policy_entity;
+        }
+
+        pub mod outbox {
+            pub mod outbox_event_entity;
+        }
+
+        pub mod session {
+            pub mod session_entity;
+        }
+
         pub mod user {
             pub mod user_entity;
         }
@@ -15,24 +46,115 @@ pub mod domain {
         pub mod user {
             pub mod user_errors;
         }
+
+        pub mod value_object {
+            pub mod value_object_errors;
+        }
+    }
+
+    pub mod events {
+        pub mod domain_event;
+    }
+
+    pub mod value_objects {
+        pub mod shared {
+            pub mod timestamp;
+        }
+
+        pub mod user {
+            pub mod email_address;
+            pub mod password_hash;
+            pub mod person_name;
+            pub mod user_id;
+        }
     }
 }
 
 pub mod application {
     pub mod ports {
+        pub mod archive {
+            pub mod archive_port;
+        }
+
+        pub mod clock {
+            pub mod clock_port;
+        }
+
+        pub mod email_domain_policy {
+            pub mod email_domain_policy_port;
+        }
+
+        pub mod email_normalizer {
+            pub mod email_normalizer_port;
+        }
+
+        pub mod events {
+            pub mod domain_event_handler_port;
+        }
+
+        pub mod file_storage {
+            pub mod file_storage_port;
+        }
+
         pub mod hasher {
             pub mod hasher_port;
         }
 
+        pub mod health {
+            pub mod health_check_port;
+        }
+
+        pub mod image_processing {
+            pub mod image_processing_port;
+        }
+
         pub mod id_generator {
             pub mod id_generator_port;
         }
 
+        pub mod job {
+            pub mod data_export_job_port;
+        }
+
+        pub mod localization {
+            pub mod localization_port;
+        }
+
+        pub mod logging {
+            pub mod log_filter_port;
+        }
+
+        pub mod metrics {
+            pub mod metrics_port;
+        }
+
         pub mod repositories {
+            pub mod data_export {
+                pub mod create_data_export_repository_port;
+                pub mod get_data_export_by_id_repository_port;
+                pub mod update_data_export_repository_port;
+            }
+
+            pub mod email_domain_policy {
+                pub mod get_email_domain_policy_repository_port;
+                pub mod update_email_domain_policy_repository_port;
+            }
+
+            pub mod outbox {
+                pub mod claim_outbox_events_repository_port;
+                pub mod update_outbox_event_repository_port;
+            }
+
+            pub mod session {
+                pub mod create_session_repository_port;
+            }
+
             pub mod user {
                 pub mod create_user_repository_port;
                 pub mod get_user_by_email_repository_port;
                 pub mod get_user_by_id_repository_port;
+                pub mod get_user_data_repository_port;
+                pub mod update_user_repository_port;
             }
         }
 
@@ -40,36 +162,127 @@ pub mod application {
             pub mod auth_port;
         }
 
+        pub mod password_policy {
+            pub mod password_policy_port;
+        }
+
         pub mod pattern_matching {
             pub mod pattern_matching_port;
         }
+
+        pub mod tracing {
+            pub mod trace_context_port;
+        }
+
+        pub mod user_search {
+            pub mod user_search_port;
+        }
     }
 
     pub mod use_cases {
+        pub mod admin {
+            pub mod admin_user_action_use_case;
+            pub mod change_log_level_use_case;
+            pub mod get_email_domain_policy_use_case;
+            pub mod import_users_use_case;
+            pub mod operator_user_action_use_case;
+            pub mod update_email_domain_policy_use_case;
+        }
+
         pub mod auth {
+            pub mod authenticate_use_case;
+            pub mod change_password_use_case;
             pub mod sign_in_use_case;
             pub mod sign_up_use_case;
         }
 
+        pub mod data_export {
+            pub mod download_data_export_use_case;
+            pub mod generate_data_export_use_case;
+            pub mod get_data_export_use_case;
+            pub mod request_data_export_use_case;
+        }
+
+        pub mod health {
+            pub mod check_readiness_use_case;
+        }
+
+        pub mod outbox {
+            pub mod dispatch_outbox_events_use_case;
+        }
+
         pub mod user {
+            pub mod get_avatar_use_case;
+            pub mod get_user_by_email_use_case;
             pub mod get_user_by_id_use_case;
+            pub mod search_users_use_case;
+            pub mod upload_avatar_use_case;
         }
     }
 
     pub mod dtos {
+        pub mod admin {
+            pub mod change_log_level_dto;
+            pub mod import_users_dto;
+            pub mod log_filter_dto;
+            pub mod update_email_domain_policy_dto;
+        }
+
         pub mod auth {
+            pub mod auth_principal_dto;
+            pub mod change_password_dto;
             pub mod sign_in_dto;
             pub mod sign_up_dto;
         }
+
+        pub mod data_export {
+            pub mod data_export_archive_dto;
+            pub mod user_data_export_dto;
+        }
+
+        pub mod user {
+            pub mod avatar_dto;
+        }
     }
 }
 
 pub mod infrastructure {
+    pub mod config {
+        pub mod app_config;
+        pub mod config_args;
+        pub mod config_loader;
+    }
+
     pub mod repositories {
+        pub mod data_export {
+            pub mod create_data_export_repository;
+            pub mod get_data_export_by_id_repository;
+            pub mod update_data_export_repository;
+        }
+
+        pub mod email_domain_policy {
+            pub mod get_email_domain_policy_repository;
+            pub mod update_email_domain_policy_repository;
+        }
+
+        pub mod outbox {
+            pub mod claim_outbox_events_repository;
+            pub mod create_outbox_events_repository;
+            pub mod update_outbox_event_repository;
+        }
+
+        pub mod session {
+            pub mod create_session_repository;
+        }
+
         pub mod user {
             pub mod create_user_repository;
             pub mod get_user_by_email_repository;
             pub mod get_user_by_id_repository;
+            pub mod get_user_data_repository;
+            pub mod in_memory_user_search_repository;
+            pub mod search_users_repository;
+            pub mod update_user_repository;
         }
     }
 
@@ -86,6 +299,53 @@ pub mod infrastructure {
             pub mod axum_handler_adapter;
         }
 
+        pub mod clock {
+            pub mod manual_clock_adapter;
+            pub mod system_clock_adapter;
+        }
+
+        pub mod domain_events {
+            pub mod tracing_domain_event_handler_adapter;
+        }
+
+        pub mod email_domain_policy {
+            pub mod cached_email_domain_policy_adapter;
+        }
+
+        pub mod email_normalizer {
+            pub mod rule_based_email_normalizer_adapter;
+        }
+
+        pub mod file_storage {
+            pub mod in_memory_file_storage_adapter;
+            pub mod local_file_storage_adapter;
+        }
+
+        pub mod image {
+            pub mod image_adapter;
+        }
+
+        pub mod json_catalog {
+            pub mod json_catalog_localization_adapter;
+        }
+
+        pub mod opentelemetry {
+            pub mod opentelemetry_trace_context_adapter;
+        }
+
+        pub mod password_policy {
+            pub mod password_strength_estimator;
+            pub mod rule_based_password_policy_adapter;
+        }
+
+        pub mod postgres {
+            pub mod postgres_health_check_adapter;
+        }
+
+        pub mod prometheus {
+            pub mod prometheus_metrics_adapter;
+        }
+
         pub mod regex {
             pub mod regex_adapter;
         }
@@ -93,44 +353,175 @@ pub mod infrastructure {
         pub mod jsonwebtoken {
             pub mod jsonwebtoken_adapter;
         }
+
+        pub mod tokio {
+            pub mod tokio_background_task_adapter;
+            pub mod tokio_data_export_job_adapter;
+            pub mod tokio_outbox_dispatcher_adapter;
+            pub mod tokio_shutdown_signal_adapter;
+        }
+
+        pub mod tracing_subscriber {
+            pub mod reloadable_log_filter_adapter;
+        }
+
+        pub mod zip {
+            pub mod zip_archive_adapter;
+        }
+    }
+
+    pub mod decorators {
+        pub mod metrics {
+            pub mod metrics_authenticate_use_case_decorator;
+            pub mod metrics_hasher_decorator;
+            pub mod metrics_sign_in_use_case_decorator;
+            pub mod metrics_sign_up_use_case_decorator;
+        }
     }
 
     pub mod gateways {
         pub mod database {
             pub mod database_gateway;
+            pub mod database_migrator;
+        }
+
+        pub mod http {
+            pub mod http_server_gateway;
+        }
+
+        pub mod telemetry {
+            pub mod redacting_writer;
+            pub mod telemetry_gateway;
         }
     }
 
     pub mod boostrap {
+        pub mod admin {
+            pub mod admin_boostrap;
+        }
+
         pub mod api {
             pub mod api_boostrap;
         }
+
+        pub mod migrate {
+            pub mod migrate_boostrap;
+        }
     }
 
     pub mod factories {
+        pub mod cli {
+            pub mod admin {
+                pub mod admin_cli_controller_factory;
+            }
+        }
+
         pub mod controller {
+            pub mod admin {
+                pub mod admin_user_action_controller_factory;
+                pub mod change_log_level_controller_factory;
+                pub mod get_email_domain_policy_controller_factory;
+                pub mod import_users_controller_factory;
+                pub mod update_email_domain_policy_controller_factory;
+            }
+
             pub mod auth {
+                pub mod change_password_controller_factory;
                 pub mod sign_in_controller_factory;
                 pub mod sign_up_controller_factory;
             }
 
+            pub mod data_export {
+                pub mod download_data_export_controller_factory;
+                pub mod export_user_data_controller_factory;
+                pub mod get_data_export_controller_factory;
+            }
+
+            pub mod health {
+                pub mod liveness_controller_factory;
+                pub mod readiness_controller_factory;
+            }
+
+            pub mod metrics {
+                pub mod get_metrics_controller_factory;
+            }
+
             pub mod user {
+                pub mod get_avatar_controller_factory;
                 pub mod get_user_by_id_controller_factory;
+                pub mod search_users_controller_factory;
+                pub mod upload_avatar_controller_factory;
+            }
+        }
+
+        pub mod job {
+            pub mod outbox_dispatcher_factory;
+        }
+
+        pub mod middleware {
+            pub mod auth {
+                pub mod auth_middleware_factory;
+            }
+
+            pub mod localization {
+                pub mod localization_middleware_factory;
+            }
+
+            pub mod request_id {
+                pub mod request_id_middleware_factory;
+            }
+
+            pub mod tracing {
+                pub mod trace_context_middleware_factory;
             }
         }
     }
 
     pub mod mappers {
         pub mod response {
+            pub mod data_export {
+                pub mod data_export_response;
+            }
+
+            pub mod email_domain_policy {
+                pub mod email_domain_policy_response;
+            }
+
             pub mod user {
                 pub mod user_response;
+                pub mod user_search_response;
             }
         }
     }
 
     pub mod models {
+        pub mod audit {
+            pub mod audit_entry_model;
+        }
+
+        pub mod data_export {
+            pub mod data_export_model;
+        }
+
+        pub mod email_domain_policy {
+            pub mod email_domain_policy_model;
+        }
+
+        pub mod errors {
+            pub mod model_errors;
+        }
+
+        pub mod outbox {
+            pub mod outbox_event_model;
+        }
+
+        pub mod session {
+            pub mod session_model;
+        }
+
         pub mod user {
             pub mod user_model;
+            pub mod user_search_model;
         }
     }
 }
@@ -144,20 +535,53 @@ pub mod presentation {
     }
 
     pub mod controllers {
+        pub mod admin {
+            pub mod admin_user_action_controller;
+            pub mod change_log_level_controller;
+            pub mod change_log_level_schema;
+            pub mod get_email_domain_policy_controller;
+            pub mod import_users_controller;
+            pub mod update_email_domain_policy_controller;
+            pub mod update_email_domain_policy_schema;
+        }
+
         pub mod auth {
+            pub mod change_password {
+                pub mod change_password_controller;
+                pub mod change_password_schema;
+            }
+
             pub mod sign_up {
                 pub mod sign_up_controller;
-                pub mod sign_up_validator;
+                pub mod sign_up_schema;
             }
 
             pub mod sign_in {
                 pub mod sign_in_controller;
-                pub mod sign_in_validator;
+                pub mod sign_in_schema;
             }
         }
 
+        pub mod data_export {
+            pub mod download_data_export_controller;
+            pub mod export_user_data_controller;
+            pub mod get_data_export_controller;
+        }
+
+        pub mod health {
+            pub mod liveness_controller;
+            pub mod readiness_controller;
+        }
+
+        pub mod metrics {
+            pub mod get_metrics_controller;
+        }
+
         pub mod user {
+            pub mod get_avatar_controller;
             pub mod get_user_by_id_controller;
+            pub mod search_users_controller;
+            pub mod upload_avatar_controller;
         }
     }
 
@@ -170,12 +594,26 @@ pub mod presentation {
             pub mod router_port;
         }
 
-        pub mod validator {
-            pub mod validator_port;
+        pub mod request_body {
+            pub mod request_body_port;
         }
     }
 
+    pub mod errors {
+        pub mod api_error;
+        pub mod api_error_mappings;
+    }
+
+    pub mod validation {
+        pub mod validation_error;
+        pub mod validation_schema;
+    }
+
     pub mod routers {
+        pub mod admin {
+            pub mod admin_router;
+        }
+
         pub mod auth {
             pub mod auth_router;
         }
@@ -184,6 +622,18 @@ pub mod presentation {
             pub mod core_router;
         }
 
+        pub mod data_export {
+            pub mod data_export_router;
+        }
+
+        pub mod health {
+            pub mod health_router;
+        }
+
+        pub mod metrics {
+            pub mod metrics_router;
+        }
+
         pub mod user {
             pub mod user_router;
         }
@@ -193,6 +643,30 @@ pub mod presentation {
         pub mod auth {
             pub mod auth_middleware;
         }
+
+        pub mod localization {
+            pub mod localization_middleware;
+        }
+
+        pub mod metrics {
+            pub mod metrics_middleware;
+        }
+
+        pub mod request_id {
+            pub mod request_id_middleware;
+        }
+
+        pub mod tracing {
+            pub mod trace_context_middleware;
+        }
+    }
+
+    pub mod cli {
+        pub mod admin {
+            pub mod admin_cli_controller;
+        }
+
+        pub mod cli_args;
     }
 
     pub mod helpers {
@@ -200,12 +674,35 @@ pub mod presentation {
             pub mod http_body_helper;
             pub mod http_response_helper;
         }
+
+        pub mod import {
+            pub mod user_import_helper;
+        }
+
+        pub mod multipart {
+            pub mod multipart_helper;
+        }
     }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let api_boostrap = ApiBootstrap;
+async fn main() -> ExitCode {
+    let cli_args = CliArgs::parse();
+
+    let result = match cli_args.command {
+        None | Some(Command::Serve) => ApiBootstrap::new(cli_args.config).setup().await,
+        Some(Command::Migrate { action }) => {
+            MigrateBootstrap::new(cli_args.config, action).run().await
+        }
+        Some(Command::Admin { action }) => AdminBootstrap::new(cli_args.config, action).run().await,
+    };
 
-    api_boostrap.setup().await
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,503 @@
+use std::pin::Pin;
+
+use serde_json::json;
+
+use crate::{
+    application::{
+        dtos::auth::sign_up_dto::SignUpDto,
+        ports::{
+            password_policy::password_policy_port::PasswordPolicyPort,
+            pattern_matching::pattern_matching_port::{PatternMatchingError, PatternMatchingPort},
+        },
+        use_cases::{
+            admin::operator_user_action_use_case::{
+                OperatorUserAction, OperatorUserActionUseCasePort,
+            },
+            auth::sign_up_use_case::{SignUpUseCaseError, SignUpUseCasePort},
+            user::{
+                get_user_by_email_use_case::GetUserByEmailUseCasePort,
+                get_user_by_id_use_case::GetUserByIdUseCasePort,
+            },
+        },
+    },
+    domain::entities::user::user_entity::UserEntity,
+    infrastructure::mappers::response::user::user_response::UserResponse,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AdminCliError {
+    InvalidInput { message: String },
+    UserNotFound,
+    OperationFailed { message: String },
+}
+
+impl std::fmt::Display for AdminCliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidInput { message } => write!(f, "Invalid input: {message}."),
+            Self::UserNotFound => write!(f, "No user matches the provided id or e-mail."),
+            Self::OperationFailed { message } => write!(f, "The operation failed: {message}."),
+        }
+    }
+}
+
+impl std::error::Error for AdminCliError {}
+
+/// How the operator designates the user an admin command applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserSelector {
+    Id(String),
+    Email(String),
+}
+
+/// Resolves to the text printed on success: the affected user as pretty-printed JSON.
+pub type AdminCliFuture<'a> = Pin<Box<dyn Future<Output = Result<String, AdminCliError>> + 'a>>;
+
+/// Drives the operator commands of the admin CLI through the same use cases the HTTP API uses.
+#[derive(Clone)]
+pub struct AdminCliController<P, R, S, O, I, E> {
+    pattern_matching_adapter: P,
+    password_policy_adapter: R,
+    sign_up_use_case: S,
+    operator_user_action_use_case: O,
+    get_user_by_id_use_case: I,
+    get_user_by_email_use_case: E,
+}
+
+impl<P, R, S, O, I, E> AdminCliController<P, R, S, O, I, E>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    R: PasswordPolicyPort + Clone + Send + Sync,
+    S: SignUpUseCasePort + Clone + Send + Sync,
+    O: OperatorUserActionUseCasePort + Clone + Send + Sync,
+    I: GetUserByIdUseCasePort + Clone + Send + Sync,
+    E: GetUserByEmailUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        pattern_matching_adapter: P,
+        password_policy_adapter: R,
+        sign_up_use_case: S,
+        operator_user_action_use_case: O,
+        get_user_by_id_use_case: I,
+        get_user_by_email_use_case: E,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            password_policy_adapter,
+            sign_up_use_case,
+            operator_user_action_use_case,
+            get_user_by_id_use_case,
+            get_user_by_email_use_case,
+        }
+    }
+
+    /// Signs up a new account, then grants it administrator privileges.
+    pub fn create_admin(
+        &self,
+        first_name: String,
+        last_name: String,
+        email: String,
+        password: String,
+    ) -> AdminCliFuture<'_> {
+        Box::pin(async move {
+            self.validate(
+                &email,
+                PatternMatchingPort::is_valid_email,
+                &PatternMatchingError::InvalidEmail,
+            )?;
+            self.validate_password(&password, &[&email, &first_name, &last_name])
+                .await?;
+
+            let sign_up_dto =
+                SignUpDto::new(first_name, last_name, email, password.clone(), password);
+
+            let user_entity = self
+                .sign_up_use_case
+                .perform(sign_up_dto)
+                .await
+                .map_err(|err| match err {
+                    SignUpUseCaseError::EmailDomainPolicyError(err) if err.reason().is_some() => {
+                        AdminCliError::InvalidInput {
+                            message: err.to_string(),
+                        }
+                    }
+                    SignUpUseCaseError::ValidationError(err) => AdminCliError::InvalidInput {
+                        message: err.to_string(),
+                    },
+                    err => Self::operation_failed(err),
+                })?;
+
+            let user_entity = self
+                .operator_user_action_use_case
+                .perform(user_entity.id.to_string(), OperatorUserAction::GrantAdmin)
+                .await
+                .map_err(Self::operation_failed)?;
+
+            Self::render(user_entity)
+        })
+    }
+
+    /// Applies `action` to the selected user.
+    pub fn perform(
+        &self,
+        user_selector: UserSelector,
+        action: OperatorUserAction,
+    ) -> AdminCliFuture<'_> {
+        Box::pin(async move {
+            if let OperatorUserAction::ResetPassword { new_password } = &action {
+                self.validate_password(new_password, &[]).await?;
+            }
+
+            let user_entity = self.find_user(user_selector).await?;
+
+            let user_entity = self
+                .operator_user_action_use_case
+                .perform(user_entity.id.to_string(), action)
+                .await
+                .map_err(Self::operation_failed)?;
+
+            Self::render(user_entity)
+        })
+    }
+
+    pub fn show_user(&self, user_selector: UserSelector) -> AdminCliFuture<'_> {
+        Box::pin(async move {
+            let user_entity = self.find_user(user_selector).await?;
+
+            Self::render(user_entity)
+        })
+    }
+
+    async fn find_user(&self, user_selector: UserSelector) -> Result<UserEntity, AdminCliError> {
+        let user_entity = match user_selector {
+            UserSelector::Id(id) => {
+                self.validate(
+                    &id,
+                    PatternMatchingPort::is_valid_uuid,
+                    &PatternMatchingError::InvalidUuid,
+                )?;
+
+                self.get_user_by_id_use_case
+                    .perform(id)
+                    .await
+                    .map_err(Self::operation_failed)?
+            }
+            UserSelector::Email(email) => self
+                .get_user_by_email_use_case
+                .perform(email)
+                .await
+                .map_err(Self::operation_failed)?,
+        };
+
+        user_entity.ok_or(AdminCliError::UserNotFound)
+    }
+
+    async fn validate_password(
+        &self,
+        password: &str,
+        personal_info: &[&str],
+    ) -> Result<(), AdminCliError> {
+        self.password_policy_adapter
+            .validate(password, personal_info)
+            .await
+            .map_err(|err| AdminCliError::InvalidInput {
+                message: err.to_string(),
+            })
+    }
+
+    fn validate(
+        &self,
+        value: &str,
+        is_valid: fn(&P, &str) -> Result<bool, PatternMatchingError>,
+        invalid_error: &PatternMatchingError,
+    ) -> Result<(), AdminCliError> {
+        match is_valid(&self.pattern_matching_adapter, value) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(AdminCliError::InvalidInput {
+                message: invalid_error.to_string(),
+            }),
+            Err(err) => Err(Self::operation_failed(err)),
+        }
+    }
+
+    fn render(user_entity: UserEntity) -> Result<String, AdminCliError> {
+        let user_response = UserResponse::from(user_entity);
+
+        serde_json::to_string_pretty(&json!({ "user": user_response }))
+            .map_err(Self::operation_failed)
+    }
+
+    fn operation_failed(err: impl std::error::Error) -> AdminCliError {
+        AdminCliError::OperationFailed {
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::struct_field_names)]
+mod tests {
+    use mockall::mock;
+
+    use crate::{
+        application::{
+            dtos::auth::sign_up_dto::SignUpDto,
+            ports::{
+                password_policy::password_policy_port::{
+                    PasswordPolicyError, PasswordPolicyFuture, PasswordPolicyPort,
+                    PasswordPolicyViolation,
+                },
+                pattern_matching::pattern_matching_port::{
+                    PatternMatchingError, PatternMatchingPort,
+                },
+            },
+            use_cases::{
+                admin::operator_user_action_use_case::{
+                    OperatorUserAction, OperatorUserActionUseCaseFuture,
+                    OperatorUserActionUseCasePort,
+                },
+                auth::sign_up_use_case::{SignUpUseCaseFuture, SignUpUseCasePort},
+                user::{
+                    get_user_by_email_use_case::{
+                        GetUserByEmailUseCaseFuture, GetUserByEmailUseCasePort,
+                    },
+                    get_user_by_id_use_case::{GetUserByIdUseCaseFuture, GetUserByIdUseCasePort},
+                },
+            },
+        },
+        domain::entities::user::user_entity::{UserEntity, UserEntityBuilder},
+        presentation::cli::admin::admin_cli_controller::{
+            AdminCliController, AdminCliError, UserSelector,
+        },
+    };
+
+    const USER_ID: &str = "d836bc7f-014e-4818-a97f-dd1bb1987b66";
+
+    mock! {
+        pub RegexAdapter {}
+
+        impl PatternMatchingPort for RegexAdapter {
+            fn is_valid_email(&self, email: &str) -> Result<bool, PatternMatchingError>;
+            fn is_valid_password_hash(&self, password_hash: &str) -> Result<bool, PatternMatchingError>;
+            fn is_valid_uuid(&self, uuid: &str) -> Result<bool, PatternMatchingError>;
+        }
+
+        impl Clone for RegexAdapter {
+            fn clone(&self) -> Self {
+                MockRegexAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub PasswordPolicyAdapter {}
+
+        impl PasswordPolicyPort for PasswordPolicyAdapter {
+            fn validate<'a>(&self, password: &str, personal_info: &[&'a str]) -> PasswordPolicyFuture<'_>;
+        }
+
+        impl Clone for PasswordPolicyAdapter {
+            fn clone(&self) -> Self {
+                MockPasswordPolicyAdapter::new()
+            }
+        }
+    }
+
+    mock! {
+        pub SignUpUseCase {}
+
+        impl SignUpUseCasePort for SignUpUseCase {
+            fn perform(&self, sign_up_dto: SignUpDto) -> SignUpUseCaseFuture<'_>;
+        }
+
+        impl Clone for SignUpUseCase {
+            fn clone(&self) -> Self {
+                MockSignUpUseCase::new()
+            }
+        }
+    }
+
+    mock! {
+        pub OperatorUserActionUseCase {}
+
+        impl OperatorUserActionUseCasePort for OperatorUserActionUseCase {
+            fn perform(
+                &self,
+                target_user_id: String,
+                action: OperatorUserAction,
+            ) -> OperatorUserActionUseCaseFuture<'_>;
+        }
+
+        impl Clone for OperatorUserActionUseCase {
+            fn clone(&self) -> Self {
+                MockOperatorUserActionUseCase::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByIdUseCase {}
+
+        impl GetUserByIdUseCasePort for GetUserByIdUseCase {
+            fn perform(&self, id: String) -> GetUserByIdUseCaseFuture<'_>;
+        }
+
+        impl Clone for GetUserByIdUseCase {
+            fn clone(&self) -> Self {
+                MockGetUserByIdUseCase::new()
+            }
+        }
+    }
+
+    mock! {
+        pub GetUserByEmailUseCase {}
+
+        impl GetUserByEmailUseCasePort for GetUserByEmailUseCase {
+            fn perform(&self, email: String) -> GetUserByEmailUseCaseFuture<'_>;
+        }
+
+        impl Clone for GetUserByEmailUseCase {
+            fn clone(&self) -> Self {
+                MockGetUserByEmailUseCase::new()
+            }
+        }
+    }
+
+    fn build_user(is_admin: bool) -> UserEntity {
+        UserEntityBuilder::default()
+            .id(USER_ID)
+            .first_name("John")
+            .last_name("Doe")
+            .email("johndoe@gmail.com")
+            .password("$2b$12$D/HbcVNFxNrOzRmoy4M0nu1ZUzJcTDt5UVUcxEb/vKfRZsTL0ORa.")
+            .is_admin(is_admin)
+            .created_at(1_695_996_669)
+            .updated_at(1_695_996_669)
+            .build()
+            .unwrap()
+    }
+
+    fn build_regex_adapter_mock() -> MockRegexAdapter {
+        let mut regex_adapter_mock = MockRegexAdapter::default();
+
+        regex_adapter_mock
+            .expect_is_valid_email()
+            .returning(|_| Ok(true));
+        regex_adapter_mock
+    }
+
+    fn build_password_policy_adapter_mock(is_valid_password: bool) -> MockPasswordPolicyAdapter {
+        let mut password_policy_adapter_mock = MockPasswordPolicyAdapter::default();
+
+        password_policy_adapter_mock
+            .expect_validate()
+            .returning(move |_, _| {
+                Box::pin(async move {
+                    if is_valid_password {
+                        return Ok(());
+                    }
+
+                    Err(PasswordPolicyError {
+                        violations: vec![PasswordPolicyViolation::TooShort { min_length: 12 }],
+                    })
+                })
+            });
+
+        password_policy_adapter_mock
+    }
+
+    #[tokio::test]
+    async fn should_sign_up_and_promote_new_admin() {
+        let mut sign_up_use_case_mock = MockSignUpUseCase::default();
+
+        sign_up_use_case_mock
+            .expect_perform()
+            .times(1)
+            .withf(|sign_up_dto| sign_up_dto.password == sign_up_dto.password_confirmation)
+            .returning(|_| Box::pin(async { Ok(build_user(false)) }));
+
+        let mut operator_user_action_use_case_mock = MockOperatorUserActionUseCase::default();
+
+        operator_user_action_use_case_mock
+            .expect_perform()
+            .times(1)
+            .withf(|id, action| id == USER_ID && *action == OperatorUserAction::GrantAdmin)
+            .returning(|_, _| Box::pin(async { Ok(build_user(true)) }));
+
+        let admin_cli_controller = AdminCliController::new(
+            build_regex_adapter_mock(),
+            build_password_policy_adapter_mock(true),
+            sign_up_use_case_mock,
+            operator_user_action_use_case_mock,
+            MockGetUserByIdUseCase::default(),
+            MockGetUserByEmailUseCase::default(),
+        );
+
+        let output = admin_cli_controller
+            .create_admin(
+                "John".to_string(),
+                "Doe".to_string(),
+                "johndoe@gmail.com".to_string(),
+                "Sup3r-S3cret-Passw0rd".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let output = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+
+        assert_eq!(output["user"]["is_admin"], true);
+    }
+
+    #[tokio::test]
+    async fn should_reject_weak_password_before_touching_the_user() {
+        let admin_cli_controller = AdminCliController::new(
+            build_regex_adapter_mock(),
+            build_password_policy_adapter_mock(false),
+            MockSignUpUseCase::default(),
+            MockOperatorUserActionUseCase::default(),
+            MockGetUserByIdUseCase::default(),
+            MockGetUserByEmailUseCase::default(),
+        );
+
+        let result = admin_cli_controller
+            .perform(
+                UserSelector::Email("johndoe@gmail.com".to_string()),
+                OperatorUserAction::ResetPassword {
+                    new_password: "short".to_string(),
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            AdminCliError::InvalidInput {
+                message: "the password must have at least 12 characters".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_selected_user_does_not_exist() {
+        let mut get_user_by_email_use_case_mock = MockGetUserByEmailUseCase::default();
+
+        get_user_by_email_use_case_mock
+            .expect_perform()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let admin_cli_controller = AdminCliController::new(
+            build_regex_adapter_mock(),
+            build_password_policy_adapter_mock(true),
+            MockSignUpUseCase::default(),
+            MockOperatorUserActionUseCase::default(),
+            MockGetUserByIdUseCase::default(),
+            get_user_by_email_use_case_mock,
+        );
+
+        let result = admin_cli_controller
+            .show_user(UserSelector::Email("nobody@gmail.com".to_string()))
+            .await;
+
+        assert_eq!(result.unwrap_err(), AdminCliError::UserNotFound);
+    }
+}
@@ -0,0 +1,122 @@
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    infrastructure::config::config_args::ConfigArgs,
+    presentation::cli::admin::admin_cli_controller::UserSelector,
+};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "User management REST API")]
+pub struct CliArgs {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Starts the HTTP server (the default when no command is given).
+    Serve,
+
+    /// Manages the database schema.
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateCommand,
+    },
+
+    /// Performs operational tasks on user accounts without going through the HTTP API.
+    Admin {
+        #[command(subcommand)]
+        action: AdminCommand,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum MigrateCommand {
+    /// Applies every pending migration.
+    Up,
+
+    /// Reverts the most recently applied migrations.
+    Down {
+        /// Number of migrations to revert.
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+    },
+
+    /// Lists every migration and whether it has been applied.
+    Status,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum AdminCommand {
+    /// Creates a new account with administrator privileges.
+    CreateAdmin {
+        #[arg(long)]
+        first_name: String,
+
+        #[arg(long)]
+        last_name: String,
+
+        #[arg(long)]
+        email: String,
+
+        /// Read from standard input when omitted, so it stays out of the shell history.
+        #[arg(long)]
+        password: Option<String>,
+    },
+
+    /// Grants administrator privileges to an existing user.
+    Promote {
+        #[command(flatten)]
+        user: UserSelectorArgs,
+    },
+
+    /// Sets a new password and revokes every session of the user.
+    ResetPassword {
+        #[command(flatten)]
+        user: UserSelectorArgs,
+
+        /// Read from standard input when omitted, so it stays out of the shell history.
+        #[arg(long)]
+        password: Option<String>,
+    },
+
+    /// Invalidates every token issued to the user so far.
+    RevokeSessions {
+        #[command(flatten)]
+        user: UserSelectorArgs,
+    },
+
+    /// Prints a user's account details.
+    ShowUser {
+        #[command(flatten)]
+        user: UserSelectorArgs,
+    },
+
+    /// Prints every effective setting and where it came from, with secrets redacted.
+    ShowConfig,
+}
+
+#[derive(Debug, Clone, Args)]
+#[group(required = true, multiple = false)]
+pub struct UserSelectorArgs {
+    /// Id of the user.
+    #[arg(long)]
+    pub id: Option<String>,
+
+    /// E-mail of the user.
+    #[arg(long)]
+    pub email: Option<String>,
+}
+
+impl UserSelectorArgs {
+    #[must_use]
+    pub fn selector(&self) -> UserSelector {
+        match (&self.id, &self.email) {
+            (Some(id), _) => UserSelector::Id(id.clone()),
+            (None, email) => UserSelector::Email(email.clone().unwrap_or_default()),
+        }
+    }
+}
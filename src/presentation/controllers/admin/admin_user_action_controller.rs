@@ -0,0 +1,117 @@
+use serde_json::json;
+
+use crate::{
+    application::{
+        ports::pattern_matching::pattern_matching_port::{
+            PatternMatchingError, PatternMatchingPort,
+        },
+        use_cases::admin::admin_user_action_use_case::{
+            AdminUserAction, AdminUserActionUseCasePort,
+        },
+    },
+    domain::errors::user::user_errors::UserError,
+    infrastructure::mappers::response::user::user_response::UserResponse,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct AdminUserActionController<P, U> {
+    pattern_matching_adapter: P,
+    admin_user_action_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<P, U> AdminUserActionController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: AdminUserActionUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        pattern_matching_adapter: P,
+        admin_user_action_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            admin_user_action_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<P, U> ControllerPort for AdminUserActionController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: AdminUserActionUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            if !principal.is_admin {
+                return self
+                    .http_response_helper
+                    .problem(&ApiError::from(UserError::AdminPrivilegesRequired));
+            }
+
+            let params = http_request_dto.params.unwrap_or_default();
+
+            let Some(action) = params
+                .get("action")
+                .and_then(|action| AdminUserAction::from_slug(action))
+            else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::UnknownAdminAction,
+                    "the requested administration action does not exist",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let id = params.get("id").cloned().unwrap_or_default();
+
+            match self.pattern_matching_adapter.is_valid_uuid(&id) {
+                Ok(true) => {}
+                Ok(false) => {
+                    return self
+                        .http_response_helper
+                        .problem(&ApiError::from(PatternMatchingError::InvalidUuid));
+                }
+                Err(err) => {
+                    return self.http_response_helper.problem(&ApiError::from(err));
+                }
+            }
+
+            match self
+                .admin_user_action_use_case
+                .perform(principal.user_id, id, action)
+                .await
+            {
+                Ok(user_entity) => {
+                    let user_response = UserResponse::from(user_entity);
+                    let body = json!({ "user": user_response });
+
+                    self.http_response_helper.ok(Some(body))
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
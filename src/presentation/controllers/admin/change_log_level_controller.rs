@@ -0,0 +1,84 @@
+use serde_json::json;
+
+use crate::{
+    application::{
+        dtos::admin::change_log_level_dto::ChangeLogLevelDto,
+        use_cases::admin::change_log_level_use_case::ChangeLogLevelUseCasePort,
+    },
+    domain::errors::user::user_errors::UserError,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::controller::controller_port::{ControllerFuture, ControllerPort},
+    },
+};
+
+#[derive(Clone)]
+pub struct ChangeLogLevelController<U> {
+    change_log_level_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> ChangeLogLevelController<U>
+where
+    U: ChangeLogLevelUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        change_log_level_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            change_log_level_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for ChangeLogLevelController<U>
+where
+    U: ChangeLogLevelUseCasePort + Clone + Send + Sync,
+{
+    type Body = ChangeLogLevelDto;
+
+    fn handle(&self, http_request_dto: HttpRequestDto<Self::Body>) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            if !principal.is_admin {
+                return self
+                    .http_response_helper
+                    .problem(&ApiError::from(UserError::AdminPrivilegesRequired));
+            }
+
+            let change_log_level_dto = http_request_dto.body;
+
+            match self
+                .change_log_level_use_case
+                .perform(principal.user_id, change_log_level_dto)
+                .await
+            {
+                Ok(log_filter_dto) => {
+                    let body = json!({
+                        "log_level": {
+                            "directives": log_filter_dto.directives,
+                            "previous_directives": log_filter_dto.previous_directives,
+                            "revert_to": log_filter_dto.revert_to,
+                            "revert_after_seconds": log_filter_dto.revert_after_seconds
+                        }
+                    });
+
+                    self.http_response_helper.ok(Some(body))
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
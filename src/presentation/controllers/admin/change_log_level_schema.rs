@@ -0,0 +1,21 @@
+use crate::{
+    application::dtos::admin::change_log_level_dto::ChangeLogLevelDto,
+    presentation::{
+        ports::request_body::request_body_port::RequestBodyPort,
+        validation::validation_schema::{FieldRules, ValidationSchema},
+    },
+};
+
+/// Rules for the change log level request body.
+#[must_use]
+pub fn change_log_level_schema() -> ValidationSchema {
+    ValidationSchema::new()
+        .field("directives", FieldRules::string().required().not_blank())
+        .field("revertAfterSeconds", FieldRules::unsigned_integer())
+}
+
+impl RequestBodyPort for ChangeLogLevelDto {
+    fn schema() -> Option<ValidationSchema> {
+        Some(change_log_level_schema())
+    }
+}
@@ -0,0 +1,79 @@
+use serde_json::json;
+
+use crate::{
+    application::use_cases::admin::get_email_domain_policy_use_case::GetEmailDomainPolicyUseCasePort,
+    domain::errors::user::user_errors::UserError,
+    infrastructure::mappers::response::email_domain_policy::email_domain_policy_response::EmailDomainPolicyResponse,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct GetEmailDomainPolicyController<U> {
+    get_email_domain_policy_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> GetEmailDomainPolicyController<U>
+where
+    U: GetEmailDomainPolicyUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        get_email_domain_policy_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            get_email_domain_policy_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for GetEmailDomainPolicyController<U>
+where
+    U: GetEmailDomainPolicyUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto<Self::Body>) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            if !principal.is_admin {
+                return self
+                    .http_response_helper
+                    .problem(&ApiError::from(UserError::AdminPrivilegesRequired));
+            }
+
+            match self
+                .get_email_domain_policy_use_case
+                .perform(principal.user_id)
+                .await
+            {
+                Ok(email_domain_policy_entity) => {
+                    match EmailDomainPolicyResponse::try_from(email_domain_policy_entity) {
+                        Ok(email_domain_policy_response) => self.http_response_helper.ok(Some(
+                            json!({ "email_domain_policy": email_domain_policy_response }),
+                        )),
+                        Err(err) => self.http_response_helper.problem(&ApiError::internal(&err)),
+                    }
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use serde_json::json;
+
+use crate::{
+    application::use_cases::admin::import_users_use_case::ImportUsersUseCasePort,
+    domain::errors::user::user_errors::UserError,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::{
+            http::http_response_helper::HttpResponseHelper,
+            import::user_import_helper::{UserImportFormat, UserImportHelper},
+        },
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct ImportUsersController<U> {
+    user_import_helper: UserImportHelper,
+    import_users_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> ImportUsersController<U>
+where
+    U: ImportUsersUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        user_import_helper: UserImportHelper,
+        import_users_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            user_import_helper,
+            import_users_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for ImportUsersController<U>
+where
+    U: ImportUsersUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            if !principal.is_admin {
+                return self
+                    .http_response_helper
+                    .problem(&ApiError::from(UserError::AdminPrivilegesRequired));
+            }
+
+            let Some(format) = http_request_dto
+                .headers
+                .unwrap_or_default()
+                .get("content-type")
+                .and_then(|content_type| UserImportFormat::from_content_type(content_type))
+            else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::UnsupportedMediaType,
+                    "the import must be sent as text/csv or application/x-ndjson",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let Some(content) = http_request_dto.raw_body else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingRequestBody,
+                    "a request body was not provided",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let dry_run = http_request_dto
+                .query
+                .unwrap_or_default()
+                .get("dryRun")
+                .is_some_and(|dry_run| dry_run == "true" || dry_run == "1");
+
+            let import_users_dto = match self.user_import_helper.parse(format, &content, dry_run) {
+                Ok(import_users_dto) => import_users_dto,
+                Err(err) => {
+                    let api_error = ApiError::new(ApiErrorCode::InvalidImportFile, err.to_string());
+
+                    return self.http_response_helper.problem(&api_error);
+                }
+            };
+
+            match self.import_users_use_case.perform(import_users_dto).await {
+                Ok(import_users_report) => {
+                    let body = json!({ "report": import_users_report });
+
+                    self.http_response_helper.ok(Some(body))
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
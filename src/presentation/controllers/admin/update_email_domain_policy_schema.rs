@@ -0,0 +1,48 @@
+use crate::{
+    application::dtos::admin::update_email_domain_policy_dto::UpdateEmailDomainPolicyDto,
+    presentation::{
+        ports::request_body::request_body_port::RequestBodyPort,
+        validation::validation_schema::{FieldRules, ValidationSchema},
+    },
+};
+
+/// Most domains each list may hold, which keeps the cached policy small.
+const MAX_LISTED_DOMAINS: usize = 1000;
+
+/// A host name made of letter, digit and hyphen labels, ending in an alphabetic top-level label.
+const DOMAIN_PATTERN: &str =
+    r"\s*(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z]{2,63}\.?\s*";
+
+/// Longest domain the `email_domain_rules` table stores, as DNS allows.
+const MAX_DOMAIN_LENGTH: usize = 253;
+
+/// Rules for the e-mail domain policy request body, which replaces the whole policy.
+#[must_use]
+pub fn update_email_domain_policy_schema() -> ValidationSchema {
+    let domains = || {
+        FieldRules::array(
+            FieldRules::string()
+                .length(None, Some(MAX_DOMAIN_LENGTH))
+                .pattern(DOMAIN_PATTERN),
+        )
+        .required()
+        .items_count(None, Some(MAX_LISTED_DOMAINS))
+    };
+
+    ValidationSchema::new()
+        .field(
+            "mode",
+            FieldRules::string()
+                .required()
+                .one_of(&["allowlist", "denylist"]),
+        )
+        .field("blockDisposable", FieldRules::boolean().required())
+        .field("allowedDomains", domains())
+        .field("deniedDomains", domains())
+}
+
+impl RequestBodyPort for UpdateEmailDomainPolicyDto {
+    fn schema() -> Option<ValidationSchema> {
+        Some(update_email_domain_policy_schema())
+    }
+}
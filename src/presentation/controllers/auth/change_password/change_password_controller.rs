@@ -0,0 +1,82 @@
+use crate::{
+    application::{
+        dtos::auth::change_password_dto::ChangePasswordDto,
+        ports::password_policy::password_policy_port::PasswordPolicyPort,
+        use_cases::auth::change_password_use_case::ChangePasswordUseCasePort,
+    },
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::controller::controller_port::{ControllerFuture, ControllerPort},
+    },
+};
+
+#[derive(Clone)]
+pub struct ChangePasswordController<P, U> {
+    password_policy_adapter: P,
+    change_password_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<P, U> ChangePasswordController<P, U>
+where
+    P: PasswordPolicyPort + Clone + Send + Sync,
+    U: ChangePasswordUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        password_policy_adapter: P,
+        change_password_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            password_policy_adapter,
+            change_password_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<P, U> ControllerPort for ChangePasswordController<P, U>
+where
+    P: PasswordPolicyPort + Clone + Send + Sync,
+    U: ChangePasswordUseCasePort + Clone + Send + Sync,
+{
+    type Body = ChangePasswordDto;
+
+    fn handle(&self, http_request_dto: HttpRequestDto<Self::Body>) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let change_password_dto = http_request_dto.body;
+
+            if let Err(err) = self
+                .password_policy_adapter
+                .validate(
+                    &change_password_dto.new_password,
+                    &[&change_password_dto.email],
+                )
+                .await
+            {
+                return self.http_response_helper.problem(&ApiError::from(err));
+            }
+
+            match self
+                .change_password_use_case
+                .perform(change_password_dto)
+                .await
+            {
+                Ok(result) => result.map_or_else(
+                    || {
+                        let api_error = ApiError::new(
+                            ApiErrorCode::InvalidCredentials,
+                            "the provided credentials are invalid",
+                        );
+
+                        self.http_response_helper.problem(&api_error)
+                    },
+                    |_| self.http_response_helper.no_content(None),
+                ),
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    application::dtos::auth::change_password_dto::ChangePasswordDto,
+    presentation::{
+        ports::request_body::request_body_port::RequestBodyPort,
+        validation::validation_schema::{FieldRules, ValidationSchema},
+    },
+};
+
+/// Rules for the change password request body.
+#[must_use]
+pub fn change_password_schema() -> ValidationSchema {
+    ValidationSchema::new()
+        .field("email", FieldRules::string().required().not_blank())
+        .field("password", FieldRules::string().required().not_blank())
+        .field("newPassword", FieldRules::string().required().not_blank())
+        .field(
+            "newPasswordConfirmation",
+            FieldRules::string().required().not_blank(),
+        )
+        .equal_fields("newPasswordConfirmation", "newPassword")
+}
+
+impl RequestBodyPort for ChangePasswordDto {
+    fn schema() -> Option<ValidationSchema> {
+        Some(change_password_schema())
+    }
+}
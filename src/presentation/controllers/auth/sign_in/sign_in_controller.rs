@@ -6,36 +6,33 @@ use crate::{
         ports::pattern_matching::pattern_matching_port::{
             PatternMatchingError, PatternMatchingPort,
         },
-        use_cases::auth::sign_in_use_case::{SignInUseCaseError, SignInUseCasePort},
+        use_cases::auth::sign_in_use_case::SignInUseCasePort,
     },
     presentation::{
-        dtos::http::{http_request_dto::HttpRequestDto, http_response_dto::HttpResponseDto},
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
         helpers::http::{
             http_body_helper::HttpBodyHelper, http_response_helper::HttpResponseHelper,
         },
-        ports::{
-            controller::controller_port::{ControllerFuture, ControllerPort},
-            validator::validator_port::ValidatorPort,
-        },
+        ports::controller::controller_port::{ControllerFuture, ControllerPort},
     },
 };
 
 #[derive(Clone)]
-pub struct SignInController<V, P, U> {
-    http_body_helper: HttpBodyHelper<V>,
+pub struct SignInController<P, U> {
+    http_body_helper: HttpBodyHelper,
     pattern_matching_adapter: P,
     sign_in_use_case: U,
     http_response_helper: HttpResponseHelper,
 }
 
-impl<V, P, U> SignInController<V, P, U>
+impl<P, U> SignInController<P, U>
 where
-    V: ValidatorPort + Clone + Send + Sync,
     P: PatternMatchingPort + Clone + Send + Sync,
     U: SignInUseCasePort + Clone + Send + Sync,
 {
     pub const fn new(
-        http_body_helper: HttpBodyHelper<V>,
+        http_body_helper: HttpBodyHelper,
         pattern_matching_adapter: P,
         sign_in_use_case: U,
         http_response_helper: HttpResponseHelper,
@@ -49,87 +46,34 @@ where
     }
 }
 
-impl<V, P, U> ControllerPort for SignInController<V, P, U>
+impl<P, U> ControllerPort for SignInController<P, U>
 where
-    V: ValidatorPort + Clone + Send + Sync,
     P: PatternMatchingPort + Clone + Send + Sync,
     U: SignInUseCasePort + Clone + Send + Sync,
 {
-    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
-        Box::pin(async move {
-            if let Some(http_response_dto) = self
-                .http_body_helper
-                .validate_request_body(http_request_dto.body.clone())
-            {
-                return http_response_dto;
-            }
-
-            let extracted_body = http_request_dto.body.unwrap();
-
-            let is_valid_email = self
-                .pattern_matching_adapter
-                .is_valid_email(extracted_body["email"].as_str().unwrap());
-
-            match is_valid_email {
-                Ok(result) => {
-                    if !result {
-                        let body = json!({
-                            "error_code": "invalid_email",
-                            "error_message": PatternMatchingError::InvalidEmail.to_string(),
-                        });
-
-                        return self.http_response_helper.bad_request(Some(body));
-                    }
-                }
-                Err(err) => {
-                    let body = json!({
-                        "error_code": "invalid_regex",
-                        "error_message": err.to_string(),
-                    });
+    type Body = SignInDto;
 
-                    return self.http_response_helper.internal_server_error(Some(body));
-                }
-            }
-
-            let is_valid_email_domain = self
-                .pattern_matching_adapter
-                .is_valid_email_domain(extracted_body["email"].as_str().unwrap());
-
-            match is_valid_email_domain {
-                Ok(result) => {
-                    if !result {
-                        let body = Some(json!({
-                            "error_code": "invalid_email_domain",
-                            "error_message": PatternMatchingError::InvalidEmailDomain.to_string(),
-                        }));
-
-                        return self.http_response_helper.bad_request(body);
-                    }
-                }
-                Err(err) => {
-                    let body = json!({
-                        "error_code": "invalid_regex",
-                        "error_message": err.to_string(),
-                    });
+    fn handle(&self, http_request_dto: HttpRequestDto<Self::Body>) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let sign_in_dto = http_request_dto.body;
 
-                    return self.http_response_helper.internal_server_error(Some(body));
-                }
+            if let Some(http_response_dto) = self.http_body_helper.validate_regex(
+                &sign_in_dto.email,
+                |v| self.pattern_matching_adapter.is_valid_email(v),
+                PatternMatchingError::InvalidEmail,
+            ) {
+                return http_response_dto;
             }
 
-            let sign_in_dto = SignInDto::new(
-                extracted_body["email"].as_str().unwrap().to_string(),
-                extracted_body["password"].as_str().unwrap().to_string(),
-            );
-
             match self.sign_in_use_case.perform(sign_in_dto).await {
                 Ok(result) => result.map_or_else(
                     || {
-                        let body = json!({
-                            "error_code": "invalid credentials",
-                            "error_message": "the provided credentials are invalid"
-                        });
+                        let api_error = ApiError::new(
+                            ApiErrorCode::InvalidCredentials,
+                            "the provided credentials are invalid",
+                        );
 
-                        self.http_response_helper.unauthorized(Some(body))
+                        self.http_response_helper.problem(&api_error)
                     },
                     |generated_auth_token| {
                         let body = json!({ "accessToken":  generated_auth_token });
@@ -137,28 +81,7 @@ where
                         self.http_response_helper.ok(Some(body))
                     },
                 ),
-                Err(err) => {
-                    let (error_code, error_message) = match err {
-                        SignInUseCaseError::HasherError(error) => {
-                            ("use_case_error", error.to_string())
-                        }
-                        SignInUseCaseError::AuthError(error) => {
-                            ("use_case_error", error.to_string())
-                        }
-                        SignInUseCaseError::DatabaseError(error) => {
-                            ("repository_error", error.to_string())
-                        }
-                    };
-
-                    HttpResponseDto {
-                        status_code: 400,
-                        body: Some(json!({
-                            "error_code": error_code,
-                            "error_message": error_message
-                        })),
-                        headers: None,
-                    }
-                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
             }
         })
     }
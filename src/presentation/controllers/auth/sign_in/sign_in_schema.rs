@@ -0,0 +1,21 @@
+use crate::{
+    application::dtos::auth::sign_in_dto::SignInDto,
+    presentation::{
+        ports::request_body::request_body_port::RequestBodyPort,
+        validation::validation_schema::{FieldRules, ValidationSchema},
+    },
+};
+
+/// Rules for the sign-in request body.
+#[must_use]
+pub fn sign_in_schema() -> ValidationSchema {
+    ValidationSchema::new()
+        .field("email", FieldRules::string().required().not_blank())
+        .field("password", FieldRules::string().required().not_blank())
+}
+
+impl RequestBodyPort for SignInDto {
+    fn schema() -> Option<ValidationSchema> {
+        Some(sign_in_schema())
+    }
+}
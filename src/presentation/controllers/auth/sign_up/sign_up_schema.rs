@@ -0,0 +1,39 @@
+use crate::{
+    application::dtos::auth::sign_up_dto::SignUpDto,
+    presentation::{
+        ports::request_body::request_body_port::RequestBodyPort,
+        validation::validation_schema::{FieldRules, ValidationSchema},
+    },
+};
+
+/// Longest name or e-mail the `users` table stores.
+const MAX_TEXT_LENGTH: usize = 255;
+
+/// Rules for the sign-up request body. The e-mail format and domain and the password policy are
+/// checked afterwards, and reported with their own problem codes.
+#[must_use]
+pub fn sign_up_schema() -> ValidationSchema {
+    let text = || {
+        FieldRules::string()
+            .required()
+            .not_blank()
+            .length(None, Some(MAX_TEXT_LENGTH))
+    };
+
+    ValidationSchema::new()
+        .field("firstName", text())
+        .field("lastName", text())
+        .field("email", text())
+        .field("password", FieldRules::string().required().not_blank())
+        .field(
+            "passwordConfirmation",
+            FieldRules::string().required().not_blank(),
+        )
+        .equal_fields("passwordConfirmation", "password")
+}
+
+impl RequestBodyPort for SignUpDto {
+    fn schema() -> Option<ValidationSchema> {
+        Some(sign_up_schema())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    application::{
+        ports::pattern_matching::pattern_matching_port::{
+            PatternMatchingError, PatternMatchingPort,
+        },
+        use_cases::data_export::download_data_export_use_case::DownloadDataExportUseCasePort,
+    },
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct DownloadDataExportController<P, U> {
+    pattern_matching_adapter: P,
+    download_data_export_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<P, U> DownloadDataExportController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: DownloadDataExportUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        pattern_matching_adapter: P,
+        download_data_export_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            download_data_export_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<P, U> ControllerPort for DownloadDataExportController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: DownloadDataExportUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let id = http_request_dto
+                .params
+                .and_then(|params| params.get("id").cloned())
+                .unwrap_or_default();
+
+            match self.pattern_matching_adapter.is_valid_uuid(&id) {
+                Ok(true) => {}
+                Ok(false) => {
+                    return self
+                        .http_response_helper
+                        .problem(&ApiError::from(PatternMatchingError::InvalidUuid));
+                }
+                Err(err) => {
+                    return self.http_response_helper.problem(&ApiError::from(err));
+                }
+            }
+
+            match self
+                .download_data_export_use_case
+                .perform(principal, id)
+                .await
+            {
+                Ok(Some(archive)) => self.http_response_helper.file(
+                    archive.content,
+                    &archive.content_type,
+                    &archive.file_name,
+                ),
+                Ok(None) => {
+                    let api_error = ApiError::new(
+                        ApiErrorCode::DataExportNotFound,
+                        "no data export with the provided id was found",
+                    );
+
+                    self.http_response_helper.problem(&api_error)
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
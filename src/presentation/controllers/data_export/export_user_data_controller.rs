@@ -0,0 +1,118 @@
+use serde_json::json;
+
+use crate::{
+    application::{
+        ports::pattern_matching::pattern_matching_port::{
+            PatternMatchingError, PatternMatchingPort,
+        },
+        use_cases::data_export::request_data_export_use_case::{
+            DataExportOutcome, RequestDataExportUseCasePort,
+        },
+    },
+    domain::errors::user::user_errors::UserError,
+    infrastructure::mappers::response::data_export::data_export_response::DataExportResponse,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+/// Serves both `/me/export` and its administrative counterpart `/admin/users/{id}/export`; the
+/// presence of the `id` path parameter selects the latter.
+#[derive(Clone)]
+pub struct ExportUserDataController<P, U> {
+    pattern_matching_adapter: P,
+    request_data_export_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<P, U> ExportUserDataController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: RequestDataExportUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        pattern_matching_adapter: P,
+        request_data_export_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            request_data_export_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<P, U> ControllerPort for ExportUserDataController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: RequestDataExportUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let target_user_id = match http_request_dto
+                .params
+                .and_then(|params| params.get("id").cloned())
+            {
+                None => principal.user_id.clone(),
+                Some(_) if !principal.is_admin => {
+                    return self
+                        .http_response_helper
+                        .problem(&ApiError::from(UserError::AdminPrivilegesRequired));
+                }
+                Some(id) => match self.pattern_matching_adapter.is_valid_uuid(&id) {
+                    Ok(true) => id,
+                    Ok(false) => {
+                        return self
+                            .http_response_helper
+                            .problem(&ApiError::from(PatternMatchingError::InvalidUuid));
+                    }
+                    Err(err) => {
+                        return self.http_response_helper.problem(&ApiError::from(err));
+                    }
+                },
+            };
+
+            match self
+                .request_data_export_use_case
+                .perform(principal.user_id, target_user_id)
+                .await
+            {
+                Ok(DataExportOutcome::Ready(archive)) => self.http_response_helper.file(
+                    archive.content,
+                    &archive.content_type,
+                    &archive.file_name,
+                ),
+                Ok(DataExportOutcome::Scheduled(data_export_entity)) => {
+                    match DataExportResponse::try_from(data_export_entity) {
+                        Ok(data_export_response) => {
+                            let location = format!("/me/exports/{}", data_export_response.id);
+
+                            self.http_response_helper
+                                .accepted(json!({ "data_export": data_export_response }), &location)
+                        }
+                        Err(err) => self.http_response_helper.problem(&ApiError::internal(&err)),
+                    }
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
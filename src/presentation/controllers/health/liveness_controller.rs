@@ -0,0 +1,36 @@
+use serde_json::json;
+
+use crate::presentation::{
+    dtos::http::http_request_dto::HttpRequestDto,
+    helpers::http::http_response_helper::HttpResponseHelper,
+    ports::{
+        controller::controller_port::{ControllerFuture, ControllerPort},
+        request_body::request_body_port::NoBody,
+    },
+};
+
+/// Answers as long as the process can serve requests; dependencies are left to readiness.
+#[derive(Clone)]
+pub struct LivenessController {
+    http_response_helper: HttpResponseHelper,
+}
+
+impl LivenessController {
+    #[must_use]
+    pub const fn new(http_response_helper: HttpResponseHelper) -> Self {
+        Self {
+            http_response_helper,
+        }
+    }
+}
+
+impl ControllerPort for LivenessController {
+    type Body = NoBody;
+
+    fn handle(&self, _http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            self.http_response_helper
+                .ok(Some(json!({ "status": "up" })))
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use serde_json::{Map, Value, json};
+
+use crate::{
+    application::{
+        ports::health::health_check_port::HealthStatus,
+        use_cases::health::check_readiness_use_case::CheckReadinessUseCasePort,
+    },
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+/// Reports every dependency check; answers 503 as soon as one of them is down so load balancers
+/// stop routing traffic to this instance.
+#[derive(Clone)]
+pub struct ReadinessController<U> {
+    check_readiness_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> ReadinessController<U>
+where
+    U: CheckReadinessUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        check_readiness_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            check_readiness_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for ReadinessController<U>
+where
+    U: CheckReadinessUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, _http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let readiness_report = self.check_readiness_use_case.perform().await;
+
+            let checks = readiness_report
+                .checks
+                .into_iter()
+                .map(|check| {
+                    let mut body = json!({
+                        "status": check.result.status.as_str(),
+                        "latency_ms": check.latency_ms,
+                    });
+
+                    if !check.result.metrics.is_empty() {
+                        body["metrics"] = json!(check.result.metrics);
+                    }
+
+                    if let Some(error) = check.result.error {
+                        body["error"] = json!(error);
+                    }
+
+                    (check.name.to_string(), body)
+                })
+                .collect::<Map<String, Value>>();
+
+            let body = json!({
+                "status": readiness_report.status.as_str(),
+                "checks": checks,
+            });
+
+            match readiness_report.status {
+                HealthStatus::Up => self.http_response_helper.ok(Some(body)),
+                HealthStatus::Down => self.http_response_helper.service_unavailable(Some(body)),
+            }
+        })
+    }
+}
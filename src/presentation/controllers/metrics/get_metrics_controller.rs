@@ -0,0 +1,51 @@
+use crate::{
+    application::ports::metrics::metrics_port::MetricsPort,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::ApiError,
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+/// Content type of the Prometheus text exposition format.
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+#[derive(Clone)]
+pub struct GetMetricsController<M> {
+    metrics_adapter: M,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<M> GetMetricsController<M>
+where
+    M: MetricsPort + Clone + Send + Sync,
+{
+    pub const fn new(metrics_adapter: M, http_response_helper: HttpResponseHelper) -> Self {
+        Self {
+            metrics_adapter,
+            http_response_helper,
+        }
+    }
+}
+
+impl<M> ControllerPort for GetMetricsController<M>
+where
+    M: MetricsPort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, _http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            match self.metrics_adapter.export() {
+                Ok(metrics) => self
+                    .http_response_helper
+                    .text(metrics, METRICS_CONTENT_TYPE),
+                Err(err) => self.http_response_helper.problem(&ApiError::internal(&err)),
+            }
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    application::{
+        ports::pattern_matching::pattern_matching_port::{
+            PatternMatchingError, PatternMatchingPort,
+        },
+        use_cases::user::get_avatar_use_case::{GetAvatarUseCaseError, GetAvatarUseCasePort},
+    },
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+/// Avatar URLs change with every upload, so responses can be cached for a full day.
+const AVATAR_MAX_AGE_SECONDS: u32 = 86_400;
+
+#[derive(Clone)]
+pub struct GetAvatarController<P, U> {
+    pattern_matching_adapter: P,
+    get_avatar_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<P, U> GetAvatarController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: GetAvatarUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        pattern_matching_adapter: P,
+        get_avatar_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            pattern_matching_adapter,
+            get_avatar_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<P, U> ControllerPort for GetAvatarController<P, U>
+where
+    P: PatternMatchingPort + Clone + Send + Sync,
+    U: GetAvatarUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let id = http_request_dto
+                .params
+                .and_then(|params| params.get("id").cloned())
+                .unwrap_or_default();
+
+            match self.pattern_matching_adapter.is_valid_uuid(&id) {
+                Ok(true) => {}
+                Ok(false) => {
+                    return self
+                        .http_response_helper
+                        .problem(&ApiError::from(PatternMatchingError::InvalidUuid));
+                }
+                Err(err) => {
+                    return self.http_response_helper.problem(&ApiError::from(err));
+                }
+            }
+
+            let size = match http_request_dto.query.unwrap_or_default().get("size") {
+                None => None,
+                Some(size) => {
+                    if let Ok(size) = size.parse::<u32>() {
+                        Some(size)
+                    } else {
+                        return self
+                            .http_response_helper
+                            .problem(&ApiError::from(GetAvatarUseCaseError::InvalidAvatarSize));
+                    }
+                }
+            };
+
+            let if_none_match = http_request_dto
+                .headers
+                .unwrap_or_default()
+                .remove("if-none-match");
+
+            match self.get_avatar_use_case.perform(id, size).await {
+                Ok(Some(avatar)) => {
+                    if if_none_match.as_deref() == Some(avatar.etag.as_str()) {
+                        return self
+                            .http_response_helper
+                            .not_modified(&avatar.etag, AVATAR_MAX_AGE_SECONDS);
+                    }
+
+                    self.http_response_helper.cached_file(
+                        avatar.content,
+                        &avatar.content_type,
+                        &avatar.etag,
+                        AVATAR_MAX_AGE_SECONDS,
+                    )
+                }
+                Ok(None) => {
+                    let api_error = ApiError::new(
+                        ApiErrorCode::AvatarNotFound,
+                        "the user has not uploaded an avatar",
+                    );
+
+                    self.http_response_helper.problem(&api_error)
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
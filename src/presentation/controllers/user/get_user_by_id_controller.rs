@@ -10,8 +10,12 @@ use crate::{
     infrastructure::mappers::response::user::user_response::UserResponse,
     presentation::{
         dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
         helpers::http::http_response_helper::HttpResponseHelper,
-        ports::controller::controller_port::{ControllerFuture, ControllerPort},
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
     },
 };
 
@@ -45,6 +49,8 @@ where
     P: PatternMatchingPort + Clone + Send + Sync,
     U: GetUserByIdUseCasePort + Clone + Send + Sync,
 {
+    type Body = NoBody;
+
     fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
         Box::pin(async move {
             let params = http_request_dto.params.unwrap();
@@ -54,33 +60,25 @@ where
             match is_valid_uuid {
                 Ok(result) => {
                     if !result {
-                        let body = json!({
-                            "error_code": "invalid_uuid",
-                            "error_message": PatternMatchingError::InvalidUuid.to_string(),
-                        });
-
-                        return self.http_response_helper.bad_request(Some(body));
+                        return self
+                            .http_response_helper
+                            .problem(&ApiError::from(PatternMatchingError::InvalidUuid));
                     }
                 }
                 Err(err) => {
-                    let body = json!({
-                        "error_code": "invalid_regex",
-                        "error_message": err.to_string(),
-                    });
-
-                    return self.http_response_helper.internal_server_error(Some(body));
+                    return self.http_response_helper.problem(&ApiError::from(err));
                 }
             }
 
-            match self.get_user_by_id_use_case.perform(id.to_string()).await {
+            match self.get_user_by_id_use_case.perform(id.clone()).await {
                 Ok(result) => result.map_or_else(
                     || {
-                        let body = json!({
-                            "error_code": "user_not_found",
-                            "error_message": "no user with the provided id was found"
-                        });
+                        let api_error = ApiError::new(
+                            ApiErrorCode::UserNotFound,
+                            "no user with the provided id was found",
+                        );
 
-                        self.http_response_helper.not_found(Some(body))
+                        self.http_response_helper.problem(&api_error)
                     },
                     |user_entity| {
                         let user_response = UserResponse::from(user_entity);
@@ -89,14 +87,7 @@ where
                         self.http_response_helper.ok(Some(body))
                     },
                 ),
-                Err(err) => {
-                    let body = json!({
-                        "error_code": "internal_server_error",
-                        "error_message": err.to_string()
-                    });
-
-                    self.http_response_helper.internal_server_error(Some(body))
-                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
             }
         })
     }
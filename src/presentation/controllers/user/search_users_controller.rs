@@ -0,0 +1,89 @@
+use serde_json::json;
+
+use crate::{
+    application::{
+        ports::user_search::user_search_port::UserSearchQuery,
+        use_cases::user::search_users_use_case::{
+            DEFAULT_SEARCH_PAGE_SIZE, SearchUsersUseCasePort,
+        },
+    },
+    infrastructure::mappers::response::user::user_search_response::UserSearchResponse,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::http::http_response_helper::HttpResponseHelper,
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct SearchUsersController<U> {
+    search_users_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> SearchUsersController<U>
+where
+    U: SearchUsersUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(search_users_use_case: U, http_response_helper: HttpResponseHelper) -> Self {
+        Self {
+            search_users_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for SearchUsersController<U>
+where
+    U: SearchUsersUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let query = http_request_dto.query.unwrap_or_default();
+
+            let Some(term) = query.get("q").filter(|term| !term.trim().is_empty()) else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingSearchQuery,
+                    "a non-empty 'q' query parameter must be provided",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let page = query.get("page").map_or(Ok(1), |page| page.parse::<u32>());
+
+            let per_page = query
+                .get("perPage")
+                .map_or(Ok(DEFAULT_SEARCH_PAGE_SIZE), |per_page| {
+                    per_page.parse::<u32>()
+                });
+
+            let (Ok(page), Ok(per_page)) = (page, per_page) else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::InvalidPagination,
+                    "'page' and 'perPage' must be positive integers",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let user_search_query = UserSearchQuery::new(term.clone(), page, per_page);
+
+            match self.search_users_use_case.perform(user_search_query).await {
+                Ok(user_search_page) => {
+                    let user_search_response = UserSearchResponse::from(user_search_page);
+
+                    self.http_response_helper
+                        .ok(Some(json!(user_search_response)))
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
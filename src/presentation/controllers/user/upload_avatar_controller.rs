@@ -0,0 +1,107 @@
+use serde_json::json;
+
+use crate::{
+    application::use_cases::user::upload_avatar_use_case::UploadAvatarUseCasePort,
+    infrastructure::mappers::response::user::user_response::UserResponse,
+    presentation::{
+        dtos::http::http_request_dto::HttpRequestDto,
+        errors::api_error::{ApiError, ApiErrorCode},
+        helpers::{
+            http::http_response_helper::HttpResponseHelper,
+            multipart::multipart_helper::MultipartHelper,
+        },
+        ports::{
+            controller::controller_port::{ControllerFuture, ControllerPort},
+            request_body::request_body_port::NoBody,
+        },
+    },
+};
+
+const AVATAR_FIELD_NAME: &str = "avatar";
+
+#[derive(Clone)]
+pub struct UploadAvatarController<U> {
+    multipart_helper: MultipartHelper,
+    upload_avatar_use_case: U,
+    http_response_helper: HttpResponseHelper,
+}
+
+impl<U> UploadAvatarController<U>
+where
+    U: UploadAvatarUseCasePort + Clone + Send + Sync,
+{
+    pub const fn new(
+        multipart_helper: MultipartHelper,
+        upload_avatar_use_case: U,
+        http_response_helper: HttpResponseHelper,
+    ) -> Self {
+        Self {
+            multipart_helper,
+            upload_avatar_use_case,
+            http_response_helper,
+        }
+    }
+}
+
+impl<U> ControllerPort for UploadAvatarController<U>
+where
+    U: UploadAvatarUseCasePort + Clone + Send + Sync,
+{
+    type Body = NoBody;
+
+    fn handle(&self, http_request_dto: HttpRequestDto) -> ControllerFuture<'_> {
+        Box::pin(async move {
+            let Some(principal) = http_request_dto.principal else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::MissingPrincipal,
+                    "the request is not authenticated",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let (Some(content_type), Some(multipart_body)) = (
+                http_request_dto
+                    .headers
+                    .unwrap_or_default()
+                    .remove("content-type"),
+                http_request_dto.binary_body,
+            ) else {
+                let api_error = ApiError::new(
+                    ApiErrorCode::UnsupportedMediaType,
+                    "the avatar must be sent as multipart/form-data",
+                );
+
+                return self.http_response_helper.problem(&api_error);
+            };
+
+            let content = match self
+                .multipart_helper
+                .extract_field(&content_type, multipart_body, AVATAR_FIELD_NAME)
+                .await
+            {
+                Ok(content) => content,
+                Err(err) => {
+                    let api_error =
+                        ApiError::new(ApiErrorCode::InvalidMultipartBody, err.to_string());
+
+                    return self.http_response_helper.problem(&api_error);
+                }
+            };
+
+            match self
+                .upload_avatar_use_case
+                .perform(principal.user_id, content)
+                .await
+            {
+                Ok(user_entity) => {
+                    let user_response = UserResponse::from(user_entity);
+
+                    self.http_response_helper
+                        .ok(Some(json!({ "user": user_response })))
+                }
+                Err(err) => self.http_response_helper.problem(&ApiError::from(err)),
+            }
+        })
+    }
+}
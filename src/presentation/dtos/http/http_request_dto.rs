@@ -1,10 +1,21 @@
 use std::collections::HashMap;
 
-use serde_json::Value;
+use crate::{
+    application::dtos::auth::auth_principal_dto::AuthPrincipalDto,
+    presentation::ports::request_body::request_body_port::NoBody,
+};
 
-pub struct HttpRequestDto {
-    pub body: Option<Value>,
+pub struct HttpRequestDto<B = NoBody> {
+    /// JSON body, already validated and deserialized into the controller's body type.
+    pub body: B,
+    /// Raw textual body, only filled for non-JSON payloads such as CSV or JSON Lines.
+    pub raw_body: Option<String>,
+    /// Raw binary body, only filled for `multipart/form-data` uploads.
+    pub binary_body: Option<Vec<u8>>,
+    pub headers: Option<HashMap<String, String>>,
     pub method: String,
     pub url: String,
     pub params: Option<HashMap<String, String>>,
+    pub query: Option<HashMap<String, String>>,
+    pub principal: Option<AuthPrincipalDto>,
 }
@@ -6,4 +6,6 @@ pub struct HttpResponseDto {
     pub status_code: u16,
     pub body: Option<Value>,
     pub headers: Option<HashMap<String, String>>,
+    /// Binary payload sent as-is instead of `body`, e.g. a downloadable file.
+    pub raw_body: Option<Vec<u8>>,
 }
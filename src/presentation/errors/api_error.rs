@@ -0,0 +1,288 @@
+use axum::{
+    body::Body,
+    http::{Response, StatusCode, header},
+};
+use serde_json::{Map, Value, json};
+
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Detail sent for server errors, whose actual cause is only logged.
+const INTERNAL_ERROR_DETAIL: &str = "an unexpected error occurred while processing the request";
+
+/// Catalog of every error the API answers with. The codes are part of the public contract, since
+/// clients branch on them, so an existing code must never be renamed or reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorCode {
+    MissingRequestBody,
+    MalformedRequestBody,
+    InvalidRequestBody,
+    InvalidEmail,
+    InvalidEmailDomain,
+    InvalidPassword,
+    InvalidUuid,
+    PasswordsDoNotMatch,
+    InvalidPagination,
+    MissingSearchQuery,
+    InvalidImportFile,
+    InvalidMultipartBody,
+    InvalidImage,
+    InvalidAvatarSize,
+    InvalidLogDirectives,
+    InvalidRevertDelay,
+    InvalidEmailDomainPolicy,
+    MissingAuthorizationHeader,
+    MissingPrincipal,
+    InvalidCredentials,
+    InvalidToken,
+    ExpiredToken,
+    RevokedToken,
+    AccountSuspended,
+    PasswordResetRequired,
+    AdminPrivilegesRequired,
+    RouteNotFound,
+    UnknownAdminAction,
+    UserNotFound,
+    AvatarNotFound,
+    DataExportNotFound,
+    UserAlreadyExists,
+    CannotTargetSelf,
+    DataExportNotReady,
+    MethodNotAllowed,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    TooManyRequests,
+    InternalServerError,
+}
+
+impl ApiErrorCode {
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::MissingRequestBody => "missing_request_body",
+            Self::MalformedRequestBody => "malformed_request_body",
+            Self::InvalidRequestBody => "invalid_request_body",
+            Self::InvalidEmail => "invalid_email",
+            Self::InvalidEmailDomain => "invalid_email_domain",
+            Self::InvalidPassword => "invalid_password",
+            Self::InvalidUuid => "invalid_uuid",
+            Self::PasswordsDoNotMatch => "passwords_do_not_match",
+            Self::InvalidPagination => "invalid_pagination",
+            Self::MissingSearchQuery => "missing_search_query",
+            Self::InvalidImportFile => "invalid_import_file",
+            Self::InvalidMultipartBody => "invalid_multipart_body",
+            Self::InvalidImage => "invalid_image",
+            Self::InvalidAvatarSize => "invalid_avatar_size",
+            Self::InvalidLogDirectives => "invalid_log_directives",
+            Self::InvalidRevertDelay => "invalid_revert_delay",
+            Self::InvalidEmailDomainPolicy => "invalid_email_domain_policy",
+            Self::MissingAuthorizationHeader => "missing_authorization_header",
+            Self::MissingPrincipal => "missing_principal",
+            Self::InvalidCredentials => "invalid_credentials",
+            Self::InvalidToken => "invalid_token",
+            Self::ExpiredToken => "expired_token",
+            Self::RevokedToken => "revoked_token",
+            Self::AccountSuspended => "account_suspended",
+            Self::PasswordResetRequired => "password_reset_required",
+            Self::AdminPrivilegesRequired => "admin_privileges_required",
+            Self::RouteNotFound => "route_not_found",
+            Self::UnknownAdminAction => "unknown_admin_action",
+            Self::UserNotFound => "user_not_found",
+            Self::AvatarNotFound => "avatar_not_found",
+            Self::DataExportNotFound => "data_export_not_found",
+            Self::UserAlreadyExists => "user_already_exists",
+            Self::CannotTargetSelf => "cannot_target_self",
+            Self::DataExportNotReady => "data_export_not_ready",
+            Self::MethodNotAllowed => "method_not_allowed",
+            Self::PayloadTooLarge => "payload_too_large",
+            Self::UnsupportedMediaType => "unsupported_media_type",
+            Self::TooManyRequests => "too_many_requests",
+            Self::InternalServerError => "internal_server_error",
+        }
+    }
+
+    #[must_use]
+    pub const fn status(self) -> u16 {
+        match self {
+            Self::MissingRequestBody
+            | Self::MalformedRequestBody
+            | Self::InvalidRequestBody
+            | Self::InvalidEmail
+            | Self::InvalidEmailDomain
+            | Self::InvalidPassword
+            | Self::InvalidUuid
+            | Self::PasswordsDoNotMatch
+            | Self::InvalidPagination
+            | Self::MissingSearchQuery
+            | Self::InvalidImportFile
+            | Self::InvalidMultipartBody
+            | Self::InvalidImage
+            | Self::InvalidAvatarSize
+            | Self::InvalidLogDirectives
+            | Self::InvalidRevertDelay
+            | Self::InvalidEmailDomainPolicy => 400,
+            Self::MissingAuthorizationHeader
+            | Self::MissingPrincipal
+            | Self::InvalidCredentials
+            | Self::InvalidToken
+            | Self::ExpiredToken
+            | Self::RevokedToken => 401,
+            Self::AccountSuspended
+            | Self::PasswordResetRequired
+            | Self::AdminPrivilegesRequired => 403,
+            Self::RouteNotFound
+            | Self::UnknownAdminAction
+            | Self::UserNotFound
+            | Self::AvatarNotFound
+            | Self::DataExportNotFound => 404,
+            Self::UserAlreadyExists | Self::CannotTargetSelf | Self::DataExportNotReady => 409,
+            Self::MethodNotAllowed => 405,
+            Self::PayloadTooLarge => 413,
+            Self::UnsupportedMediaType => 415,
+            Self::TooManyRequests => 429,
+            Self::InternalServerError => 500,
+        }
+    }
+
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::MissingRequestBody => "Missing request body",
+            Self::MalformedRequestBody => "Malformed request body",
+            Self::InvalidRequestBody => "Invalid request body",
+            Self::InvalidEmail => "Invalid e-mail",
+            Self::InvalidEmailDomain => "E-mail domain not allowed",
+            Self::InvalidPassword => "Invalid password",
+            Self::InvalidUuid => "Invalid identifier",
+            Self::PasswordsDoNotMatch => "Passwords do not match",
+            Self::InvalidPagination => "Invalid pagination",
+            Self::MissingSearchQuery => "Missing search query",
+            Self::InvalidImportFile => "Invalid import file",
+            Self::InvalidMultipartBody => "Invalid multipart body",
+            Self::InvalidImage => "Invalid image",
+            Self::InvalidAvatarSize => "Invalid avatar size",
+            Self::InvalidLogDirectives => "Invalid log directives",
+            Self::InvalidRevertDelay => "Invalid revert delay",
+            Self::InvalidEmailDomainPolicy => "Invalid e-mail domain policy",
+            Self::MissingAuthorizationHeader => "Missing authorization header",
+            Self::MissingPrincipal => "Not authenticated",
+            Self::InvalidCredentials => "Invalid credentials",
+            Self::InvalidToken => "Invalid token",
+            Self::ExpiredToken => "Expired token",
+            Self::RevokedToken => "Revoked token",
+            Self::AccountSuspended => "Account suspended",
+            Self::PasswordResetRequired => "Password reset required",
+            Self::AdminPrivilegesRequired => "Administrator privileges required",
+            Self::RouteNotFound => "Route not found",
+            Self::UnknownAdminAction => "Unknown administration action",
+            Self::UserNotFound => "User not found",
+            Self::AvatarNotFound => "Avatar not found",
+            Self::DataExportNotFound => "Data export not found",
+            Self::UserAlreadyExists => "User already exists",
+            Self::CannotTargetSelf => "Cannot target own account",
+            Self::DataExportNotReady => "Data export not ready",
+            Self::MethodNotAllowed => "Method not allowed",
+            Self::PayloadTooLarge => "Payload too large",
+            Self::UnsupportedMediaType => "Unsupported media type",
+            Self::TooManyRequests => "Too many requests",
+            Self::InternalServerError => "Internal server error",
+        }
+    }
+
+    /// Problem type URI, relative to the API's own origin as RFC 9457 allows.
+    #[must_use]
+    pub fn problem_type(self) -> String {
+        format!("/problems/{}", self.code().replace('_', "-"))
+    }
+}
+
+/// An error rendered as an RFC 9457 `application/problem+json` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    code: ApiErrorCode,
+    detail: String,
+    extensions: Map<String, Value>,
+}
+
+impl ApiError {
+    #[must_use]
+    pub fn new(code: ApiErrorCode, detail: impl Into<String>) -> Self {
+        Self {
+            code,
+            detail: detail.into(),
+            extensions: Map::new(),
+        }
+    }
+
+    /// Logs `error` and hides it behind a generic detail, so internals such as SQL errors never
+    /// reach the client.
+    #[must_use]
+    pub fn internal(error: &impl std::fmt::Display) -> Self {
+        tracing::error!("Request failed with an internal error: {error}.");
+
+        Self::new(ApiErrorCode::InternalServerError, INTERNAL_ERROR_DETAIL)
+    }
+
+    /// Adds a member such as `errors` next to the standard ones.
+    #[must_use]
+    pub fn with_extension(mut self, key: &str, value: Value) -> Self {
+        self.extensions.insert(key.to_string(), value);
+        self
+    }
+
+    #[must_use]
+    pub const fn code(&self) -> ApiErrorCode {
+        self.code
+    }
+
+    #[must_use]
+    pub const fn status(&self) -> u16 {
+        self.code.status()
+    }
+
+    /// Builds the problem details body; `instance` is the path of the request that failed.
+    #[must_use]
+    pub fn to_problem_details(&self, instance: Option<&str>) -> Value {
+        let mut problem_details = Map::new();
+
+        problem_details.insert("type".to_string(), json!(self.code.problem_type()));
+        problem_details.insert("title".to_string(), json!(self.code.title()));
+        problem_details.insert("status".to_string(), json!(self.code.status()));
+        problem_details.insert("detail".to_string(), json!(self.detail));
+
+        if let Some(instance) = instance {
+            problem_details.insert("instance".to_string(), json!(instance));
+        }
+
+        problem_details.insert("code".to_string(), json!(self.code.code()));
+
+        for (key, value) in &self.extensions {
+            problem_details.insert(key.clone(), value.clone());
+        }
+
+        Value::Object(problem_details)
+    }
+
+    /// Renders the error for code paths outside controllers, such as middlewares.
+    ///
+    /// # Panics
+    ///
+    /// Never panics in practice: the status comes from the catalog and the header is static.
+    #[must_use]
+    pub fn into_response(self, instance: Option<&str>) -> Response<Body> {
+        Response::builder()
+            .status(
+                StatusCode::from_u16(self.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            )
+            .header(header::CONTENT_TYPE, PROBLEM_JSON_CONTENT_TYPE)
+            .body(Body::from(self.to_problem_details(instance).to_string()))
+            .unwrap()
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code.code(), self.detail)
+    }
+}
+
+impl std::error::Error for ApiError {}
@@ -0,0 +1,486 @@
+//! Maps application errors onto the API error catalog. Anything that is not the caller's fault
+//! becomes an internal error, whose cause is logged instead of returned.
+
+use serde_json::json;
+
+use crate::{
+    application::{
+        ports::{
+            auth::auth_port::AuthError,
+            email_domain_policy::email_domain_policy_port::EmailDomainPolicyError,
+            image_processing::image_processing_port::ImageProcessingError,
+            logging::log_filter_port::LogFilterError,
+            password_policy::password_policy_port::{PasswordPolicyError, PasswordPolicyViolation},
+            pattern_matching::pattern_matching_port::PatternMatchingError,
+            repositories::user::create_user_repository_port::CreateUserRepositoryError,
+        },
+        use_cases::{
+            admin::{
+                admin_user_action_use_case::AdminUserActionUseCaseError,
+                change_log_level_use_case::ChangeLogLevelUseCaseError,
+                get_email_domain_policy_use_case::GetEmailDomainPolicyUseCaseError,
+                import_users_use_case::ImportUsersUseCaseError,
+                update_email_domain_policy_use_case::UpdateEmailDomainPolicyUseCaseError,
+            },
+            auth::{
+                authenticate_use_case::AuthenticateUseCaseError,
+                change_password_use_case::ChangePasswordUseCaseError,
+                sign_in_use_case::SignInUseCaseError, sign_up_use_case::SignUpUseCaseError,
+            },
+            data_export::{
+                download_data_export_use_case::DownloadDataExportUseCaseError,
+                get_data_export_use_case::GetDataExportUseCaseError,
+                request_data_export_use_case::RequestDataExportUseCaseError,
+            },
+            user::{
+                get_avatar_use_case::GetAvatarUseCaseError,
+                get_user_by_id_use_case::GetUserByIdUseCaseError,
+                search_users_use_case::SearchUsersUseCaseError,
+                upload_avatar_use_case::UploadAvatarUseCaseError,
+            },
+        },
+    },
+    domain::errors::{
+        user::user_errors::UserError, value_object::value_object_errors::ValueObjectError,
+    },
+    presentation::errors::api_error::{ApiError, ApiErrorCode},
+};
+
+impl From<UserError> for ApiError {
+    fn from(error: UserError) -> Self {
+        let code = match error {
+            UserError::PasswordsDoNotMatch => ApiErrorCode::PasswordsDoNotMatch,
+            UserError::UserAlreadyExists => ApiErrorCode::UserAlreadyExists,
+            UserError::UserNotFound => ApiErrorCode::UserNotFound,
+            UserError::UserSuspended => ApiErrorCode::AccountSuspended,
+            UserError::PasswordResetRequired => ApiErrorCode::PasswordResetRequired,
+            UserError::AdminPrivilegesRequired => ApiErrorCode::AdminPrivilegesRequired,
+            UserError::CannotTargetSelf => ApiErrorCode::CannotTargetSelf,
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
+
+impl From<AuthError> for ApiError {
+    fn from(error: AuthError) -> Self {
+        let code = match error {
+            AuthError::InvalidTokenError => ApiErrorCode::InvalidToken,
+            AuthError::ExpiredTokenError => ApiErrorCode::ExpiredToken,
+            AuthError::RevokedTokenError => ApiErrorCode::RevokedToken,
+            AuthError::GenerateTokenError { .. } | AuthError::UnexpectedError => {
+                return Self::internal(&error);
+            }
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
+
+impl From<PatternMatchingError> for ApiError {
+    fn from(error: PatternMatchingError) -> Self {
+        let code = match error {
+            PatternMatchingError::InvalidEmail => ApiErrorCode::InvalidEmail,
+            PatternMatchingError::InvalidUuid => ApiErrorCode::InvalidUuid,
+            PatternMatchingError::InvalidRegex | PatternMatchingError::InvalidPasswordHash => {
+                return Self::internal(&error);
+            }
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
+
+impl From<PasswordPolicyError> for ApiError {
+    /// Lists each violated rule under `errors`, with its parameters so clients and catalogs can
+    /// phrase the message themselves.
+    fn from(error: PasswordPolicyError) -> Self {
+        let errors = error
+            .violations
+            .iter()
+            .map(|violation| {
+                let mut error = json!({ "error": violation.code() });
+
+                match violation {
+                    PasswordPolicyViolation::TooShort { min_length } => {
+                        error["min_length"] = json!(min_length);
+                    }
+                    PasswordPolicyViolation::TooLong { max_length } => {
+                        error["max_length"] = json!(max_length);
+                    }
+                    PasswordPolicyViolation::TooManyBytes { max_bytes } => {
+                        error["max_bytes"] = json!(max_bytes);
+                    }
+                    PasswordPolicyViolation::MissingCharacterClass { character_class } => {
+                        error["character_class"] = json!(character_class.name());
+                    }
+                    PasswordPolicyViolation::TooWeak { score, min_score } => {
+                        error["score"] = json!(score);
+                        error["min_score"] = json!(min_score);
+                    }
+                    PasswordPolicyViolation::ContainsPersonalInfo
+                    | PasswordPolicyViolation::Breached => {}
+                }
+
+                error["message"] = json!(violation.to_string());
+
+                error
+            })
+            .collect::<Vec<_>>();
+
+        Self::new(
+            ApiErrorCode::InvalidPassword,
+            "the provided password does not satisfy the password policy",
+        )
+        .with_extension("errors", json!(errors))
+    }
+}
+
+impl From<EmailDomainPolicyError> for ApiError {
+    /// Names the refused domain and why, so clients can tell a typo from a blocked provider.
+    fn from(error: EmailDomainPolicyError) -> Self {
+        let (EmailDomainPolicyError::DomainNotAllowed { domain }
+        | EmailDomainPolicyError::DomainDenied { domain }
+        | EmailDomainPolicyError::DisposableDomain { domain }) = &error
+        else {
+            return Self::internal(&error);
+        };
+
+        Self::new(ApiErrorCode::InvalidEmailDomain, error.to_string())
+            .with_extension("domain", json!(domain))
+            .with_extension("reason", json!(error.reason()))
+    }
+}
+
+impl From<ImageProcessingError> for ApiError {
+    fn from(error: ImageProcessingError) -> Self {
+        match error {
+            ImageProcessingError::DecodeError { .. } => {
+                Self::new(ApiErrorCode::InvalidImage, error.to_string())
+            }
+            ImageProcessingError::EncodeError { .. } => Self::internal(&error),
+        }
+    }
+}
+
+impl From<LogFilterError> for ApiError {
+    fn from(error: LogFilterError) -> Self {
+        match error {
+            LogFilterError::InvalidDirectives { .. } => {
+                Self::new(ApiErrorCode::InvalidLogDirectives, error.to_string())
+            }
+            LogFilterError::ReloadError { .. } => Self::internal(&error),
+        }
+    }
+}
+
+/// E-mails and names come from the caller, while ids, hashes and timestamps are produced by the
+/// server, so an invalid one of those is a bug.
+impl From<ValueObjectError> for ApiError {
+    fn from(error: ValueObjectError) -> Self {
+        match error {
+            ValueObjectError::InvalidEmailAddress { .. } => {
+                Self::new(ApiErrorCode::InvalidEmail, error.to_string())
+            }
+            ValueObjectError::InvalidPersonName { .. } => {
+                Self::new(ApiErrorCode::InvalidRequestBody, error.to_string())
+            }
+            ValueObjectError::InvalidUserId { .. }
+            | ValueObjectError::InvalidPasswordHash
+            | ValueObjectError::InvalidTimestamp { .. } => Self::internal(&error),
+        }
+    }
+}
+
+impl From<SignInUseCaseError> for ApiError {
+    fn from(error: SignInUseCaseError) -> Self {
+        match error {
+            SignInUseCaseError::UserError(error) => Self::from(error),
+            SignInUseCaseError::AuthError(error) => Self::from(error),
+            SignInUseCaseError::HasherError(_)
+            | SignInUseCaseError::DatabaseError(_)
+            | SignInUseCaseError::SessionRepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<SignUpUseCaseError> for ApiError {
+    fn from(error: SignUpUseCaseError) -> Self {
+        match error {
+            SignUpUseCaseError::UserError(error) => Self::from(error),
+            SignUpUseCaseError::EmailDomainPolicyError(error) => Self::from(error),
+            SignUpUseCaseError::ValidationError(error) => Self::from(error),
+            SignUpUseCaseError::HasherError(_)
+            | SignUpUseCaseError::DatabaseError(_)
+            | SignUpUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<ChangePasswordUseCaseError> for ApiError {
+    fn from(error: ChangePasswordUseCaseError) -> Self {
+        match error {
+            ChangePasswordUseCaseError::UserError(error) => Self::from(error),
+            ChangePasswordUseCaseError::HasherError(_)
+            | ChangePasswordUseCaseError::DatabaseError(_)
+            | ChangePasswordUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<AuthenticateUseCaseError> for ApiError {
+    fn from(error: AuthenticateUseCaseError) -> Self {
+        match error {
+            AuthenticateUseCaseError::AuthError(error) => Self::from(error),
+            AuthenticateUseCaseError::UserError(error) => Self::from(error),
+            AuthenticateUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<AdminUserActionUseCaseError> for ApiError {
+    fn from(error: AdminUserActionUseCaseError) -> Self {
+        match error {
+            AdminUserActionUseCaseError::UserError(error) => Self::from(error),
+            AdminUserActionUseCaseError::DatabaseError(_)
+            | AdminUserActionUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<ChangeLogLevelUseCaseError> for ApiError {
+    fn from(error: ChangeLogLevelUseCaseError) -> Self {
+        match error {
+            ChangeLogLevelUseCaseError::UserError(error) => Self::from(error),
+            ChangeLogLevelUseCaseError::LogFilterError(error) => Self::from(error),
+            ChangeLogLevelUseCaseError::RevertDelayOutOfRange { max_seconds } => {
+                Self::new(ApiErrorCode::InvalidRevertDelay, error.to_string())
+                    .with_extension("max_seconds", json!(max_seconds))
+            }
+            ChangeLogLevelUseCaseError::DatabaseError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<GetEmailDomainPolicyUseCaseError> for ApiError {
+    fn from(error: GetEmailDomainPolicyUseCaseError) -> Self {
+        match error {
+            GetEmailDomainPolicyUseCaseError::UserError(error) => Self::from(error),
+            GetEmailDomainPolicyUseCaseError::DatabaseError(_)
+            | GetEmailDomainPolicyUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<UpdateEmailDomainPolicyUseCaseError> for ApiError {
+    fn from(error: UpdateEmailDomainPolicyUseCaseError) -> Self {
+        match error {
+            UpdateEmailDomainPolicyUseCaseError::UserError(error) => Self::from(error),
+            UpdateEmailDomainPolicyUseCaseError::UnknownMode { .. } => {
+                Self::new(ApiErrorCode::InvalidEmailDomainPolicy, error.to_string())
+            }
+            UpdateEmailDomainPolicyUseCaseError::DomainInBothLists { ref domain } => {
+                Self::new(ApiErrorCode::InvalidEmailDomainPolicy, error.to_string())
+                    .with_extension("domain", json!(domain))
+            }
+            UpdateEmailDomainPolicyUseCaseError::DatabaseError(_)
+            | UpdateEmailDomainPolicyUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<ImportUsersUseCaseError> for ApiError {
+    fn from(error: ImportUsersUseCaseError) -> Self {
+        match error {
+            ImportUsersUseCaseError::EmptyImport | ImportUsersUseCaseError::TooManyRows { .. } => {
+                Self::new(ApiErrorCode::InvalidImportFile, error.to_string())
+            }
+            // An account created with one of the e-mails after the rows were checked.
+            ImportUsersUseCaseError::RepositoryError(
+                CreateUserRepositoryError::UniqueViolation { .. },
+            ) => Self::from(UserError::UserAlreadyExists),
+            // Invalid rows are reported per row, so a pattern matching error here is a bug.
+            ImportUsersUseCaseError::PatternMatchingError(_)
+            | ImportUsersUseCaseError::EmailDomainPolicyError(_)
+            | ImportUsersUseCaseError::HasherError(_)
+            | ImportUsersUseCaseError::ValidationError(_)
+            | ImportUsersUseCaseError::DatabaseError(_)
+            | ImportUsersUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<GetUserByIdUseCaseError> for ApiError {
+    fn from(error: GetUserByIdUseCaseError) -> Self {
+        Self::internal(&error)
+    }
+}
+
+impl From<SearchUsersUseCaseError> for ApiError {
+    fn from(error: SearchUsersUseCaseError) -> Self {
+        match error {
+            SearchUsersUseCaseError::EmptySearchTerm => {
+                Self::new(ApiErrorCode::MissingSearchQuery, error.to_string())
+            }
+            SearchUsersUseCaseError::SearchError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<UploadAvatarUseCaseError> for ApiError {
+    fn from(error: UploadAvatarUseCaseError) -> Self {
+        match error {
+            UploadAvatarUseCaseError::AvatarTooLarge => {
+                Self::new(ApiErrorCode::PayloadTooLarge, error.to_string())
+            }
+            UploadAvatarUseCaseError::UnsupportedAvatarType => {
+                Self::new(ApiErrorCode::UnsupportedMediaType, error.to_string())
+            }
+            UploadAvatarUseCaseError::UserError(error) => Self::from(error),
+            UploadAvatarUseCaseError::ImageProcessingError(error) => Self::from(error),
+            UploadAvatarUseCaseError::FileStorageError(_)
+            | UploadAvatarUseCaseError::DatabaseError(_)
+            | UploadAvatarUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<GetAvatarUseCaseError> for ApiError {
+    fn from(error: GetAvatarUseCaseError) -> Self {
+        match error {
+            GetAvatarUseCaseError::InvalidAvatarSize => {
+                Self::new(ApiErrorCode::InvalidAvatarSize, error.to_string())
+            }
+            GetAvatarUseCaseError::FileStorageError(_)
+            | GetAvatarUseCaseError::DatabaseError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<RequestDataExportUseCaseError> for ApiError {
+    fn from(error: RequestDataExportUseCaseError) -> Self {
+        match error {
+            RequestDataExportUseCaseError::UserError(error) => Self::from(error),
+            RequestDataExportUseCaseError::ArchiveError(_)
+            | RequestDataExportUseCaseError::DatabaseError(_)
+            | RequestDataExportUseCaseError::RepositoryError(_) => Self::internal(&error),
+        }
+    }
+}
+
+impl From<GetDataExportUseCaseError> for ApiError {
+    fn from(error: GetDataExportUseCaseError) -> Self {
+        Self::internal(&error)
+    }
+}
+
+impl From<DownloadDataExportUseCaseError> for ApiError {
+    fn from(error: DownloadDataExportUseCaseError) -> Self {
+        match error {
+            DownloadDataExportUseCaseError::DataExportNotReady => {
+                Self::new(ApiErrorCode::DataExportNotReady, error.to_string())
+            }
+            DownloadDataExportUseCaseError::DatabaseError(_) => Self::internal(&error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        application::{
+            ports::{
+                auth::auth_port::AuthError,
+                password_policy::password_policy_port::{
+                    PasswordPolicyError, PasswordPolicyViolation,
+                },
+                repositories::user::get_user_by_email_repository_port::GetUserByEmailRepositoryError,
+            },
+            use_cases::auth::sign_in_use_case::SignInUseCaseError,
+        },
+        domain::errors::user::user_errors::UserError,
+        presentation::errors::api_error::{ApiError, ApiErrorCode},
+    };
+
+    #[test]
+    fn should_map_suspended_user_to_forbidden_problem() {
+        let api_error = ApiError::from(SignInUseCaseError::UserError(UserError::UserSuspended));
+
+        assert_eq!(api_error.code(), ApiErrorCode::AccountSuspended);
+        assert_eq!(api_error.status(), 403);
+    }
+
+    #[test]
+    fn should_map_expired_token_to_unauthorized_problem() {
+        let api_error = ApiError::from(SignInUseCaseError::AuthError(AuthError::ExpiredTokenError));
+
+        assert_eq!(api_error.code(), ApiErrorCode::ExpiredToken);
+        assert_eq!(api_error.status(), 401);
+    }
+
+    #[test]
+    fn should_hide_repository_error_details_behind_internal_problem() {
+        let api_error = ApiError::from(SignInUseCaseError::DatabaseError(
+            GetUserByEmailRepositoryError::FindByEmailError {
+                message: "connection refused".to_string(),
+            },
+        ));
+
+        let problem_details = api_error.to_problem_details(Some("/api/v1/auth/sign-in"));
+
+        assert_eq!(api_error.status(), 500);
+        assert_eq!(
+            problem_details,
+            json!({
+                "type": "/problems/internal-server-error",
+                "title": "Internal server error",
+                "status": 500,
+                "detail": "an unexpected error occurred while processing the request",
+                "instance": "/api/v1/auth/sign-in",
+                "code": "internal_server_error"
+            })
+        );
+    }
+
+    #[test]
+    fn should_include_extensions_in_problem_details() {
+        let api_error = ApiError::new(ApiErrorCode::InvalidRequestBody, "invalid fields")
+            .with_extension("errors", json!([{ "field": "email" }]));
+
+        let problem_details = api_error.to_problem_details(None);
+
+        assert_eq!(problem_details["errors"], json!([{ "field": "email" }]));
+        assert_eq!(problem_details["code"], "invalid_request_body");
+        assert!(problem_details.get("instance").is_none());
+    }
+
+    #[test]
+    fn should_list_every_password_policy_violation() {
+        let api_error = ApiError::from(PasswordPolicyError {
+            violations: vec![
+                PasswordPolicyViolation::TooShort { min_length: 12 },
+                PasswordPolicyViolation::Breached,
+            ],
+        });
+
+        let problem_details = api_error.to_problem_details(None);
+
+        assert_eq!(api_error.code(), ApiErrorCode::InvalidPassword);
+        assert_eq!(
+            problem_details["errors"],
+            json!([
+                {
+                    "error": "password_too_short",
+                    "min_length": 12,
+                    "message": "the password must have at least 12 characters"
+                },
+                {
+                    "error": "password_breached",
+                    "message": "the password has appeared in a data breach and must not be used"
+                }
+            ])
+        );
+    }
+}
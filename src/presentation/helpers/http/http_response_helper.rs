@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use serde_json::{Value, json};
 
-use crate::presentation::dtos::http::http_response_dto::HttpResponseDto;
+use crate::presentation::{
+    dtos::http::http_response_dto::HttpResponseDto,
+    errors::api_error::{ApiError, PROBLEM_JSON_CONTENT_TYPE},
+};
 
 #[derive(Clone)]
 pub struct HttpResponseHelper;
@@ -20,73 +23,118 @@ impl HttpResponseHelper {
                 status_code: 200,
                 body: None,
                 headers: None,
+                raw_body: None,
             },
             |value| HttpResponseDto {
                 status_code: 200,
                 body: Some(json!(value)),
                 headers: None,
+                raw_body: None,
             },
         )
     }
 
     #[must_use]
-    pub fn bad_request(&self, body: Option<Value>) -> HttpResponseDto {
-        body.map_or(
-            HttpResponseDto {
-                status_code: 400,
-                body: None,
-                headers: None,
-            },
-            |value| HttpResponseDto {
-                status_code: 400,
-                body: Some(json!(value)),
-                headers: None,
-            },
-        )
+    pub fn created(&self, body: Value, location: &str) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
+
+        headers.insert("Location".to_string(), location.to_string());
+
+        HttpResponseDto {
+            status_code: 201,
+            body: Some(body),
+            headers: Some(headers),
+            raw_body: None,
+        }
     }
 
     #[must_use]
-    pub fn too_many_requests(&self, body: Option<Value>) -> HttpResponseDto {
-        body.map_or(
-            HttpResponseDto {
-                status_code: 429,
-                body: None,
-                headers: None,
-            },
-            |value| HttpResponseDto {
-                status_code: 429,
-                body: Some(json!(value)),
-                headers: None,
-            },
-        )
+    pub fn accepted(&self, body: Value, location: &str) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
+
+        headers.insert("Location".to_string(), location.to_string());
+
+        HttpResponseDto {
+            status_code: 202,
+            body: Some(body),
+            headers: Some(headers),
+            raw_body: None,
+        }
     }
 
     #[must_use]
-    pub fn unauthorized(&self, body: Option<Value>) -> HttpResponseDto {
-        body.map_or(
-            HttpResponseDto {
-                status_code: 401,
-                body: None,
-                headers: None,
-            },
-            |value| HttpResponseDto {
-                status_code: 401,
-                body: Some(json!(value)),
-                headers: None,
-            },
-        )
+    pub fn file(&self, content: Vec<u8>, content_type: &str, file_name: &str) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
+
+        headers.insert("Content-Type".to_string(), content_type.to_string());
+        headers.insert(
+            "Content-Disposition".to_string(),
+            format!("attachment; filename=\"{file_name}\""),
+        );
+
+        HttpResponseDto {
+            status_code: 200,
+            body: None,
+            headers: Some(headers),
+            raw_body: Some(content),
+        }
     }
 
     #[must_use]
-    pub fn created(&self, body: Value, location: &str) -> HttpResponseDto {
+    pub fn text(&self, content: String, content_type: &str) -> HttpResponseDto {
         let mut headers: HashMap<String, String> = HashMap::new();
 
-        headers.insert("Location".to_string(), location.to_string());
+        headers.insert("Content-Type".to_string(), content_type.to_string());
 
         HttpResponseDto {
-            status_code: 201,
-            body: Some(body),
+            status_code: 200,
+            body: None,
+            headers: Some(headers),
+            raw_body: Some(content.into_bytes()),
+        }
+    }
+
+    /// Serves a file that clients may cache privately and revalidate with `If-None-Match`.
+    #[must_use]
+    pub fn cached_file(
+        &self,
+        content: Vec<u8>,
+        content_type: &str,
+        etag: &str,
+        max_age: u32,
+    ) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
+
+        headers.insert("Content-Type".to_string(), content_type.to_string());
+        headers.insert("ETag".to_string(), etag.to_string());
+        headers.insert(
+            "Cache-Control".to_string(),
+            format!("private, max-age={max_age}"),
+        );
+
+        HttpResponseDto {
+            status_code: 200,
+            body: None,
             headers: Some(headers),
+            raw_body: Some(content),
+        }
+    }
+
+    #[must_use]
+    pub fn not_modified(&self, etag: &str, max_age: u32) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
+
+        headers.insert("ETag".to_string(), etag.to_string());
+        headers.insert(
+            "Cache-Control".to_string(),
+            format!("private, max-age={max_age}"),
+        );
+
+        HttpResponseDto {
+            status_code: 304,
+            body: None,
+            headers: Some(headers),
+            raw_body: Some(Vec::new()),
         }
     }
 
@@ -97,59 +145,49 @@ impl HttpResponseHelper {
                 status_code: 204,
                 body: None,
                 headers: None,
+                raw_body: None,
             },
             |value| HttpResponseDto {
                 status_code: 204,
                 body: Some(json!(value)),
                 headers: None,
+                raw_body: None,
             },
         )
     }
 
+    /// Answers with an RFC 9457 problem details body; the handler adapter fills in `instance`.
     #[must_use]
-    pub fn internal_server_error(&self, body: Option<Value>) -> HttpResponseDto {
-        body.map_or(
-            HttpResponseDto {
-                status_code: 500,
-                body: None,
-                headers: None,
-            },
-            |value| HttpResponseDto {
-                status_code: 500,
-                body: Some(json!(value)),
-                headers: None,
-            },
-        )
-    }
+    pub fn problem(&self, api_error: &ApiError) -> HttpResponseDto {
+        let mut headers: HashMap<String, String> = HashMap::new();
 
-    #[must_use]
-    pub fn not_found(&self, body: Option<Value>) -> HttpResponseDto {
-        body.map_or(
-            HttpResponseDto {
-                status_code: 404,
-                body: None,
-                headers: None,
-            },
-            |value| HttpResponseDto {
-                status_code: 404,
-                body: Some(json!(value)),
-                headers: None,
-            },
-        )
+        headers.insert(
+            "Content-Type".to_string(),
+            PROBLEM_JSON_CONTENT_TYPE.to_string(),
+        );
+
+        HttpResponseDto {
+            status_code: api_error.status(),
+            body: Some(api_error.to_problem_details(None)),
+            headers: Some(headers),
+            raw_body: None,
+        }
     }
 
     #[must_use]
-    pub fn conflict(&self, body: Option<Value>) -> HttpResponseDto {
+    pub fn service_unavailable(&self, body: Option<Value>) -> HttpResponseDto {
         body.map_or(
             HttpResponseDto {
-                status_code: 409,
+                status_code: 503,
                 body: None,
                 headers: None,
+                raw_body: None,
             },
             |value| HttpResponseDto {
-                status_code: 409,
+                status_code: 503,
                 body: Some(json!(value)),
                 headers: None,
+                raw_body: None,
             },
         )
     }
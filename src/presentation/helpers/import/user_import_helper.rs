@@ -0,0 +1,312 @@
+use serde_json::Value;
+
+use crate::application::dtos::admin::import_users_dto::{
+    ImportUserIssueDto, ImportUserRowDto, ImportUserRowErrorDto, ImportUsersDto,
+};
+
+const REQUIRED_COLUMNS: [&str; 3] = ["firstName", "lastName", "email"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserImportFormat {
+    Csv,
+    JsonLines,
+}
+
+impl UserImportFormat {
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match media_type.as_str() {
+            "text/csv" => Some(Self::Csv),
+            "application/x-ndjson" | "application/jsonl" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UserImportHelperError {
+    MissingHeader,
+    MissingColumns { columns: Vec<String> },
+}
+
+impl std::fmt::Display for UserImportHelperError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "the csv file does not contain a header row"),
+            Self::MissingColumns { columns } => {
+                write!(
+                    f,
+                    "the csv header is missing the columns: {}",
+                    columns.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for UserImportHelperError {}
+
+#[derive(Default)]
+struct ImportUserFields {
+    first_name: Option<String>,
+    last_name: Option<String>,
+    email: Option<String>,
+    password: Option<String>,
+    password_hash: Option<String>,
+}
+
+impl ImportUserFields {
+    fn set(&mut self, column: &str, value: String) {
+        match column {
+            "firstName" => self.first_name = Some(value),
+            "lastName" => self.last_name = Some(value),
+            "email" => self.email = Some(value),
+            "password" => self.password = Some(value),
+            "passwordHash" => self.password_hash = Some(value),
+            _ => {}
+        }
+    }
+
+    fn into_row(self, line: usize) -> ImportUserRowDto {
+        ImportUserRowDto {
+            line,
+            first_name: self.first_name.unwrap_or_default(),
+            last_name: self.last_name.unwrap_or_default(),
+            email: self.email.unwrap_or_default(),
+            password: self.password,
+            password_hash: self.password_hash,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UserImportHelper;
+
+impl UserImportHelper {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Parses an import payload into rows, keeping the 1-based line number of each record so
+    /// errors can be traced back to the uploaded file. Records that cannot be parsed are
+    /// returned as rejected rows rather than failing the whole import.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserImportHelperError` if a CSV payload has no header or the header lacks
+    /// required columns.
+    pub fn parse(
+        &self,
+        format: UserImportFormat,
+        content: &str,
+        dry_run: bool,
+    ) -> Result<ImportUsersDto, UserImportHelperError> {
+        let (rows, rejected_rows) = match format {
+            UserImportFormat::Csv => Self::parse_csv(content)?,
+            UserImportFormat::JsonLines => Self::parse_json_lines(content),
+        };
+
+        Ok(ImportUsersDto::new(rows, rejected_rows, dry_run))
+    }
+
+    fn parse_csv(
+        content: &str,
+    ) -> Result<(Vec<ImportUserRowDto>, Vec<ImportUserRowErrorDto>), UserImportHelperError> {
+        let mut records = Self::split_csv_records(content).into_iter();
+
+        let Some((_, Ok(header))) = records.next() else {
+            return Err(UserImportHelperError::MissingHeader);
+        };
+
+        let columns = header
+            .iter()
+            .map(|column| Self::normalize_column(column))
+            .collect::<Vec<_>>();
+
+        let missing_columns = REQUIRED_COLUMNS
+            .iter()
+            .filter(|required| !columns.iter().any(|column| column == *required))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        if !missing_columns.is_empty() {
+            return Err(UserImportHelperError::MissingColumns {
+                columns: missing_columns,
+            });
+        }
+
+        let mut rows = vec![];
+        let mut rejected_rows = vec![];
+
+        for (line, record) in records {
+            let values = match record {
+                Ok(values) if values.len() == columns.len() => values,
+                Ok(values) => {
+                    rejected_rows.push(Self::malformed_row(
+                        line,
+                        format!(
+                            "expected {} columns but found {}",
+                            columns.len(),
+                            values.len()
+                        ),
+                    ));
+
+                    continue;
+                }
+                Err(message) => {
+                    rejected_rows.push(Self::malformed_row(line, message));
+
+                    continue;
+                }
+            };
+
+            let mut fields = ImportUserFields::default();
+
+            for (column, value) in columns.iter().zip(values) {
+                fields.set(column, value);
+            }
+
+            rows.push(fields.into_row(line));
+        }
+
+        Ok((rows, rejected_rows))
+    }
+
+    fn parse_json_lines(content: &str) -> (Vec<ImportUserRowDto>, Vec<ImportUserRowErrorDto>) {
+        let mut rows = vec![];
+        let mut rejected_rows = vec![];
+
+        for (index, raw_line) in content.lines().enumerate() {
+            let line = index + 1;
+
+            if raw_line.trim().is_empty() {
+                continue;
+            }
+
+            let Ok(Value::Object(object)) = serde_json::from_str::<Value>(raw_line) else {
+                rejected_rows.push(Self::malformed_row(
+                    line,
+                    "the line is not a valid json object".to_string(),
+                ));
+
+                continue;
+            };
+
+            let mut fields = ImportUserFields::default();
+            let mut issues = vec![];
+
+            for (key, value) in object {
+                let column = Self::normalize_column(&key);
+
+                match value {
+                    Value::String(value) => fields.set(&column, value),
+                    Value::Null => {}
+                    _ => issues.push(ImportUserIssueDto::new(
+                        &column,
+                        "invalid_type",
+                        "the field must be a string",
+                    )),
+                }
+            }
+
+            if issues.is_empty() {
+                rows.push(fields.into_row(line));
+            } else {
+                rejected_rows.push(ImportUserRowErrorDto::new(line, fields.email, issues));
+            }
+        }
+
+        (rows, rejected_rows)
+    }
+
+    /// Splits CSV content into records following RFC 4180 quoting, so quoted fields may contain
+    /// commas, escaped quotes and line breaks. Each record carries the line it starts on.
+    fn split_csv_records(content: &str) -> Vec<(usize, Result<Vec<String>, String>)> {
+        let mut records = vec![];
+        let mut fields = vec![];
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut line = 1;
+        let mut record_line = 1;
+        let mut chars = content.chars().peekable();
+
+        while let Some(character) = chars.next() {
+            match character {
+                '"' if in_quotes => {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                }
+                '"' if field.is_empty() => in_quotes = true,
+                ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+                '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+                '\n' if !in_quotes => {
+                    fields.push(std::mem::take(&mut field));
+
+                    let record = std::mem::take(&mut fields);
+
+                    if !(record.len() == 1 && record[0].trim().is_empty()) {
+                        records.push((record_line, Ok(record)));
+                    }
+
+                    line += 1;
+                    record_line = line;
+                }
+                '\n' => {
+                    line += 1;
+                    field.push(character);
+                }
+                _ => field.push(character),
+            }
+        }
+
+        if in_quotes {
+            records.push((
+                record_line,
+                Err("the record contains an unterminated quoted field".to_string()),
+            ));
+        } else if !field.is_empty() || !fields.is_empty() {
+            fields.push(field);
+            records.push((record_line, Ok(fields)));
+        }
+
+        records
+    }
+
+    /// Accepts both `camelCase` and `snake_case` column names.
+    fn normalize_column(column: &str) -> String {
+        let flattened = column.trim().to_ascii_lowercase().replace(['_', '-'], "");
+
+        match flattened.as_str() {
+            "firstname" => "firstName".to_string(),
+            "lastname" => "lastName".to_string(),
+            "passwordhash" => "passwordHash".to_string(),
+            _ => flattened,
+        }
+    }
+
+    fn malformed_row(line: usize, message: String) -> ImportUserRowErrorDto {
+        ImportUserRowErrorDto::new(
+            line,
+            None,
+            vec![ImportUserIssueDto::new("row", "malformed_row", message)],
+        )
+    }
+}
+
+impl Default for UserImportHelper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,91 @@
+use std::io::Cursor;
+
+use multer::Multipart;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MultipartHelperError {
+    MissingBoundary,
+    MissingField { field_name: String },
+    InvalidBody { message: String },
+}
+
+impl std::fmt::Display for MultipartHelperError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingBoundary => {
+                write!(f, "the multipart content type does not declare a boundary")
+            }
+            Self::MissingField { field_name } => {
+                write!(
+                    f,
+                    "the multipart body does not contain the field '{field_name}'"
+                )
+            }
+            Self::InvalidBody { message } => {
+                write!(f, "the multipart body is malformed: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MultipartHelperError {}
+
+#[derive(Clone)]
+pub struct MultipartHelper;
+
+impl MultipartHelper {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the content of the first part named `field_name` of a `multipart/form-data` body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MultipartHelperError` if the boundary is missing, the body cannot be parsed or
+    /// the field is absent.
+    pub async fn extract_field(
+        &self,
+        content_type: &str,
+        body: Vec<u8>,
+        field_name: &str,
+    ) -> Result<Vec<u8>, MultipartHelperError> {
+        let boundary = multer::parse_boundary(content_type)
+            .map_err(|_| MultipartHelperError::MissingBoundary)?;
+
+        let mut multipart = Multipart::with_reader(Cursor::new(body), boundary);
+
+        while let Some(field) =
+            multipart
+                .next_field()
+                .await
+                .map_err(|err| MultipartHelperError::InvalidBody {
+                    message: err.to_string(),
+                })?
+        {
+            if field.name() != Some(field_name) {
+                continue;
+            }
+
+            let content = field
+                .bytes()
+                .await
+                .map_err(|err| MultipartHelperError::InvalidBody {
+                    message: err.to_string(),
+                })?;
+
+            return Ok(content.to_vec());
+        }
+
+        Err(MultipartHelperError::MissingField {
+            field_name: field_name.to_string(),
+        })
+    }
+}
+
+impl Default for MultipartHelper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,30 +1,36 @@
 use axum::{
     body::Body,
-    http::{Request, Response, StatusCode},
+    extract::OriginalUri,
+    http::{Request, Response},
     middleware::Next,
 };
-use serde_json::json;
 
-use crate::application::ports::auth::auth_port::AuthPort;
+use crate::{
+    application::use_cases::auth::authenticate_use_case::AuthenticateUseCasePort,
+    presentation::errors::api_error::{ApiError, ApiErrorCode},
+};
 
 #[derive(Clone)]
-pub struct AuthMiddleware<A> {
-    auth_port: A,
+pub struct AuthMiddleware<U> {
+    authenticate_use_case: U,
 }
 
-impl<A> AuthMiddleware<A>
+impl<U> AuthMiddleware<U>
 where
-    A: AuthPort + Clone + Send + Sync,
+    U: AuthenticateUseCasePort + Clone + Send + Sync,
 {
-    pub const fn new(auth_port: A) -> Self {
-        Self { auth_port }
+    pub const fn new(authenticate_use_case: U) -> Self {
+        Self {
+            authenticate_use_case,
+        }
     }
 
     /// Process an incoming HTTP request, validating the `Authorization` header.
     ///
-    /// This middleware checks for the presence of the `Authorization` header and verifies
-    /// its token using the `auth_port`. If the header is missing, empty, or the token
-    /// verification fails, it returns a `401 Unauthorized` response with a JSON error.
+    /// This middleware checks for the presence of the `Authorization` header and authenticates
+    /// its token using the `authenticate_use_case`. On success the resulting principal is stored
+    /// in the request extensions so that handlers know who is acting. If the header is missing,
+    /// empty, or the token is rejected, it returns a problem details response.
     ///
     /// # Parameters
     /// - `request`: the incoming HTTP request.
@@ -33,61 +39,156 @@ where
     /// # Returns
     /// - A `Response<Body>` representing either:
     ///   - The result of the next handler if the token is valid.
-    ///   - A `401 Unauthorized` JSON response if the token is missing, empty, or invalid.
-    ///
-    /// # Panics
-    /// - Panics if `serde_json::to_string` fails (should not happen with valid JSON literals).
-    /// - Panics if building the response with `Response::builder().body(...)` fails.
-    pub async fn process(&self, request: Request<Body>, next: Next) -> Response<Body> {
+    ///   - A `401 Unauthorized` problem if the token is missing, empty, invalid or revoked.
+    ///   - A `403 Forbidden` problem if the account behind the token is suspended.
+    pub async fn process(&self, mut request: Request<Body>, next: Next) -> Response<Body> {
+        // Nested routers strip their prefix from the URI, so the path is read from the original.
+        let instance = request
+            .extensions()
+            .get::<OriginalUri>()
+            .map_or_else(|| request.uri().path(), |original_uri| original_uri.path())
+            .to_string();
+
         let authorization_token =
             if let Some(authorization_header) = request.headers().get("Authorization") {
                 match authorization_header.to_str() {
                     Ok(value) if !value.is_empty() => value,
                     _ => {
-                        let body = serde_json::to_string(&json!({
-                            "error_code": "missing_authorization_header",
-                            "error_message": "authorization header is empty"
-                        }))
-                        .unwrap();
-
-                        return Response::builder()
-                            .status(StatusCode::UNAUTHORIZED)
-                            .header("content-type", "application/json")
-                            .body(Body::from(body))
-                            .unwrap();
+                        return ApiError::new(
+                            ApiErrorCode::MissingAuthorizationHeader,
+                            "authorization header is empty",
+                        )
+                        .into_response(Some(&instance));
                     }
                 }
             } else {
-                let body = serde_json::to_string(&json!({
-                    "error_code": "missing_authorization_header",
-                    "error_message": "authorization header is missing"
-                }))
-                .unwrap();
-
-                return Response::builder()
-                    .status(StatusCode::UNAUTHORIZED)
-                    .header("content-type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap();
+                return ApiError::new(
+                    ApiErrorCode::MissingAuthorizationHeader,
+                    "authorization header is missing",
+                )
+                .into_response(Some(&instance));
             };
 
-        let splitted_token = authorization_token.trim_start_matches("Bearer ").trim();
+        let splitted_token = authorization_token
+            .trim_start_matches("Bearer ")
+            .trim()
+            .to_string();
 
-        match self.auth_port.verify_auth_token(splitted_token) {
-            Ok(()) => next.run(request).await,
-            Err(err) => {
-                let body = serde_json::to_string(&json!({
-                    "error_code": "authorization_middleware",
-                    "error_message": err.to_string()
-                }))
-                .unwrap();
+        match self.authenticate_use_case.perform(splitted_token).await {
+            Ok(auth_principal) => {
+                request.extensions_mut().insert(auth_principal);
 
-                Response::builder()
-                    .status(StatusCode::UNAUTHORIZED)
-                    .header("content-type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap()
+                next.run(request).await
             }
+            Err(err) => ApiError::from(err).into_response(Some(&instance)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+        middleware,
+        routing::get,
+    };
+    use mockall::mock;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::{
+        application::{
+            ports::{
+                auth::auth_port::AuthPort,
+                repositories::user::get_user_by_id_repository_port::{
+                    GetUserByIdFuture, GetUserByIdRepositoryPort,
+                },
+            },
+            use_cases::auth::authenticate_use_case::AuthenticateUseCase,
+        },
+        infrastructure::adapters::{
+            clock::manual_clock_adapter::ManualClockAdapter,
+            jsonwebtoken::jsonwebtoken_adapter::JsonWebTokenAdapter,
+        },
+        presentation::middlewares::auth::auth_middleware::AuthMiddleware,
+    };
+
+    const NOW: i64 = 1_695_996_669;
+
+    mock! {
+        pub GetUserByIdRepository {}
+
+        impl Clone for GetUserByIdRepository {
+            fn clone(&self) -> Self;
+        }
+
+        impl GetUserByIdRepositoryPort for GetUserByIdRepository {
+            fn execute(&self, id: String) -> GetUserByIdFuture<'_>;
+        }
+    }
+
+    /// The token is rejected before any user is looked up.
+    fn build_get_user_by_id_repository_mock() -> MockGetUserByIdRepository {
+        let mut get_user_by_id_repository_mock = MockGetUserByIdRepository::default();
+
+        get_user_by_id_repository_mock.expect_execute().times(0);
+
+        get_user_by_id_repository_mock
+            .expect_clone()
+            .returning(build_get_user_by_id_repository_mock);
+
+        get_user_by_id_repository_mock
+    }
+
+    fn build_router() -> Router {
+        let auth_middleware = AuthMiddleware::new(AuthenticateUseCase::new(
+            JsonWebTokenAdapter::new("secret", 3600, ManualClockAdapter::new(NOW)),
+            build_get_user_by_id_repository_mock(),
+        ));
+
+        Router::new()
+            .route("/protected", get(|| async { "protected" }))
+            .layer(middleware::from_fn(move |request, next| {
+                let auth_middleware = auth_middleware.clone();
+
+                async move { auth_middleware.process(request, next).await }
+            }))
+    }
+
+    async fn send_with_token(token: &str) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .uri("/protected")
+            .header("Authorization", format!("Bearer {token}"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice::<Value>(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn should_return_unauthorized_if_token_signature_is_invalid() {
+        let forged_token =
+            JsonWebTokenAdapter::new("other-secret", 3600, ManualClockAdapter::new(NOW))
+                .generate_auth_token("dba86129-90be-4409-a5a3-396db9335a57")
+                .unwrap();
+
+        let (status, body) = send_with_token(&forged_token.value).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_token");
+    }
+
+    #[tokio::test]
+    async fn should_return_unauthorized_if_token_is_not_a_jwt() {
+        let (status, body) = send_with_token("not-a-jwt").await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_token");
+    }
+}
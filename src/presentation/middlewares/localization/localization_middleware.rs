@@ -0,0 +1,270 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{HeaderValue, Request, Response, header},
+    middleware::Next,
+};
+use serde_json::{Map, Value};
+
+use crate::{
+    application::ports::localization::localization_port::LocalizationPort,
+    presentation::errors::api_error::PROBLEM_JSON_CONTENT_TYPE,
+};
+
+#[derive(Clone)]
+pub struct LocalizationMiddleware<L> {
+    localization_adapter: L,
+}
+
+impl<L> LocalizationMiddleware<L>
+where
+    L: LocalizationPort + Clone + Send + Sync,
+{
+    pub const fn new(localization_adapter: L) -> Self {
+        Self {
+            localization_adapter,
+        }
+    }
+
+    /// Translates problem details into the locale negotiated from `Accept-Language`. The `title`
+    /// and `detail` are looked up by problem `code` and each validation error's `message` by its
+    /// `error` code; anything without a translation stays in English. Other responses pass
+    /// through untouched.
+    pub async fn process(&self, request: Request<Body>, next: Next) -> Response<Body> {
+        let accept_language = request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok());
+
+        let locale = self.localization_adapter.negotiate_locale(accept_language);
+
+        let response = next.run(request).await;
+
+        if !Self::is_problem(&response) {
+            return response;
+        }
+
+        let mut response = self.localize_problem(response, &locale).await;
+
+        if let Ok(header_value) = HeaderValue::from_str(&locale) {
+            response
+                .headers_mut()
+                .insert(header::CONTENT_LANGUAGE, header_value);
+        }
+
+        response.headers_mut().append(
+            header::VARY,
+            HeaderValue::from_static(header::ACCEPT_LANGUAGE.as_str()),
+        );
+
+        response
+    }
+
+    fn is_problem(response: &Response<Body>) -> bool {
+        response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with(PROBLEM_JSON_CONTENT_TYPE))
+    }
+
+    async fn localize_problem(&self, response: Response<Body>, locale: &str) -> Response<Body> {
+        let (mut parts, body) = response.into_parts();
+
+        let Ok(body_bytes) = to_bytes(body, usize::MAX).await else {
+            return Response::from_parts(parts, Body::empty());
+        };
+
+        let Ok(Value::Object(mut problem_details)) = serde_json::from_slice::<Value>(&body_bytes)
+        else {
+            return Response::from_parts(parts, Body::from(body_bytes));
+        };
+
+        if let Some(code) = problem_details.get("code").and_then(Value::as_str) {
+            let title_key = format!("problems.{code}.title");
+            let detail_key = format!("problems.{code}.detail");
+
+            self.translate_member(&mut problem_details, "title", locale, &title_key);
+            self.translate_member(&mut problem_details, "detail", locale, &detail_key);
+        }
+
+        if let Some(Value::Array(errors)) = problem_details.get_mut("errors") {
+            for field_error in errors.iter_mut().filter_map(Value::as_object_mut) {
+                if let Some(error) = field_error.get("error").and_then(Value::as_str) {
+                    let message_key = format!("validation.{error}");
+
+                    self.translate_member(field_error, "message", locale, &message_key);
+                }
+            }
+        }
+
+        parts.headers.remove(header::CONTENT_LENGTH);
+
+        Response::from_parts(
+            parts,
+            Body::from(Value::Object(problem_details).to_string()),
+        )
+    }
+
+    /// Replaces `member` with the translation of `key`, filling placeholders from the other
+    /// members of the same object.
+    fn translate_member(
+        &self,
+        object: &mut Map<String, Value>,
+        member: &str,
+        locale: &str,
+        key: &str,
+    ) {
+        let args = object
+            .iter()
+            .filter_map(|(name, value)| match value {
+                Value::String(value) => Some((name.as_str(), value.clone())),
+                Value::Number(value) => Some((name.as_str(), value.to_string())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(message) = self.localization_adapter.translate(locale, key, &args) {
+            object.insert(member.to_string(), Value::String(message));
+        }
+    }
+}
+
+#[cfg(test)]
+// The mock's matchers for `negotiate_locale` take an `&Option<&str>`.
+#[allow(clippy::ref_option_ref)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, header},
+        middleware,
+        routing::get,
+    };
+    use mockall::mock;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::{
+        application::ports::localization::localization_port::LocalizationPort,
+        presentation::{
+            errors::api_error::{ApiError, ApiErrorCode},
+            middlewares::localization::localization_middleware::LocalizationMiddleware,
+            validation::validation_error::{ValidationError, ValidationErrorKind},
+        },
+    };
+
+    mock! {
+        pub LocalizationAdapter {}
+
+        impl Clone for LocalizationAdapter {
+            fn clone(&self) -> Self;
+        }
+
+        impl LocalizationPort for LocalizationAdapter {
+            fn negotiate_locale<'a>(&self, accept_language: Option<&'a str>) -> String;
+            fn translate<'a>(&self, locale: &str, key: &str, args: &[(&'a str, String)]) -> Option<String>;
+        }
+    }
+
+    fn build_localization_mock() -> MockLocalizationAdapter {
+        let mut localization_mock = MockLocalizationAdapter::default();
+
+        localization_mock
+            .expect_negotiate_locale()
+            .returning(|accept_language| match accept_language {
+                Some("pt-BR,pt;q=0.9") => "pt-BR".to_string(),
+                _ => "en".to_string(),
+            });
+
+        localization_mock
+            .expect_translate()
+            .returning(|locale, key, args| match (locale, key) {
+                ("pt-BR", "problems.invalid_request_body.title") => {
+                    Some("Corpo da requisição inválido".to_string())
+                }
+                ("pt-BR", "validation.missing") => args
+                    .iter()
+                    .find(|(name, _)| *name == "pointer")
+                    .map(|(_, pointer)| format!("o campo '{pointer}' é obrigatório")),
+                _ => None,
+            });
+
+        localization_mock
+            .expect_clone()
+            .returning(build_localization_mock);
+
+        localization_mock
+    }
+
+    fn build_router() -> Router {
+        let localization_middleware = LocalizationMiddleware::new(build_localization_mock());
+
+        Router::new()
+            .route(
+                "/error",
+                get(|| async {
+                    ApiError::new(
+                        ApiErrorCode::InvalidRequestBody,
+                        "some fields are not valid",
+                    )
+                    .with_extension(
+                        "errors",
+                        json!([ValidationError::new(
+                            "/email".to_string(),
+                            ValidationErrorKind::Missing
+                        )
+                        .to_json()]),
+                    )
+                    .into_response(Some("/error"))
+                }),
+            )
+            .route("/ok", get(|| async { "ok" }))
+            .layer(middleware::from_fn(move |request, next| {
+                let localization_middleware = localization_middleware.clone();
+
+                async move { localization_middleware.process(request, next).await }
+            }))
+    }
+
+    #[tokio::test]
+    async fn should_translate_problem_and_fall_back_to_english_per_member() {
+        let request = Request::builder()
+            .uri("/error")
+            .header(header::ACCEPT_LANGUAGE, "pt-BR,pt;q=0.9")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router().oneshot(request).await.unwrap();
+
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "pt-BR");
+        assert_eq!(response.headers()[header::VARY], "accept-language");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = serde_json::from_slice::<Value>(&body).unwrap();
+
+        assert_eq!(body["title"], "Corpo da requisição inválido");
+        assert_eq!(body["detail"], "some fields are not valid");
+        assert_eq!(
+            body["errors"][0]["message"],
+            "o campo '/email' é obrigatório"
+        );
+        assert_eq!(body["errors"][0]["error"], "missing");
+    }
+
+    #[tokio::test]
+    async fn should_leave_non_problem_responses_untouched() {
+        let request = Request::builder()
+            .uri("/ok")
+            .header(header::ACCEPT_LANGUAGE, "pt-BR,pt;q=0.9")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router().oneshot(request).await.unwrap();
+
+        assert!(response.headers().get(header::CONTENT_LANGUAGE).is_none());
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        assert_eq!(&body[..], b"ok");
+    }
+}
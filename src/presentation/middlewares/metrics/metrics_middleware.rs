@@ -0,0 +1,49 @@
+use std::time::Instant;
+
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{Request, Response},
+    middleware::Next,
+};
+
+use crate::application::ports::metrics::metrics_port::MetricsPort;
+
+/// Label used for requests that matched no route, so unknown paths cannot create new series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Clone)]
+pub struct MetricsMiddleware<M> {
+    metrics_adapter: M,
+}
+
+impl<M> MetricsMiddleware<M>
+where
+    M: MetricsPort + Clone + Send + Sync,
+{
+    pub const fn new(metrics_adapter: M) -> Self {
+        Self { metrics_adapter }
+    }
+
+    /// Times the request and records it under its method, matched route template and status.
+    pub async fn process(&self, request: Request<Body>, next: Next) -> Response<Body> {
+        let method = request.method().to_string();
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map_or(UNMATCHED_ROUTE, MatchedPath::as_str)
+            .to_string();
+
+        let started_at = Instant::now();
+        let response = next.run(request).await;
+
+        self.metrics_adapter.observe_http_request(
+            &method,
+            &route,
+            response.status().as_u16(),
+            started_at.elapsed(),
+        );
+
+        response
+    }
+}
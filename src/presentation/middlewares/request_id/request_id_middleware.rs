@@ -0,0 +1,233 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{HeaderValue, Request, Response, header},
+    middleware::Next,
+};
+use serde_json::Value;
+
+use crate::{
+    application::ports::id_generator::id_generator_port::IdGeneratorPort,
+    presentation::errors::api_error::PROBLEM_JSON_CONTENT_TYPE,
+};
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest caller-provided request id that is kept; longer ones are replaced.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Identifies a request across log lines, error bodies and the caller's own logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+#[derive(Clone)]
+pub struct RequestIdMiddleware<I> {
+    id_generator_adapter: I,
+}
+
+impl<I> RequestIdMiddleware<I>
+where
+    I: IdGeneratorPort + Clone + Send + Sync,
+{
+    pub const fn new(id_generator_adapter: I) -> Self {
+        Self {
+            id_generator_adapter,
+        }
+    }
+
+    /// Reuses the caller's `X-Request-Id` when it is safe to log, otherwise generates one. The id
+    /// is added to the request extensions, echoed in the response headers and added to JSON
+    /// error bodies.
+    pub async fn process(&self, mut request: Request<Body>, next: Next) -> Response<Body> {
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| Self::is_valid_request_id(value))
+            .map_or_else(|| self.id_generator_adapter.generate_id(), str::to_string);
+
+        request
+            .extensions_mut()
+            .insert(RequestId(request_id.clone()));
+
+        let response = next.run(request).await;
+
+        let mut response = if Self::is_json_error(&response) {
+            Self::attach_to_error_body(response, &request_id).await
+        } else {
+            response
+        };
+
+        if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+            response
+                .headers_mut()
+                .insert(REQUEST_ID_HEADER, header_value);
+        }
+
+        response
+    }
+
+    fn is_valid_request_id(request_id: &str) -> bool {
+        !request_id.is_empty()
+            && request_id.len() <= MAX_REQUEST_ID_LENGTH
+            && request_id
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || "-_.:".contains(char))
+    }
+
+    fn is_json_error(response: &Response<Body>) -> bool {
+        let is_json = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| {
+                content_type.starts_with("application/json")
+                    || content_type.starts_with(PROBLEM_JSON_CONTENT_TYPE)
+            });
+
+        is_json && (response.status().is_client_error() || response.status().is_server_error())
+    }
+
+    async fn attach_to_error_body(response: Response<Body>, request_id: &str) -> Response<Body> {
+        let (mut parts, body) = response.into_parts();
+
+        let Ok(body_bytes) = to_bytes(body, usize::MAX).await else {
+            return Response::from_parts(parts, Body::empty());
+        };
+
+        let Ok(Value::Object(mut error_body)) = serde_json::from_slice::<Value>(&body_bytes) else {
+            return Response::from_parts(parts, Body::from(body_bytes));
+        };
+
+        error_body.insert(
+            "request_id".to_string(),
+            Value::String(request_id.to_string()),
+        );
+
+        parts.headers.remove(header::CONTENT_LENGTH);
+
+        Response::from_parts(parts, Body::from(Value::Object(error_body).to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::Request,
+        middleware,
+        routing::get,
+    };
+    use mockall::mock;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::{
+        application::ports::id_generator::id_generator_port::IdGeneratorPort,
+        presentation::{
+            errors::api_error::{ApiError, ApiErrorCode},
+            middlewares::request_id::request_id_middleware::{
+                REQUEST_ID_HEADER, RequestId, RequestIdMiddleware,
+            },
+        },
+    };
+
+    mock! {
+        pub IdGeneratorAdapter {}
+
+        impl Clone for IdGeneratorAdapter {
+            fn clone(&self) -> Self;
+        }
+
+        impl IdGeneratorPort for IdGeneratorAdapter {
+            fn generate_id(&self) -> String;
+        }
+    }
+
+    fn build_id_generator_mock() -> MockIdGeneratorAdapter {
+        let mut id_generator_mock = MockIdGeneratorAdapter::default();
+
+        id_generator_mock
+            .expect_generate_id()
+            .returning(|| "generated-request-id".to_string());
+
+        id_generator_mock
+            .expect_clone()
+            .returning(build_id_generator_mock);
+
+        id_generator_mock
+    }
+
+    fn build_router(id_generator_mock: MockIdGeneratorAdapter) -> Router {
+        let request_id_middleware = RequestIdMiddleware::new(id_generator_mock);
+
+        Router::new()
+            .route(
+                "/error",
+                get(|request: Request<Body>| async move {
+                    let request_id = request.extensions().get::<RequestId>().cloned();
+
+                    assert!(request_id.is_some());
+
+                    ApiError::new(ApiErrorCode::InvalidRequestBody, "the request is invalid")
+                        .into_response(Some("/error"))
+                }),
+            )
+            .layer(middleware::from_fn(move |request, next| {
+                let request_id_middleware = request_id_middleware.clone();
+
+                async move { request_id_middleware.process(request, next).await }
+            }))
+    }
+
+    #[tokio::test]
+    async fn should_reuse_caller_request_id_in_header_and_error_body() {
+        let request = Request::builder()
+            .uri("/error")
+            .header(REQUEST_ID_HEADER, "caller-id-123")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router(build_id_generator_mock())
+            .oneshot(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "caller-id-123");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = serde_json::from_slice::<Value>(&body).unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "type": "/problems/invalid-request-body",
+                "title": "Invalid request body",
+                "status": 400,
+                "detail": "the request is invalid",
+                "instance": "/error",
+                "code": "invalid_request_body",
+                "request_id": "caller-id-123"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_generate_request_id_if_caller_id_is_unsafe_to_log() {
+        let request = Request::builder()
+            .uri("/error")
+            .header(REQUEST_ID_HEADER, "id with spaces\tand tabs")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router(build_id_generator_mock())
+            .oneshot(request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers()[REQUEST_ID_HEADER],
+            "generated-request-id"
+        );
+    }
+}
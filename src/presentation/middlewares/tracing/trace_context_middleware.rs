@@ -0,0 +1,265 @@
+use std::{collections::HashMap, time::Instant};
+
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{HeaderName, HeaderValue, Request, Response},
+    middleware::Next,
+};
+use tracing::Instrument;
+
+use crate::{
+    application::ports::tracing::trace_context_port::TraceContextPort,
+    presentation::middlewares::request_id::request_id_middleware::RequestId,
+};
+
+/// Label used for requests that matched no route, so unknown paths cannot create new span names.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Clone)]
+pub struct TraceContextMiddleware<T> {
+    trace_context_adapter: T,
+}
+
+impl<T> TraceContextMiddleware<T>
+where
+    T: TraceContextPort + Clone + Send + Sync,
+{
+    pub const fn new(trace_context_adapter: T) -> Self {
+        Self {
+            trace_context_adapter,
+        }
+    }
+
+    /// Runs the request inside a server span continuing the caller's trace, if any, and returns
+    /// the span's `traceparent` so clients can look the request up.
+    pub async fn process(&self, request: Request<Body>, next: Next) -> Response<Body> {
+        let method = request.method().to_string();
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map_or(UNMATCHED_ROUTE, MatchedPath::as_str)
+            .to_string();
+
+        let request_headers = request
+            .headers()
+            .iter()
+            .filter_map(|(key, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (key.as_str().to_string(), value.to_string()))
+            })
+            .collect::<HashMap<String, String>>();
+
+        let span = tracing::info_span!(
+            "http_request",
+            otel.name = format!("{method} {route}"),
+            otel.kind = "server",
+            otel.status_code = tracing::field::Empty,
+            http.request.method = %method,
+            http.route = %route,
+            url.path = %request.uri().path(),
+            http.response.status_code = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+        );
+
+        if let Some(RequestId(request_id)) = request.extensions().get::<RequestId>() {
+            span.record("request_id", request_id.as_str());
+        }
+
+        self.trace_context_adapter
+            .continue_trace(&span, &request_headers);
+
+        let started_at = Instant::now();
+        let mut response = next.run(request).instrument(span.clone()).await;
+        let status_code = response.status();
+
+        span.record("http.response.status_code", status_code.as_u16());
+
+        if status_code.is_server_error() {
+            span.record("otel.status_code", "ERROR");
+        }
+
+        tracing::debug!(
+            parent: &span,
+            latency_ms = started_at.elapsed().as_millis(),
+            status = status_code.as_u16(),
+            "finished processing request"
+        );
+
+        for (key, value) in self.trace_context_adapter.trace_headers(&span) {
+            if let (Ok(key), Ok(value)) = (HeaderName::try_from(key), HeaderValue::try_from(value))
+            {
+                response.headers_mut().insert(key, value);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::{Path, Request},
+        middleware,
+        routing::get,
+    };
+    use opentelemetry::{
+        global,
+        trace::{SpanId, TraceId},
+    };
+    use opentelemetry_sdk::{
+        propagation::TraceContextPropagator,
+        trace::{InMemorySpanExporter, SdkTracerProvider, SpanData},
+    };
+    use tower::ServiceExt;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use crate::{
+        application::use_cases::user::search_users_use_case::SearchUsersUseCase,
+        infrastructure::{
+            adapters::{
+                axum::axum_handler_adapter::AxumHandlerAdapter,
+                opentelemetry::opentelemetry_trace_context_adapter::OpenTelemetryTraceContextAdapter,
+            },
+            gateways::telemetry::telemetry_gateway::TelemetryGateway,
+            repositories::user::in_memory_user_search_repository::InMemoryUserSearchRepository,
+        },
+        presentation::{
+            controllers::user::search_users_controller::SearchUsersController,
+            helpers::http::http_response_helper::HttpResponseHelper,
+            middlewares::tracing::trace_context_middleware::TraceContextMiddleware,
+        },
+    };
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const REMOTE_SPAN_ID: &str = "00f067aa0ba902b7";
+
+    /// Wires the search endpoint end to end, since its repository needs no database.
+    fn build_router() -> Router {
+        let search_users_controller_adapter = AxumHandlerAdapter::new(SearchUsersController::new(
+            SearchUsersUseCase::new(InMemoryUserSearchRepository::new(vec![])),
+            HttpResponseHelper::new(),
+        ));
+
+        let trace_context_middleware =
+            TraceContextMiddleware::new(OpenTelemetryTraceContextAdapter);
+
+        Router::new()
+            .route(
+                "/users/search",
+                get(
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        search_users_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    },
+                ),
+            )
+            .layer(middleware::from_fn(move |request, next| {
+                let trace_context_middleware = trace_context_middleware.clone();
+
+                async move { trace_context_middleware.process(request, next).await }
+            }))
+    }
+
+    fn find_span<'a>(spans: &'a [SpanData], name: &str) -> &'a SpanData {
+        spans
+            .iter()
+            .find(|span| span.name == name)
+            .unwrap_or_else(|| panic!("span '{name}' was not exported"))
+    }
+
+    #[tokio::test]
+    async fn should_continue_incoming_trace_across_controller_use_case_and_repository() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let span_exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(span_exporter.clone())
+            .build();
+
+        let subscriber =
+            tracing_subscriber::registry().with(TelemetryGateway::tracing_layer(&tracer_provider));
+        let _default_subscriber = tracing::subscriber::set_default(subscriber);
+
+        let request = Request::builder()
+            .uri("/users/search?q=ada")
+            .header("traceparent", format!("00-{TRACE_ID}-{REMOTE_SPAN_ID}-01"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router().oneshot(request).await.unwrap();
+
+        let spans = span_exporter.get_finished_spans().unwrap();
+        let server_span = find_span(&spans, "GET /users/search");
+        let controller_span = find_span(&spans, "SearchUsersController");
+        let use_case_span = find_span(&spans, "SearchUsersUseCase::perform");
+        let repository_span = find_span(&spans, "InMemoryUserSearchRepository::search");
+
+        let trace_id = TraceId::from_hex(TRACE_ID).unwrap();
+
+        for span in [server_span, controller_span, use_case_span, repository_span] {
+            assert_eq!(span.span_context.trace_id(), trace_id);
+        }
+
+        assert_eq!(server_span.parent_span_id.to_string(), REMOTE_SPAN_ID);
+        assert_eq!(
+            controller_span.parent_span_id,
+            server_span.span_context.span_id()
+        );
+        assert_eq!(
+            use_case_span.parent_span_id,
+            controller_span.span_context.span_id()
+        );
+        assert_eq!(
+            repository_span.parent_span_id,
+            use_case_span.span_context.span_id()
+        );
+
+        let traceparent = response.headers()["traceparent"].to_str().unwrap();
+
+        assert_eq!(
+            traceparent,
+            format!("00-{TRACE_ID}-{}-01", server_span.span_context.span_id())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_start_new_trace_without_traceparent_header() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let span_exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(span_exporter.clone())
+            .build();
+
+        let subscriber =
+            tracing_subscriber::registry().with(TelemetryGateway::tracing_layer(&tracer_provider));
+        let _default_subscriber = tracing::subscriber::set_default(subscriber);
+
+        let request = Request::builder()
+            .uri("/users/search?q=ada")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = build_router().oneshot(request).await.unwrap();
+
+        let spans = span_exporter.get_finished_spans().unwrap();
+        let server_span = find_span(&spans, "GET /users/search");
+
+        assert_eq!(server_span.parent_span_id, SpanId::INVALID);
+        assert!(
+            response.headers()["traceparent"]
+                .to_str()
+                .unwrap()
+                .contains(&server_span.span_context.trace_id().to_string())
+        );
+    }
+}
@@ -1,11 +1,15 @@
 use std::pin::Pin;
 
-use crate::presentation::dtos::http::{
-    http_request_dto::HttpRequestDto, http_response_dto::HttpResponseDto,
+use crate::presentation::{
+    dtos::http::{http_request_dto::HttpRequestDto, http_response_dto::HttpResponseDto},
+    ports::request_body::request_body_port::RequestBodyPort,
 };
 
 pub type ControllerFuture<'a> = Pin<Box<dyn Future<Output = HttpResponseDto> + Send + 'a>>;
 
 pub trait ControllerPort: Send + Sync {
-    fn handle(&self, request: HttpRequestDto) -> ControllerFuture<'_>;
+    /// Type the JSON body is deserialized into before `handle` runs.
+    type Body: RequestBodyPort;
+
+    fn handle(&self, request: HttpRequestDto<Self::Body>) -> ControllerFuture<'_>;
 }
@@ -0,0 +1,21 @@
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::presentation::validation::validation_schema::ValidationSchema;
+
+/// A typed request body. The handler adapter checks the JSON against `schema` and then
+/// deserializes it once, so controllers only ever see the typed value.
+pub trait RequestBodyPort: DeserializeOwned + Send + 'static {
+    /// Rules the JSON body must satisfy before it is deserialized. `None` means the endpoint
+    /// takes no JSON body, so none is read.
+    fn schema() -> Option<ValidationSchema>;
+}
+
+/// Body of endpoints that take no JSON body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct NoBody;
+
+impl RequestBodyPort for NoBody {
+    fn schema() -> Option<ValidationSchema> {
+        None
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Path, Request},
+    http::StatusCode,
+    middleware::{self},
+    routing::{get, post, put},
+};
+use tower_http::limit::RequestBodyLimitLayer;
+
+use crate::{
+    application::use_cases::{
+        admin::import_users_use_case::MAX_IMPORT_ROWS,
+        auth::authenticate_use_case::AuthenticateUseCasePort,
+    },
+    infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
+    presentation::{
+        middlewares::auth::auth_middleware::AuthMiddleware,
+        ports::{controller::controller_port::ControllerPort, router::router_port::RouterPort},
+    },
+};
+
+/// Rejects oversized imports before they are buffered and parsed. Two kilobytes per row fit the
+/// longest names, e-mail and password along with the JSONL keys.
+const IMPORT_USERS_BODY_LIMIT: usize = MAX_IMPORT_ROWS * 2 * 1024;
+
+pub struct AdminRouter<A, C, I, L, P, U> {
+    auth_middleware: AuthMiddleware<A>,
+    admin_user_action_controller: C,
+    import_users_controller: I,
+    change_log_level_controller: L,
+    get_email_domain_policy_controller: P,
+    update_email_domain_policy_controller: U,
+}
+
+impl<A, C, I, L, P, U> AdminRouter<A, C, I, L, P, U>
+where
+    A: AuthenticateUseCasePort + Clone + Send + Sync,
+    C: ControllerPort + Clone + Send + Sync,
+    I: ControllerPort + Clone + Send + Sync,
+    L: ControllerPort + Clone + Send + Sync,
+    P: ControllerPort + Clone + Send + Sync,
+    U: ControllerPort + Clone + Send + Sync,
+{
+    #[must_use]
+    pub const fn new(
+        auth_middleware: AuthMiddleware<A>,
+        admin_user_action_controller: C,
+        import_users_controller: I,
+        change_log_level_controller: L,
+        get_email_domain_policy_controller: P,
+        update_email_domain_policy_controller: U,
+    ) -> Self {
+        Self {
+            auth_middleware,
+            admin_user_action_controller,
+            import_users_controller,
+            change_log_level_controller,
+            get_email_domain_policy_controller,
+            update_email_domain_policy_controller,
+        }
+    }
+}
+
+impl<A, C, I, L, P, U> RouterPort for AdminRouter<A, C, I, L, P, U>
+where
+    A: AuthenticateUseCasePort + Clone + Send + Sync + 'static,
+    C: ControllerPort + Clone + Send + Sync + 'static,
+    I: ControllerPort + Clone + Send + Sync + 'static,
+    L: ControllerPort + Clone + Send + Sync + 'static,
+    P: ControllerPort + Clone + Send + Sync + 'static,
+    U: ControllerPort + Clone + Send + Sync + 'static,
+{
+    fn register_routes(self) -> Router {
+        let auth_middleware = self.auth_middleware;
+        let admin_user_action_controller_adapter =
+            AxumHandlerAdapter::new(self.admin_user_action_controller);
+        let import_users_controller_adapter = AxumHandlerAdapter::new(self.import_users_controller);
+        let change_log_level_controller_adapter =
+            AxumHandlerAdapter::new(self.change_log_level_controller);
+        let get_email_domain_policy_controller_adapter =
+            AxumHandlerAdapter::new(self.get_email_domain_policy_controller);
+        let update_email_domain_policy_controller_adapter =
+            AxumHandlerAdapter::new(self.update_email_domain_policy_controller);
+
+        Router::new()
+            .route(
+                "/admin/email-domain-policy",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        get_email_domain_policy_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .put({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        update_email_domain_policy_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/admin/log-level",
+                put({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        change_log_level_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/admin/users/import",
+                post({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        import_users_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .layer(RequestBodyLimitLayer::new(IMPORT_USERS_BODY_LIMIT))
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/admin/users/{id}/{action}",
+                post({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        admin_user_action_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route_layer(middleware::from_fn({
+                move |request, next| {
+                    let auth_middleware = auth_middleware.clone();
+
+                    async move { auth_middleware.process(request, next).await }
+                }
+            }))
+    }
+}
@@ -14,37 +14,46 @@ use crate::{
     },
 };
 
-pub struct AuthRouter<SignUpController, SignInController> {
+#[allow(clippy::struct_field_names)]
+pub struct AuthRouter<SignUpController, SignInController, ChangePasswordController> {
     sign_up_controller: SignUpController,
     sign_in_controller: SignInController,
+    change_password_controller: ChangePasswordController,
 }
 
-impl<SignUpController, SignInController> AuthRouter<SignUpController, SignInController>
+impl<SignUpController, SignInController, ChangePasswordController>
+    AuthRouter<SignUpController, SignInController, ChangePasswordController>
 where
     SignUpController: ControllerPort + Clone + Send + Sync,
     SignInController: ControllerPort + Clone + Send + Sync,
+    ChangePasswordController: ControllerPort + Clone + Send + Sync,
 {
     #[must_use]
     pub const fn new(
         sign_up_controller: SignUpController,
         sign_in_controller: SignInController,
+        change_password_controller: ChangePasswordController,
     ) -> Self {
         Self {
             sign_up_controller,
             sign_in_controller,
+            change_password_controller,
         }
     }
 }
 
-impl<SignUpController, SignInController> RouterPort
-    for AuthRouter<SignUpController, SignInController>
+impl<SignUpController, SignInController, ChangePasswordController> RouterPort
+    for AuthRouter<SignUpController, SignInController, ChangePasswordController>
 where
     SignUpController: ControllerPort + Clone + Send + Sync + 'static,
     SignInController: ControllerPort + Clone + Send + Sync + 'static,
+    ChangePasswordController: ControllerPort + Clone + Send + Sync + 'static,
 {
     fn register_routes(self) -> Router {
         let sign_up_controller_adapter = AxumHandlerAdapter::new(self.sign_up_controller);
         let sign_in_controller_adapter = AxumHandlerAdapter::new(self.sign_in_controller);
+        let change_password_controller_adapter =
+            AxumHandlerAdapter::new(self.change_password_controller);
 
         Router::new()
             .route(
@@ -67,5 +76,15 @@ where
                     }
                 }),
             )
+            .route(
+                "/auth/change-password",
+                post({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        change_password_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                }),
+            )
     }
 }
@@ -1,93 +1,323 @@
-use axum::{Json, Router, http::StatusCode, response::IntoResponse};
+use axum::{Router, http::Uri, middleware, response::Response};
 use serde_json::json;
 use tower::ServiceBuilder;
 use tower_governor::{GovernorError, GovernorLayer, governor::GovernorConfigBuilder};
 use tower_helmet::HelmetLayer;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use tower_http::cors::{AllowOrigin, CorsLayer};
 
-use crate::presentation::{
-    ports::{controller::controller_port::ControllerPort, router::router_port::RouterPort},
-    routers::{auth::auth_router::AuthRouter, user::user_router::UserRouter},
+use crate::{
+    application::{
+        ports::{
+            id_generator::id_generator_port::IdGeneratorPort,
+            localization::localization_port::LocalizationPort, metrics::metrics_port::MetricsPort,
+            tracing::trace_context_port::TraceContextPort,
+        },
+        use_cases::auth::authenticate_use_case::AuthenticateUseCasePort,
+    },
+    infrastructure::config::app_config::{CorsConfig, RateLimitConfig},
+    presentation::{
+        errors::api_error::{ApiError, ApiErrorCode},
+        middlewares::{
+            auth::auth_middleware::AuthMiddleware,
+            localization::localization_middleware::LocalizationMiddleware,
+            metrics::metrics_middleware::MetricsMiddleware,
+            request_id::request_id_middleware::RequestIdMiddleware,
+            tracing::trace_context_middleware::TraceContextMiddleware,
+        },
+        ports::{controller::controller_port::ControllerPort, router::router_port::RouterPort},
+        routers::{
+            admin::admin_router::AdminRouter, auth::auth_router::AuthRouter,
+            data_export::data_export_router::DataExportRouter, health::health_router::HealthRouter,
+            metrics::metrics_router::MetricsRouter, user::user_router::UserRouter,
+        },
+    },
 };
 
 #[allow(clippy::struct_field_names)]
-pub struct CoreRouter<SU, SI, G> {
+pub struct CoreRouter<
+    M,
+    T,
+    I,
+    N,
+    A,
+    SU,
+    SI,
+    CP,
+    G,
+    S,
+    UA,
+    GA,
+    AD,
+    IU,
+    LL,
+    GP,
+    UP,
+    EX,
+    GE,
+    DE,
+    LV,
+    RD,
+    GM,
+> {
+    rate_limit_config: RateLimitConfig,
+    cors_config: CorsConfig,
+    metrics_adapter: M,
+    trace_context_middleware: TraceContextMiddleware<T>,
+    request_id_middleware: RequestIdMiddleware<I>,
+    localization_middleware: LocalizationMiddleware<N>,
+    auth_middleware: AuthMiddleware<A>,
     sign_up_controller: SU,
     sign_in_controller: SI,
+    change_password_controller: CP,
     get_user_by_id_controller: G,
+    search_users_controller: S,
+    upload_avatar_controller: UA,
+    get_avatar_controller: GA,
+    admin_user_action_controller: AD,
+    import_users_controller: IU,
+    change_log_level_controller: LL,
+    get_email_domain_policy_controller: GP,
+    update_email_domain_policy_controller: UP,
+    export_user_data_controller: EX,
+    get_data_export_controller: GE,
+    download_data_export_controller: DE,
+    liveness_controller: LV,
+    readiness_controller: RD,
+    get_metrics_controller: GM,
 }
 
-impl<SU, SI, G> CoreRouter<SU, SI, G>
+impl<M, T, I, N, A, SU, SI, CP, G, S, UA, GA, AD, IU, LL, GP, UP, EX, GE, DE, LV, RD, GM>
+    CoreRouter<M, T, I, N, A, SU, SI, CP, G, S, UA, GA, AD, IU, LL, GP, UP, EX, GE, DE, LV, RD, GM>
 where
+    M: MetricsPort + Clone + Send + Sync,
+    T: TraceContextPort + Clone + Send + Sync,
+    I: IdGeneratorPort + Clone + Send + Sync,
+    N: LocalizationPort + Clone + Send + Sync,
+    A: AuthenticateUseCasePort + Clone + Send + Sync,
     SU: ControllerPort + Clone + Send + Sync,
     SI: ControllerPort + Clone + Send + Sync,
+    CP: ControllerPort + Clone + Send + Sync,
     G: ControllerPort + Clone + Send + Sync,
+    S: ControllerPort + Clone + Send + Sync,
+    UA: ControllerPort + Clone + Send + Sync,
+    GA: ControllerPort + Clone + Send + Sync,
+    AD: ControllerPort + Clone + Send + Sync,
+    IU: ControllerPort + Clone + Send + Sync,
+    LL: ControllerPort + Clone + Send + Sync,
+    GP: ControllerPort + Clone + Send + Sync,
+    UP: ControllerPort + Clone + Send + Sync,
+    EX: ControllerPort + Clone + Send + Sync,
+    GE: ControllerPort + Clone + Send + Sync,
+    DE: ControllerPort + Clone + Send + Sync,
+    LV: ControllerPort + Clone + Send + Sync,
+    RD: ControllerPort + Clone + Send + Sync,
+    GM: ControllerPort + Clone + Send + Sync,
 {
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
+        rate_limit_config: RateLimitConfig,
+        cors_config: CorsConfig,
+        metrics_adapter: M,
+        trace_context_middleware: TraceContextMiddleware<T>,
+        request_id_middleware: RequestIdMiddleware<I>,
+        localization_middleware: LocalizationMiddleware<N>,
+        auth_middleware: AuthMiddleware<A>,
         sign_up_controller: SU,
         sign_in_controller: SI,
+        change_password_controller: CP,
         get_user_by_id_controller: G,
+        search_users_controller: S,
+        upload_avatar_controller: UA,
+        get_avatar_controller: GA,
+        admin_user_action_controller: AD,
+        import_users_controller: IU,
+        change_log_level_controller: LL,
+        get_email_domain_policy_controller: GP,
+        update_email_domain_policy_controller: UP,
+        export_user_data_controller: EX,
+        get_data_export_controller: GE,
+        download_data_export_controller: DE,
+        liveness_controller: LV,
+        readiness_controller: RD,
+        get_metrics_controller: GM,
     ) -> Self {
         Self {
+            rate_limit_config,
+            cors_config,
+            metrics_adapter,
+            trace_context_middleware,
+            request_id_middleware,
+            localization_middleware,
+            auth_middleware,
             sign_up_controller,
             sign_in_controller,
+            change_password_controller,
             get_user_by_id_controller,
+            search_users_controller,
+            upload_avatar_controller,
+            get_avatar_controller,
+            admin_user_action_controller,
+            import_users_controller,
+            change_log_level_controller,
+            get_email_domain_policy_controller,
+            update_email_domain_policy_controller,
+            export_user_data_controller,
+            get_data_export_controller,
+            download_data_export_controller,
+            liveness_controller,
+            readiness_controller,
+            get_metrics_controller,
         }
     }
+
+    /// Maps rate limiter errors to problem details responses, counting the requests it turned
+    /// away.
+    fn build_governor_error_response(err: &GovernorError, metrics_adapter: &M) -> Response {
+        let api_error = match err {
+            GovernorError::TooManyRequests { wait_time, .. } => {
+                metrics_adapter.record_rate_limit_rejection();
+
+                ApiError::new(ApiErrorCode::TooManyRequests, "rate limit exceeded")
+                    .with_extension("retry_after_seconds", json!(wait_time))
+            }
+            GovernorError::UnableToExtractKey | GovernorError::Other { .. } => {
+                ApiError::internal(&err)
+            }
+        };
+
+        api_error.into_response(None)
+    }
+
+    fn build_cors_middleware(cors_config: &CorsConfig) -> CorsLayer {
+        if cors_config.allows_any_origin() {
+            return CorsLayer::permissive();
+        }
+
+        let allowed_origins = cors_config
+            .allowed_origins
+            .iter()
+            .filter_map(|origin| origin.parse().ok())
+            .collect::<Vec<_>>();
+
+        CorsLayer::permissive().allow_origin(AllowOrigin::list(allowed_origins))
+    }
 }
 
-impl<SU, SI, G> RouterPort for CoreRouter<SU, SI, G>
+impl<M, T, I, N, A, SU, SI, CP, G, S, UA, GA, AD, IU, LL, GP, UP, EX, GE, DE, LV, RD, GM> RouterPort
+    for CoreRouter<
+        M,
+        T,
+        I,
+        N,
+        A,
+        SU,
+        SI,
+        CP,
+        G,
+        S,
+        UA,
+        GA,
+        AD,
+        IU,
+        LL,
+        GP,
+        UP,
+        EX,
+        GE,
+        DE,
+        LV,
+        RD,
+        GM,
+    >
 where
+    M: MetricsPort + Clone + Send + Sync + 'static,
+    T: TraceContextPort + Clone + Send + Sync + 'static,
+    I: IdGeneratorPort + Clone + Send + Sync + 'static,
+    N: LocalizationPort + Clone + Send + Sync + 'static,
+    A: AuthenticateUseCasePort + Clone + Send + Sync + 'static,
     SU: ControllerPort + Clone + Send + Sync + 'static,
     SI: ControllerPort + Clone + Send + Sync + 'static,
+    CP: ControllerPort + Clone + Send + Sync + 'static,
     G: ControllerPort + Clone + Send + Sync + 'static,
+    S: ControllerPort + Clone + Send + Sync + 'static,
+    UA: ControllerPort + Clone + Send + Sync + 'static,
+    GA: ControllerPort + Clone + Send + Sync + 'static,
+    AD: ControllerPort + Clone + Send + Sync + 'static,
+    IU: ControllerPort + Clone + Send + Sync + 'static,
+    LL: ControllerPort + Clone + Send + Sync + 'static,
+    GP: ControllerPort + Clone + Send + Sync + 'static,
+    UP: ControllerPort + Clone + Send + Sync + 'static,
+    EX: ControllerPort + Clone + Send + Sync + 'static,
+    GE: ControllerPort + Clone + Send + Sync + 'static,
+    DE: ControllerPort + Clone + Send + Sync + 'static,
+    LV: ControllerPort + Clone + Send + Sync + 'static,
+    RD: ControllerPort + Clone + Send + Sync + 'static,
+    GM: ControllerPort + Clone + Send + Sync + 'static,
 {
     fn register_routes(self) -> Router {
-        let auth_router =
-            AuthRouter::new(self.sign_up_controller, self.sign_in_controller).register_routes();
+        let auth_router = AuthRouter::new(
+            self.sign_up_controller,
+            self.sign_in_controller,
+            self.change_password_controller,
+        )
+        .register_routes();
+
+        let user_router = UserRouter::new(
+            self.auth_middleware.clone(),
+            self.get_user_by_id_controller,
+            self.search_users_controller,
+            self.upload_avatar_controller,
+            self.get_avatar_controller,
+        )
+        .register_routes();
+
+        let admin_router = AdminRouter::new(
+            self.auth_middleware.clone(),
+            self.admin_user_action_controller,
+            self.import_users_controller,
+            self.change_log_level_controller,
+            self.get_email_domain_policy_controller,
+            self.update_email_domain_policy_controller,
+        )
+        .register_routes();
 
-        let user_router = UserRouter::new(self.get_user_by_id_controller).register_routes();
-        let cors_middleware = CorsLayer::permissive();
-        let trace_layer_middleware = TraceLayer::new_for_http();
+        let data_export_router = DataExportRouter::new(
+            self.auth_middleware,
+            self.export_user_data_controller,
+            self.get_data_export_controller,
+            self.download_data_export_controller,
+        )
+        .register_routes();
+
+        let health_router = HealthRouter::new(self.liveness_controller, self.readiness_controller)
+            .register_routes();
+
+        let metrics_router = MetricsRouter::new(self.get_metrics_controller).register_routes();
+        let metrics_middleware = MetricsMiddleware::new(self.metrics_adapter.clone());
+        let rate_limit_metrics_adapter = self.metrics_adapter;
+        let trace_context_middleware = self.trace_context_middleware;
+        let request_id_middleware = self.request_id_middleware;
+        let localization_middleware = self.localization_middleware;
+
+        let cors_middleware = Self::build_cors_middleware(&self.cors_config);
 
         let governor_config = GovernorConfigBuilder::default()
-            .per_second(2)
-            .burst_size(5)
+            .per_second(self.rate_limit_config.replenish_interval_seconds)
+            .burst_size(self.rate_limit_config.burst_size)
             .finish()
             .unwrap();
 
         let governor_middleware =
-            GovernorLayer::new(governor_config).error_handler(|err: GovernorError| match err {
-                GovernorError::TooManyRequests { .. } => (
-                    StatusCode::TOO_MANY_REQUESTS,
-                    Json(json!({
-                        "error_code": "too_many_requests",
-                        "error_message": "rate limit exceeded"
-                    })),
-                )
-                    .into_response(),
-
-                GovernorError::UnableToExtractKey => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({
-                        "error_code": "unable_to_extract_key",
-                        "error_message": "a key extractor must be provided"
-                    })),
-                )
-                    .into_response(),
-                GovernorError::Other { msg, .. } => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({
-                        "error_code": "internal_server_error",
-                        "error_message": msg
-                    })),
-                )
-                    .into_response(),
+            GovernorLayer::new(governor_config).error_handler(move |err: GovernorError| {
+                Self::build_governor_error_response(&err, &rate_limit_metrics_adapter)
             });
 
         let helmet_middleware = HelmetLayer::with_defaults();
-        let merged_routers = auth_router.merge(user_router);
+        let merged_routers = auth_router
+            .merge(user_router)
+            .merge(admin_router)
+            .merge(data_export_router);
 
         Router::new()
             .nest("/api/v1", merged_routers)
@@ -97,12 +327,35 @@ where
                     .layer(governor_middleware)
                     .layer(helmet_middleware),
             )
-            .layer(trace_layer_middleware)
-            .fallback(|| async {
-                (
-                    StatusCode::NOT_FOUND,
-                    Json(json!({ "error": "router not found" })),
+            // Merged after the layers above so probes and scrapes are never rate limited.
+            .merge(health_router)
+            .merge(metrics_router)
+            .fallback(|uri: Uri| async move {
+                ApiError::new(
+                    ApiErrorCode::RouteNotFound,
+                    format!("no route matches {}", uri.path()),
                 )
+                .into_response(Some(uri.path()))
             })
+            .layer(middleware::from_fn(move |request, next| {
+                let metrics_middleware = metrics_middleware.clone();
+
+                async move { metrics_middleware.process(request, next).await }
+            }))
+            .layer(middleware::from_fn(move |request, next| {
+                let trace_context_middleware = trace_context_middleware.clone();
+
+                async move { trace_context_middleware.process(request, next).await }
+            }))
+            .layer(middleware::from_fn(move |request, next| {
+                let localization_middleware = localization_middleware.clone();
+
+                async move { localization_middleware.process(request, next).await }
+            }))
+            .layer(middleware::from_fn(move |request, next| {
+                let request_id_middleware = request_id_middleware.clone();
+
+                async move { request_id_middleware.process(request, next).await }
+            }))
     }
 }
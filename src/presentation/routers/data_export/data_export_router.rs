@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Path, Request},
+    http::StatusCode,
+    middleware::{self},
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::auth::authenticate_use_case::AuthenticateUseCasePort,
+    infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
+    presentation::{
+        middlewares::auth::auth_middleware::AuthMiddleware,
+        ports::{controller::controller_port::ControllerPort, router::router_port::RouterPort},
+    },
+};
+
+pub struct DataExportRouter<A, E, G, D> {
+    auth_middleware: AuthMiddleware<A>,
+    export_user_data_controller: E,
+    get_data_export_controller: G,
+    download_data_export_controller: D,
+}
+
+impl<A, E, G, D> DataExportRouter<A, E, G, D>
+where
+    A: AuthenticateUseCasePort + Clone + Send + Sync,
+    E: ControllerPort + Clone + Send + Sync,
+    G: ControllerPort + Clone + Send + Sync,
+    D: ControllerPort + Clone + Send + Sync,
+{
+    #[must_use]
+    pub const fn new(
+        auth_middleware: AuthMiddleware<A>,
+        export_user_data_controller: E,
+        get_data_export_controller: G,
+        download_data_export_controller: D,
+    ) -> Self {
+        Self {
+            auth_middleware,
+            export_user_data_controller,
+            get_data_export_controller,
+            download_data_export_controller,
+        }
+    }
+}
+
+impl<A, E, G, D> RouterPort for DataExportRouter<A, E, G, D>
+where
+    A: AuthenticateUseCasePort + Clone + Send + Sync + 'static,
+    E: ControllerPort + Clone + Send + Sync + 'static,
+    G: ControllerPort + Clone + Send + Sync + 'static,
+    D: ControllerPort + Clone + Send + Sync + 'static,
+{
+    fn register_routes(self) -> Router {
+        let auth_middleware = self.auth_middleware;
+        let export_user_data_controller_adapter =
+            AxumHandlerAdapter::new(self.export_user_data_controller);
+        let admin_export_user_data_controller_adapter = export_user_data_controller_adapter.clone();
+        let get_data_export_controller_adapter =
+            AxumHandlerAdapter::new(self.get_data_export_controller);
+        let download_data_export_controller_adapter =
+            AxumHandlerAdapter::new(self.download_data_export_controller);
+
+        Router::new()
+            .route(
+                "/me/export",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        export_user_data_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/me/exports/{id}",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        get_data_export_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/me/exports/{id}/archive",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        download_data_export_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/admin/users/{id}/export",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        admin_export_user_data_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route_layer(middleware::from_fn({
+                move |request, next| {
+                    let auth_middleware = auth_middleware.clone();
+
+                    async move { auth_middleware.process(request, next).await }
+                }
+            }))
+    }
+}
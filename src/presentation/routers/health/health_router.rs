@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Path, Request},
+    routing::get,
+};
+
+use crate::{
+    infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
+    presentation::ports::{
+        controller::controller_port::ControllerPort, router::router_port::RouterPort,
+    },
+};
+
+pub struct HealthRouter<L, R> {
+    liveness_controller: L,
+    readiness_controller: R,
+}
+
+impl<L, R> HealthRouter<L, R>
+where
+    L: ControllerPort + Clone + Send + Sync,
+    R: ControllerPort + Clone + Send + Sync,
+{
+    #[must_use]
+    pub const fn new(liveness_controller: L, readiness_controller: R) -> Self {
+        Self {
+            liveness_controller,
+            readiness_controller,
+        }
+    }
+}
+
+impl<L, R> RouterPort for HealthRouter<L, R>
+where
+    L: ControllerPort + Clone + Send + Sync + 'static,
+    R: ControllerPort + Clone + Send + Sync + 'static,
+{
+    fn register_routes(self) -> Router {
+        let liveness_controller_adapter = AxumHandlerAdapter::new(self.liveness_controller);
+        let readiness_controller_adapter = AxumHandlerAdapter::new(self.readiness_controller);
+
+        Router::new()
+            .route(
+                "/health/live",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        liveness_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                }),
+            )
+            .route(
+                "/health/ready",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        readiness_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                }),
+            )
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Path, Request},
+    routing::get,
+};
+
+use crate::{
+    infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
+    presentation::ports::{
+        controller::controller_port::ControllerPort, router::router_port::RouterPort,
+    },
+};
+
+pub struct MetricsRouter<M> {
+    get_metrics_controller: M,
+}
+
+impl<M> MetricsRouter<M>
+where
+    M: ControllerPort + Clone + Send + Sync,
+{
+    #[must_use]
+    pub const fn new(get_metrics_controller: M) -> Self {
+        Self {
+            get_metrics_controller,
+        }
+    }
+}
+
+impl<M> RouterPort for MetricsRouter<M>
+where
+    M: ControllerPort + Clone + Send + Sync + 'static,
+{
+    fn register_routes(self) -> Router {
+        let get_metrics_controller_adapter = AxumHandlerAdapter::new(self.get_metrics_controller);
+
+        Router::new().route(
+            "/metrics",
+            get({
+                move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                    get_metrics_controller_adapter
+                        .adapt_handler(path, request)
+                        .await
+                }
+            }),
+        )
+    }
+}
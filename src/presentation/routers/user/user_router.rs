@@ -6,59 +6,129 @@ use axum::{
     extract::{Path, Request},
     http::StatusCode,
     middleware::{self},
-    routing::get,
+    routing::{get, put},
 };
+use tower_http::limit::RequestBodyLimitLayer;
 
 use crate::{
-    infrastructure::adapters::{
-        axum::axum_handler_adapter::AxumHandlerAdapter,
-        jsonwebtoken::jsonwebtoken_adapter::JsonWebTokenAdapter,
+    application::use_cases::{
+        auth::authenticate_use_case::AuthenticateUseCasePort,
+        user::upload_avatar_use_case::MAX_AVATAR_BYTES,
     },
+    infrastructure::adapters::axum::axum_handler_adapter::AxumHandlerAdapter,
     presentation::{
         middlewares::auth::auth_middleware::AuthMiddleware,
         ports::{controller::controller_port::ControllerPort, router::router_port::RouterPort},
     },
 };
 
-pub struct UserRouter<C> {
-    get_user_by_id_controller: C,
+/// Leaves room for the multipart boundaries and headers around the avatar itself.
+const AVATAR_UPLOAD_BODY_LIMIT: usize = MAX_AVATAR_BYTES + 64 * 1024;
+
+#[allow(clippy::struct_field_names)]
+pub struct UserRouter<A, G, S, UA, GA> {
+    auth_middleware: AuthMiddleware<A>,
+    get_user_by_id_controller: G,
+    search_users_controller: S,
+    upload_avatar_controller: UA,
+    get_avatar_controller: GA,
 }
 
-impl<C> UserRouter<C>
+impl<A, G, S, UA, GA> UserRouter<A, G, S, UA, GA>
 where
-    C: ControllerPort + Clone + Send + Sync,
+    A: AuthenticateUseCasePort + Clone + Send + Sync,
+    G: ControllerPort + Clone + Send + Sync,
+    S: ControllerPort + Clone + Send + Sync,
+    UA: ControllerPort + Clone + Send + Sync,
+    GA: ControllerPort + Clone + Send + Sync,
 {
     #[must_use]
-    pub const fn new(get_user_by_id_controller: C) -> Self {
+    pub const fn new(
+        auth_middleware: AuthMiddleware<A>,
+        get_user_by_id_controller: G,
+        search_users_controller: S,
+        upload_avatar_controller: UA,
+        get_avatar_controller: GA,
+    ) -> Self {
         Self {
+            auth_middleware,
             get_user_by_id_controller,
+            search_users_controller,
+            upload_avatar_controller,
+            get_avatar_controller,
         }
     }
 }
 
-impl<C> RouterPort for UserRouter<C>
+impl<A, G, S, UA, GA> RouterPort for UserRouter<A, G, S, UA, GA>
 where
-    C: ControllerPort + Clone + Send + Sync + 'static,
+    A: AuthenticateUseCasePort + Clone + Send + Sync + 'static,
+    G: ControllerPort + Clone + Send + Sync + 'static,
+    S: ControllerPort + Clone + Send + Sync + 'static,
+    UA: ControllerPort + Clone + Send + Sync + 'static,
+    GA: ControllerPort + Clone + Send + Sync + 'static,
 {
     fn register_routes(self) -> Router {
-        let auth_middleware = AuthMiddleware::new(JsonWebTokenAdapter);
-        let axum_handler_adapter = AxumHandlerAdapter::new(self.get_user_by_id_controller);
+        let auth_middleware = self.auth_middleware;
+        let get_user_by_id_controller_adapter =
+            AxumHandlerAdapter::new(self.get_user_by_id_controller);
+        let search_users_controller_adapter = AxumHandlerAdapter::new(self.search_users_controller);
+        let upload_avatar_controller_adapter =
+            AxumHandlerAdapter::new(self.upload_avatar_controller);
+        let get_avatar_controller_adapter = AxumHandlerAdapter::new(self.get_avatar_controller);
 
-        Router::new().route(
-            "/users/{id}",
-            get({
-                move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
-                    axum_handler_adapter.adapt_handler(path, request).await
-                }
-            })
-            .options(|| async { StatusCode::OK })
-            .layer(middleware::from_fn({
+        Router::new()
+            .route(
+                "/users/search",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        search_users_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/me/avatar",
+                put({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        upload_avatar_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .layer(RequestBodyLimitLayer::new(AVATAR_UPLOAD_BODY_LIMIT))
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/users/{id}/avatar",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        get_avatar_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route(
+                "/users/{id}",
+                get({
+                    move |path: Path<HashMap<String, String>>, request: Request<Body>| async move {
+                        get_user_by_id_controller_adapter
+                            .adapt_handler(path, request)
+                            .await
+                    }
+                })
+                .options(|| async { StatusCode::OK }),
+            )
+            .route_layer(middleware::from_fn({
                 move |request, next| {
                     let auth_middleware = auth_middleware.clone();
 
                     async move { auth_middleware.process(request, next).await }
                 }
-            })),
-        )
+            }))
     }
 }
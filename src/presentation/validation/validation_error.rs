@@ -0,0 +1,165 @@
+use serde_json::{Map, Value, json};
+use serde_path_to_error::Segment;
+
+/// Why a value failed validation. The code doubles as the key of the translated message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    InvalidBody,
+    Missing,
+    InvalidType {
+        expected_type: &'static str,
+    },
+    Empty,
+    TooShort {
+        min_length: usize,
+    },
+    TooLong {
+        max_length: usize,
+    },
+    PatternMismatch,
+    NotAllowed {
+        allowed_values: Vec<String>,
+    },
+    TooFewItems {
+        min_items: usize,
+    },
+    TooManyItems {
+        max_items: usize,
+    },
+    NotEqual {
+        other_pointer: String,
+    },
+    /// The value passed the schema but could not be deserialized into the body type.
+    InvalidValue {
+        reason: String,
+    },
+}
+
+impl ValidationErrorKind {
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidBody => "invalid_body",
+            Self::Missing => "missing",
+            Self::InvalidType { .. } => "invalid_type",
+            Self::Empty => "empty",
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::PatternMismatch => "pattern_mismatch",
+            Self::NotAllowed { .. } => "not_allowed",
+            Self::TooFewItems { .. } => "too_few_items",
+            Self::TooManyItems { .. } => "too_many_items",
+            Self::NotEqual { .. } => "not_equal",
+            Self::InvalidValue { .. } => "invalid_value",
+        }
+    }
+}
+
+/// A rule a request body broke, located by the JSON pointer of the offending value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pointer: String,
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    #[must_use]
+    pub const fn new(pointer: String, kind: ValidationErrorKind) -> Self {
+        Self { pointer, kind }
+    }
+
+    #[must_use]
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+
+    /// Renders the error with the rule's parameters and an English message that the
+    /// localization middleware translates from the `error` code.
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let pointer = &self.pointer;
+        let mut error = Map::new();
+
+        error.insert("pointer".to_string(), json!(pointer));
+        error.insert("error".to_string(), json!(self.kind.code()));
+
+        let message = match &self.kind {
+            ValidationErrorKind::InvalidBody => {
+                "the request body must be a JSON object".to_string()
+            }
+            ValidationErrorKind::Missing => format!("the field '{pointer}' is required"),
+            ValidationErrorKind::InvalidType { expected_type } => {
+                error.insert("expected_type".to_string(), json!(expected_type));
+                format!("the field '{pointer}' must be of type {expected_type}")
+            }
+            ValidationErrorKind::Empty => format!("the field '{pointer}' must not be empty"),
+            ValidationErrorKind::TooShort { min_length } => {
+                error.insert("min_length".to_string(), json!(min_length));
+                format!("the field '{pointer}' must have at least {min_length} characters")
+            }
+            ValidationErrorKind::TooLong { max_length } => {
+                error.insert("max_length".to_string(), json!(max_length));
+                format!("the field '{pointer}' must have at most {max_length} characters")
+            }
+            ValidationErrorKind::PatternMismatch => {
+                format!("the field '{pointer}' is not in the expected format")
+            }
+            ValidationErrorKind::NotAllowed { allowed_values } => {
+                let allowed_values = allowed_values.join(", ");
+                let message = format!("the field '{pointer}' must be one of: {allowed_values}");
+
+                error.insert("allowed_values".to_string(), json!(allowed_values));
+                message
+            }
+            ValidationErrorKind::TooFewItems { min_items } => {
+                error.insert("min_items".to_string(), json!(min_items));
+                format!("the field '{pointer}' must have at least {min_items} items")
+            }
+            ValidationErrorKind::TooManyItems { max_items } => {
+                error.insert("max_items".to_string(), json!(max_items));
+                format!("the field '{pointer}' must have at most {max_items} items")
+            }
+            ValidationErrorKind::NotEqual { other_pointer } => {
+                error.insert("other_pointer".to_string(), json!(other_pointer));
+                format!("the field '{pointer}' must match '{other_pointer}'")
+            }
+            ValidationErrorKind::InvalidValue { reason } => {
+                error.insert("reason".to_string(), json!(reason));
+                format!("the field '{pointer}' has an invalid value")
+            }
+        };
+
+        error.insert("message".to_string(), json!(message));
+
+        Value::Object(error)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for ValidationError {
+    /// Locates a deserialization failure by the path serde walked to reach it.
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let pointer = err
+            .path()
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Seq { index } => Some(format!("/{index}")),
+                Segment::Map { key } => {
+                    Some(format!("/{}", key.replace('~', "~0").replace('/', "~1")))
+                }
+                Segment::Enum { .. } | Segment::Unknown => None,
+            })
+            .collect::<String>();
+
+        Self::new(
+            pointer,
+            ValidationErrorKind::InvalidValue {
+                reason: err.into_inner().to_string(),
+            },
+        )
+    }
+}
@@ -0,0 +1,491 @@
+use regex::Regex;
+use serde_json::Value;
+
+use crate::presentation::validation::validation_error::{ValidationError, ValidationErrorKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Integer,
+    UnsignedInteger,
+    Number,
+    Boolean,
+    Object,
+    Array,
+}
+
+impl FieldType {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::UnsignedInteger => "non-negative integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::UnsignedInteger => value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::Object => value.is_object(),
+            Self::Array => value.is_array(),
+        }
+    }
+}
+
+/// Rules for a single value. A `null` value counts as absent, so it only fails `required`.
+#[derive(Debug, Clone)]
+pub struct FieldRules {
+    field_type: FieldType,
+    required: bool,
+    not_blank: bool,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+    allowed_values: Option<Vec<String>>,
+    schema: Option<ValidationSchema>,
+    items: Option<Box<Self>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl FieldRules {
+    const fn of_type(field_type: FieldType) -> Self {
+        Self {
+            field_type,
+            required: false,
+            not_blank: false,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            allowed_values: None,
+            schema: None,
+            items: None,
+            min_items: None,
+            max_items: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn string() -> Self {
+        Self::of_type(FieldType::String)
+    }
+
+    #[must_use]
+    pub const fn integer() -> Self {
+        Self::of_type(FieldType::Integer)
+    }
+
+    #[must_use]
+    pub const fn unsigned_integer() -> Self {
+        Self::of_type(FieldType::UnsignedInteger)
+    }
+
+    #[must_use]
+    pub const fn number() -> Self {
+        Self::of_type(FieldType::Number)
+    }
+
+    #[must_use]
+    pub const fn boolean() -> Self {
+        Self::of_type(FieldType::Boolean)
+    }
+
+    /// A nested object whose members are validated by `schema`.
+    #[must_use]
+    pub fn object(schema: ValidationSchema) -> Self {
+        Self {
+            schema: Some(schema),
+            ..Self::of_type(FieldType::Object)
+        }
+    }
+
+    /// An array whose every item is validated by `items`.
+    #[must_use]
+    pub fn array(items: Self) -> Self {
+        Self {
+            items: Some(Box::new(items)),
+            ..Self::of_type(FieldType::Array)
+        }
+    }
+
+    #[must_use]
+    pub const fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Rejects strings made only of whitespace.
+    #[must_use]
+    pub const fn not_blank(mut self) -> Self {
+        self.not_blank = true;
+        self
+    }
+
+    /// Bounds the length of a string, counted in characters.
+    #[must_use]
+    pub const fn length(mut self, min_length: Option<usize>, max_length: Option<usize>) -> Self {
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    /// Requires a string to match `pattern` as a whole.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regex. Patterns are written in the code, so this is a
+    /// programming error caught as soon as the schema is built.
+    #[must_use]
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        let anchored_pattern = format!("^(?:{pattern})$");
+
+        self.pattern = Some(
+            Regex::new(&anchored_pattern)
+                .unwrap_or_else(|err| panic!("the pattern {pattern} is invalid: {err}")),
+        );
+        self
+    }
+
+    /// Restricts a string to one of `allowed_values`.
+    #[must_use]
+    pub fn one_of(mut self, allowed_values: &[&str]) -> Self {
+        self.allowed_values = Some(allowed_values.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    /// Bounds the number of items of an array.
+    #[must_use]
+    pub const fn items_count(mut self, min_items: Option<usize>, max_items: Option<usize>) -> Self {
+        self.min_items = min_items;
+        self.max_items = max_items;
+        self
+    }
+
+    fn collect_errors(
+        &self,
+        pointer: &str,
+        value: Option<&Value>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let error = |kind| ValidationError::new(pointer.to_string(), kind);
+
+        let Some(value) = value.filter(|value| !value.is_null()) else {
+            if self.required {
+                errors.push(error(ValidationErrorKind::Missing));
+            }
+
+            return;
+        };
+
+        if !self.field_type.matches(value) {
+            errors.push(error(ValidationErrorKind::InvalidType {
+                expected_type: self.field_type.name(),
+            }));
+
+            return;
+        }
+
+        if let Some(text) = value.as_str() {
+            errors.extend(self.string_errors(text).into_iter().map(error));
+        }
+
+        if let Some(items) = value.as_array() {
+            if let Some(min_items) = self.min_items.filter(|min_items| items.len() < *min_items) {
+                errors.push(error(ValidationErrorKind::TooFewItems { min_items }));
+            }
+
+            if let Some(max_items) = self.max_items.filter(|max_items| items.len() > *max_items) {
+                errors.push(error(ValidationErrorKind::TooManyItems { max_items }));
+            }
+
+            if let Some(item_rules) = &self.items {
+                for (index, item) in items.iter().enumerate() {
+                    item_rules.collect_errors(&format!("{pointer}/{index}"), Some(item), errors);
+                }
+            }
+        }
+
+        if let Some(schema) = &self.schema {
+            schema.collect_errors(pointer, value, errors);
+        }
+    }
+
+    fn string_errors(&self, text: &str) -> Vec<ValidationErrorKind> {
+        if self.not_blank && text.trim().is_empty() {
+            return vec![ValidationErrorKind::Empty];
+        }
+
+        let mut errors = Vec::new();
+        let length = text.chars().count();
+
+        if let Some(min_length) = self.min_length.filter(|min_length| length < *min_length) {
+            errors.push(ValidationErrorKind::TooShort { min_length });
+        }
+
+        if let Some(max_length) = self.max_length.filter(|max_length| length > *max_length) {
+            errors.push(ValidationErrorKind::TooLong { max_length });
+        }
+
+        if self
+            .pattern
+            .as_ref()
+            .is_some_and(|pattern| !pattern.is_match(text))
+        {
+            errors.push(ValidationErrorKind::PatternMismatch);
+        }
+
+        if let Some(allowed_values) = self
+            .allowed_values
+            .as_ref()
+            .filter(|allowed_values| !allowed_values.iter().any(|allowed| allowed == text))
+        {
+            errors.push(ValidationErrorKind::NotAllowed {
+                allowed_values: allowed_values.clone(),
+            });
+        }
+
+        errors
+    }
+}
+
+/// Declares the members of a JSON object and the rules each one must follow. Validation
+/// reports every broken rule at once, each located by a JSON pointer.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationSchema {
+    fields: Vec<(String, FieldRules)>,
+    equal_fields: Vec<(String, String)>,
+}
+
+impl ValidationSchema {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            equal_fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn field(mut self, name: &str, rules: FieldRules) -> Self {
+        self.fields.push((name.to_string(), rules));
+        self
+    }
+
+    /// Requires the member `field` to equal the member `other`, such as a password and its
+    /// confirmation. The mismatch is reported on `field`, and only when both are present.
+    #[must_use]
+    pub fn equal_fields(mut self, field: &str, other: &str) -> Self {
+        self.equal_fields
+            .push((field.to_string(), other.to_string()));
+        self
+    }
+
+    /// Returns every rule `value` breaks, in declaration order.
+    #[must_use]
+    pub fn validate_value(&self, value: &Value) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        self.collect_errors("", value, &mut errors);
+
+        errors
+    }
+
+    fn collect_errors(&self, pointer: &str, value: &Value, errors: &mut Vec<ValidationError>) {
+        let Some(members) = value.as_object() else {
+            // Nested objects are type checked by their `FieldRules`, so this is the body itself.
+            errors.push(ValidationError::new(
+                pointer.to_string(),
+                ValidationErrorKind::InvalidBody,
+            ));
+
+            return;
+        };
+
+        for (name, rules) in &self.fields {
+            rules.collect_errors(
+                &Self::member_pointer(pointer, name),
+                members.get(name),
+                errors,
+            );
+        }
+
+        for (field, other) in &self.equal_fields {
+            let field_value = members.get(field).filter(|value| !value.is_null());
+            let other_value = members.get(other).filter(|value| !value.is_null());
+
+            if let (Some(field_value), Some(other_value)) = (field_value, other_value)
+                && field_value != other_value
+            {
+                errors.push(ValidationError::new(
+                    Self::member_pointer(pointer, field),
+                    ValidationErrorKind::NotEqual {
+                        other_pointer: Self::member_pointer(pointer, other),
+                    },
+                ));
+            }
+        }
+    }
+
+    /// Appends `name` to `pointer`, escaping it as RFC 6901 requires.
+    fn member_pointer(pointer: &str, name: &str) -> String {
+        format!("{pointer}/{}", name.replace('~', "~0").replace('/', "~1"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::presentation::validation::{
+        validation_error::{ValidationError, ValidationErrorKind},
+        validation_schema::{FieldRules, ValidationSchema},
+    };
+
+    fn build_schema() -> ValidationSchema {
+        ValidationSchema::new()
+            .field("name", FieldRules::string().required().not_blank())
+            .field(
+                "code",
+                FieldRules::string()
+                    .length(Some(2), Some(4))
+                    .pattern("[A-Z]+"),
+            )
+            .field("role", FieldRules::string().one_of(&["admin", "member"]))
+            .field(
+                "address",
+                FieldRules::object(
+                    ValidationSchema::new().field("city", FieldRules::string().required()),
+                ),
+            )
+            .field(
+                "tags",
+                FieldRules::array(FieldRules::string().not_blank()).items_count(Some(1), Some(2)),
+            )
+            .field("password", FieldRules::string().required())
+            .field("passwordConfirmation", FieldRules::string().required())
+            .equal_fields("passwordConfirmation", "password")
+    }
+
+    #[test]
+    fn should_accept_valid_value() {
+        let value = json!({
+            "name": "John",
+            "code": "AB",
+            "role": "admin",
+            "address": { "city": "Recife" },
+            "tags": ["rust"],
+            "password": "secret",
+            "passwordConfirmation": "secret"
+        });
+
+        assert!(build_schema().validate_value(&value).is_empty());
+    }
+
+    #[test]
+    fn should_report_every_error_with_pointer() {
+        let value = json!({
+            "name": "  ",
+            "code": "abcde",
+            "role": "owner",
+            "address": { "city": 10 },
+            "tags": ["rust", " ", "axum"],
+            "password": "secret",
+            "passwordConfirmation": "other"
+        });
+
+        let errors = build_schema().validate_value(&value);
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new("/name".to_string(), ValidationErrorKind::Empty),
+                ValidationError::new(
+                    "/code".to_string(),
+                    ValidationErrorKind::TooLong { max_length: 4 }
+                ),
+                ValidationError::new("/code".to_string(), ValidationErrorKind::PatternMismatch),
+                ValidationError::new(
+                    "/role".to_string(),
+                    ValidationErrorKind::NotAllowed {
+                        allowed_values: vec!["admin".to_string(), "member".to_string()]
+                    }
+                ),
+                ValidationError::new(
+                    "/address/city".to_string(),
+                    ValidationErrorKind::InvalidType {
+                        expected_type: "string"
+                    }
+                ),
+                ValidationError::new(
+                    "/tags".to_string(),
+                    ValidationErrorKind::TooManyItems { max_items: 2 }
+                ),
+                ValidationError::new("/tags/1".to_string(), ValidationErrorKind::Empty),
+                ValidationError::new(
+                    "/passwordConfirmation".to_string(),
+                    ValidationErrorKind::NotEqual {
+                        other_pointer: "/password".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_treat_null_as_missing() {
+        let value = json!({ "name": null, "password": "secret" });
+
+        let errors = build_schema().validate_value(&value);
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new("/name".to_string(), ValidationErrorKind::Missing),
+                ValidationError::new(
+                    "/passwordConfirmation".to_string(),
+                    ValidationErrorKind::Missing
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_non_object_body() {
+        let errors = build_schema().validate_value(&json!(["not", "an", "object"]));
+
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                String::new(),
+                ValidationErrorKind::InvalidBody
+            )]
+        );
+    }
+
+    #[test]
+    fn should_render_error_with_parameters_and_message() {
+        let error = ValidationError::new(
+            "/code".to_string(),
+            ValidationErrorKind::TooShort { min_length: 2 },
+        );
+
+        assert_eq!(
+            error.to_json(),
+            json!({
+                "pointer": "/code",
+                "error": "too_short",
+                "min_length": 2,
+                "message": "the field '/code' must have at least 2 characters"
+            })
+        );
+    }
+}